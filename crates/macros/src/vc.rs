@@ -276,6 +276,7 @@ impl Parse for VarConstAttrItem {
     }
 }
 
+#[allow(clippy::large_enum_variant)]
 enum ConstType {
     Value {
         _colon: Token![:],
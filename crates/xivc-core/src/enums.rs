@@ -108,6 +108,8 @@ impl Display for Clan {
     pub const limited;
     /// Returns `true` if the job has an associated soul crystal.
     pub const job: bool = true;
+    /// Returns the maximum level the job can reach.
+    pub const max_level: u8 = 100;
     /// Returns the human friendly name of the job.
     pub const name: &'static str;
 }]
@@ -222,6 +224,7 @@ pub enum Job {
     #[caster]
     #[name = "Blue Mage"]
     #[limited]
+    #[max_level = 80]
     BLU,
     /// The tank job Gunbreaker.
     #[tank]
@@ -257,6 +260,14 @@ impl Job {
             ActionStat::AttackPower
         }
     }
+    /// Returns the stat used to heal for the job.
+    pub const fn heal_stat(&self) -> ActionStat {
+        if self.healer() {
+            ActionStat::HealingMagic
+        } else {
+            self.attack_stat()
+        }
+    }
     /// Returns the potency of auto attacks for the job.
     pub const fn aa_potency(&self) -> u8 {
         match self {
@@ -0,0 +1,136 @@
+//! Consumable items.
+//!
+//! Unlike job actions, items can be used by every job. Every [`Item`] shares
+//! a single [cooldown], and using one locks the player for a longer
+//! [animation lock] than an ability does.
+//!
+//! Food is not an item, as it lasts for the whole fight.
+//! Instead, it is applied directly to a player's stats with [`Food`].
+//!
+//! [cooldown]: ITEM_COOLDOWN
+//! [animation lock]: ITEM_LOCK
+//! [`Food`]: crate::math::Food
+
+use macros::var_consts;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    enums::ActionCategory,
+    math::{PlayerStats, StatBonus},
+    status_effect,
+    world::{
+        status::{StatusEffect, StatusEventExt},
+        Action, ActorRef, EventSink, WorldRef,
+    },
+};
+
+/// The cooldown in milliseconds shared by every item.
+pub const ITEM_COOLDOWN: u32 = 270000;
+/// The base animation lock in milliseconds of using an item.
+pub const ITEM_LOCK: u16 = 1100;
+
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(rename_all = "snake_case")
+)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[repr(u8)]
+#[var_consts {
+    /// Returns the human friendly name of the item.
+    pub const name: &'static str;
+}]
+#[allow(missing_docs)] // no reason to document the variants.
+/// A consumable item.
+pub enum Item {
+    #[name = "Grade 1 Gemdraught of Strength"]
+    Grade1GemdraughtOfStrength,
+    #[name = "Grade 1 Gemdraught of Dexterity"]
+    Grade1GemdraughtOfDexterity,
+    #[name = "Grade 1 Gemdraught of Intelligence"]
+    Grade1GemdraughtOfIntelligence,
+    #[name = "Grade 1 Gemdraught of Mind"]
+    Grade1GemdraughtOfMind,
+    #[name = "Grade 2 Gemdraught of Strength"]
+    Grade2GemdraughtOfStrength,
+    #[name = "Grade 2 Gemdraught of Dexterity"]
+    Grade2GemdraughtOfDexterity,
+    #[name = "Grade 2 Gemdraught of Intelligence"]
+    Grade2GemdraughtOfIntelligence,
+    #[name = "Grade 2 Gemdraught of Mind"]
+    Grade2GemdraughtOfMind,
+}
+
+impl Item {
+    /// Returns the [`ActionCategory`] of the item.
+    ///
+    /// This is always [`ActionCategory::Item`].
+    pub const fn category(&self) -> ActionCategory {
+        ActionCategory::Item
+    }
+
+    /// Returns the status effect applied when the item is used.
+    pub fn status(&self) -> StatusEffect {
+        match self {
+            Self::Grade1GemdraughtOfStrength => MEDICATED_STR_1,
+            Self::Grade1GemdraughtOfDexterity => MEDICATED_DEX_1,
+            Self::Grade1GemdraughtOfIntelligence => MEDICATED_INT_1,
+            Self::Grade1GemdraughtOfMind => MEDICATED_MND_1,
+            Self::Grade2GemdraughtOfStrength => MEDICATED_STR_2,
+            Self::Grade2GemdraughtOfDexterity => MEDICATED_DEX_2,
+            Self::Grade2GemdraughtOfIntelligence => MEDICATED_INT_2,
+            Self::Grade2GemdraughtOfMind => MEDICATED_MND_2,
+        }
+    }
+
+    /// Uses the item, submitting all events to the supplied event sink.
+    pub fn cast_snap<'w, W: WorldRef<'w>, E: EventSink<'w, W>>(&self, event_sink: &mut E) {
+        let this_id = event_sink.source().id();
+        event_sink.apply_status(self.status(), 1, this_id, 0);
+    }
+}
+
+impl From<Item> for Action {
+    fn from(value: Item) -> Self {
+        Action::Item(value)
+    }
+}
+
+// gemdraughts raise the main stat by 10%, up to a maximum for each grade.
+const MEDICATED_1: StatBonus = StatBonus::new(10, 351);
+const MEDICATED_2: StatBonus = StatBonus::new(10, 392);
+
+/// The status effect "Medicated" from a Grade 1 Gemdraught of Strength.
+pub static MEDICATED_STR_1: StatusEffect = status_effect!(
+    "Medicated" 30000 { stats { |_, s| PlayerStats { str: MEDICATED_1.apply(s.str), ..s } } }
+);
+/// The status effect "Medicated" from a Grade 1 Gemdraught of Dexterity.
+pub static MEDICATED_DEX_1: StatusEffect = status_effect!(
+    "Medicated" 30000 { stats { |_, s| PlayerStats { dex: MEDICATED_1.apply(s.dex), ..s } } }
+);
+/// The status effect "Medicated" from a Grade 1 Gemdraught of Intelligence.
+pub static MEDICATED_INT_1: StatusEffect = status_effect!(
+    "Medicated" 30000 { stats { |_, s| PlayerStats { int: MEDICATED_1.apply(s.int), ..s } } }
+);
+/// The status effect "Medicated" from a Grade 1 Gemdraught of Mind.
+pub static MEDICATED_MND_1: StatusEffect = status_effect!(
+    "Medicated" 30000 { stats { |_, s| PlayerStats { mnd: MEDICATED_1.apply(s.mnd), ..s } } }
+);
+/// The status effect "Medicated" from a Grade 2 Gemdraught of Strength.
+pub static MEDICATED_STR_2: StatusEffect = status_effect!(
+    "Medicated" 30000 { stats { |_, s| PlayerStats { str: MEDICATED_2.apply(s.str), ..s } } }
+);
+/// The status effect "Medicated" from a Grade 2 Gemdraught of Dexterity.
+pub static MEDICATED_DEX_2: StatusEffect = status_effect!(
+    "Medicated" 30000 { stats { |_, s| PlayerStats { dex: MEDICATED_2.apply(s.dex), ..s } } }
+);
+/// The status effect "Medicated" from a Grade 2 Gemdraught of Intelligence.
+pub static MEDICATED_INT_2: StatusEffect = status_effect!(
+    "Medicated" 30000 { stats { |_, s| PlayerStats { int: MEDICATED_2.apply(s.int), ..s } } }
+);
+/// The status effect "Medicated" from a Grade 2 Gemdraught of Mind.
+pub static MEDICATED_MND_2: StatusEffect = status_effect!(
+    "Medicated" 30000 { stats { |_, s| PlayerStats { mnd: MEDICATED_2.apply(s.mnd), ..s } } }
+);
@@ -0,0 +1,683 @@
+use core::{
+    error::Error,
+    fmt::{self, Display},
+};
+
+use macros::var_consts;
+use rand::{distributions::Distribution, seq::SliceRandom, Rng};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    enums::{ActionCategory, DamageInstance},
+    err,
+    job::{
+        role::{HealerRoleAction, SWIFTCAST},
+        CastInitInfo, Job, JobAction, JobEvent, JobState,
+    },
+    job_cd_struct,
+    math::SpeedStat,
+    status_effect,
+    timing::{DurationInfo, EventCascade, ScaleTime},
+    util::{status_proc_error, ActionTargettingExt as _},
+    world::{
+        status::{consume_status, StatusEffect, StatusEventExt},
+        Action, ActionTargetting, ActorRef, DamageEventExt, Event, EventError, EventSink,
+        HealEventExt, WorldRef,
+    },
+};
+
+/// The [`Job`] struct for Astrologian.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct AstJob;
+
+/// The damage over time status "Combust III".
+pub static COMBUST_III: StatusEffect = status_effect!("Combust III" 30000 multi);
+/// The status effect "The Balance".
+pub static THE_BALANCE: StatusEffect = status_effect!(
+    "The Balance" 15000 { damage { out = 106 / 100 } }
+);
+/// The status effect "The Spear".
+pub static THE_SPEAR: StatusEffect = status_effect!(
+    "The Spear" 15000 { damage { out = 106 / 100 } }
+);
+/// The status effect "The Arrow".
+pub static THE_ARROW: StatusEffect = status_effect!("The Arrow" 15000);
+/// The barrier status "The Spire".
+pub static THE_SPIRE: StatusEffect = status_effect!("The Spire" 30000);
+/// The status effect "The Bole".
+pub static THE_BOLE: StatusEffect = status_effect!(
+    "The Bole" 15000 { damage { in = 90 / 100 } }
+);
+/// The healing over time status "The Ewer".
+pub static THE_EWER: StatusEffect = status_effect!("The Ewer" 15000);
+/// The status effect "Divination".
+pub static DIVINATION: StatusEffect = status_effect!(
+    "Divination" 20000 { damage { out = 106 / 100 } }
+);
+/// The status effect "Divining".
+pub static DIVINING: StatusEffect = status_effect!("Divining" 30000);
+/// The status effect "Lightspeed".
+pub static LIGHTSPEED: StatusEffect = status_effect!("Lightspeed" 15000);
+
+/// The time in milliseconds for a placed Earthly Star to mature
+/// into a Giant Dominance.
+const STAR_MATURE: u32 = 10000;
+/// The time in milliseconds before a placed Earthly Star detonates on its own.
+const STAR_DURATION: u32 = 20000;
+
+impl Job for AstJob {
+    type Action = AstAction;
+    type State = AstState;
+    type CastError = AstError;
+    type Event = AstEvent;
+    type CdGroup = AstCdGroup;
+    type CdMap<T> = AstCdMap<T>;
+
+    fn check_cast<'w, W: WorldRef<'w>, E: EventSink<'w, W>>(
+        action: Self::Action,
+        state: &Self::State,
+        _: &'w W,
+        event_sink: &mut E,
+    ) -> Result<CastInitInfo<Self::CdGroup>, EventError> {
+        let this = event_sink.source();
+
+        let di = this.duration_info();
+
+        use AstAction::*;
+
+        let cast = Self::cast_time(action, &this);
+        let cast = if this.has_own_status(SWIFTCAST) {
+            ScaleTime::zero()
+        } else {
+            cast
+        };
+
+        let gcd = action.gcd().map(|v| di.scale(v)).unwrap_or_default() as u16;
+        let (lock, snap) = di.get_cast(cast, 600);
+
+        let cd = action
+            .cd_group()
+            .map(|v| (v, action.cooldown(), action.cd_charges()));
+
+        let alt_cd = action.alt_cd_group().map(|v| (v, 1000, 1));
+
+        match action {
+            AstralDraw if state.umbral_draw => err!(AstError::Draw(UmbralDraw)),
+            UmbralDraw if !state.umbral_draw => err!(AstError::Draw(AstralDraw)),
+            Play if state.card.is_none() => err!(AstError::Card),
+            LordOfCrowns if state.minor_arcana != Some(MinorArcana::LordOfCrowns) => {
+                err!(AstError::MinorArcana(MinorArcana::LordOfCrowns))
+            }
+            LadyOfCrowns if state.minor_arcana != Some(MinorArcana::LadyOfCrowns) => {
+                err!(AstError::MinorArcana(MinorArcana::LadyOfCrowns))
+            }
+            Oracle if !this.has_own_status(DIVINING) => err!(AstError::Divining),
+            StellarDetonation if state.star.is_none() => err!(AstError::EarthlyStar),
+            _ => (),
+        }
+
+        Ok(CastInitInfo {
+            gcd,
+            lock,
+            snap,
+            mp: action.mp_cost(),
+            cd,
+            alt_cd,
+        })
+    }
+
+    fn cast_snap<'w, W: WorldRef<'w>, E: EventSink<'w, W>>(
+        action: Self::Action,
+        state: &mut Self::State,
+        _: &'w W,
+        event_sink: &mut E,
+    ) -> Result<(), EventError> {
+        let this = event_sink.source();
+        let this_id = this.id();
+
+        use AstAction::*;
+
+        let dl = action.effect_delay();
+
+        // swiftcast is not used up if lightspeed already made the spell instant.
+        if Self::cast_time(action, &this).duration() > 0 {
+            consume_status(event_sink, SWIFTCAST, 0);
+        }
+
+        let dmg = |potency| DamageInstance::new(potency).magical();
+
+        // single target heals and cards will target the caster
+        // if the current target is not a party member.
+        let party_target = this.target_party(SINGLE_HEAL).map_or(this_id, |v| v.id());
+
+        match action {
+            FallMalefic => {
+                let t = this.target_enemy(SPELL)?.id();
+                event_sink.damage(action, dmg(270), t, dl);
+            }
+            CombustIII => {
+                let t = this.target_enemy(SPELL)?.id();
+                event_sink.apply_dot(COMBUST_III, dmg(70), SpeedStat::SpellSpeed, 1, t, dl);
+            }
+            GravityII | Oracle => {
+                let potency = if action == Oracle {
+                    if !consume_status(event_sink, DIVINING, 0) {
+                        err!(AstError::Divining);
+                    }
+                    860
+                } else {
+                    140
+                };
+                for (t, d) in this
+                    .target_enemy_aoe(TG_CIRCLE, EventCascade::new(dl, 1))?
+                    .id()
+                {
+                    event_sink.damage(action, dmg(potency), t, d);
+                }
+            }
+            BeneficII => event_sink.heal(action, 800, party_target, dl),
+            Helios => {
+                for (t, d) in this
+                    .target_party_aoe(ActionTargetting::circle(15), EventCascade::new(dl, 1))?
+                    .id()
+                {
+                    event_sink.heal(action, 400, t, d);
+                }
+            }
+            AstralDraw => {
+                state.card = Some(event_sink.random(AstralCard));
+                state.minor_arcana = Some(MinorArcana::LordOfCrowns);
+                state.umbral_draw = true;
+            }
+            UmbralDraw => {
+                state.card = Some(event_sink.random(UmbralCard));
+                state.minor_arcana = Some(MinorArcana::LadyOfCrowns);
+                state.umbral_draw = false;
+            }
+            Play => {
+                let Some(card) = state.card.take() else {
+                    err!(AstError::Card);
+                };
+                event_sink.apply_status(card.status(), 1, party_target, dl);
+                if card == Card::Ewer {
+                    // the regen ticks every 3s for 15s
+                    for tick in 0..5 {
+                        event_sink.heal(action, 200, party_target, dl + tick * 3000);
+                    }
+                }
+            }
+            LordOfCrowns => {
+                state.minor_arcana = None;
+                for (t, d) in this
+                    .target_enemy_aoe(ActionTargetting::circle(20), EventCascade::new(dl, 1))?
+                    .id()
+                {
+                    event_sink.damage(action, dmg(400), t, d);
+                }
+            }
+            LadyOfCrowns => {
+                state.minor_arcana = None;
+                for (t, d) in this
+                    .target_party_aoe(ActionTargetting::circle(20), EventCascade::new(dl, 1))?
+                    .id()
+                {
+                    event_sink.heal(action, 400, t, d);
+                }
+            }
+            Divination => {
+                for (t, d) in this
+                    .target_party_aoe(ActionTargetting::circle(30), EventCascade::new(dl, 1))?
+                    .id()
+                {
+                    event_sink.apply_status(DIVINATION, 1, t, d);
+                }
+                event_sink.apply_status(DIVINING, 1, this_id, dl);
+            }
+            Lightspeed => event_sink.apply_status(LIGHTSPEED, 1, this_id, dl),
+            EarthlyStar => {
+                let id = state.star_count;
+                state.star_count = state.star_count.wrapping_add(1);
+                state.star = Some(Star { id, mature: false });
+                event_sink.event(
+                    JobEvent::ast(AstEvent::GiantDominance(id), this_id),
+                    dl + STAR_MATURE,
+                );
+                event_sink.event(
+                    JobEvent::ast(AstEvent::StellarExplosion(id), this_id),
+                    dl + STAR_DURATION,
+                );
+            }
+            StellarDetonation => {
+                let Some(star) = state.star.take() else {
+                    err!(AstError::EarthlyStar);
+                };
+                stellar_explosion(star, event_sink, dl);
+            }
+            Role(v) => v.cast_snap(action, event_sink)?,
+        }
+
+        Ok(())
+    }
+
+    fn event<'w, W: WorldRef<'w>, E: EventSink<'w, W>>(
+        state: &mut Self::State,
+        _: &'w W,
+        event: &Event,
+        event_sink: &mut E,
+    ) {
+        let this_id = event_sink.source().id();
+        match event {
+            Event::Job(JobEvent::Ast(ev), src_id) if *src_id == this_id => match (ev, state.star) {
+                // the events for a star that was already detonated are ignored.
+                (AstEvent::GiantDominance(id), Some(star)) if star.id == *id => {
+                    state.star = Some(Star {
+                        mature: true,
+                        ..star
+                    });
+                }
+                (AstEvent::StellarExplosion(id), Some(star)) if star.id == *id => {
+                    state.star = None;
+                    stellar_explosion(star, event_sink, 0);
+                }
+                _ => (),
+            },
+            _ => (),
+        }
+    }
+}
+
+impl AstJob {
+    // returns the cast time of an action after lightspeed is applied.
+    fn cast_time<'w>(action: AstAction, this: &impl ActorRef<'w>) -> ScaleTime {
+        if this.has_own_status(LIGHTSPEED) {
+            ScaleTime::spell(action.cast().duration().saturating_sub(2500))
+        } else {
+            action.cast()
+        }
+    }
+}
+
+/// Executes the damage and healing of an Earthly Star detonating.
+fn stellar_explosion<'w, W: WorldRef<'w>, E: EventSink<'w, W>>(
+    star: Star,
+    event_sink: &mut E,
+    delay: u32,
+) {
+    let this = event_sink.source();
+    let action = AstAction::StellarDetonation;
+    let (damage, heal) = if star.mature { (310, 720) } else { (205, 540) };
+    // the star is placed at the feet of the astrologian.
+    if let Ok(iter) = this.target_enemy_aoe(STAR_CIRCLE, EventCascade::new(delay, 1)) {
+        for (t, d) in iter.id() {
+            event_sink.damage(action, DamageInstance::new(damage).magical(), t, d);
+        }
+    }
+    if let Ok(iter) = this.target_party_aoe(STAR_CIRCLE, EventCascade::new(delay, 1)) {
+        for (t, d) in iter.id() {
+            event_sink.heal(action, heal, t, d);
+        }
+    }
+}
+
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Copy, Debug)]
+/// A custom cast error for Astrologian actions.
+pub enum AstError {
+    /// The other draw action must be used.
+    Draw(AstAction),
+    /// No card has been drawn.
+    Card,
+    /// The specified Minor Arcana card has not been drawn.
+    MinorArcana(MinorArcana),
+    /// Not under the effect of Divining.
+    Divining,
+    /// No Earthly Star has been placed.
+    EarthlyStar,
+}
+
+impl From<AstError> for EventError {
+    fn from(value: AstError) -> Self {
+        Self::Job(value.into())
+    }
+}
+
+impl Display for AstError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Draw(v) => write!(f, "The next draw must be \"{}\".", v.name()),
+            Self::Card => write!(f, "No card has been drawn."),
+            Self::MinorArcana(v) => write!(f, "\"{}\" has not been drawn.", v.name()),
+            Self::Divining => status_proc_error(f, DIVINING),
+            Self::EarthlyStar => write!(f, "No Earthly Star has been placed."),
+        }
+    }
+}
+
+impl Error for AstError {}
+
+const SPELL: ActionTargetting = ActionTargetting::single(25);
+const SINGLE_HEAL: ActionTargetting = ActionTargetting::single(30);
+const TG_CIRCLE: ActionTargetting = ActionTargetting::target_circle(5, 25);
+const STAR_CIRCLE: ActionTargetting = ActionTargetting::circle(20);
+
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(rename_all = "snake_case")
+)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[repr(u8)]
+#[var_consts {
+    /// Returns the base GCD recast time, or `None` if the action is not a gcd.
+    pub const gcd: ScaleTime?;
+    /// Returns the base milliseconds the action takes to cast.
+    pub const cast: ScaleTime = ScaleTime::zero();
+    /// Returns the human friendly name of the action.
+    pub const name: &'static str;
+    /// Returns the cooldown of the skill in milliseconds.
+    pub const cooldown: u32 = 0;
+    /// Returns the number of charges a skill has, or `1` if it is a single charge skill.
+    pub const cd_charges: u8 = 1;
+    /// Returns the delay in milliseconds for the damage/statuses to be applied.
+    pub const effect_delay: u32 = 0;
+    /// Returns the MP cost of the action.
+    pub const mp_cost: u16 = 0;
+    /// Returns the [`ActionCategory`] this action is part of.
+    pub const category: ActionCategory;
+
+    pub const spell for {
+        gcd = ScaleTime::spell(2500);
+        category = ActionCategory::Spell;
+    }
+    pub const ability for {
+        category = ActionCategory::Ability;
+    }
+}]
+#[allow(missing_docs)] // no reason to document the variants.
+/// An action specific to the Astrologian job.
+pub enum AstAction {
+    #[spell]
+    #[cast = ScaleTime::spell(1500)]
+    #[mp_cost = 400]
+    #[name = "Fall Malefic"]
+    FallMalefic,
+    #[spell]
+    #[mp_cost = 400]
+    #[name = "Combust III"]
+    CombustIII,
+    #[spell]
+    #[cast = ScaleTime::spell(1500)]
+    #[mp_cost = 400]
+    #[name = "Gravity II"]
+    GravityII,
+    #[spell]
+    #[cast = ScaleTime::spell(1500)]
+    #[mp_cost = 700]
+    #[name = "Benefic II"]
+    BeneficII,
+    #[spell]
+    #[cast = ScaleTime::spell(1500)]
+    #[mp_cost = 700]
+    #[name = "Helios"]
+    Helios,
+    #[ability]
+    #[cooldown = 55000]
+    #[name = "Astral Draw"]
+    AstralDraw,
+    #[ability]
+    #[cooldown = 55000]
+    #[name = "Umbral Draw"]
+    UmbralDraw,
+    #[ability]
+    #[name = "Play"]
+    Play,
+    #[ability]
+    #[name = "Lord of Crowns"]
+    LordOfCrowns,
+    #[ability]
+    #[name = "Lady of Crowns"]
+    LadyOfCrowns,
+    #[ability]
+    #[cooldown = 120000]
+    #[name = "Divination"]
+    Divination,
+    #[ability]
+    #[name = "Oracle"]
+    Oracle,
+    #[ability]
+    #[cooldown = 60000]
+    #[cd_charges = 2]
+    #[name = "Lightspeed"]
+    Lightspeed,
+    #[ability]
+    #[cooldown = 60000]
+    #[name = "Earthly Star"]
+    EarthlyStar,
+    #[ability]
+    #[name = "Stellar Detonation"]
+    StellarDetonation,
+    #[gcd((v) => v.gcd())]
+    #[cast((v) => v.cast())]
+    #[name((v) => v.name())]
+    #[cooldown((v) => v.cooldown())]
+    #[cd_charges((v) => v.cd_charges())]
+    #[effect_delay((v) => v.effect_delay())]
+    #[mp_cost((v) => v.mp_cost())]
+    #[category((v) => v.category())]
+    Role(HealerRoleAction),
+}
+
+impl JobAction for AstAction {
+    fn category(&self) -> ActionCategory {
+        self.category()
+    }
+
+    fn gcd(&self) -> bool {
+        self.gcd().is_some()
+    }
+}
+
+impl From<AstAction> for Action {
+    fn from(value: AstAction) -> Self {
+        Action::Job(value.into())
+    }
+}
+
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+/// The state of the Astrologian job gauges and cooldowns.
+pub struct AstState {
+    /// The card that is currently drawn.
+    pub card: Option<Card>,
+    /// The Minor Arcana card that is currently drawn.
+    pub minor_arcana: Option<MinorArcana>,
+    /// If the next draw will be Umbral Draw.
+    pub umbral_draw: bool,
+    /// The Earthly Star that is currently placed.
+    pub star: Option<Star>,
+    /// The number of Earthly Stars that have been placed.
+    ///
+    /// This is used to tell the events of different stars apart.
+    pub star_count: u8,
+}
+
+impl JobState for AstState {
+    fn advance(&mut self, _: u32) {}
+}
+
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+/// A placed Earthly Star.
+pub struct Star {
+    /// The id of the star, taken from [`AstState::star_count`].
+    pub id: u8,
+    /// If the star has matured into a Giant Dominance.
+    pub mature: bool,
+}
+
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+/// A custom event for Astrologian.
+pub enum AstEvent {
+    /// The Earthly Star with the specified id matures.
+    GiantDominance(u8),
+    /// The Earthly Star with the specified id detonates on its own.
+    StellarExplosion(u8),
+}
+
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+/// A card that can be drawn by Astral Draw or Umbral Draw.
+pub enum Card {
+    /// The card "The Balance".
+    Balance,
+    /// The card "The Arrow".
+    Arrow,
+    /// The card "The Spire".
+    Spire,
+    /// The card "The Spear".
+    Spear,
+    /// The card "The Bole".
+    Bole,
+    /// The card "The Ewer".
+    Ewer,
+}
+
+impl Card {
+    /// Returns the status effect applied when the card is played.
+    pub const fn status(&self) -> StatusEffect {
+        match self {
+            Self::Balance => THE_BALANCE,
+            Self::Arrow => THE_ARROW,
+            Self::Spire => THE_SPIRE,
+            Self::Spear => THE_SPEAR,
+            Self::Bole => THE_BOLE,
+            Self::Ewer => THE_EWER,
+        }
+    }
+}
+
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+/// A Minor Arcana card.
+pub enum MinorArcana {
+    /// The card "Lord of Crowns".
+    LordOfCrowns,
+    /// The card "Lady of Crowns".
+    LadyOfCrowns,
+}
+
+impl MinorArcana {
+    /// Returns the human friendly name of the card.
+    pub const fn name(&self) -> &'static str {
+        match self {
+            Self::LordOfCrowns => "Lord of Crowns",
+            Self::LadyOfCrowns => "Lady of Crowns",
+        }
+    }
+}
+
+/// The random event for the card drawn by Astral Draw.
+///
+/// # Examples
+/// An [`EventRng`] that always draws The Balance.
+/// ```
+/// # use core::any::TypeId;
+/// # use rand::{distributions::Distribution, rngs::mock::StepRng, RngCore};
+/// # use xivc_core::{
+/// #     job::ast::{AstralCard, Card},
+/// #     util::convert,
+/// #     world::EventRng,
+/// # };
+/// struct BalanceRng<R>(R);
+///
+/// impl<R: RngCore> EventRng for BalanceRng<R> {
+///     fn random<D, T>(&mut self, distr: D) -> T
+///     where
+///         D: Distribution<T> + 'static,
+///         T: 'static,
+///     {
+///         if TypeId::of::<D>() == TypeId::of::<AstralCard>() {
+///             if let Ok(card) = convert(Card::Balance) {
+///                 return card;
+///             }
+///         }
+///         distr.sample(&mut self.0)
+///     }
+/// }
+///
+/// let mut rng = BalanceRng(StepRng::new(0, 0x9E3779B97F4A7C15));
+/// assert_eq!(rng.random::<_, Card>(AstralCard), Card::Balance);
+/// ```
+///
+/// [`EventRng`]: crate::world::EventRng
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Copy, Debug, Default, Hash, PartialEq, Eq)]
+pub struct AstralCard;
+impl Distribution<Card> for AstralCard {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Card {
+        use Card::*;
+        *[Balance, Arrow, Spire].choose(rng).unwrap()
+    }
+}
+
+/// The random event for the card drawn by Umbral Draw.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Copy, Debug, Default, Hash, PartialEq, Eq)]
+pub struct UmbralCard;
+impl Distribution<Card> for UmbralCard {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Card {
+        use Card::*;
+        *[Spear, Bole, Ewer].choose(rng).unwrap()
+    }
+}
+
+job_cd_struct! {
+    AstAction =>
+
+    #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+    #[derive(Clone, Debug, Default)]
+    /// The cooldown map for Astrologian actions.
+    pub AstCdMap
+
+    #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+    #[derive(Copy, Clone, Debug)]
+    /// The various cooldown groups an Astrologian action can be part of.
+    pub AstCdGroup
+
+    "Draw"
+    draw Draw: AstralDraw UmbralDraw;
+    "Divination"
+    divination Divination: Divination;
+    "Lightspeed"
+    lightspeed Lightspeed: Lightspeed;
+    "Earthly Star"
+    earthly_star EarthlyStar: EarthlyStar;
+    "Swiftcast"
+    swiftcast Swiftcast: Role(HealerRoleAction::Swiftcast);
+    "Lucid Dreaming"
+    lucid_dreaming LucidDreaming: Role(HealerRoleAction::LucidDreaming);
+    "Surecast"
+    surecast Surecast: Role(HealerRoleAction::Surecast);
+    "Rescue"
+    rescue Rescue: Role(HealerRoleAction::Rescue);
+    "Lightspeed Charge"
+    lightspeed_chg LightspeedChg;
+}
+
+impl AstAction {
+    /// Returns the alternate [cooldown group] that this action is part of.
+    ///
+    /// Returns `None` if this action does not have an alternate cooldown.
+    /// This action is used for the 1s cooldown between uses of charged actions.
+    ///
+    /// [cooldown group]: AstCdGroup
+    pub const fn alt_cd_group(&self) -> Option<AstCdGroup> {
+        match self {
+            Self::Lightspeed => Some(AstCdGroup::LightspeedChg),
+            _ => None,
+        }
+    }
+}
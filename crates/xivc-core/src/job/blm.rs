@@ -0,0 +1,761 @@
+use core::{
+    error::Error,
+    fmt::{self, Display},
+};
+
+use macros::var_consts;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    enums::{ActionCategory, DamageElement, DamageInstance, DamageType},
+    err,
+    job::{
+        role::{CasterRoleAction, SWIFTCAST},
+        CastInitInfo, Job, JobAction, JobState,
+    },
+    job_cd_struct, job_effect_wrapper,
+    math::SpeedStat,
+    status_effect,
+    timing::{DurationInfo, EventCascade, ScaleTime},
+    util::{status_proc_error, ActionTargettingExt as _, GaugeU8},
+    world::{
+        status::{consume_status, consume_status_stack, JobEffect, StatusEffect, StatusEventExt},
+        Action, ActionTargetting, ActorRef, DamageEventExt, Event, EventError, EventSink, WorldRef,
+    },
+};
+
+/// The [`Job`] struct for Black Mage.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct BlmJob;
+
+/// The status effect "Firestarter".
+pub static FIRESTARTER: StatusEffect = status_effect!("Firestarter" 30000);
+/// The status effect "Thunderhead".
+pub static THUNDERHEAD: StatusEffect = status_effect!("Thunderhead" 30000);
+/// The status effect "Triplecast".
+pub static TRIPLECAST: StatusEffect = status_effect!("Triplecast" 15000);
+/// The status effect "Ley Lines".
+pub static LEY_LINES: StatusEffect = status_effect!(
+    "Ley Lines" 20000 { haste { |_| 85 } }
+);
+/// The damage over time status "High Thunder".
+pub static HIGH_THUNDER: StatusEffect = status_effect!("High Thunder" 30000 multi);
+/// The damage over time status "High Thunder II".
+pub static HIGH_THUNDER_II: StatusEffect = status_effect!("High Thunder II" 24000 multi);
+
+/// The MP regenerated per tick under each stack of Umbral Ice,
+/// on top of the natural MP regeneration.
+const UMBRAL_ICE_REGEN: [u16; 3] = [3000, 4500, 6000];
+/// The time it takes to gain a stack of Polyglot under Enochian.
+const POLYGLOT_TIME: u32 = 30000;
+
+impl Job for BlmJob {
+    type Action = BlmAction;
+    type State = BlmState;
+    type CastError = BlmError;
+    type Event = ();
+    type CdGroup = BlmCdGroup;
+    type CdMap<T> = BlmCdMap<T>;
+
+    fn check_cast<'w, W: WorldRef<'w>, E: EventSink<'w, W>>(
+        action: Self::Action,
+        state: &Self::State,
+        _: &'w W,
+        event_sink: &mut E,
+    ) -> Result<CastInitInfo<Self::CdGroup>, EventError> {
+        let this = event_sink.source();
+
+        let di = this.duration_info();
+
+        use BlmAction::*;
+
+        let firestarter = action == FireIII && this.has_own_status(FIRESTARTER);
+
+        let cast =
+            if firestarter || this.has_own_status(TRIPLECAST) || this.has_own_status(SWIFTCAST) {
+                ScaleTime::zero()
+            } else {
+                state.cast_time(action)
+            };
+
+        let gcd = action.gcd().map(|v| di.scale(v)).unwrap_or_default() as u16;
+        let (lock, snap) = di.get_cast(cast, 600);
+
+        let cd = action
+            .cd_group()
+            .map(|v| (v, action.cooldown(), action.cd_charges()));
+
+        let alt_cd = action.alt_cd_group().map(|v| (v, 1000, 1));
+
+        match action {
+            FireIV | Despair | Flare | Manafont if state.astral_fire() == 0 => {
+                err!(BlmError::AstralFire)
+            }
+            BlizzardIV | Freeze | UmbralSoul if state.umbral_ice() == 0 => {
+                err!(BlmError::UmbralIce)
+            }
+            Transpose | Amplifier if state.enochian == Enochian::None => {
+                err!(BlmError::Enochian)
+            }
+            Paradox if !state.paradox => err!(BlmError::Paradox),
+            Xenoglossy | Foul if state.polyglot == 0 => err!(BlmError::Polyglot(1)),
+            FlareStar if state.astral_soul < 6 => err!(BlmError::AstralSoul(6)),
+            HighThunder | HighThunderII if !this.has_own_status(THUNDERHEAD) => {
+                err!(BlmError::Thunderhead)
+            }
+            _ => (),
+        }
+
+        let mp = match action {
+            _ if firestarter => 0,
+            // these spells consume all remaining MP
+            Despair | Flare => {
+                if this.mp() < 800 {
+                    err!(EventError::Mp(800));
+                }
+                this.mp()
+            }
+            _ => state.mp_cost(action),
+        };
+
+        Ok(CastInitInfo {
+            gcd,
+            lock,
+            snap,
+            mp,
+            cd,
+            alt_cd,
+        })
+    }
+
+    fn cast_snap<'w, W: WorldRef<'w>, E: EventSink<'w, W>>(
+        action: Self::Action,
+        state: &mut Self::State,
+        _: &'w W,
+        event_sink: &mut E,
+    ) -> Result<(), EventError> {
+        let this = event_sink.source();
+        let this_id = this.id();
+
+        use BlmAction::*;
+
+        let dl = action.effect_delay();
+
+        // firestarter takes priority over triplecast, which takes priority
+        // over swiftcast, and spells with no cast time don't use up any of them
+        if !(action == FireIII && consume_status(event_sink, FIRESTARTER, 0))
+            && action.cast().duration() > 0
+            && !consume_status_stack(event_sink, TRIPLECAST, 0)
+        {
+            consume_status(event_sink, SWIFTCAST, 0);
+        }
+
+        // umbral hearts remove the increased mp cost of fire spells
+        if state.astral_fire() > 0 && action.fire() && !matches!(action, Despair | Flare) {
+            state.umbral_hearts -= 1;
+        }
+
+        let fire = |potency| {
+            DamageInstance::new(potency)
+                .magical()
+                .element(DamageElement::Fire)
+        };
+        let ice = |potency| {
+            DamageInstance::new(potency)
+                .magical()
+                .element(DamageElement::Ice)
+        };
+
+        match action {
+            Fire => {
+                let t = this.target_enemy(SPELL)?.id();
+                event_sink.damage(action, fire(180), t, dl);
+                let enochian = match state.enochian {
+                    Enochian::AstralFire(v) => Enochian::AstralFire((v + 1).min(3)),
+                    Enochian::UmbralIce(_) => Enochian::None,
+                    Enochian::None => Enochian::AstralFire(1),
+                };
+                set_enochian(state, enochian, event_sink);
+            }
+            Blizzard => {
+                let t = this.target_enemy(SPELL)?.id();
+                event_sink.damage(action, ice(180), t, dl);
+                let enochian = match state.enochian {
+                    Enochian::UmbralIce(v) => Enochian::UmbralIce((v + 1).min(3)),
+                    Enochian::AstralFire(_) => Enochian::None,
+                    Enochian::None => Enochian::UmbralIce(1),
+                };
+                set_enochian(state, enochian, event_sink);
+            }
+            FireIII | BlizzardIII | FireIV | BlizzardIV | Despair => {
+                let t = this.target_enemy(SPELL)?.id();
+                let damage = match action {
+                    FireIII => fire(290),
+                    BlizzardIII => ice(290),
+                    FireIV => fire(300),
+                    BlizzardIV => ice(320),
+                    _ => fire(350),
+                };
+                event_sink.damage(action, damage, t, dl);
+                match action {
+                    FireIII | Despair => set_enochian(state, Enochian::AstralFire(3), event_sink),
+                    BlizzardIII => set_enochian(state, Enochian::UmbralIce(3), event_sink),
+                    FireIV => state.astral_soul += 1,
+                    _ => state.umbral_hearts.set_max(),
+                }
+            }
+            HighFireII | HighBlizzardII | Flare | Freeze | FlareStar => {
+                let iter = this
+                    .target_enemy_aoe(TG_CIRCLE, EventCascade::new(dl, 1))?
+                    .id();
+                let (damage, falloff) = match action {
+                    HighFireII => (fire(100), 100),
+                    HighBlizzardII => (ice(100), 100),
+                    Flare => (fire(240), 30),
+                    Freeze => (ice(120), 100),
+                    _ => (DamageInstance::new(500).magical(), 35),
+                };
+                for (t, d, f) in iter.falloff(falloff) {
+                    event_sink.damage(action, damage.falloff(f), t, d);
+                }
+                match action {
+                    HighFireII => set_enochian(state, Enochian::AstralFire(3), event_sink),
+                    HighBlizzardII => set_enochian(state, Enochian::UmbralIce(3), event_sink),
+                    Flare => {
+                        state.umbral_hearts.clear();
+                        state.astral_soul += 3;
+                        set_enochian(state, Enochian::AstralFire(3), event_sink);
+                    }
+                    Freeze => state.umbral_hearts.set_max(),
+                    _ => state.astral_soul.clear(),
+                }
+            }
+            Paradox => {
+                let t = this.target_enemy(SPELL)?.id();
+                state.paradox = false;
+                event_sink.damage(action, DamageInstance::new(520).magical(), t, dl);
+                match state.enochian {
+                    Enochian::AstralFire(_) => {
+                        event_sink.apply_status(FIRESTARTER, 1, this_id, dl);
+                    }
+                    Enochian::UmbralIce(v) => {
+                        set_enochian(state, Enochian::UmbralIce((v + 1).min(3)), event_sink);
+                    }
+                    Enochian::None => (),
+                }
+            }
+            Xenoglossy => {
+                let t = this.target_enemy(SPELL)?.id();
+                state.polyglot -= 1;
+                event_sink.damage(action, DamageInstance::new(890).magical(), t, dl);
+            }
+            Foul => {
+                state.polyglot -= 1;
+                for (t, d, f) in this
+                    .target_enemy_aoe(TG_CIRCLE, EventCascade::new(dl, 1))?
+                    .id()
+                    .falloff(40)
+                {
+                    event_sink.damage(action, DamageInstance::new(600).magical().falloff(f), t, d);
+                }
+            }
+            HighThunder => {
+                let t = this.target_enemy(SPELL)?.id();
+                if !consume_status(event_sink, THUNDERHEAD, 0) {
+                    err!(BlmError::Thunderhead);
+                }
+                let damage = |potency| {
+                    DamageInstance::new(potency)
+                        .magical()
+                        .element(DamageElement::Lightning)
+                };
+                event_sink.damage(action, damage(150), t, dl);
+                event_sink.remove_status(HIGH_THUNDER_II, t, dl);
+                event_sink.apply_dot(HIGH_THUNDER, damage(60), SpeedStat::SpellSpeed, 1, t, dl);
+            }
+            HighThunderII => {
+                let iter = this
+                    .target_enemy_aoe(TG_CIRCLE, EventCascade::new(dl, 1))?
+                    .id();
+                if !consume_status(event_sink, THUNDERHEAD, 0) {
+                    err!(BlmError::Thunderhead);
+                }
+                let damage = |potency| {
+                    DamageInstance::new(potency)
+                        .magical()
+                        .element(DamageElement::Lightning)
+                };
+                for (t, d) in iter {
+                    event_sink.damage(action, damage(100), t, d);
+                    event_sink.remove_status(HIGH_THUNDER, t, d);
+                    event_sink.apply_dot(
+                        HIGH_THUNDER_II,
+                        damage(40),
+                        SpeedStat::SpellSpeed,
+                        1,
+                        t,
+                        d,
+                    );
+                }
+            }
+            UmbralSoul => {
+                state.umbral_hearts += 1;
+                let enochian = Enochian::UmbralIce((state.umbral_ice() + 1).min(3));
+                set_enochian(state, enochian, event_sink);
+            }
+            Transpose => {
+                let enochian = match state.enochian {
+                    Enochian::AstralFire(_) => Enochian::UmbralIce(1),
+                    Enochian::UmbralIce(_) => Enochian::AstralFire(1),
+                    Enochian::None => Enochian::None,
+                };
+                set_enochian(state, enochian, event_sink);
+            }
+            Manafont => {
+                event_sink.event(Event::AddMp(10000, this_id), dl);
+                state.umbral_hearts.set_max();
+                state.paradox = true;
+                set_enochian(state, Enochian::AstralFire(3), event_sink);
+                event_sink.apply_status(THUNDERHEAD, 1, this_id, dl);
+            }
+            Triplecast => {
+                event_sink.apply_status(TRIPLECAST, 3, this_id, 0);
+            }
+            LeyLines => {
+                event_sink.apply_status(LEY_LINES, 1, this_id, 0);
+            }
+            Amplifier => {
+                state.polyglot += 1;
+            }
+            Role(v) => v.cast_snap(action, event_sink)?,
+        }
+
+        Ok(())
+    }
+
+    fn effect(state: &Self::State) -> Option<&dyn JobEffect> {
+        Some(BlmJobEffect::new(state))
+    }
+}
+
+/// Changes the element of the Enochian gauge, updating
+/// the rest of the gauge as appropriate.
+fn set_enochian<'w, W: WorldRef<'w>, E: EventSink<'w, W>>(
+    state: &mut BlmState,
+    enochian: Enochian,
+    event_sink: &mut E,
+) {
+    let this_id = event_sink.source().id();
+    let old = state.enochian;
+    match (old, enochian) {
+        (Enochian::AstralFire(_), Enochian::AstralFire(_))
+        | (Enochian::UmbralIce(_), Enochian::UmbralIce(_))
+        | (_, Enochian::None) => (),
+        _ => event_sink.apply_status(THUNDERHEAD, 1, this_id, 0),
+    }
+    match (old, enochian) {
+        (Enochian::AstralFire(3), Enochian::UmbralIce(_)) => state.paradox = true,
+        (Enochian::UmbralIce(3), Enochian::AstralFire(_)) if state.umbral_hearts == 3 => {
+            state.paradox = true
+        }
+        _ => (),
+    }
+    if !matches!(enochian, Enochian::AstralFire(_)) {
+        state.astral_soul.clear();
+    }
+    if enochian == Enochian::None {
+        state.polyglot_timer = 0;
+        state.paradox = false;
+    }
+    state.enochian = enochian;
+}
+
+job_effect_wrapper! {
+    #[derive(Debug)]
+    struct BlmJobEffect(BlmState);
+}
+impl JobEffect for BlmJobEffect {
+    fn damage(&self, damage: u64, dmg_ty: DamageType, dmg_el: DamageElement) -> u64 {
+        let damage = match (self.0.enochian, dmg_el) {
+            (Enochian::AstralFire(v), DamageElement::Fire) => damage * (120 + 20 * v as u64) / 100,
+            (Enochian::AstralFire(v), DamageElement::Ice)
+            | (Enochian::UmbralIce(v), DamageElement::Fire) => damage * (100 - 10 * v as u64) / 100,
+            _ => damage,
+        };
+        // enochian increases the damage of all magic damage
+        if self.0.enochian != Enochian::None && dmg_ty.magical() {
+            damage * 127 / 100
+        } else {
+            damage
+        }
+    }
+
+    fn mp_regen(&self, regen: u64) -> u64 {
+        match self.0.enochian {
+            // astral fire stops natural mp regeneration entirely
+            Enochian::AstralFire(_) => 0,
+            Enochian::UmbralIce(v @ 1..=3) => regen + UMBRAL_ICE_REGEN[v as usize - 1] as u64,
+            _ => regen,
+        }
+    }
+}
+
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Copy, Debug)]
+/// A custom cast error for Black Mage actions.
+pub enum BlmError {
+    /// Not under the effect of Astral Fire.
+    AstralFire,
+    /// Not under the effect of Umbral Ice.
+    UmbralIce,
+    /// Not under the effect of Astral Fire or Umbral Ice.
+    Enochian,
+    /// Paradox is not available.
+    Paradox,
+    /// Not enough Polyglot.
+    Polyglot(u8),
+    /// Not enough Astral Soul.
+    AstralSoul(u8),
+    /// Not under the effect of Thunderhead.
+    Thunderhead,
+}
+
+impl From<BlmError> for EventError {
+    fn from(value: BlmError) -> Self {
+        Self::Job(value.into())
+    }
+}
+
+impl Display for BlmError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::AstralFire => write!(f, "Not under the effect of Astral Fire."),
+            Self::UmbralIce => write!(f, "Not under the effect of Umbral Ice."),
+            Self::Enochian => write!(f, "Not under the effect of Astral Fire or Umbral Ice."),
+            Self::Paradox => write!(f, "Paradox is not available."),
+            Self::Polyglot(v) => write!(f, "Not enough Polyglot, needed at least {}.", v),
+            Self::AstralSoul(v) => write!(f, "Not enough Astral Soul, needed at least {}.", v),
+            Self::Thunderhead => status_proc_error(f, THUNDERHEAD),
+        }
+    }
+}
+
+impl Error for BlmError {}
+
+const SPELL: ActionTargetting = ActionTargetting::single(25);
+const TG_CIRCLE: ActionTargetting = ActionTargetting::target_circle(5, 25);
+
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(rename_all = "snake_case")
+)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[repr(u8)]
+#[var_consts {
+    /// Returns `true` if the action is a fire spell.
+    pub const fire;
+    /// Returns `true` if the action is an ice spell.
+    pub const ice;
+    /// Returns the base GCD recast time, or `None` if the action is not a gcd.
+    pub const gcd: ScaleTime?;
+    /// Returns the base milliseconds the action takes to cast.
+    pub const cast: ScaleTime = ScaleTime::zero();
+    /// Returns the human friendly name of the action.
+    pub const name: &'static str;
+    /// Returns the cooldown of the skill in milliseconds.
+    pub const cooldown: u32 = 0;
+    /// Returns the number of charges a skill has, or `1` if it is a single charge skill.
+    pub const cd_charges: u8 = 1;
+    /// Returns the delay in milliseconds for the damage/statuses to be applied.
+    pub const effect_delay: u32 = 0;
+    /// Returns the base MP cost of the action, before any modifiers from Enochian.
+    pub const mp_cost: u16 = 0;
+    /// Returns the [`ActionCategory`] this action is part of.
+    pub const category: ActionCategory;
+
+    pub const spell for {
+        gcd = ScaleTime::spell(2500);
+        category = ActionCategory::Spell;
+    }
+    pub const ability for {
+        category = ActionCategory::Ability;
+    }
+}]
+#[allow(missing_docs)] // no reason to document the variants.
+/// An action specific to the Black Mage job.
+pub enum BlmAction {
+    #[spell]
+    #[fire]
+    #[cast = ScaleTime::spell(2500)]
+    #[mp_cost = 800]
+    #[name = "Fire"]
+    Fire,
+    #[spell]
+    #[ice]
+    #[cast = ScaleTime::spell(2500)]
+    #[mp_cost = 400]
+    #[name = "Blizzard"]
+    Blizzard,
+    #[spell]
+    #[fire]
+    #[cast = ScaleTime::spell(3500)]
+    #[mp_cost = 2000]
+    #[name = "Fire III"]
+    FireIII,
+    #[spell]
+    #[ice]
+    #[cast = ScaleTime::spell(3500)]
+    #[mp_cost = 800]
+    #[name = "Blizzard III"]
+    BlizzardIII,
+    #[spell]
+    #[fire]
+    #[cast = ScaleTime::spell(2000)]
+    #[mp_cost = 800]
+    #[name = "Fire IV"]
+    FireIV,
+    #[spell]
+    #[ice]
+    #[cast = ScaleTime::spell(2000)]
+    #[mp_cost = 800]
+    #[name = "Blizzard IV"]
+    BlizzardIV,
+    #[spell]
+    #[fire]
+    #[cast = ScaleTime::spell(3000)]
+    #[name = "Despair"]
+    Despair,
+    #[spell]
+    #[fire]
+    #[cast = ScaleTime::spell(3000)]
+    #[mp_cost = 1500]
+    #[name = "High Fire II"]
+    HighFireII,
+    #[spell]
+    #[ice]
+    #[cast = ScaleTime::spell(3000)]
+    #[mp_cost = 800]
+    #[name = "High Blizzard II"]
+    HighBlizzardII,
+    #[spell]
+    #[fire]
+    #[cast = ScaleTime::spell(4000)]
+    #[name = "Flare"]
+    Flare,
+    #[spell]
+    #[ice]
+    #[cast = ScaleTime::spell(2800)]
+    #[mp_cost = 1000]
+    #[name = "Freeze"]
+    Freeze,
+    #[spell]
+    #[cast = ScaleTime::spell(3000)]
+    #[name = "Flare Star"]
+    FlareStar,
+    #[spell]
+    #[mp_cost = 1600]
+    #[name = "Paradox"]
+    Paradox,
+    #[spell]
+    #[name = "Xenoglossy"]
+    Xenoglossy,
+    #[spell]
+    #[name = "Foul"]
+    Foul,
+    #[spell]
+    #[name = "High Thunder"]
+    HighThunder,
+    #[spell]
+    #[name = "High Thunder II"]
+    HighThunderII,
+    #[spell]
+    #[name = "Umbral Soul"]
+    UmbralSoul,
+    #[ability]
+    #[cooldown = 5000]
+    #[name = "Transpose"]
+    Transpose,
+    #[ability]
+    #[cooldown = 100000]
+    #[name = "Manafont"]
+    Manafont,
+    #[ability]
+    #[cooldown = 60000]
+    #[cd_charges = 2]
+    #[name = "Triplecast"]
+    Triplecast,
+    #[ability]
+    #[cooldown = 120000]
+    #[name = "Ley Lines"]
+    LeyLines,
+    #[ability]
+    #[cooldown = 120000]
+    #[name = "Amplifier"]
+    Amplifier,
+    #[gcd((v) => v.gcd())]
+    #[cast((v) => v.cast())]
+    #[name((v) => v.name())]
+    #[cooldown((v) => v.cooldown())]
+    #[cd_charges((v) => v.cd_charges())]
+    #[effect_delay((v) => v.effect_delay())]
+    #[mp_cost((v) => v.mp_cost())]
+    #[category((v) => v.category())]
+    Role(CasterRoleAction),
+}
+
+impl JobAction for BlmAction {
+    fn category(&self) -> ActionCategory {
+        self.category()
+    }
+
+    fn gcd(&self) -> bool {
+        self.gcd().is_some()
+    }
+}
+
+impl From<BlmAction> for Action {
+    fn from(value: BlmAction) -> Self {
+        Action::Job(value.into())
+    }
+}
+
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+/// The element of the Enochian gauge.
+pub enum Enochian {
+    /// Neither Astral Fire or Umbral Ice is active.
+    #[default]
+    None,
+    /// Astral Fire is active with some number of stacks.
+    AstralFire(u8),
+    /// Umbral Ice is active with some number of stacks.
+    UmbralIce(u8),
+}
+
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+/// The state of the Black Mage job gauges and cooldowns.
+pub struct BlmState {
+    /// The element of the Enochian gauge.
+    pub enochian: Enochian,
+    /// The Umbral Hearts gauge.
+    pub umbral_hearts: GaugeU8<3>,
+    /// The Polyglot gauge.
+    pub polyglot: GaugeU8<3>,
+    /// The time in milliseconds Enochian has been active
+    /// since the last stack of Polyglot was gained.
+    pub polyglot_timer: u32,
+    /// The Astral Soul gauge.
+    pub astral_soul: GaugeU8<6>,
+    /// If Paradox is available.
+    pub paradox: bool,
+}
+
+impl BlmState {
+    /// Returns the number of stacks of Astral Fire.
+    pub fn astral_fire(&self) -> u8 {
+        match self.enochian {
+            Enochian::AstralFire(v) => v,
+            _ => 0,
+        }
+    }
+
+    /// Returns the number of stacks of Umbral Ice.
+    pub fn umbral_ice(&self) -> u8 {
+        match self.enochian {
+            Enochian::UmbralIce(v) => v,
+            _ => 0,
+        }
+    }
+
+    /// Returns the cast time of an action, after the cast time reduction
+    /// from 3 stacks of Astral Fire or Umbral Ice.
+    pub fn cast_time(&self, action: BlmAction) -> ScaleTime {
+        let halved = match self.enochian {
+            Enochian::AstralFire(3) => action.ice(),
+            Enochian::UmbralIce(3) => action.fire(),
+            _ => false,
+        };
+        if halved {
+            ScaleTime::spell(action.cast().duration() / 2)
+        } else {
+            action.cast()
+        }
+    }
+
+    /// Returns the MP cost of an action, after the modifiers from
+    /// Astral Fire, Umbral Ice, and Umbral Hearts.
+    pub fn mp_cost(&self, action: BlmAction) -> u16 {
+        let cost = action.mp_cost();
+        match self.enochian {
+            Enochian::AstralFire(_) if action.fire() && self.umbral_hearts == 0 => cost * 2,
+            Enochian::AstralFire(v) if action.ice() => cost * [50, 25, 0][v as usize - 1] / 100,
+            Enochian::UmbralIce(v) if action.ice() => cost * [75, 50, 0][v as usize - 1] / 100,
+            Enochian::UmbralIce(_) if action == BlmAction::Paradox => 0,
+            _ => cost,
+        }
+    }
+}
+
+impl JobState for BlmState {
+    fn advance(&mut self, time: u32) {
+        if self.enochian != Enochian::None {
+            self.polyglot_timer += time;
+            while self.polyglot_timer >= POLYGLOT_TIME {
+                self.polyglot_timer -= POLYGLOT_TIME;
+                self.polyglot += 1;
+            }
+        }
+    }
+}
+
+job_cd_struct! {
+    BlmAction =>
+
+    #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+    #[derive(Clone, Debug, Default)]
+    /// The cooldown map for Black Mage actions.
+    pub BlmCdMap
+
+    #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+    #[derive(Copy, Clone, Debug)]
+    /// The various cooldown groups a Black Mage action can be part of.
+    pub BlmCdGroup
+
+    "Transpose"
+    transpose Transpose: Transpose;
+    "Manafont"
+    manafont Manafont: Manafont;
+    "Triplecast"
+    triplecast Triplecast: Triplecast;
+    "Ley Lines"
+    ley_lines LeyLines: LeyLines;
+    "Amplifier"
+    amplifier Amplifier: Amplifier;
+    "Swiftcast"
+    swiftcast Swiftcast: Role(CasterRoleAction::Swiftcast);
+    "Lucid Dreaming"
+    lucid_dreaming LucidDreaming: Role(CasterRoleAction::LucidDreaming);
+    "Addle"
+    addle Addle: Role(CasterRoleAction::Addle);
+    "Triplecast Charge"
+    triplecast_chg TriplecastChg;
+}
+
+impl BlmAction {
+    /// Returns the alternate [cooldown group] that this action is part of.
+    ///
+    /// Returns `None` if this action does not have an alternate cooldown.
+    /// This action is used for the 1s cooldown between uses of charged actions.
+    ///
+    /// [cooldown group]: BlmCdGroup
+    pub const fn alt_cd_group(&self) -> Option<BlmCdGroup> {
+        match self {
+            Self::Triplecast => Some(BlmCdGroup::TriplecastChg),
+            _ => None,
+        }
+    }
+}
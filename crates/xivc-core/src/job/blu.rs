@@ -0,0 +1,579 @@
+use core::{
+    error::Error,
+    fmt::{self, Display},
+};
+
+use macros::var_consts;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    enums::{ActionCategory, DamageInstance},
+    err,
+    job::{
+        role::{CasterRoleAction, SWIFTCAST},
+        CastInitInfo, Job, JobAction, JobEvent, JobState,
+    },
+    job_cd_struct,
+    math::SpeedStat,
+    status_effect,
+    timing::{DurationInfo, EventCascade, ScaleTime},
+    util::ActionTargettingExt as _,
+    world::{
+        status::{consume_status, StatusEffect, StatusEventExt},
+        Action, ActionTargetting, ActorRef, DamageEventExt, Event, EventError, EventSink, WorldRef,
+    },
+};
+
+/// The [`Job`] struct for Blue Mage.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct BluJob;
+
+/// The number of slots in the active spell loadout.
+pub const LOADOUT_SIZE: usize = 24;
+
+/// The status effect "Waxing Nocturne".
+pub static WAXING_NOCTURNE: StatusEffect = status_effect!(
+    "Waxing Nocturne" 15000 { damage { out = 150 / 100 } }
+);
+/// The status effect "Waning Nocturne".
+pub static WANING_NOCTURNE: StatusEffect = status_effect!("Waning Nocturne" 15000);
+/// The status effect "Boost".
+pub static BOOST: StatusEffect = status_effect!("Boost" 30000);
+/// The status effect "Harmonized".
+pub static HARMONIZED: StatusEffect = status_effect!("Harmonized" 30000);
+/// The status effect "Tingling".
+pub static TINGLING: StatusEffect = status_effect!("Tingling" 15000);
+/// The damage over time status "Bleeding" applied by Song of Torment.
+pub static BLEEDING: StatusEffect = status_effect!("Bleeding" 30000 multi);
+/// The damage over time status "Bleeding" applied by Nightbloom.
+pub static NIGHTBLOOM_BLEEDING: StatusEffect = status_effect!("Bleeding" 60000 multi);
+/// The status effect "Surpanakha's Fury".
+pub static SURPANAKHAS_FURY: StatusEffect = status_effect!("Surpanakha's Fury" 3000);
+/// The status effect "Phantom Flurry".
+pub static PHANTOM_FLURRY: StatusEffect = status_effect!("Phantom Flurry" 5000);
+
+impl Job for BluJob {
+    type Action = BluAction;
+    type State = BluState;
+    type CastError = BluError;
+    type Event = BluEvent;
+    type CdGroup = BluCdGroup;
+    type CdMap<T> = BluCdMap<T>;
+
+    fn check_cast<'w, W: WorldRef<'w>, E: EventSink<'w, W>>(
+        action: Self::Action,
+        state: &Self::State,
+        _: &'w W,
+        event_sink: &mut E,
+    ) -> Result<CastInitInfo<Self::CdGroup>, EventError> {
+        let this = event_sink.source();
+
+        let di = this.duration_info();
+
+        use BluAction::*;
+
+        let cast = if this.has_own_status(SWIFTCAST) {
+            ScaleTime::zero()
+        } else {
+            action.cast()
+        };
+
+        let gcd = action.gcd().map(|v| di.scale(v)).unwrap_or_default() as u16;
+        let (lock, snap) = di.get_cast(cast, 600);
+
+        // the finishing kick of phantom flurry does not trigger the cooldown.
+        let cd = if action == PhantomFlurry && this.has_own_status(PHANTOM_FLURRY) {
+            None
+        } else {
+            action
+                .cd_group()
+                .map(|v| (v, action.cooldown(), action.cd_charges()))
+        };
+
+        let alt_cd = action.alt_cd_group().map(|v| (v, 1000, 1));
+
+        if !state.is_loaded(action) {
+            err!(BluError::NotLoaded(action));
+        }
+        if this.has_own_status(WANING_NOCTURNE) {
+            err!(BluError::WaningNocturne);
+        }
+
+        Ok(CastInitInfo {
+            gcd,
+            lock,
+            snap,
+            mp: action.mp_cost(),
+            cd,
+            alt_cd,
+        })
+    }
+
+    fn cast_snap<'w, W: WorldRef<'w>, E: EventSink<'w, W>>(
+        action: Self::Action,
+        _: &mut Self::State,
+        _: &'w W,
+        event_sink: &mut E,
+    ) -> Result<(), EventError> {
+        let this = event_sink.source();
+        let this_id = this.id();
+
+        use BluAction::*;
+
+        let dl = action.effect_delay();
+
+        // swiftcast is only used up by spells with a cast time.
+        if action.cast().duration() > 0 {
+            consume_status(event_sink, SWIFTCAST, 0);
+        }
+
+        // any other action interrupts the phantom flurry channel
+        // and breaks the chain of surpanakhas.
+        if action != PhantomFlurry && this.has_own_status(PHANTOM_FLURRY) {
+            event_sink.remove_status(PHANTOM_FLURRY, this_id, 0);
+        }
+        if action != Surpanakha && this.has_own_status(SURPANAKHAS_FURY) {
+            event_sink.remove_status(SURPANAKHAS_FURY, this_id, 0);
+        }
+
+        // boost and harmonized never exist at the same time,
+        // so only one of them will ever be consumed.
+        let (mult, bonus) = if action.damage_spell() {
+            let mult = if consume_status(event_sink, BOOST, 0) {
+                150
+            } else if action.physical() && consume_status(event_sink, HARMONIZED, 0) {
+                180
+            } else {
+                100
+            };
+            let bonus = if action.physical() && consume_status(event_sink, TINGLING, 0) {
+                100
+            } else {
+                0
+            };
+            (mult, bonus)
+        } else {
+            (100, 0)
+        };
+
+        let dmg = |potency: u64| {
+            let damage = DamageInstance::new((potency + bonus) * mult / 100);
+            if action.physical() {
+                damage.blunt()
+            } else {
+                damage.magical()
+            }
+        };
+
+        match action {
+            SonicBoom => {
+                let t = this.target_enemy(SPELL)?.id();
+                event_sink.damage(action, dmg(210), t, dl);
+            }
+            SongOfTorment => {
+                let t = this.target_enemy(SPELL)?.id();
+                event_sink.damage(action, dmg(50), t, dl);
+                event_sink.apply_dot(BLEEDING, dmg(50), SpeedStat::SpellSpeed, 1, t, dl);
+            }
+            Tingle => {
+                for (t, d) in this
+                    .target_enemy_aoe(
+                        ActionTargetting::target_circle(6, 20),
+                        EventCascade::new(dl, 1),
+                    )?
+                    .id()
+                {
+                    event_sink.damage(action, dmg(100), t, d);
+                }
+                event_sink.apply_status(TINGLING, 1, this_id, dl);
+            }
+            TripleTrident => {
+                let t = this.target_enemy(MELEE)?.id();
+                for _ in 0..3 {
+                    event_sink.damage(action, dmg(150).piercing(), t, dl);
+                }
+            }
+            MoonFlute => {
+                event_sink.apply_status(WAXING_NOCTURNE, 1, this_id, dl);
+                event_sink.apply_status(WANING_NOCTURNE, 1, this_id, dl + 15000);
+            }
+            Bristle => {
+                if this.has_own_status(HARMONIZED) {
+                    event_sink.remove_status(HARMONIZED, this_id, 0);
+                }
+                event_sink.apply_status(BOOST, 1, this_id, dl);
+            }
+            Whistle => {
+                if this.has_own_status(BOOST) {
+                    event_sink.remove_status(BOOST, this_id, 0);
+                }
+                event_sink.apply_status(HARMONIZED, 1, this_id, dl);
+            }
+            Nightbloom => {
+                for (t, d) in this
+                    .target_enemy_aoe(ActionTargetting::circle(10), EventCascade::new(dl, 1))?
+                    .id()
+                {
+                    event_sink.damage(action, dmg(400), t, d);
+                    event_sink.apply_dot(
+                        NIGHTBLOOM_BLEEDING,
+                        dmg(75),
+                        SpeedStat::SpellSpeed,
+                        1,
+                        t,
+                        d,
+                    );
+                }
+            }
+            MatraMagic => {
+                let t = this.target_enemy(SPELL)?.id();
+                for _ in 0..8 {
+                    event_sink.damage(action, dmg(50), t, dl);
+                }
+            }
+            Surpanakha => {
+                let stacks = this.get_own_status(SURPANAKHAS_FURY).map_or(0, |v| v.stack);
+                for (t, d) in this.target_enemy_aoe(CONE, EventCascade::new(dl, 1))?.id() {
+                    event_sink.damage(action, dmg(200 * (100 + 50 * stacks as u64) / 100), t, d);
+                }
+                event_sink.apply_status(SURPANAKHAS_FURY, (stacks + 1).min(3), this_id, dl);
+            }
+            ShockStrike => {
+                for (t, d) in this
+                    .target_enemy_aoe(
+                        ActionTargetting::target_circle(3, 25),
+                        EventCascade::new(dl, 1),
+                    )?
+                    .id()
+                {
+                    event_sink.damage(action, dmg(400), t, d);
+                }
+            }
+            JKick => {
+                for (t, d) in this
+                    .target_enemy_aoe(
+                        ActionTargetting::target_circle(6, 25),
+                        EventCascade::new(dl, 1),
+                    )?
+                    .id()
+                {
+                    event_sink.damage(action, dmg(300), t, d);
+                }
+            }
+            PhantomFlurry => {
+                if this.has_own_status(PHANTOM_FLURRY) {
+                    // ends the channel with a finishing kick.
+                    event_sink.remove_status(PHANTOM_FLURRY, this_id, 0);
+                    for (t, d) in this
+                        .target_enemy_aoe(
+                            ActionTargetting::cone(16, 120),
+                            EventCascade::new(dl, 1),
+                        )?
+                        .id()
+                    {
+                        event_sink.damage(action, dmg(600), t, d);
+                    }
+                } else {
+                    event_sink.apply_status(PHANTOM_FLURRY, 1, this_id, dl);
+                    for (t, d) in this.target_enemy_aoe(CONE, EventCascade::new(dl, 1))?.id() {
+                        event_sink.damage(action, dmg(200), t, d);
+                    }
+                    // the channel hits every second for 5 seconds.
+                    for tick in 1..5 {
+                        event_sink.event(
+                            JobEvent::blu(BluEvent::PhantomFlurry, this_id),
+                            dl + tick * 1000,
+                        );
+                    }
+                }
+            }
+            Role(v) => v.cast_snap(action, event_sink)?,
+        }
+
+        Ok(())
+    }
+
+    fn event<'w, W: WorldRef<'w>, E: EventSink<'w, W>>(
+        _: &mut Self::State,
+        _: &'w W,
+        event: &Event,
+        event_sink: &mut E,
+    ) {
+        let this = event_sink.source();
+        let this_id = this.id();
+        match event {
+            Event::Job(JobEvent::Blu(BluEvent::PhantomFlurry), src_id)
+                if *src_id == this_id && this.has_own_status(PHANTOM_FLURRY) =>
+            {
+                let Ok(iter) = this.target_enemy_aoe(CONE, EventCascade::new(0, 1)) else {
+                    return;
+                };
+                for (t, d) in iter.id() {
+                    event_sink.damage(
+                        BluAction::PhantomFlurry,
+                        DamageInstance::new(200).blunt(),
+                        t,
+                        d,
+                    );
+                }
+            }
+            _ => (),
+        }
+    }
+}
+
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Copy, Debug)]
+/// A custom cast error for Blue Mage actions.
+pub enum BluError {
+    /// The spell is not in the active spell loadout.
+    NotLoaded(BluAction),
+    /// Under the effect of Waning Nocturne.
+    WaningNocturne,
+}
+
+impl From<BluError> for EventError {
+    fn from(value: BluError) -> Self {
+        Self::Job(value.into())
+    }
+}
+
+impl Display for BluError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::NotLoaded(v) => {
+                write!(f, "\"{}\" is not in the active spell loadout.", v.name())
+            }
+            Self::WaningNocturne => write!(f, "Cannot act under Waning Nocturne."),
+        }
+    }
+}
+
+impl Error for BluError {}
+
+const SPELL: ActionTargetting = ActionTargetting::single(25);
+const MELEE: ActionTargetting = ActionTargetting::single(3);
+const CONE: ActionTargetting = ActionTargetting::cone(8, 120);
+
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(rename_all = "snake_case")
+)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[repr(u8)]
+#[var_consts {
+    /// Returns the base GCD recast time, or `None` if the action is not a gcd.
+    pub const gcd: ScaleTime?;
+    /// Returns the base milliseconds the action takes to cast.
+    pub const cast: ScaleTime = ScaleTime::zero();
+    /// Returns the human friendly name of the action.
+    pub const name: &'static str;
+    /// Returns the cooldown of the skill in milliseconds.
+    pub const cooldown: u32 = 0;
+    /// Returns the number of charges a skill has, or `1` if it is a single charge skill.
+    pub const cd_charges: u8 = 1;
+    /// Returns the delay in milliseconds for the damage/statuses to be applied.
+    pub const effect_delay: u32 = 0;
+    /// Returns the MP cost of the action.
+    pub const mp_cost: u16 = 0;
+    /// Returns the [`ActionCategory`] this action is part of.
+    pub const category: ActionCategory;
+    /// Returns `true` if the action is a damage spell that consumes Boost and Harmonized.
+    pub const damage_spell;
+    /// Returns `true` if the action deals physical damage.
+    pub const physical;
+
+    pub const spell for {
+        gcd = ScaleTime::spell(2500);
+        category = ActionCategory::Spell;
+    }
+    pub const ability for {
+        category = ActionCategory::Ability;
+    }
+}]
+#[allow(missing_docs)] // no reason to document the variants.
+/// An action specific to the Blue Mage job.
+pub enum BluAction {
+    #[spell]
+    #[damage_spell]
+    #[cast = ScaleTime::spell(1000)]
+    #[mp_cost = 200]
+    #[name = "Sonic Boom"]
+    SonicBoom,
+    #[spell]
+    #[damage_spell]
+    #[cast = ScaleTime::spell(2000)]
+    #[mp_cost = 400]
+    #[name = "Song of Torment"]
+    SongOfTorment,
+    #[spell]
+    #[damage_spell]
+    #[cast = ScaleTime::spell(2000)]
+    #[mp_cost = 500]
+    #[name = "Tingle"]
+    Tingle,
+    #[spell]
+    #[damage_spell]
+    #[physical]
+    #[cast = ScaleTime::spell(2000)]
+    #[cooldown = 90000]
+    #[mp_cost = 300]
+    #[name = "Triple Trident"]
+    TripleTrident,
+    #[spell]
+    #[cast = ScaleTime::spell(2000)]
+    #[mp_cost = 500]
+    #[name = "Moon Flute"]
+    MoonFlute,
+    #[spell]
+    #[cast = ScaleTime::spell(1000)]
+    #[mp_cost = 400]
+    #[name = "Bristle"]
+    Bristle,
+    #[spell]
+    #[cast = ScaleTime::spell(1000)]
+    #[mp_cost = 200]
+    #[name = "Whistle"]
+    Whistle,
+    #[ability]
+    #[cooldown = 120000]
+    #[name = "Nightbloom"]
+    Nightbloom,
+    #[ability]
+    #[cooldown = 120000]
+    #[name = "Matra Magic"]
+    MatraMagic,
+    #[ability]
+    #[cooldown = 30000]
+    #[cd_charges = 4]
+    #[name = "Surpanakha"]
+    Surpanakha,
+    #[ability]
+    #[cooldown = 60000]
+    #[name = "Shock Strike"]
+    ShockStrike,
+    #[ability]
+    #[physical]
+    #[cooldown = 60000]
+    #[name = "J Kick"]
+    JKick,
+    #[ability]
+    #[physical]
+    #[cooldown = 120000]
+    #[name = "Phantom Flurry"]
+    PhantomFlurry,
+    #[gcd((v) => v.gcd())]
+    #[cast((v) => v.cast())]
+    #[name((v) => v.name())]
+    #[cooldown((v) => v.cooldown())]
+    #[cd_charges((v) => v.cd_charges())]
+    #[effect_delay((v) => v.effect_delay())]
+    #[mp_cost((v) => v.mp_cost())]
+    #[category((v) => v.category())]
+    Role(CasterRoleAction),
+}
+
+impl JobAction for BluAction {
+    fn category(&self) -> ActionCategory {
+        self.category()
+    }
+
+    fn gcd(&self) -> bool {
+        self.gcd().is_some()
+    }
+}
+
+impl From<BluAction> for Action {
+    fn from(value: BluAction) -> Self {
+        Action::Job(value.into())
+    }
+}
+
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+/// The state of the Blue Mage job gauges and cooldowns.
+pub struct BluState {
+    /// The active spell loadout.
+    ///
+    /// Only the spells in this loadout can be used.
+    pub loadout: [Option<BluAction>; LOADOUT_SIZE],
+}
+
+impl BluState {
+    /// Creates a new state with the specified active spell loadout.
+    pub const fn new(loadout: [Option<BluAction>; LOADOUT_SIZE]) -> Self {
+        Self { loadout }
+    }
+
+    /// Returns `true` if the spell is in the active spell loadout.
+    pub fn is_loaded(&self, action: BluAction) -> bool {
+        self.loadout.contains(&Some(action))
+    }
+}
+
+impl JobState for BluState {
+    fn advance(&mut self, _: u32) {}
+}
+
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+/// A custom event for Blue Mage.
+pub enum BluEvent {
+    /// A hit of the Phantom Flurry channel.
+    PhantomFlurry,
+}
+
+job_cd_struct! {
+    BluAction =>
+
+    #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+    #[derive(Clone, Debug, Default)]
+    /// The cooldown map for Blue Mage actions.
+    pub BluCdMap
+
+    #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+    #[derive(Copy, Clone, Debug)]
+    /// The various cooldown groups a Blue Mage action can be part of.
+    pub BluCdGroup
+
+    "Triple Trident"
+    triple_trident TripleTrident: TripleTrident;
+    "Nightbloom"
+    nightbloom Nightbloom: Nightbloom;
+    "Matra Magic"
+    matra_magic MatraMagic: MatraMagic;
+    "Surpanakha"
+    surpanakha Surpanakha: Surpanakha;
+    "Shock Strike"
+    shock_strike ShockStrike: ShockStrike;
+    "J Kick"
+    j_kick JKick: JKick;
+    "Phantom Flurry"
+    phantom_flurry PhantomFlurry: PhantomFlurry;
+    "Swiftcast"
+    swiftcast Swiftcast: Role(CasterRoleAction::Swiftcast);
+    "Lucid Dreaming"
+    lucid_dreaming LucidDreaming: Role(CasterRoleAction::LucidDreaming);
+    "Addle"
+    addle Addle: Role(CasterRoleAction::Addle);
+    "Surpanakha Charge"
+    surpanakha_chg SurpanakhaChg;
+}
+
+impl BluAction {
+    /// Returns the alternate [cooldown group] that this action is part of.
+    ///
+    /// Returns `None` if this action does not have an alternate cooldown.
+    /// This action is used for the 1s cooldown between uses of charged actions.
+    ///
+    /// [cooldown group]: BluCdGroup
+    pub const fn alt_cd_group(&self) -> Option<BluCdGroup> {
+        match self {
+            Self::Surpanakha => Some(BluCdGroup::SurpanakhaChg),
+            _ => None,
+        }
+    }
+}
@@ -11,7 +11,7 @@ use serde::{Deserialize, Serialize};
 use crate::{
     bool_job_dist,
     enums::{ActionCategory, DamageInstance},
-    job::{CastInitInfo, Job, JobAction, JobEvent, JobState},
+    job::{role::RangedRoleAction, CastInitInfo, Job, JobAction, JobEvent, JobState},
     job_cd_struct, job_effect_wrapper,
     math::SpeedStat,
     status_effect,
@@ -504,6 +504,7 @@ impl Job for BrdJob {
                     )
                 }
             }
+            Role(v) => v.cast_snap(action, event_sink)?,
         }
 
         Ok(())
@@ -518,43 +519,33 @@ impl Job for BrdJob {
         let this = event_sink.source();
         let this_id = this.id();
         match event {
-            Event::Job(JobEvent::Brd(event), src_id) => {
-                if *src_id == this_id {
-                    match event {
-                        BrdEvent::SongTick { gen } => {
-                            if let Some((song, time)) = &state.song {
-                                // the song generations match
-                                if *gen == state.song_gen {
-                                    debug_assert!(time % 3000 == 0, "song ticks don't line up");
-                                    if *time == 0 {
-                                        if let BrdSong::Paeon(rep) = song {
-                                            // add army's ethos if paeon is falling off
-                                            // with more than 1 stack
-                                            if rep.value() > 0 {
-                                                event_sink.apply_status(
-                                                    ETHOS,
-                                                    rep.value(),
-                                                    this_id,
-                                                    0,
-                                                );
-                                            }
+            Event::Job(JobEvent::Brd(event), src_id) if *src_id == this_id => {
+                match event {
+                    BrdEvent::SongTick { gen } => {
+                        if let Some((song, time)) = &state.song {
+                            // the song generations match
+                            if *gen == state.song_gen {
+                                debug_assert!(time % 3000 == 0, "song ticks don't line up");
+                                if *time == 0 {
+                                    if let BrdSong::Paeon(rep) = song {
+                                        // add army's ethos if paeon is falling off
+                                        // with more than 1 stack
+                                        if rep.value() > 0 {
+                                            event_sink.apply_status(ETHOS, rep.value(), this_id, 0);
                                         }
-                                        // remove the song now
-                                        state.song = None;
-                                    } else {
-                                        // 80% chance for rep proc
-                                        if event_sink.random(RepertoireProc) {
-                                            repertoire(state, this_id, event_sink);
-                                        }
-
-                                        event_sink.event(
-                                            JobEvent::brd(
-                                                BrdEvent::song_tick(state.song_gen),
-                                                this_id,
-                                            ),
-                                            3000,
-                                        );
                                     }
+                                    // remove the song now
+                                    state.song = None;
+                                } else {
+                                    // 80% chance for rep proc
+                                    if event_sink.random(RepertoireProc) {
+                                        repertoire(state, this_id, event_sink);
+                                    }
+
+                                    event_sink.event(
+                                        JobEvent::brd(BrdEvent::song_tick(state.song_gen), this_id),
+                                        3000,
+                                    );
                                 }
                             }
                         }
@@ -795,6 +786,13 @@ pub enum BrdAction {
     #[skill]
     #[name = "Radiant Encore"]
     RadiantEncore,
+    #[gcd((v) => v.gcd())]
+    #[name((v) => v.name())]
+    #[cooldown((v) => v.cooldown())]
+    #[cd_charges((v) => v.cd_charges())]
+    #[effect_delay((v) => v.effect_delay())]
+    #[category((v) => v.category())]
+    Role(RangedRoleAction),
 }
 
 impl JobAction for BrdAction {
@@ -961,6 +959,12 @@ job_cd_struct! {
     finale Finale: RadiantFinale;
     "Pitch Perfect"
     pitch Pitch: PitchPerfect;
+    "Second Wind"
+    second_wind SecondWind: Role(RangedRoleAction::SecondWind);
+    "Head Graze"
+    head_graze HeadGraze: Role(RangedRoleAction::HeadGraze);
+    "Peloton"
+    peloton Peloton: Role(RangedRoleAction::Peloton);
     "Bloodletter/Heartbreak Shot/Rain of Death Charge"
     bloodletter_chg BloodLetterChg;
 }
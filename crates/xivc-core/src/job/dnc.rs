@@ -1,4 +1,7 @@
-use core::fmt::{self, Display};
+use core::{
+    error::Error,
+    fmt::{self, Display},
+};
 
 use macros::var_consts;
 
@@ -10,10 +13,10 @@ use crate::{
     bool_job_dist,
     enums::{ActionCategory, DamageInstance},
     err,
-    job::{CastInitInfo, Job, JobAction, JobState},
+    job::{role::RangedRoleAction, CastInitInfo, Job, JobAction, JobState},
     job_cd_struct, status_effect,
     timing::{DurationInfo, EventCascade, ScaleTime},
-    util::{combo_pot, ActionTargettingExt as _, ComboState, GaugeU8},
+    util::{combo_pot, status_proc_error, ActionTargettingExt as _, ComboState, GaugeU8},
     world::{
         status::{consume_status, StatusEffect, StatusEventExt, StatusEventKind},
         Action, ActionTargetting, ActorId, ActorRef, DamageEventExt, Event, EventError, EventSink,
@@ -136,15 +139,15 @@ impl Job for DncJob {
             StarfallDance if !this.has_own_status(STARFALL) => {
                 err!(DncError::Starfall);
             }
-            ReverseCascade | RisingWindmill => {
-                if !this.has_own_status(SILKEN_SYMM) && !this.has_own_status(FLOURISH_SYMM) {
-                    err!(DncError::Symmetry);
-                }
+            ReverseCascade | RisingWindmill
+                if !this.has_own_status(SILKEN_SYMM) && !this.has_own_status(FLOURISH_SYMM) =>
+            {
+                err!(DncError::Symmetry);
             }
-            Fountainfall | Bloodshower => {
-                if !this.has_own_status(SILKEN_FLOW) && !this.has_own_status(FLOURISH_FLOW) {
-                    err!(DncError::Flow);
-                }
+            Fountainfall | Bloodshower
+                if !this.has_own_status(SILKEN_FLOW) && !this.has_own_status(FLOURISH_FLOW) =>
+            {
+                err!(DncError::Flow);
             }
             Tillana if !this.has_own_status(FLOURISH_FINISH) => {
                 err!(DncError::Tillana);
@@ -155,10 +158,10 @@ impl Job for DncJob {
             TechnicalFinish if !this.has_own_status(TECHNICAL_STEP) => {
                 err!(DncError::TechnicalStep);
             }
-            Jete | Pirouette | Emboite | Entrechat => {
-                if !this.has_own_status(STANDARD_STEP) && !this.has_own_status(TECHNICAL_STEP) {
-                    err!(DncError::Step);
-                }
+            Jete | Pirouette | Emboite | Entrechat
+                if !this.has_own_status(STANDARD_STEP) && !this.has_own_status(TECHNICAL_STEP) =>
+            {
+                err!(DncError::Step);
             }
             Flourish if !this.in_combat() => {
                 err!(EventError::InCombat);
@@ -640,6 +643,7 @@ impl Job for DncJob {
             EnAvant | CuringWaltz | ShieldSamba | Improvisation | ImprovisedFinish => {
                 // todo: implement healing/utility skills
             }
+            Role(v) => v.cast_snap(action, event_sink)?,
         }
 
         Ok(())
@@ -651,15 +655,13 @@ impl Job for DncJob {
         event: &Event,
         event_sink: &mut E,
     ) {
-        match event {
-            Event::Status(event) => {
-                if event.target == event_sink.source().id() && matches!(event.kind, StatusEventKind::Remove) {
-                    if event.status == STANDARD_STEP || event.status == TECHNICAL_STEP {
-                        state.step = StepGauge::None;
-                    }
-                }
+        if let Event::Status(event) = event {
+            if event.target == event_sink.source().id()
+                && matches!(event.kind, StatusEventKind::Remove)
+                && (event.status == STANDARD_STEP || event.status == TECHNICAL_STEP)
+            {
+                state.step = StepGauge::None;
             }
-            _ => ()
         }
     }
 }
@@ -714,16 +716,42 @@ impl From<DncError> for EventError {
 
 impl Display for DncError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        #[allow(clippy::match_single_binding)]
         match self {
-            _ => {
-                let _ = f;
-                todo!()
-            } // TODO: Error Display
+            Self::Feather => write!(f, "Not enough Fourfold Feathers."),
+            Self::Fan3 => status_proc_error(f, FAN_DANCE_3),
+            Self::Fan4 => status_proc_error(f, FAN_DANCE_4),
+            Self::Esprit => write!(f, "Not enough Esprit gauge, needed at least 50."),
+            Self::Starfall => status_proc_error(f, STARFALL),
+            Self::Symmetry => write!(
+                f,
+                "Not under the effect of '{}' or '{}'.",
+                SILKEN_SYMM.name, FLOURISH_SYMM.name
+            ),
+            Self::Flow => write!(
+                f,
+                "Not under the effect of '{}' or '{}'.",
+                SILKEN_FLOW.name, FLOURISH_FLOW.name
+            ),
+            Self::Tillana => status_proc_error(f, FLOURISH_FINISH),
+            Self::StandardStep => status_proc_error(f, STANDARD_STEP),
+            Self::TechnicalStep => status_proc_error(f, TECHNICAL_STEP),
+            Self::Step => write!(
+                f,
+                "Not under the effect of '{}' or '{}'.",
+                STANDARD_STEP.name, TECHNICAL_STEP.name
+            ),
+            Self::StepInvalid => write!(f, "Cannot be cast while dancing."),
+            Self::PartnerActive => write!(f, "A dance partner has already been chosen."),
+            Self::PartnerInactive => write!(f, "No dance partner has been chosen."),
+            Self::LastDance => status_proc_error(f, LAST_DANCE_READY),
+            Self::FinishingMove => status_proc_error(f, FINISHING_MOVE_READY),
+            Self::DanceOfTheDawn => status_proc_error(f, DANCE_OF_THE_DAWN_READY),
         }
     }
 }
 
+impl Error for DncError {}
+
 const RANGED: ActionTargetting = ActionTargetting::single(25);
 const CIRCLE: ActionTargetting = ActionTargetting::circle(5);
 const DANCE: ActionTargetting = ActionTargetting::circle(15);
@@ -902,6 +930,14 @@ pub enum DncAction {
     #[skill]
     #[name = "Dance of the Dawn"]
     DanceOfTheDawn,
+    #[step_valid]
+    #[gcd((v) => v.gcd())]
+    #[name((v) => v.name())]
+    #[cooldown((v) => v.cooldown())]
+    #[cd_charges((v) => v.cd_charges())]
+    #[effect_delay((v) => v.effect_delay())]
+    #[category((v) => v.category())]
+    Role(RangedRoleAction),
 }
 
 impl JobAction for DncAction {
@@ -1076,6 +1112,12 @@ job_cd_struct! {
     fan_4 Fan4: FanDance4;
     "Improvised Finish"
     improv_finish ImprovFinish: ImprovisedFinish;
+    "Second Wind"
+    second_wind SecondWind: Role(RangedRoleAction::SecondWind);
+    "Head Graze"
+    head_graze HeadGraze: Role(RangedRoleAction::HeadGraze);
+    "Peloton"
+    peloton Peloton: Role(RangedRoleAction::Peloton);
 }
 
 bool_job_dist! {
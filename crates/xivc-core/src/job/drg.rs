@@ -0,0 +1,765 @@
+use core::{
+    error::Error,
+    fmt::{self, Display},
+};
+
+use macros::var_consts;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    enums::{ActionCategory, DamageInstance},
+    err,
+    job::{role::MeleeRoleAction, CastInitInfo, Job, JobAction, JobState},
+    job_cd_struct,
+    math::SpeedStat,
+    status_effect,
+    timing::{DurationInfo, EventCascade, ScaleTime},
+    util::{
+        combo_pos_pot, combo_pot, status_proc_error, ActionTargettingExt as _, ComboState, GaugeU8,
+    },
+    world::{
+        status::{consume_status, StatusEffect, StatusEventExt},
+        Action, ActionTargetting, ActorRef, DamageEventExt, EventError, EventSink, Positional,
+        WorldRef,
+    },
+};
+
+/// The [`Job`] struct for Dragoon.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct DrgJob;
+
+/// The status effect "Power Surge".
+pub static POWER_SURGE: StatusEffect = status_effect!(
+    "Power Surge" 30000 { damage { out = 110 / 100 } }
+);
+/// The status effect "Lance Charge".
+pub static LANCE_CHARGE: StatusEffect = status_effect!(
+    "Lance Charge" 20000 { damage { out = 110 / 100 } }
+);
+/// The status effect "Battle Litany".
+pub static BATTLE_LITANY: StatusEffect = status_effect!(
+    "Battle Litany" 20000 { crit { out = 100 } }
+);
+/// The status effect "Life of the Dragon".
+pub static LIFE_OF_THE_DRAGON: StatusEffect = status_effect!(
+    "Life of the Dragon" 20000 { damage { out = 115 / 100 } }
+);
+/// The status effect "Life Surge".
+pub static LIFE_SURGE: StatusEffect = status_effect!("Life Surge" 5000);
+/// The status effect "Nastrond Ready".
+pub static NASTROND_READY: StatusEffect = status_effect!("Nastrond Ready" 20000);
+/// The status effect "Starcross Ready".
+pub static STARCROSS_READY: StatusEffect = status_effect!("Starcross Ready" 20000);
+/// The status effect "Dive Ready".
+pub static DIVE_READY: StatusEffect = status_effect!("Dive Ready" 15000);
+/// The status effect "Dragon's Flight".
+pub static DRAGONS_FLIGHT: StatusEffect = status_effect!("Dragon's Flight" 30000);
+/// The status effect "Draconian Fire".
+pub static DRACONIAN_FIRE: StatusEffect = status_effect!("Draconian Fire" 30000);
+/// The DoT effect "Chaotic Spring".
+pub static CHAOTIC_SPRING: StatusEffect = status_effect!("Chaotic Spring" 24000 multi);
+
+impl Job for DrgJob {
+    type Action = DrgAction;
+    type State = DrgState;
+    type CastError = DrgError;
+    type Event = ();
+    type CdGroup = DrgCdGroup;
+    type CdMap<T> = DrgCdMap<T>;
+
+    fn check_cast<'w, W: WorldRef<'w>, E: EventSink<'w, W>>(
+        action: Self::Action,
+        state: &Self::State,
+        _: &'w W,
+        event_sink: &mut E,
+    ) -> Result<CastInitInfo<Self::CdGroup>, EventError> {
+        let this = event_sink.source();
+
+        let di = this.duration_info();
+
+        let gcd = action.gcd().map(|v| di.scale(v)).unwrap_or_default() as u16;
+        let (lock, snap) = di.get_cast(action.cast(), 600);
+
+        let cd = action
+            .cd_group()
+            .map(|v| (v, action.cooldown(), action.cd_charges()));
+
+        let alt_cd = action.alt_cd_group().map(|v| (v, 1000, 1));
+
+        use DrgAction::*;
+        match action {
+            RaidenThrust | DraconianFury if !this.has_own_status(DRACONIAN_FIRE) => {
+                err!(DrgError::DraconianFire)
+            }
+            Drakesbane if !state.combos.check_main_for(action) => err!(DrgError::Drakesbane),
+            Stardiver if !this.has_own_status(LIFE_OF_THE_DRAGON) => {
+                err!(DrgError::LifeOfTheDragon)
+            }
+            Nastrond if !this.has_own_status(NASTROND_READY) => err!(DrgError::Nastrond),
+            Starcross if !this.has_own_status(STARCROSS_READY) => err!(DrgError::Starcross),
+            MirageDive if !this.has_own_status(DIVE_READY) => err!(DrgError::DiveReady),
+            RiseOfTheDragon if !this.has_own_status(DRAGONS_FLIGHT) => {
+                err!(DrgError::DragonsFlight)
+            }
+            WyrmwindThrust if state.focus < 2 => err!(DrgError::Focus(2)),
+            _ => (),
+        }
+
+        Ok(CastInitInfo {
+            gcd,
+            lock,
+            snap,
+            mp: 0,
+            cd,
+            alt_cd,
+        })
+    }
+
+    fn cast_snap<'w, W: WorldRef<'w>, E: EventSink<'w, W>>(
+        action: Self::Action,
+        state: &mut Self::State,
+        _: &'w W,
+        event_sink: &mut E,
+    ) -> Result<(), EventError> {
+        let this = event_sink.source();
+        let this_id = this.id();
+
+        use DrgAction::*;
+
+        let dl = action.effect_delay();
+
+        let surge = action.category() == ActionCategory::Weaponskill
+            && consume_status(event_sink, LIFE_SURGE, 0);
+        let dmg = |potency| {
+            let damage = DamageInstance::new(potency).piercing();
+            if surge {
+                damage.force_crit()
+            } else {
+                damage
+            }
+        };
+
+        match action {
+            TrueThrust | RaidenThrust => {
+                let t = this.target_enemy(MELEE)?.id();
+                let potency = if action == RaidenThrust {
+                    if !consume_status(event_sink, DRACONIAN_FIRE, 0) {
+                        err!(DrgError::DraconianFire);
+                    }
+                    state.focus += 1;
+                    320
+                } else {
+                    230
+                };
+                state.combos.main.set(MainCombo::TrueThrust);
+                event_sink.damage(action, dmg(potency), t, dl);
+            }
+            LanceBarrage | SpiralBlow => {
+                let t = this.target_enemy(MELEE)?.id();
+                let combo = state.combos.check_main_for(action);
+                if combo {
+                    state.combos.main.set(if action == LanceBarrage {
+                        MainCombo::LanceBarrage
+                    } else {
+                        MainCombo::SpiralBlow
+                    });
+                } else {
+                    state.combos.main.reset();
+                }
+                let potency = if action == LanceBarrage {
+                    combo_pot(130, 340, combo)
+                } else {
+                    if combo {
+                        event_sink.apply_status(POWER_SURGE, 1, this_id, dl);
+                    }
+                    combo_pot(140, 300, combo)
+                };
+                event_sink.damage(action, dmg(potency), t, dl);
+            }
+            HeavensThrust => {
+                let t = this.target_enemy(MELEE)?.id();
+                let combo = state.combos.check_main_for(action);
+                if combo {
+                    state.combos.main.set(MainCombo::HeavensThrust);
+                } else {
+                    state.combos.main.reset();
+                }
+                event_sink.damage(action, dmg(combo_pot(140, 440, combo)), t, dl);
+            }
+            ChaoticSpring => {
+                let t = this.target_enemy(MELEE)?.id();
+                let combo = state.combos.check_main_for(action);
+                if combo {
+                    state.combos.main.set(MainCombo::ChaoticSpring);
+                } else {
+                    state.combos.main.reset();
+                }
+                let pos = this.check_positional(Positional::Rear, t);
+                event_sink.damage(
+                    action,
+                    dmg(combo_pos_pot(140, 180, 300, 340, combo, pos)),
+                    t,
+                    dl,
+                );
+                event_sink.apply_dot(
+                    CHAOTIC_SPRING,
+                    DamageInstance::new(45).piercing(),
+                    SpeedStat::SkillSpeed,
+                    1,
+                    t,
+                    dl,
+                );
+            }
+            FangAndClaw | WheelingThrust => {
+                let t = this.target_enemy(MELEE)?.id();
+                let combo = state.combos.check_main_for(action);
+                let positional = if action == FangAndClaw {
+                    Positional::Flank
+                } else {
+                    Positional::Rear
+                };
+                if combo {
+                    state.combos.main.set(MainCombo::Finisher);
+                } else {
+                    state.combos.main.reset();
+                }
+                let pos = this.check_positional(positional, t);
+                event_sink.damage(
+                    action,
+                    dmg(combo_pos_pot(100, 140, 300, 340, combo, pos)),
+                    t,
+                    dl,
+                );
+            }
+            Drakesbane => {
+                let t = this.target_enemy(MELEE)?.id();
+                if !state.combos.check_main_for(action) {
+                    err!(DrgError::Drakesbane);
+                }
+                state.combos.main.reset();
+                event_sink.damage(action, dmg(440), t, dl);
+                event_sink.apply_status(DRACONIAN_FIRE, 1, this_id, dl);
+            }
+            DoomSpike | DraconianFury => {
+                let iter = this.target_enemy_aoe(LINE, EventCascade::new(dl, 1))?.id();
+                let potency = if action == DraconianFury {
+                    if !consume_status(event_sink, DRACONIAN_FIRE, 0) {
+                        err!(DrgError::DraconianFire);
+                    }
+                    130
+                } else {
+                    110
+                };
+                let mut hit = false;
+                for (t, d) in iter {
+                    hit = true;
+                    event_sink.damage(action, dmg(potency), t, d);
+                }
+                if hit {
+                    if action == DraconianFury {
+                        state.focus += 1;
+                    }
+                    state.combos.aoe.set(AoeCombo::DoomSpike);
+                } else {
+                    state.combos.aoe.reset();
+                }
+            }
+            SonicThrust | CoerthanTorment => {
+                let iter = this.target_enemy_aoe(LINE, EventCascade::new(dl, 1))?.id();
+                let combo = state.combos.check_aoe_for(action);
+                let mut hit = false;
+                for (t, d) in iter {
+                    hit = true;
+                    let potency = if action == SonicThrust {
+                        combo_pot(100, 120, combo)
+                    } else {
+                        combo_pot(100, 150, combo)
+                    };
+                    event_sink.damage(action, dmg(potency), t, d);
+                }
+                if hit && combo && action == SonicThrust {
+                    state.combos.aoe.set(AoeCombo::SonicThrust);
+                    event_sink.apply_status(POWER_SURGE, 1, this_id, dl);
+                } else {
+                    state.combos.aoe.reset();
+                    if hit && combo {
+                        event_sink.apply_status(DRACONIAN_FIRE, 1, this_id, dl);
+                    }
+                }
+            }
+            PiercingTalon => {
+                let t = this.target_enemy(RANGED)?.id();
+                event_sink.damage(action, dmg(150), t, dl);
+            }
+            HighJump => {
+                let t = this.target_enemy(JUMP)?.id();
+                event_sink.damage(action, DamageInstance::new(400).piercing(), t, dl);
+                event_sink.apply_status(DIVE_READY, 1, this_id, dl);
+            }
+            MirageDive => {
+                let t = this.target_enemy(JUMP)?.id();
+                if !consume_status(event_sink, DIVE_READY, 0) {
+                    err!(DrgError::DiveReady);
+                }
+                event_sink.damage(action, DamageInstance::new(380).piercing(), t, dl);
+            }
+            Geirskogul | Nastrond => {
+                let iter = this
+                    .target_enemy_aoe(LINE, EventCascade::new(dl, 1))?
+                    .id()
+                    .falloff(50);
+                let potency = if action == Nastrond {
+                    if !consume_status(event_sink, NASTROND_READY, 0) {
+                        err!(DrgError::Nastrond);
+                    }
+                    720
+                } else {
+                    event_sink.apply_status(LIFE_OF_THE_DRAGON, 1, this_id, 0);
+                    event_sink.apply_status(NASTROND_READY, 1, this_id, 0);
+                    280
+                };
+                for (t, d, f) in iter {
+                    event_sink.damage(
+                        action,
+                        DamageInstance::new(potency).piercing().falloff(f),
+                        t,
+                        d,
+                    );
+                }
+            }
+            Stardiver | Starcross | DragonfireDive | RiseOfTheDragon => {
+                let iter = this
+                    .target_enemy_aoe(TG_CIRCLE, EventCascade::new(dl, 1))?
+                    .id()
+                    .falloff(50);
+                let potency = match action {
+                    Stardiver => {
+                        event_sink.apply_status(STARCROSS_READY, 1, this_id, 0);
+                        840
+                    }
+                    Starcross => {
+                        if !consume_status(event_sink, STARCROSS_READY, 0) {
+                            err!(DrgError::Starcross);
+                        }
+                        1000
+                    }
+                    DragonfireDive => {
+                        event_sink.apply_status(DRAGONS_FLIGHT, 1, this_id, 0);
+                        500
+                    }
+                    _ => {
+                        if !consume_status(event_sink, DRAGONS_FLIGHT, 0) {
+                            err!(DrgError::DragonsFlight);
+                        }
+                        550
+                    }
+                };
+                for (t, d, f) in iter {
+                    event_sink.damage(
+                        action,
+                        DamageInstance::new(potency).piercing().falloff(f),
+                        t,
+                        d,
+                    );
+                }
+            }
+            WyrmwindThrust => {
+                let iter = this
+                    .target_enemy_aoe(LINE, EventCascade::new(dl, 1))?
+                    .id()
+                    .falloff(50);
+                if !state.focus.consume(2) {
+                    err!(DrgError::Focus(2));
+                }
+                for (t, d, f) in iter {
+                    event_sink.damage(action, DamageInstance::new(440).piercing().falloff(f), t, d);
+                }
+            }
+            LifeSurge => {
+                event_sink.apply_status(LIFE_SURGE, 1, this_id, 0);
+            }
+            LanceCharge => {
+                event_sink.apply_status(LANCE_CHARGE, 1, this_id, 0);
+            }
+            BattleLitany => {
+                for (t, d) in this
+                    .target_party_aoe(ActionTargetting::circle(30), EventCascade::new(dl, 3))?
+                    .id()
+                {
+                    event_sink.apply_status(BATTLE_LITANY, 1, t, d);
+                }
+            }
+            Role(v) => v.cast_snap(action, event_sink)?,
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Copy, Debug)]
+/// A custom cast error for Dragoon actions.
+pub enum DrgError {
+    /// Not under the effect of Draconian Fire.
+    DraconianFire,
+    /// Not executed following Fang and Claw or Wheeling Thrust.
+    Drakesbane,
+    /// Not under the effect of Life of the Dragon.
+    LifeOfTheDragon,
+    /// Not under the effect of Nastrond Ready.
+    Nastrond,
+    /// Not under the effect of Starcross Ready.
+    Starcross,
+    /// Not under the effect of Dive Ready.
+    DiveReady,
+    /// Not under the effect of Dragon's Flight.
+    DragonsFlight,
+    /// Not enough Firstminds' Focus.
+    Focus(u8),
+}
+
+impl From<DrgError> for EventError {
+    fn from(value: DrgError) -> Self {
+        Self::Job(value.into())
+    }
+}
+
+impl Display for DrgError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::DraconianFire => status_proc_error(f, DRACONIAN_FIRE),
+            Self::Drakesbane => write!(
+                f,
+                "Not executed following '{}' or '{}'.",
+                DrgAction::FangAndClaw.name(),
+                DrgAction::WheelingThrust.name(),
+            ),
+            Self::LifeOfTheDragon => status_proc_error(f, LIFE_OF_THE_DRAGON),
+            Self::Nastrond => status_proc_error(f, NASTROND_READY),
+            Self::Starcross => status_proc_error(f, STARCROSS_READY),
+            Self::DiveReady => status_proc_error(f, DIVE_READY),
+            Self::DragonsFlight => status_proc_error(f, DRAGONS_FLIGHT),
+            Self::Focus(v) => write!(f, "Not enough Firstminds' Focus, needed at least {}.", v),
+        }
+    }
+}
+
+impl Error for DrgError {}
+
+const MELEE: ActionTargetting = ActionTargetting::single(3);
+const RANGED: ActionTargetting = ActionTargetting::single(20);
+const JUMP: ActionTargetting = ActionTargetting::single(20);
+const LINE: ActionTargetting = ActionTargetting::line(15);
+const TG_CIRCLE: ActionTargetting = ActionTargetting::target_circle(5, 20);
+
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(rename_all = "snake_case")
+)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[repr(u8)]
+#[var_consts {
+    /// Returns the base GCD recast time, or `None` if the action is not a gcd.
+    pub const gcd: ScaleTime?;
+    /// Returns the base milliseconds the action takes to cast.
+    pub const cast: ScaleTime = ScaleTime::zero();
+    /// Returns the human friendly name of the action.
+    pub const name: &'static str;
+    /// Returns the cooldown of the skill in milliseconds.
+    pub const cooldown: u32 = 0;
+    /// Returns the number of charges a skill has, or `1` if it is a single charge skill.
+    pub const cd_charges: u8 = 1;
+    /// Returns the delay in milliseconds for the damage/statuses to be applied.
+    pub const effect_delay: u32 = 0;
+    /// Returns the [`ActionCategory`] this action is part of.
+    pub const category: ActionCategory;
+
+    pub const skill for {
+        gcd = ScaleTime::skill(2500);
+        category = ActionCategory::Weaponskill;
+    }
+    pub const ability for {
+        category = ActionCategory::Ability;
+    }
+}]
+#[allow(missing_docs)] // no reason to document the variants.
+/// An action specific to the Dragoon job.
+pub enum DrgAction {
+    #[skill]
+    #[name = "True Thrust"]
+    TrueThrust,
+    #[skill]
+    #[name = "Raiden Thrust"]
+    RaidenThrust,
+    #[skill]
+    #[name = "Lance Barrage"]
+    LanceBarrage,
+    #[skill]
+    #[name = "Spiral Blow"]
+    SpiralBlow,
+    #[skill]
+    #[name = "Heavens' Thrust"]
+    HeavensThrust,
+    #[skill]
+    #[name = "Chaotic Spring"]
+    ChaoticSpring,
+    #[skill]
+    #[name = "Fang and Claw"]
+    FangAndClaw,
+    #[skill]
+    #[name = "Wheeling Thrust"]
+    WheelingThrust,
+    #[skill]
+    #[name = "Drakesbane"]
+    Drakesbane,
+    #[skill]
+    #[name = "Doom Spike"]
+    DoomSpike,
+    #[skill]
+    #[name = "Draconian Fury"]
+    DraconianFury,
+    #[skill]
+    #[name = "Sonic Thrust"]
+    SonicThrust,
+    #[skill]
+    #[name = "Coerthan Torment"]
+    CoerthanTorment,
+    #[skill]
+    #[name = "Piercing Talon"]
+    PiercingTalon,
+    #[ability]
+    #[cooldown = 30000]
+    #[name = "High Jump"]
+    HighJump,
+    #[ability]
+    #[cooldown = 1000]
+    #[name = "Mirage Dive"]
+    MirageDive,
+    #[ability]
+    #[cooldown = 60000]
+    #[name = "Geirskogul"]
+    Geirskogul,
+    #[ability]
+    #[cooldown = 2000]
+    #[name = "Nastrond"]
+    Nastrond,
+    #[ability]
+    #[cooldown = 30000]
+    #[name = "Stardiver"]
+    Stardiver,
+    #[ability]
+    #[cooldown = 1000]
+    #[name = "Starcross"]
+    Starcross,
+    #[ability]
+    #[cooldown = 120000]
+    #[name = "Dragonfire Dive"]
+    DragonfireDive,
+    #[ability]
+    #[cooldown = 1000]
+    #[name = "Rise of the Dragon"]
+    RiseOfTheDragon,
+    #[ability]
+    #[cooldown = 10000]
+    #[name = "Wyrmwind Thrust"]
+    WyrmwindThrust,
+    #[ability]
+    #[cooldown = 40000]
+    #[cd_charges = 2]
+    #[name = "Life Surge"]
+    LifeSurge,
+    #[ability]
+    #[cooldown = 60000]
+    #[name = "Lance Charge"]
+    LanceCharge,
+    #[ability]
+    #[cooldown = 120000]
+    #[name = "Battle Litany"]
+    BattleLitany,
+    #[gcd((v) => v.gcd())]
+    #[cast((v) => v.cast())]
+    #[name((v) => v.name())]
+    #[cooldown((v) => v.cooldown())]
+    #[cd_charges((v) => v.cd_charges())]
+    #[effect_delay((v) => v.effect_delay())]
+    #[category((v) => v.category())]
+    Role(MeleeRoleAction),
+}
+
+impl JobAction for DrgAction {
+    fn category(&self) -> ActionCategory {
+        self.category()
+    }
+
+    fn gcd(&self) -> bool {
+        self.gcd().is_some()
+    }
+}
+
+impl From<DrgAction> for Action {
+    fn from(value: DrgAction) -> Self {
+        Action::Job(value.into())
+    }
+}
+
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+/// The state of the Dragoon job gauges, cooldowns, and combos.
+pub struct DrgState {
+    /// The combos for Dragoon.
+    pub combos: DrgCombos,
+    /// The Firstminds' Focus gauge.
+    pub focus: GaugeU8<2>,
+}
+
+impl JobState for DrgState {
+    fn advance(&mut self, time: u32) {
+        self.combos.advance(time);
+    }
+}
+
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+/// The combos for Dragoon.
+pub struct DrgCombos {
+    /// The main combo.
+    ///
+    /// Includes both the Heavens' Thrust and Chaotic Spring branches.
+    pub main: ComboState<MainCombo>,
+    /// The AoE combo.
+    ///
+    /// Includes the Sonic Thrust and Coerthan Torment combo.
+    pub aoe: ComboState<AoeCombo>,
+}
+
+impl DrgCombos {
+    /// Checks that the main combo prerequisite is met for a certain action.
+    pub fn check_main_for(&self, action: DrgAction) -> bool {
+        let c = match action {
+            DrgAction::LanceBarrage | DrgAction::SpiralBlow => MainCombo::TrueThrust,
+            DrgAction::HeavensThrust => MainCombo::LanceBarrage,
+            DrgAction::ChaoticSpring => MainCombo::SpiralBlow,
+            DrgAction::FangAndClaw => MainCombo::HeavensThrust,
+            DrgAction::WheelingThrust => MainCombo::ChaoticSpring,
+            DrgAction::Drakesbane => MainCombo::Finisher,
+            _ => return true,
+        };
+        self.main.check(c)
+    }
+
+    /// Checks that the AoE combo prerequisite is met for a certain action.
+    pub fn check_aoe_for(&self, action: DrgAction) -> bool {
+        let c = match action {
+            DrgAction::SonicThrust => AoeCombo::DoomSpike,
+            DrgAction::CoerthanTorment => AoeCombo::SonicThrust,
+            _ => return true,
+        };
+        self.aoe.check(c)
+    }
+
+    /// Advances the combos forward by a certain amount of time.
+    ///
+    /// See TODO: Advance Functions for more information.
+    pub fn advance(&mut self, time: u32) {
+        self.main.advance(time);
+        self.aoe.advance(time);
+    }
+}
+
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+/// The possible states the main combo can be in.
+pub enum MainCombo {
+    /// Combo Action: True Thrust or Raiden Thrust is met.
+    TrueThrust,
+    /// Combo Action: Lance Barrage is met.
+    LanceBarrage,
+    /// Combo Action: Spiral Blow is met.
+    SpiralBlow,
+    /// Combo Action: Heavens' Thrust is met.
+    HeavensThrust,
+    /// Combo Action: Chaotic Spring is met.
+    ChaoticSpring,
+    /// Combo Action: Fang and Claw or Wheeling Thrust is met.
+    Finisher,
+}
+
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+/// The possible states the AoE combo can be in.
+pub enum AoeCombo {
+    /// Combo Action: Doom Spike or Draconian Fury is met.
+    DoomSpike,
+    /// Combo Action: Sonic Thrust is met.
+    SonicThrust,
+}
+
+job_cd_struct! {
+    DrgAction =>
+
+    #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+    #[derive(Clone, Debug, Default)]
+    /// The cooldown map for Dragoon actions.
+    pub DrgCdMap
+
+    #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+    #[derive(Copy, Clone, Debug)]
+    /// The various cooldown groups a Dragoon action can be part of.
+    pub DrgCdGroup
+
+    "High Jump"
+    jump Jump: HighJump;
+    "Mirage Dive"
+    mirage Mirage: MirageDive;
+    "Geirskogul"
+    geirskogul Geirskogul: Geirskogul;
+    "Nastrond"
+    nastrond Nastrond: Nastrond;
+    "Stardiver"
+    stardiver Stardiver: Stardiver;
+    "Starcross"
+    starcross Starcross: Starcross;
+    "Dragonfire Dive"
+    dfd DragonfireDive: DragonfireDive;
+    "Rise of the Dragon"
+    rise Rise: RiseOfTheDragon;
+    "Wyrmwind Thrust"
+    wyrmwind Wyrmwind: WyrmwindThrust;
+    "Life Surge"
+    life_surge LifeSurge: LifeSurge;
+    "Lance Charge"
+    lance_charge LanceCharge: LanceCharge;
+    "Battle Litany"
+    litany Litany: BattleLitany;
+    "True North"
+    true_north TrueNorth: Role(MeleeRoleAction::TrueNorth);
+    "Feint"
+    feint Feint: Role(MeleeRoleAction::Feint);
+    "Bloodbath"
+    bloodbath Bloodbath: Role(MeleeRoleAction::Bloodbath);
+    "Second Wind"
+    second_wind SecondWind: Role(MeleeRoleAction::SecondWind);
+    "Life Surge Charge"
+    life_surge_chg LifeSurgeChg;
+    "True North Charge"
+    true_north_chg TrueNorthChg;
+}
+
+impl DrgAction {
+    /// Returns the alternate [cooldown group] that this action is part of.
+    ///
+    /// Returns `None` if this action does not have an alternate cooldown.
+    /// This action is used for the 1s cooldown between uses of charged actions.
+    ///
+    /// [cooldown group]: DrgCdGroup
+    pub const fn alt_cd_group(&self) -> Option<DrgCdGroup> {
+        match self {
+            Self::LifeSurge => Some(DrgCdGroup::LifeSurgeChg),
+            Self::Role(MeleeRoleAction::TrueNorth) => Some(DrgCdGroup::TrueNorthChg),
+            _ => None,
+        }
+    }
+}
@@ -0,0 +1,777 @@
+use core::{
+    error::Error,
+    fmt::{self, Display},
+};
+
+use macros::var_consts;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    enums::{ActionCategory, DamageElement, DamageInstance, DamageType},
+    err,
+    job::{role::TankRoleAction, CastInitInfo, Job, JobAction, JobEvent, JobState},
+    job_cd_struct, job_effect_wrapper,
+    math::SpeedStat,
+    status_effect,
+    timing::{DurationInfo, EventCascade, ScaleTime},
+    util::{combo_pot, status_proc_error, ActionTargettingExt as _, ComboState, GaugeU8},
+    world::{
+        status::{consume_status, consume_status_stack, JobEffect, StatusEffect, StatusEventExt},
+        Action, ActionTargetting, ActorRef, DamageEventExt, Event, EventError, EventSink, Faction,
+        WorldRef,
+    },
+};
+
+/// The [`Job`] struct for Dark Knight.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct DrkJob;
+
+/// The status effect "Delirium".
+pub static DELIRIUM: StatusEffect = status_effect!("Delirium" 15000);
+/// The status effect "Blood Weapon".
+pub static BLOOD_WEAPON: StatusEffect = status_effect!("Blood Weapon" 15000);
+/// The status effect "Scorn".
+pub static SCORN: StatusEffect = status_effect!("Scorn" 30000);
+/// The ground effect "Salted Earth".
+///
+/// This is never applied to a target, and is only used for its duration.
+pub static SALTED_EARTH: StatusEffect = status_effect!("Salted Earth" 15000 multi);
+
+/// The maximum duration of Darkside in milliseconds.
+pub const DARKSIDE_MAX: u32 = 60000;
+
+// the potencies and delays of the attacks the living shadow executes
+const LIVING_SHADOW: [(u64, u32); 6] = [
+    (420, 6800),
+    (420, 8960),
+    (420, 11120),
+    (420, 13280),
+    (420, 15440),
+    (620, 17600),
+];
+
+impl Job for DrkJob {
+    type Action = DrkAction;
+    type State = DrkState;
+    type CastError = DrkError;
+    type Event = DrkEvent;
+    type CdGroup = DrkCdGroup;
+    type CdMap<T> = DrkCdMap<T>;
+
+    fn check_cast<'w, W: WorldRef<'w>, E: EventSink<'w, W>>(
+        action: Self::Action,
+        state: &Self::State,
+        _: &'w W,
+        event_sink: &mut E,
+    ) -> Result<CastInitInfo<Self::CdGroup>, EventError> {
+        let this = event_sink.source();
+
+        let di = this.duration_info();
+
+        let gcd = action.gcd().map(|v| di.scale(v)).unwrap_or_default() as u16;
+        let (lock, snap) = di.get_cast(action.cast(), 600);
+
+        let cd = action
+            .cd_group()
+            .map(|v| (v, action.cooldown(), action.cd_charges()));
+
+        let alt_cd = action.alt_cd_group().map(|v| (v, 1000, 1));
+
+        use DrkAction::*;
+        match action {
+            Bloodspiller | Quietus if state.blood < 50 => err!(DrkError::Blood(50)),
+            ScarletDelirium | Comeuppance | Torcleaver | Impalement
+                if !this.has_own_status(DELIRIUM) =>
+            {
+                err!(DrkError::Delirium)
+            }
+            Comeuppance | Torcleaver if !state.combos.check_delirium_for(action) => {
+                err!(DrkError::DeliriumCombo(match action {
+                    Comeuppance => ScarletDelirium,
+                    _ => Comeuppance,
+                }))
+            }
+            Shadowbringer if state.darkside == 0 => err!(DrkError::Darkside),
+            SaltAndDarkness if state.salted_earth == 0 => err!(DrkError::SaltedEarth),
+            Disesteem if !this.has_own_status(SCORN) => err!(DrkError::Scorn),
+            _ => (),
+        }
+
+        Ok(CastInitInfo {
+            gcd,
+            lock,
+            snap,
+            mp: action.mp_cost(),
+            cd,
+            alt_cd,
+        })
+    }
+
+    fn cast_snap<'w, W: WorldRef<'w>, E: EventSink<'w, W>>(
+        action: Self::Action,
+        state: &mut Self::State,
+        _: &'w W,
+        event_sink: &mut E,
+    ) -> Result<(), EventError> {
+        let this = event_sink.source();
+        let this_id = this.id();
+
+        use DrkAction::*;
+
+        let dl = action.effect_delay();
+
+        if action.category().skill_or_spell() && consume_status_stack(event_sink, BLOOD_WEAPON, 0) {
+            state.blood += 10;
+            event_sink.event(Event::AddMp(600, this_id), dl);
+        }
+
+        match action {
+            HardSlash => {
+                let t = this.target_enemy(MELEE)?.id();
+                state.combos.main.set(MainCombo::HardSlash);
+                event_sink.damage(action, DamageInstance::new(300).slashing(), t, dl);
+            }
+            SyphonStrike => {
+                let t = this.target_enemy(MELEE)?.id();
+                let combo = if state.combos.check_main_for(action) {
+                    state.combos.main.set(MainCombo::SyphonStrike);
+                    event_sink.event(Event::AddMp(600, this_id), dl);
+                    true
+                } else {
+                    state.combos.main.reset();
+                    false
+                };
+                event_sink.damage(
+                    action,
+                    DamageInstance::new(combo_pot(240, 380, combo)).slashing(),
+                    t,
+                    dl,
+                );
+            }
+            Souleater => {
+                let t = this.target_enemy(MELEE)?.id();
+                let combo = state.combos.check_main_for(action);
+                state.combos.main.reset();
+                if combo {
+                    state.blood += 20;
+                }
+                event_sink.damage(
+                    action,
+                    DamageInstance::new(combo_pot(280, 480, combo)).slashing(),
+                    t,
+                    dl,
+                );
+            }
+            Unleash => {
+                let mut hit = false;
+                for (t, d) in this
+                    .target_enemy_aoe(CIRCLE, EventCascade::new(dl, 1))?
+                    .id()
+                {
+                    hit = true;
+                    event_sink.damage(action, DamageInstance::new(120).magical(), t, d);
+                }
+                if hit {
+                    state.combos.aoe.set(AoeCombo::Unleash);
+                } else {
+                    state.combos.aoe.reset();
+                }
+            }
+            StalwartSoul => {
+                let combo = state.combos.check_aoe_for(action);
+                state.combos.aoe.reset();
+                let mut hit = false;
+                for (t, d) in this
+                    .target_enemy_aoe(CIRCLE, EventCascade::new(dl, 1))?
+                    .id()
+                {
+                    hit = true;
+                    event_sink.damage(
+                        action,
+                        DamageInstance::new(combo_pot(120, 160, combo)).magical(),
+                        t,
+                        d,
+                    );
+                }
+                if hit && combo {
+                    state.blood += 20;
+                    event_sink.event(Event::AddMp(600, this_id), dl);
+                }
+            }
+            Unmend => {
+                let t = this.target_enemy(RANGED)?.id();
+                event_sink.damage(action, DamageInstance::new(150).magical(), t, dl);
+            }
+            Bloodspiller => {
+                let t = this.target_enemy(MELEE)?.id();
+                if !state.blood.consume(50) {
+                    err!(DrkError::Blood(50));
+                }
+                event_sink.damage(action, DamageInstance::new(580).magical(), t, dl);
+            }
+            Quietus => {
+                let iter = this
+                    .target_enemy_aoe(CIRCLE, EventCascade::new(dl, 1))?
+                    .id();
+                if !state.blood.consume(50) {
+                    err!(DrkError::Blood(50));
+                }
+                for (t, d) in iter {
+                    event_sink.damage(action, DamageInstance::new(240).magical(), t, d);
+                }
+            }
+            ScarletDelirium | Comeuppance | Torcleaver => {
+                let t = this.target_enemy(MELEE)?.id();
+                if !consume_status_stack(event_sink, DELIRIUM, 0) {
+                    err!(DrkError::Delirium);
+                }
+                let potency = match action {
+                    ScarletDelirium => {
+                        state.combos.delirium.set(DeliriumCombo::ScarletDelirium);
+                        600
+                    }
+                    Comeuppance => {
+                        state.combos.delirium.set(DeliriumCombo::Comeuppance);
+                        700
+                    }
+                    _ => {
+                        state.combos.delirium.reset();
+                        800
+                    }
+                };
+                event_sink.damage(action, DamageInstance::new(potency).magical(), t, dl);
+            }
+            Impalement => {
+                let iter = this
+                    .target_enemy_aoe(CIRCLE, EventCascade::new(dl, 1))?
+                    .id();
+                if !consume_status_stack(event_sink, DELIRIUM, 0) {
+                    err!(DrkError::Delirium);
+                }
+                for (t, d) in iter {
+                    event_sink.damage(action, DamageInstance::new(320).magical(), t, d);
+                }
+            }
+            EdgeOfShadow => {
+                let t = this.target_enemy(MELEE)?.id();
+                state.add_darkside();
+                event_sink.damage(action, DamageInstance::new(460).magical(), t, dl);
+            }
+            FloodOfShadow => {
+                let iter = this.target_enemy_aoe(LINE, EventCascade::new(dl, 1))?.id();
+                state.add_darkside();
+                for (t, d) in iter {
+                    event_sink.damage(action, DamageInstance::new(160).magical(), t, d);
+                }
+            }
+            CarveAndSpit => {
+                let t = this.target_enemy(MELEE)?.id();
+                event_sink.event(Event::AddMp(600, this_id), dl);
+                event_sink.damage(action, DamageInstance::new(540).magical(), t, dl);
+            }
+            AbyssalDrain => {
+                for (t, d) in this
+                    .target_enemy_aoe(TG_CIRCLE, EventCascade::new(dl, 1))?
+                    .id()
+                {
+                    event_sink.damage(action, DamageInstance::new(240).magical(), t, d);
+                }
+            }
+            SaltedEarth => {
+                state.salted_earth = SALTED_EARTH.duration as u16;
+                // salted earth is a ground effect, so the enemies hit
+                // are decided on every tick instead of when it is placed.
+                let mut delay = dl;
+                while delay < dl + SALTED_EARTH.duration {
+                    event_sink.event(JobEvent::drk(DrkEvent::SaltedEarthTick, this_id), delay);
+                    delay += 3000;
+                }
+            }
+            SaltAndDarkness => {
+                for (t, d) in this
+                    .target_enemy_aoe(CIRCLE, EventCascade::new(dl, 1))?
+                    .id()
+                {
+                    event_sink.damage(action, DamageInstance::new(500).magical(), t, d);
+                }
+            }
+            Shadowbringer => {
+                for (t, d, f) in this
+                    .target_enemy_aoe(LINE, EventCascade::new(dl, 1))?
+                    .id()
+                    .falloff(50)
+                {
+                    event_sink.damage(action, DamageInstance::new(600).magical().falloff(f), t, d);
+                }
+            }
+            Delirium => {
+                event_sink.apply_status(DELIRIUM, 3, this_id, 0);
+                event_sink.apply_status(BLOOD_WEAPON, 3, this_id, 0);
+            }
+            LivingShadow => {
+                this.target_enemy(MELEE)?;
+                // the damage of each attack is resolved when it lands,
+                // so that it uses the buffs active at that time.
+                for (potency, delay) in LIVING_SHADOW {
+                    event_sink.event(
+                        JobEvent::drk(DrkEvent::LivingShadow { potency }, this_id),
+                        delay,
+                    );
+                }
+                event_sink.apply_status(SCORN, 1, this_id, 0);
+            }
+            Disesteem => {
+                let iter = this
+                    .target_enemy_aoe(LINE, EventCascade::new(dl, 1))?
+                    .id()
+                    .falloff(50);
+                if !consume_status(event_sink, SCORN, 0) {
+                    err!(DrkError::Scorn);
+                }
+                for (t, d, f) in iter {
+                    event_sink.damage(action, DamageInstance::new(1000).magical().falloff(f), t, d);
+                }
+            }
+            Role(v) => v.cast_snap(action, event_sink)?,
+        }
+
+        Ok(())
+    }
+
+    fn event<'w, W: WorldRef<'w>, E: EventSink<'w, W>>(
+        state: &mut Self::State,
+        _: &'w W,
+        event: &Event,
+        event_sink: &mut E,
+    ) {
+        let this = event_sink.source();
+        let this_id = this.id();
+        match event {
+            Event::Job(JobEvent::Drk(DrkEvent::LivingShadow { potency }), src_id)
+                if *src_id == this_id =>
+            {
+                if let Some(target) = this.target() {
+                    event_sink.damage(
+                        DrkAction::LivingShadow,
+                        DamageInstance::new(*potency).magical(),
+                        target.id(),
+                        0,
+                    );
+                }
+            }
+            // positions are not part of the world, so the area is centered on the caster.
+            Event::Job(JobEvent::Drk(DrkEvent::SaltedEarthTick), src_id)
+                if *src_id == this_id && state.salted_earth > 0 =>
+            {
+                for target in this.actors_for_action(Some(Faction::Enemy), CIRCLE) {
+                    event_sink.dot_tick(
+                        DrkAction::SaltedEarth,
+                        DamageInstance::new(50).magical(),
+                        SpeedStat::SkillSpeed,
+                        target.id(),
+                        0,
+                    );
+                }
+            }
+            _ => (),
+        }
+    }
+
+    fn effect(state: &Self::State) -> Option<&dyn JobEffect> {
+        Some(DrkJobEffect::new(state))
+    }
+}
+
+job_effect_wrapper! {
+    #[derive(Debug)]
+    struct DrkJobEffect(DrkState);
+}
+impl JobEffect for DrkJobEffect {
+    fn damage(&self, damage: u64, _: DamageType, _: DamageElement) -> u64 {
+        if self.0.darkside > 0 {
+            damage * 110 / 100
+        } else {
+            damage
+        }
+    }
+}
+
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Copy, Debug)]
+/// A custom cast error for Dark Knight actions.
+pub enum DrkError {
+    /// Not enough Blood gauge.
+    Blood(u8),
+    /// Darkside is not active.
+    Darkside,
+    /// Not under the effect of Delirium.
+    Delirium,
+    /// Not executed following the specified action.
+    DeliriumCombo(DrkAction),
+    /// Not under the effect of Scorn.
+    Scorn,
+    /// Salted Earth is not active.
+    SaltedEarth,
+}
+
+impl From<DrkError> for EventError {
+    fn from(value: DrkError) -> Self {
+        Self::Job(value.into())
+    }
+}
+
+impl Display for DrkError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Blood(v) => write!(f, "Not enough Blood Gauge, needed at least {}.", v),
+            Self::Darkside => write!(f, "Darkside is not active."),
+            Self::Delirium => status_proc_error(f, DELIRIUM),
+            Self::DeliriumCombo(ac) => write!(f, "Not executed following '{}'.", ac.name()),
+            Self::Scorn => status_proc_error(f, SCORN),
+            Self::SaltedEarth => write!(f, "'{}' is not active.", DrkAction::SaltedEarth.name()),
+        }
+    }
+}
+
+impl Error for DrkError {}
+
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+/// A custom event for Dark Knight.
+pub enum DrkEvent {
+    /// The Living Shadow will perform an attack.
+    LivingShadow {
+        /// The potency of the attack.
+        potency: u64,
+    },
+    /// Salted Earth will deal damage to the enemies standing in it.
+    SaltedEarthTick,
+}
+
+const MELEE: ActionTargetting = ActionTargetting::single(3);
+const RANGED: ActionTargetting = ActionTargetting::single(20);
+const CIRCLE: ActionTargetting = ActionTargetting::circle(5);
+const LINE: ActionTargetting = ActionTargetting::line(10);
+const TG_CIRCLE: ActionTargetting = ActionTargetting::target_circle(5, 3);
+
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(rename_all = "snake_case")
+)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[repr(u8)]
+#[var_consts {
+    /// Returns the base GCD recast time, or `None` if the action is not a gcd.
+    pub const gcd: ScaleTime?;
+    /// Returns the base milliseconds the action takes to cast.
+    pub const cast: ScaleTime = ScaleTime::zero();
+    /// Returns the human friendly name of the action.
+    pub const name: &'static str;
+    /// Returns the cooldown of the skill in milliseconds.
+    pub const cooldown: u32 = 0;
+    /// Returns the number of charges a skill has, or `1` if it is a single charge skill.
+    pub const cd_charges: u8 = 1;
+    /// Returns the delay in milliseconds for the damage/statuses to be applied.
+    pub const effect_delay: u32 = 0;
+    /// Returns the MP cost of the action.
+    pub const mp_cost: u16 = 0;
+    /// Returns the [`ActionCategory`] this action is part of.
+    pub const category: ActionCategory;
+
+    pub const skill for {
+        gcd = ScaleTime::skill(2500);
+        category = ActionCategory::Weaponskill;
+    }
+    pub const spell for {
+        gcd = ScaleTime::spell(2500);
+        category = ActionCategory::Spell;
+    }
+    pub const ability for {
+        category = ActionCategory::Ability;
+    }
+}]
+#[allow(missing_docs)] // no reason to document the variants.
+/// An action specific to the Dark Knight job.
+pub enum DrkAction {
+    #[skill]
+    #[name = "Hard Slash"]
+    HardSlash,
+    #[skill]
+    #[name = "Syphon Strike"]
+    SyphonStrike,
+    #[skill]
+    #[name = "Souleater"]
+    Souleater,
+    #[spell]
+    #[name = "Unleash"]
+    Unleash,
+    #[skill]
+    #[name = "Stalwart Soul"]
+    StalwartSoul,
+    #[spell]
+    #[name = "Unmend"]
+    Unmend,
+    #[skill]
+    #[name = "Bloodspiller"]
+    Bloodspiller,
+    #[skill]
+    #[name = "Quietus"]
+    Quietus,
+    #[skill]
+    #[name = "Scarlet Delirium"]
+    ScarletDelirium,
+    #[skill]
+    #[name = "Comeuppance"]
+    Comeuppance,
+    #[skill]
+    #[name = "Torcleaver"]
+    Torcleaver,
+    #[skill]
+    #[name = "Impalement"]
+    Impalement,
+    #[ability]
+    #[cooldown = 1000]
+    #[mp_cost = 3000]
+    #[name = "Edge of Shadow"]
+    EdgeOfShadow,
+    #[ability]
+    #[cooldown = 1000]
+    #[mp_cost = 3000]
+    #[name = "Flood of Shadow"]
+    FloodOfShadow,
+    #[ability]
+    #[cooldown = 60000]
+    #[name = "Carve and Spit"]
+    CarveAndSpit,
+    #[ability]
+    #[cooldown = 60000]
+    #[name = "Abyssal Drain"]
+    AbyssalDrain,
+    #[ability]
+    #[cooldown = 90000]
+    #[name = "Salted Earth"]
+    SaltedEarth,
+    #[ability]
+    #[cooldown = 20000]
+    #[name = "Salt and Darkness"]
+    SaltAndDarkness,
+    #[ability]
+    #[cooldown = 60000]
+    #[cd_charges = 2]
+    #[name = "Shadowbringer"]
+    Shadowbringer,
+    #[ability]
+    #[cooldown = 60000]
+    #[name = "Delirium"]
+    Delirium,
+    #[ability]
+    #[cooldown = 120000]
+    #[name = "Living Shadow"]
+    LivingShadow,
+    #[skill]
+    #[name = "Disesteem"]
+    Disesteem,
+    #[gcd((v) => v.gcd())]
+    #[cast((v) => v.cast())]
+    #[name((v) => v.name())]
+    #[cooldown((v) => v.cooldown())]
+    #[cd_charges((v) => v.cd_charges())]
+    #[effect_delay((v) => v.effect_delay())]
+    #[mp_cost((v) => v.mp_cost())]
+    #[category((v) => v.category())]
+    Role(TankRoleAction),
+}
+
+impl JobAction for DrkAction {
+    fn category(&self) -> ActionCategory {
+        self.category()
+    }
+
+    fn gcd(&self) -> bool {
+        self.gcd().is_some()
+    }
+}
+
+impl From<DrkAction> for Action {
+    fn from(value: DrkAction) -> Self {
+        Action::Job(value.into())
+    }
+}
+
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+/// The state of the Dark Knight job gauges, cooldowns, and combos.
+pub struct DrkState {
+    /// The combos for Dark Knight.
+    pub combos: DrkCombos,
+    /// The Blood gauge.
+    pub blood: GaugeU8<100>,
+    /// The remaining time of Darkside in milliseconds.
+    ///
+    /// Darkside is active while this is greater than `0`.
+    pub darkside: u32,
+    /// The remaining time of Salted Earth in milliseconds.
+    pub salted_earth: u16,
+}
+
+impl DrkState {
+    /// Extends Darkside by 30 seconds, up to a maximum of [`DARKSIDE_MAX`].
+    pub fn add_darkside(&mut self) {
+        self.darkside = (self.darkside + 30000).min(DARKSIDE_MAX);
+    }
+}
+
+impl JobState for DrkState {
+    fn advance(&mut self, time: u32) {
+        self.combos.advance(time);
+        self.darkside = self.darkside.saturating_sub(time);
+        self.salted_earth = (self.salted_earth as u32).saturating_sub(time) as u16;
+    }
+}
+
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+/// The combos for Dark Knight.
+pub struct DrkCombos {
+    /// The main combo.
+    ///
+    /// Includes the Syphon Strike and Souleater combo.
+    pub main: ComboState<MainCombo>,
+    /// The AoE combo.
+    ///
+    /// Includes the Stalwart Soul combo.
+    pub aoe: ComboState<AoeCombo>,
+    /// The combo for the Delirium chain.
+    pub delirium: ComboState<DeliriumCombo>,
+}
+
+impl DrkCombos {
+    /// Checks that the main combo prerequisite is met for a certain action.
+    pub fn check_main_for(&self, action: DrkAction) -> bool {
+        let c = match action {
+            DrkAction::SyphonStrike => MainCombo::HardSlash,
+            DrkAction::Souleater => MainCombo::SyphonStrike,
+            _ => return true,
+        };
+        self.main.check(c)
+    }
+
+    /// Checks that the AoE combo prerequisite is met for a certain action.
+    pub fn check_aoe_for(&self, action: DrkAction) -> bool {
+        let c = match action {
+            DrkAction::StalwartSoul => AoeCombo::Unleash,
+            _ => return true,
+        };
+        self.aoe.check(c)
+    }
+
+    /// Checks that the Delirium combo prerequisite is met for a certain action.
+    pub fn check_delirium_for(&self, action: DrkAction) -> bool {
+        let c = match action {
+            DrkAction::Comeuppance => DeliriumCombo::ScarletDelirium,
+            DrkAction::Torcleaver => DeliriumCombo::Comeuppance,
+            _ => return true,
+        };
+        self.delirium.check(c)
+    }
+
+    /// Advances the combos forward by a certain amount of time.
+    ///
+    /// See TODO: Advance Functions for more information.
+    pub fn advance(&mut self, time: u32) {
+        self.main.advance(time);
+        self.aoe.advance(time);
+        self.delirium.advance(time);
+    }
+}
+
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+/// The possible states the main combo can be in.
+pub enum MainCombo {
+    /// Combo Action: Hard Slash is met.
+    HardSlash,
+    /// Combo Action: Syphon Strike is met.
+    SyphonStrike,
+}
+
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+/// The possible states the AoE combo can be in.
+pub enum AoeCombo {
+    /// Combo Action: Unleash is met.
+    Unleash,
+}
+
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+/// The possible states the Delirium combo can be in.
+pub enum DeliriumCombo {
+    /// Combo Action: Scarlet Delirium is met.
+    ScarletDelirium,
+    /// Combo Action: Comeuppance is met.
+    Comeuppance,
+}
+
+job_cd_struct! {
+    DrkAction =>
+
+    #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+    #[derive(Clone, Debug, Default)]
+    /// The cooldown map for Dark Knight actions.
+    pub DrkCdMap
+
+    #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+    #[derive(Copy, Clone, Debug)]
+    /// The various cooldown groups a Dark Knight action can be part of.
+    pub DrkCdGroup
+
+    "Edge/Flood of Shadow"
+    shadow Shadow: EdgeOfShadow FloodOfShadow;
+    "Carve and Spit and Abyssal Drain"
+    carve Carve: CarveAndSpit AbyssalDrain;
+    "Salted Earth"
+    salted_earth SaltedEarth: SaltedEarth;
+    "Salt and Darkness"
+    salt_darkness SaltAndDarkness: SaltAndDarkness;
+    "Shadowbringer"
+    shadowbringer Shadowbringer: Shadowbringer;
+    "Delirium"
+    delirium Delirium: Delirium;
+    "Living Shadow"
+    living_shadow LivingShadow: LivingShadow;
+    "Rampart"
+    rampart Rampart: Role(TankRoleAction::Rampart);
+    "Reprisal"
+    reprisal Reprisal: Role(TankRoleAction::Reprisal);
+    "Arm's Length"
+    arms_length ArmsLength: Role(TankRoleAction::ArmsLength);
+    "Provoke"
+    provoke Provoke: Role(TankRoleAction::Provoke);
+    "Shirk"
+    shirk Shirk: Role(TankRoleAction::Shirk);
+    "Interject"
+    interject Interject: Role(TankRoleAction::Interject);
+    "Low Blow"
+    low_blow LowBlow: Role(TankRoleAction::LowBlow);
+    "Shadowbringer Charge"
+    shadowbringer_chg ShadowbringerChg;
+}
+
+impl DrkAction {
+    /// Returns the alternate [cooldown group] that this action is part of.
+    ///
+    /// Returns `None` if this action does not have an alternate cooldown.
+    /// This action is used for the 1s cooldown between uses of charged actions.
+    ///
+    /// [cooldown group]: DrkCdGroup
+    pub const fn alt_cd_group(&self) -> Option<DrkCdGroup> {
+        match self {
+            Self::Shadowbringer => Some(DrkCdGroup::ShadowbringerChg),
+            _ => None,
+        }
+    }
+}
@@ -0,0 +1,739 @@
+use core::{
+    error::Error,
+    fmt::{self, Display},
+};
+
+use macros::var_consts;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    enums::{ActionCategory, DamageInstance},
+    err,
+    job::{role::TankRoleAction, CastInitInfo, Job, JobAction, JobState},
+    job_cd_struct,
+    math::SpeedStat,
+    status_effect,
+    timing::{DurationInfo, EventCascade, ScaleTime},
+    util::{combo_pot, status_proc_error, ActionTargettingExt as _, ComboState, GaugeU8},
+    world::{
+        status::{consume_status, StatusEffect, StatusEventExt},
+        Action, ActionTargetting, ActorRef, DamageEventExt, EventError, EventSink, WorldRef,
+    },
+};
+
+/// The [`Job`] struct for Gunbreaker.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct GnbJob;
+
+/// The status effect "No Mercy".
+pub static NO_MERCY: StatusEffect = status_effect!(
+    "No Mercy" 20000 { damage { out = 120 / 100 } }
+);
+/// The status effect "Ready to Break".
+pub static READY_TO_BREAK: StatusEffect = status_effect!("Ready to Break" 30000);
+/// The status effect "Ready to Rip".
+pub static READY_TO_RIP: StatusEffect = status_effect!("Ready to Rip" 10000);
+/// The status effect "Ready to Tear".
+pub static READY_TO_TEAR: StatusEffect = status_effect!("Ready to Tear" 10000);
+/// The status effect "Ready to Gouge".
+pub static READY_TO_GOUGE: StatusEffect = status_effect!("Ready to Gouge" 10000);
+/// The status effect "Ready to Blast".
+pub static READY_TO_BLAST: StatusEffect = status_effect!("Ready to Blast" 10000);
+/// The status effect "Ready to Raze".
+pub static READY_TO_RAZE: StatusEffect = status_effect!("Ready to Raze" 10000);
+/// The status effect "Ready to Reign".
+pub static READY_TO_REIGN: StatusEffect = status_effect!("Ready to Reign" 30000);
+/// The DoT effect "Sonic Break".
+pub static SONIC_BREAK: StatusEffect = status_effect!("Sonic Break" 30000 multi);
+/// The DoT effect "Bow Shock".
+pub static BOW_SHOCK: StatusEffect = status_effect!("Bow Shock" 15000 multi);
+
+impl Job for GnbJob {
+    type Action = GnbAction;
+    type State = GnbState;
+    type CastError = GnbError;
+    type Event = ();
+    type CdGroup = GnbCdGroup;
+    type CdMap<T> = GnbCdMap<T>;
+
+    fn check_cast<'w, W: WorldRef<'w>, E: EventSink<'w, W>>(
+        action: Self::Action,
+        state: &Self::State,
+        _: &'w W,
+        event_sink: &mut E,
+    ) -> Result<CastInitInfo<Self::CdGroup>, EventError> {
+        let this = event_sink.source();
+
+        let di = this.duration_info();
+
+        let gcd = action.gcd().map(|v| di.scale(v)).unwrap_or_default() as u16;
+        let (lock, snap) = di.get_cast(action.cast(), 600);
+
+        let cd = action
+            .cd_group()
+            .map(|v| (v, action.cooldown(), action.cd_charges()));
+
+        use GnbAction::*;
+        match action {
+            BurstStrike | FatedCircle | GnashingFang | DoubleDown if state.cartridge < 1 => {
+                err!(GnbError::Cartridge(1))
+            }
+            SavageClaw | WickedTalon if !state.combos.check_gnashing_for(action) => {
+                err!(GnbError::GnashingCombo(match action {
+                    SavageClaw => GnashingFang,
+                    _ => SavageClaw,
+                }))
+            }
+            SonicBreak if !this.has_own_status(READY_TO_BREAK) => err!(GnbError::Break),
+            JugularRip if !this.has_own_status(READY_TO_RIP) => err!(GnbError::Rip),
+            AbdomenTear if !this.has_own_status(READY_TO_TEAR) => err!(GnbError::Tear),
+            EyeGouge if !this.has_own_status(READY_TO_GOUGE) => err!(GnbError::Gouge),
+            Hypervelocity if !this.has_own_status(READY_TO_BLAST) => err!(GnbError::Blast),
+            FatedBrand if !this.has_own_status(READY_TO_RAZE) => err!(GnbError::Raze),
+            ReignOfBeasts if !this.has_own_status(READY_TO_REIGN) => err!(GnbError::Reign),
+            NobleBlood | LionHeart if !state.combos.check_reign_for(action) => {
+                err!(GnbError::ReignCombo(match action {
+                    NobleBlood => ReignOfBeasts,
+                    _ => NobleBlood,
+                }))
+            }
+            _ => (),
+        }
+
+        Ok(CastInitInfo {
+            gcd,
+            lock,
+            snap,
+            mp: 0,
+            cd,
+            alt_cd: None,
+        })
+    }
+
+    fn cast_snap<'w, W: WorldRef<'w>, E: EventSink<'w, W>>(
+        action: Self::Action,
+        state: &mut Self::State,
+        _: &'w W,
+        event_sink: &mut E,
+    ) -> Result<(), EventError> {
+        let this = event_sink.source();
+        let this_id = this.id();
+
+        use GnbAction::*;
+
+        let dl = action.effect_delay();
+
+        match action {
+            KeenEdge => {
+                let t = this.target_enemy(MELEE)?.id();
+                state.combos.main.set(MainCombo::KeenEdge);
+                event_sink.damage(action, DamageInstance::new(300).slashing(), t, dl);
+            }
+            BrutalShell => {
+                let t = this.target_enemy(MELEE)?.id();
+                let combo = if state.combos.check_main_for(action) {
+                    state.combos.main.set(MainCombo::BrutalShell);
+                    true
+                } else {
+                    state.combos.main.reset();
+                    false
+                };
+                event_sink.damage(
+                    action,
+                    DamageInstance::new(combo_pot(160, 380, combo)).slashing(),
+                    t,
+                    dl,
+                );
+            }
+            SolidBarrel => {
+                let t = this.target_enemy(MELEE)?.id();
+                let combo = state.combos.check_main_for(action);
+                state.combos.main.reset();
+                if combo {
+                    state.cartridge += 1;
+                }
+                event_sink.damage(
+                    action,
+                    DamageInstance::new(combo_pot(140, 460, combo)).slashing(),
+                    t,
+                    dl,
+                );
+            }
+            DemonSlice => {
+                let mut hit = false;
+                for (t, d) in this
+                    .target_enemy_aoe(CIRCLE, EventCascade::new(dl, 1))?
+                    .id()
+                {
+                    hit = true;
+                    event_sink.damage(action, DamageInstance::new(100).slashing(), t, d);
+                }
+                if hit {
+                    state.combos.aoe.set(AoeCombo::DemonSlice);
+                } else {
+                    state.combos.aoe.reset();
+                }
+            }
+            DemonSlaughter => {
+                let combo = state.combos.check_aoe_for(action);
+                state.combos.aoe.reset();
+                let mut hit = false;
+                for (t, d) in this
+                    .target_enemy_aoe(CIRCLE, EventCascade::new(dl, 1))?
+                    .id()
+                {
+                    hit = true;
+                    event_sink.damage(
+                        action,
+                        DamageInstance::new(combo_pot(100, 160, combo)).slashing(),
+                        t,
+                        d,
+                    );
+                }
+                if hit && combo {
+                    state.cartridge += 1;
+                }
+            }
+            LightningShot => {
+                let t = this.target_enemy(RANGED)?.id();
+                event_sink.damage(action, DamageInstance::new(150).slashing(), t, dl);
+            }
+            BurstStrike => {
+                let t = this.target_enemy(MELEE)?.id();
+                if !state.cartridge.consume(1) {
+                    err!(GnbError::Cartridge(1));
+                }
+                event_sink.damage(action, DamageInstance::new(460).slashing(), t, dl);
+                event_sink.apply_status(READY_TO_BLAST, 1, this_id, 0);
+            }
+            FatedCircle => {
+                let iter = this
+                    .target_enemy_aoe(CIRCLE, EventCascade::new(dl, 1))?
+                    .id();
+                if !state.cartridge.consume(1) {
+                    err!(GnbError::Cartridge(1));
+                }
+                for (t, d) in iter {
+                    event_sink.damage(action, DamageInstance::new(300).slashing(), t, d);
+                }
+                event_sink.apply_status(READY_TO_RAZE, 1, this_id, 0);
+            }
+            GnashingFang => {
+                let t = this.target_enemy(MELEE)?.id();
+                if !state.cartridge.consume(1) {
+                    err!(GnbError::Cartridge(1));
+                }
+                state.combos.gnashing.set(GnashingCombo::GnashingFang);
+                event_sink.damage(action, DamageInstance::new(500).slashing(), t, dl);
+                event_sink.apply_status(READY_TO_RIP, 1, this_id, 0);
+            }
+            SavageClaw => {
+                let t = this.target_enemy(MELEE)?.id();
+                state.combos.gnashing.set(GnashingCombo::SavageClaw);
+                event_sink.damage(action, DamageInstance::new(560).slashing(), t, dl);
+                event_sink.apply_status(READY_TO_TEAR, 1, this_id, 0);
+            }
+            WickedTalon => {
+                let t = this.target_enemy(MELEE)?.id();
+                state.combos.gnashing.reset();
+                event_sink.damage(action, DamageInstance::new(640).slashing(), t, dl);
+                event_sink.apply_status(READY_TO_GOUGE, 1, this_id, 0);
+            }
+            JugularRip | AbdomenTear | EyeGouge | Hypervelocity => {
+                let t = this.target_enemy(MELEE)?.id();
+                let (ready, potency) = match action {
+                    JugularRip => (READY_TO_RIP, 240),
+                    AbdomenTear => (READY_TO_TEAR, 280),
+                    EyeGouge => (READY_TO_GOUGE, 320),
+                    _ => (READY_TO_BLAST, 220),
+                };
+                if !consume_status(event_sink, ready, 0) {
+                    err!(match action {
+                        JugularRip => GnbError::Rip,
+                        AbdomenTear => GnbError::Tear,
+                        EyeGouge => GnbError::Gouge,
+                        _ => GnbError::Blast,
+                    });
+                }
+                event_sink.damage(action, DamageInstance::new(potency).slashing(), t, dl);
+            }
+            FatedBrand => {
+                let iter = this
+                    .target_enemy_aoe(CIRCLE, EventCascade::new(dl, 1))?
+                    .id();
+                if !consume_status(event_sink, READY_TO_RAZE, 0) {
+                    err!(GnbError::Raze);
+                }
+                for (t, d) in iter {
+                    event_sink.damage(action, DamageInstance::new(120).slashing(), t, d);
+                }
+            }
+            DoubleDown => {
+                let iter = this
+                    .target_enemy_aoe(CIRCLE, EventCascade::new(dl, 1))?
+                    .id()
+                    .falloff(85);
+                if !state.cartridge.consume(1) {
+                    err!(GnbError::Cartridge(1));
+                }
+                for (t, d, f) in iter {
+                    event_sink.damage(
+                        action,
+                        DamageInstance::new(1200).slashing().falloff(f),
+                        t,
+                        d,
+                    );
+                }
+            }
+            SonicBreak => {
+                let t = this.target_enemy(MELEE)?.id();
+                if !consume_status(event_sink, READY_TO_BREAK, 0) {
+                    err!(GnbError::Break);
+                }
+                event_sink.damage(action, DamageInstance::new(300).slashing(), t, dl);
+                event_sink.apply_dot(
+                    SONIC_BREAK,
+                    DamageInstance::new(60).slashing(),
+                    SpeedStat::SkillSpeed,
+                    1,
+                    t,
+                    dl,
+                );
+            }
+            BowShock => {
+                for (t, d) in this
+                    .target_enemy_aoe(CIRCLE, EventCascade::new(dl, 1))?
+                    .id()
+                {
+                    event_sink.damage(action, DamageInstance::new(150).slashing(), t, d);
+                    event_sink.apply_dot(
+                        BOW_SHOCK,
+                        DamageInstance::new(60).slashing(),
+                        SpeedStat::SkillSpeed,
+                        1,
+                        t,
+                        d,
+                    );
+                }
+            }
+            BlastingZone => {
+                let t = this.target_enemy(MELEE)?.id();
+                event_sink.damage(action, DamageInstance::new(800).slashing(), t, dl);
+            }
+            NoMercy => {
+                event_sink.apply_status(NO_MERCY, 1, this_id, 0);
+                event_sink.apply_status(READY_TO_BREAK, 1, this_id, 0);
+            }
+            Bloodfest => {
+                this.target_enemy(RANGED)?;
+                state.cartridge.set_max();
+                event_sink.apply_status(READY_TO_REIGN, 1, this_id, 0);
+            }
+            ReignOfBeasts | NobleBlood | LionHeart => {
+                let iter = this
+                    .target_enemy_aoe(TG_CIRCLE, EventCascade::new(dl, 1))?
+                    .id()
+                    .falloff(40);
+                let potency = match action {
+                    ReignOfBeasts => {
+                        if !consume_status(event_sink, READY_TO_REIGN, 0) {
+                            err!(GnbError::Reign);
+                        }
+                        state.combos.reign.set(ReignCombo::ReignOfBeasts);
+                        800
+                    }
+                    NobleBlood => {
+                        state.combos.reign.set(ReignCombo::NobleBlood);
+                        1000
+                    }
+                    _ => {
+                        state.combos.reign.reset();
+                        1200
+                    }
+                };
+                for (t, d, f) in iter {
+                    event_sink.damage(
+                        action,
+                        DamageInstance::new(potency).slashing().falloff(f),
+                        t,
+                        d,
+                    );
+                }
+            }
+            Role(v) => v.cast_snap(action, event_sink)?,
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Copy, Debug)]
+/// A custom cast error for Gunbreaker actions.
+pub enum GnbError {
+    /// Not enough cartridges in the Powder gauge.
+    Cartridge(u8),
+    /// Not executed following the specified action.
+    GnashingCombo(GnbAction),
+    /// Not executed following the specified action.
+    ReignCombo(GnbAction),
+    /// Not under the effect of Ready to Break.
+    Break,
+    /// Not under the effect of Ready to Rip.
+    Rip,
+    /// Not under the effect of Ready to Tear.
+    Tear,
+    /// Not under the effect of Ready to Gouge.
+    Gouge,
+    /// Not under the effect of Ready to Blast.
+    Blast,
+    /// Not under the effect of Ready to Raze.
+    Raze,
+    /// Not under the effect of Ready to Reign.
+    Reign,
+}
+
+impl From<GnbError> for EventError {
+    fn from(value: GnbError) -> Self {
+        Self::Job(value.into())
+    }
+}
+
+impl Display for GnbError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Cartridge(v) => write!(f, "Not enough cartridges, needed at least {}.", v),
+            Self::GnashingCombo(ac) | Self::ReignCombo(ac) => {
+                write!(f, "Not executed following '{}'.", ac.name())
+            }
+            Self::Break => status_proc_error(f, READY_TO_BREAK),
+            Self::Rip => status_proc_error(f, READY_TO_RIP),
+            Self::Tear => status_proc_error(f, READY_TO_TEAR),
+            Self::Gouge => status_proc_error(f, READY_TO_GOUGE),
+            Self::Blast => status_proc_error(f, READY_TO_BLAST),
+            Self::Raze => status_proc_error(f, READY_TO_RAZE),
+            Self::Reign => status_proc_error(f, READY_TO_REIGN),
+        }
+    }
+}
+
+impl Error for GnbError {}
+
+const MELEE: ActionTargetting = ActionTargetting::single(3);
+const RANGED: ActionTargetting = ActionTargetting::single(20);
+const CIRCLE: ActionTargetting = ActionTargetting::circle(5);
+const TG_CIRCLE: ActionTargetting = ActionTargetting::target_circle(5, 3);
+
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(rename_all = "snake_case")
+)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[repr(u8)]
+#[var_consts {
+    /// Returns the base GCD recast time, or `None` if the action is not a gcd.
+    pub const gcd: ScaleTime?;
+    /// Returns the base milliseconds the action takes to cast.
+    pub const cast: ScaleTime = ScaleTime::zero();
+    /// Returns the human friendly name of the action.
+    pub const name: &'static str;
+    /// Returns the cooldown of the skill in milliseconds.
+    pub const cooldown: u32 = 0;
+    /// Returns the number of charges a skill has, or `1` if it is a single charge skill.
+    pub const cd_charges: u8 = 1;
+    /// Returns the delay in milliseconds for the damage/statuses to be applied.
+    pub const effect_delay: u32 = 0;
+    /// Returns the [`ActionCategory`] this action is part of.
+    pub const category: ActionCategory;
+
+    pub const skill for {
+        gcd = ScaleTime::skill(2500);
+        category = ActionCategory::Weaponskill;
+    }
+    pub const ability for {
+        category = ActionCategory::Ability;
+    }
+}]
+#[allow(missing_docs)] // no reason to document the variants.
+/// An action specific to the Gunbreaker job.
+pub enum GnbAction {
+    #[skill]
+    #[name = "Keen Edge"]
+    KeenEdge,
+    #[ability]
+    #[cooldown = 60000]
+    #[name = "No Mercy"]
+    NoMercy,
+    #[skill]
+    #[name = "Brutal Shell"]
+    BrutalShell,
+    #[skill]
+    #[name = "Demon Slice"]
+    DemonSlice,
+    #[skill]
+    #[name = "Lightning Shot"]
+    LightningShot,
+    #[skill]
+    #[name = "Solid Barrel"]
+    SolidBarrel,
+    #[skill]
+    #[name = "Burst Strike"]
+    BurstStrike,
+    #[skill]
+    #[name = "Demon Slaughter"]
+    DemonSlaughter,
+    #[skill]
+    #[name = "Sonic Break"]
+    SonicBreak,
+    #[skill]
+    #[cooldown = 30000]
+    #[name = "Gnashing Fang"]
+    GnashingFang,
+    #[skill]
+    #[name = "Savage Claw"]
+    SavageClaw,
+    #[skill]
+    #[name = "Wicked Talon"]
+    WickedTalon,
+    #[ability]
+    #[cooldown = 60000]
+    #[name = "Bow Shock"]
+    BowShock,
+    #[ability]
+    #[cooldown = 1000]
+    #[name = "Jugular Rip"]
+    JugularRip,
+    #[ability]
+    #[cooldown = 1000]
+    #[name = "Abdomen Tear"]
+    AbdomenTear,
+    #[ability]
+    #[cooldown = 1000]
+    #[name = "Eye Gouge"]
+    EyeGouge,
+    #[skill]
+    #[name = "Fated Circle"]
+    FatedCircle,
+    #[ability]
+    #[cooldown = 120000]
+    #[name = "Bloodfest"]
+    Bloodfest,
+    #[ability]
+    #[cooldown = 30000]
+    #[name = "Blasting Zone"]
+    BlastingZone,
+    #[ability]
+    #[cooldown = 1000]
+    #[name = "Hypervelocity"]
+    Hypervelocity,
+    #[skill]
+    #[cooldown = 60000]
+    #[name = "Double Down"]
+    DoubleDown,
+    #[ability]
+    #[cooldown = 1000]
+    #[name = "Fated Brand"]
+    FatedBrand,
+    #[skill]
+    #[name = "Reign of Beasts"]
+    ReignOfBeasts,
+    #[skill]
+    #[name = "Noble Blood"]
+    NobleBlood,
+    #[skill]
+    #[name = "Lion Heart"]
+    LionHeart,
+    #[gcd((v) => v.gcd())]
+    #[cast((v) => v.cast())]
+    #[name((v) => v.name())]
+    #[cooldown((v) => v.cooldown())]
+    #[cd_charges((v) => v.cd_charges())]
+    #[effect_delay((v) => v.effect_delay())]
+    #[category((v) => v.category())]
+    Role(TankRoleAction),
+}
+
+impl JobAction for GnbAction {
+    fn category(&self) -> ActionCategory {
+        self.category()
+    }
+
+    fn gcd(&self) -> bool {
+        self.gcd().is_some()
+    }
+}
+
+impl From<GnbAction> for Action {
+    fn from(value: GnbAction) -> Self {
+        Action::Job(value.into())
+    }
+}
+
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+/// The state of the Gunbreaker job gauges, cooldowns, and combos.
+pub struct GnbState {
+    /// The combos for Gunbreaker.
+    pub combos: GnbCombos,
+    /// The Powder gauge.
+    pub cartridge: GaugeU8<3>,
+}
+
+impl JobState for GnbState {
+    fn advance(&mut self, time: u32) {
+        self.combos.advance(time);
+    }
+}
+
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+/// The combos for Gunbreaker.
+pub struct GnbCombos {
+    /// The main combo.
+    ///
+    /// Includes the Brutal Shell and Solid Barrel combo.
+    pub main: ComboState<MainCombo>,
+    /// The AoE combo.
+    ///
+    /// Includes the Demon Slaughter combo.
+    pub aoe: ComboState<AoeCombo>,
+    /// The Gnashing Fang combo.
+    pub gnashing: ComboState<GnashingCombo>,
+    /// The Reign of Beasts combo.
+    pub reign: ComboState<ReignCombo>,
+}
+
+impl GnbCombos {
+    /// Checks that the main combo prerequisite is met for a certain action.
+    pub fn check_main_for(&self, action: GnbAction) -> bool {
+        let c = match action {
+            GnbAction::BrutalShell => MainCombo::KeenEdge,
+            GnbAction::SolidBarrel => MainCombo::BrutalShell,
+            _ => return true,
+        };
+        self.main.check(c)
+    }
+
+    /// Checks that the AoE combo prerequisite is met for a certain action.
+    pub fn check_aoe_for(&self, action: GnbAction) -> bool {
+        let c = match action {
+            GnbAction::DemonSlaughter => AoeCombo::DemonSlice,
+            _ => return true,
+        };
+        self.aoe.check(c)
+    }
+
+    /// Checks that the Gnashing Fang combo prerequisite is met for a certain action.
+    pub fn check_gnashing_for(&self, action: GnbAction) -> bool {
+        let c = match action {
+            GnbAction::SavageClaw => GnashingCombo::GnashingFang,
+            GnbAction::WickedTalon => GnashingCombo::SavageClaw,
+            _ => return true,
+        };
+        self.gnashing.check(c)
+    }
+
+    /// Checks that the Reign of Beasts combo prerequisite is met for a certain action.
+    pub fn check_reign_for(&self, action: GnbAction) -> bool {
+        let c = match action {
+            GnbAction::NobleBlood => ReignCombo::ReignOfBeasts,
+            GnbAction::LionHeart => ReignCombo::NobleBlood,
+            _ => return true,
+        };
+        self.reign.check(c)
+    }
+
+    /// Advances the combos forward by a certain amount of time.
+    ///
+    /// See TODO: Advance Functions for more information.
+    pub fn advance(&mut self, time: u32) {
+        self.main.advance(time);
+        self.aoe.advance(time);
+        self.gnashing.advance(time);
+        self.reign.advance(time);
+    }
+}
+
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+/// The possible states the main combo can be in.
+pub enum MainCombo {
+    /// Combo Action: Keen Edge is met.
+    KeenEdge,
+    /// Combo Action: Brutal Shell is met.
+    BrutalShell,
+}
+
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+/// The possible states the AoE combo can be in.
+pub enum AoeCombo {
+    /// Combo Action: Demon Slice is met.
+    DemonSlice,
+}
+
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+/// The possible states the Gnashing Fang combo can be in.
+pub enum GnashingCombo {
+    /// Combo Action: Gnashing Fang is met.
+    GnashingFang,
+    /// Combo Action: Savage Claw is met.
+    SavageClaw,
+}
+
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+/// The possible states the Reign of Beasts combo can be in.
+pub enum ReignCombo {
+    /// Combo Action: Reign of Beasts is met.
+    ReignOfBeasts,
+    /// Combo Action: Noble Blood is met.
+    NobleBlood,
+}
+
+job_cd_struct! {
+    GnbAction =>
+
+    #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+    #[derive(Clone, Debug, Default)]
+    /// The cooldown map for Gunbreaker actions.
+    pub GnbCdMap
+
+    #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+    #[derive(Copy, Clone, Debug)]
+    /// The various cooldown groups a Gunbreaker action can be part of.
+    pub GnbCdGroup
+
+    "No Mercy"
+    no_mercy NoMercy: NoMercy;
+    "Gnashing Fang"
+    gnashing Gnashing: GnashingFang;
+    "Bow Shock"
+    bow_shock BowShock: BowShock;
+    "Continuation"
+    continuation Continuation: JugularRip AbdomenTear EyeGouge Hypervelocity FatedBrand;
+    "Bloodfest"
+    bloodfest Bloodfest: Bloodfest;
+    "Blasting Zone"
+    blasting_zone BlastingZone: BlastingZone;
+    "Double Down"
+    double_down DoubleDown: DoubleDown;
+    "Rampart"
+    rampart Rampart: Role(TankRoleAction::Rampart);
+    "Reprisal"
+    reprisal Reprisal: Role(TankRoleAction::Reprisal);
+    "Arm's Length"
+    arms_length ArmsLength: Role(TankRoleAction::ArmsLength);
+    "Provoke"
+    provoke Provoke: Role(TankRoleAction::Provoke);
+    "Shirk"
+    shirk Shirk: Role(TankRoleAction::Shirk);
+    "Interject"
+    interject Interject: Role(TankRoleAction::Interject);
+    "Low Blow"
+    low_blow LowBlow: Role(TankRoleAction::LowBlow);
+}
@@ -0,0 +1,684 @@
+use core::{
+    error::Error,
+    fmt::{self, Display},
+};
+
+use macros::var_consts;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    enums::{ActionCategory, DamageInstance},
+    err,
+    job::{role::RangedRoleAction, CastInitInfo, Job, JobAction, JobEvent, JobState},
+    job_cd_struct, status_effect,
+    timing::{DurationInfo, EventCascade, ScaleTime},
+    util::{combo_pot, status_proc_error, ActionTargettingExt as _, ComboState, GaugeU8},
+    world::{
+        status::{consume_status, consume_status_stack, StatusEffect, StatusEventExt},
+        Action, ActionTargetting, ActorId, ActorRef, DamageEventExt, Event, EventError, EventSink,
+        WorldRef,
+    },
+};
+
+/// The [`Job`] struct for Machinist.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct MchJob;
+
+/// The status effect "Reassembled".
+pub static REASSEMBLED: StatusEffect = status_effect!("Reassembled" 5000);
+/// The status effect "Overheated".
+pub static OVERHEATED: StatusEffect = status_effect!("Overheated" 10000);
+/// The status effect "Hypercharged".
+pub static HYPERCHARGED: StatusEffect = status_effect!("Hypercharged" 30000);
+/// The status effect "Excavator Ready".
+pub static EXCAVATOR_READY: StatusEffect = status_effect!("Excavator Ready" 30000);
+/// The status effect "Full Metal Machinist".
+pub static FULL_METAL_MACHINIST: StatusEffect = status_effect!("Full Metal Machinist" 30000);
+/// The status effect "Wildfire".
+pub static WILDFIRE: StatusEffect = status_effect!("Wildfire" 10000 multi);
+
+impl Job for MchJob {
+    type Action = MchAction;
+    type State = MchState;
+    type CastError = MchError;
+    type Event = MchEvent;
+    type CdGroup = MchCdGroup;
+    type CdMap<T> = MchCdMap<T>;
+
+    fn check_cast<'w, W: WorldRef<'w>, E: EventSink<'w, W>>(
+        action: Self::Action,
+        state: &Self::State,
+        _: &'w W,
+        event_sink: &mut E,
+    ) -> Result<CastInitInfo<Self::CdGroup>, EventError> {
+        let this = event_sink.source();
+
+        let di = this.duration_info();
+
+        let gcd = action.gcd().map(|v| di.scale(v)).unwrap_or_default() as u16;
+        let (lock, snap) = di.get_cast(action.cast(), 600);
+
+        let cd = action
+            .cd_group()
+            .map(|v| (v, action.cooldown(), action.cd_charges()));
+
+        let alt_cd = action.alt_cd_group().map(|v| (v, 1000, 1));
+
+        use MchAction::*;
+        match action {
+            HeatBlast | AutoCrossbow if !this.has_own_status(OVERHEATED) => {
+                err!(MchError::Overheated)
+            }
+            Hypercharge if this.has_own_status(OVERHEATED) => err!(MchError::Overheated),
+            Hypercharge if state.heat < 50 && !this.has_own_status(HYPERCHARGED) => {
+                err!(MchError::Heat(50))
+            }
+            AutomatonQueen if state.queen => err!(MchError::Queen),
+            AutomatonQueen if state.battery < 50 => err!(MchError::Battery(50)),
+            Excavator if !this.has_own_status(EXCAVATOR_READY) => err!(MchError::Excavator),
+            FullMetalField if !this.has_own_status(FULL_METAL_MACHINIST) => {
+                err!(MchError::FullMetalField)
+            }
+            _ => (),
+        }
+
+        Ok(CastInitInfo {
+            gcd,
+            lock,
+            snap,
+            mp: 0,
+            cd,
+            alt_cd,
+        })
+    }
+
+    fn cast_snap<'w, W: WorldRef<'w>, E: EventSink<'w, W>>(
+        action: Self::Action,
+        state: &mut Self::State,
+        _: &'w W,
+        event_sink: &mut E,
+    ) -> Result<(), EventError> {
+        let this = event_sink.source();
+        let this_id = this.id();
+
+        use MchAction::*;
+
+        let dl = action.effect_delay();
+
+        let reassembled = action.category() == ActionCategory::Weaponskill
+            && consume_status(event_sink, REASSEMBLED, 0);
+        // overheated increases the potency of single target weaponskills.
+        let overheated = if action.category() == ActionCategory::Weaponskill
+            && this.has_own_status(OVERHEATED)
+        {
+            20
+        } else {
+            0
+        };
+        let dmg = |potency| {
+            let damage = DamageInstance::new(potency).piercing();
+            if reassembled {
+                damage.force_crit().force_dhit()
+            } else {
+                damage
+            }
+        };
+
+        match action {
+            HeatedSplitShot => {
+                let t = this.target_enemy(RANGED)?.id();
+                state.combos.main.set(MainCombo::SplitShot);
+                state.heat += 5;
+                event_sink.damage(action, dmg(220 + overheated), t, dl);
+            }
+            HeatedSlugShot => {
+                let t = this.target_enemy(RANGED)?.id();
+                let combo = state.combos.check_main_for(action);
+                if combo {
+                    state.combos.main.set(MainCombo::SlugShot);
+                    state.heat += 5;
+                } else {
+                    state.combos.main.reset();
+                }
+                event_sink.damage(action, dmg(combo_pot(140, 320, combo) + overheated), t, dl);
+            }
+            HeatedCleanShot => {
+                let t = this.target_enemy(RANGED)?.id();
+                let combo = state.combos.check_main_for(action);
+                state.combos.main.reset();
+                if combo {
+                    state.heat += 5;
+                    state.battery += 10;
+                }
+                event_sink.damage(action, dmg(combo_pot(160, 400, combo) + overheated), t, dl);
+            }
+            Scattergun => {
+                state.heat += 10;
+                for (t, d) in this.target_enemy_aoe(CONE, EventCascade::new(dl, 1))?.id() {
+                    event_sink.damage(action, dmg(160), t, d);
+                }
+            }
+            Drill | AirAnchor => {
+                let t = this.target_enemy(RANGED)?.id();
+                if action == AirAnchor {
+                    state.battery += 20;
+                }
+                event_sink.damage(action, dmg(600 + overheated), t, dl);
+            }
+            ChainSaw | Excavator | FullMetalField => {
+                let iter = match action {
+                    ChainSaw => this.target_enemy_aoe(LINE, EventCascade::new(dl, 1))?,
+                    _ => this.target_enemy_aoe(TG_CIRCLE, EventCascade::new(dl, 1))?,
+                }
+                .id()
+                .falloff(if action == FullMetalField { 50 } else { 35 });
+                let damage = match action {
+                    ChainSaw => {
+                        state.battery += 20;
+                        event_sink.apply_status(EXCAVATOR_READY, 1, this_id, dl);
+                        dmg(600)
+                    }
+                    Excavator => {
+                        if !consume_status(event_sink, EXCAVATOR_READY, 0) {
+                            err!(MchError::Excavator);
+                        }
+                        state.battery += 20;
+                        dmg(600)
+                    }
+                    _ => {
+                        if !consume_status(event_sink, FULL_METAL_MACHINIST, 0) {
+                            err!(MchError::FullMetalField);
+                        }
+                        dmg(900).force_crit().force_dhit()
+                    }
+                };
+                for (t, d, f) in iter {
+                    event_sink.damage(action, damage.falloff(f), t, d);
+                }
+            }
+            HeatBlast | AutoCrossbow => {
+                let iter = match action {
+                    HeatBlast => this.target_enemy_aoe(RANGED, EventCascade::new(dl, 1))?,
+                    _ => this.target_enemy_aoe(CONE, EventCascade::new(dl, 1))?,
+                }
+                .id();
+                if !consume_status_stack(event_sink, OVERHEATED, 0) {
+                    err!(MchError::Overheated);
+                }
+                let potency = if action == HeatBlast {
+                    200 + overheated
+                } else {
+                    160
+                };
+                for (t, d) in iter {
+                    event_sink.damage(action, dmg(potency), t, d);
+                }
+                // heat blast and auto crossbow reduce the recast of
+                // double check and checkmate by 15 seconds.
+                event_sink.event(
+                    Event::AdvCd(MchCdGroup::DoubleCheck.into(), 15000, this_id),
+                    0,
+                );
+                event_sink.event(
+                    Event::AdvCd(MchCdGroup::Checkmate.into(), 15000, this_id),
+                    0,
+                );
+            }
+            DoubleCheck | Checkmate => {
+                for (t, d, f) in this
+                    .target_enemy_aoe(TG_CIRCLE, EventCascade::new(dl, 1))?
+                    .id()
+                    .falloff(50)
+                {
+                    event_sink.damage(action, DamageInstance::new(170).piercing().falloff(f), t, d);
+                }
+            }
+            Reassemble => {
+                event_sink.apply_status(REASSEMBLED, 1, this_id, 0);
+            }
+            Hypercharge => {
+                if !consume_status(event_sink, HYPERCHARGED, 0) && !state.heat.consume(50) {
+                    err!(MchError::Heat(50));
+                }
+                event_sink.apply_status(OVERHEATED, 5, this_id, 0);
+            }
+            BarrelStabilizer => {
+                event_sink.apply_status(HYPERCHARGED, 1, this_id, 0);
+                event_sink.apply_status(FULL_METAL_MACHINIST, 1, this_id, 0);
+            }
+            Wildfire => {
+                let t = this.target_enemy(RANGED)?.id();
+                state.wildfire = Some((t, 0));
+                event_sink.apply_status(WILDFIRE, 1, t, dl);
+                event_sink.event(
+                    JobEvent::mch(MchEvent::Wildfire, this_id),
+                    dl + WILDFIRE.duration,
+                );
+            }
+            AutomatonQueen => {
+                let battery = state.battery.value();
+                if battery < 50 {
+                    err!(MchError::Battery(50));
+                }
+                state.battery.clear();
+                state.queen = true;
+                // the queen lasts 1 second for every 5 battery consumed.
+                let duration = battery as u32 * 200;
+                let mut queen = |attack, delay| {
+                    event_sink.event(
+                        JobEvent::mch(MchEvent::Queen { attack, battery }, this_id),
+                        delay,
+                    )
+                };
+                queen(QueenAttack::RollerDash, 5000);
+                let mut delay = 6500;
+                while delay < duration {
+                    queen(QueenAttack::ArmPunch, delay);
+                    delay += 1500;
+                }
+                queen(QueenAttack::PileBunker, delay);
+                queen(QueenAttack::CrownedCollider, delay + 1500);
+            }
+            Role(v) => v.cast_snap(action, event_sink)?,
+        }
+
+        Ok(())
+    }
+
+    fn event<'w, W: WorldRef<'w>, E: EventSink<'w, W>>(
+        state: &mut Self::State,
+        _: &'w W,
+        event: &Event,
+        event_sink: &mut E,
+    ) {
+        let this = event_sink.source();
+        let this_id = this.id();
+        match event {
+            // wildfire counts the weaponskills that hit its target.
+            Event::Damage(event)
+                if event.source == this_id
+                    && event.action.category() == ActionCategory::Weaponskill =>
+            {
+                if let Some((target, hits)) = &mut state.wildfire {
+                    if *target == event.target {
+                        *hits = (*hits + 1).min(6);
+                    }
+                }
+            }
+            Event::Job(JobEvent::Mch(event), src_id) if *src_id == this_id => match event {
+                MchEvent::Wildfire => {
+                    if let Some((target, hits)) = state.wildfire.take() {
+                        event_sink.damage(
+                            MchAction::Wildfire,
+                            DamageInstance::new(240 * hits as u64).piercing(),
+                            target,
+                            0,
+                        );
+                    }
+                }
+                MchEvent::Queen { attack, battery } => {
+                    if *attack == QueenAttack::CrownedCollider {
+                        state.queen = false;
+                    }
+                    if let Some(target) = this.target() {
+                        event_sink.damage(
+                            MchAction::AutomatonQueen,
+                            DamageInstance::new(attack.potency(*battery)).blunt(),
+                            target.id(),
+                            0,
+                        );
+                    }
+                }
+            },
+            _ => (),
+        }
+    }
+}
+
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Copy, Debug)]
+/// A custom cast error for Machinist actions.
+pub enum MchError {
+    /// Not enough Heat.
+    Heat(u8),
+    /// Not enough Battery.
+    Battery(u8),
+    /// Not under the effect of Overheated, or
+    /// already under the effect of Overheated for Hypercharge.
+    Overheated,
+    /// The Automaton Queen is already summoned.
+    Queen,
+    /// Not under the effect of Excavator Ready.
+    Excavator,
+    /// Not under the effect of Full Metal Machinist.
+    FullMetalField,
+}
+
+impl From<MchError> for EventError {
+    fn from(value: MchError) -> Self {
+        Self::Job(value.into())
+    }
+}
+
+impl Display for MchError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Heat(v) => write!(f, "Not enough Heat, needed at least {}.", v),
+            Self::Battery(v) => write!(f, "Not enough Battery, needed at least {}.", v),
+            Self::Overheated => status_proc_error(f, OVERHEATED),
+            Self::Queen => write!(f, "The Automaton Queen is already summoned."),
+            Self::Excavator => status_proc_error(f, EXCAVATOR_READY),
+            Self::FullMetalField => status_proc_error(f, FULL_METAL_MACHINIST),
+        }
+    }
+}
+
+impl Error for MchError {}
+
+const RANGED: ActionTargetting = ActionTargetting::single(25);
+const CONE: ActionTargetting = ActionTargetting::cone(12, 90);
+const LINE: ActionTargetting = ActionTargetting::line(25);
+const TG_CIRCLE: ActionTargetting = ActionTargetting::target_circle(5, 25);
+
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(rename_all = "snake_case")
+)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[repr(u8)]
+#[var_consts {
+    /// Returns the base GCD recast time, or `None` if the action is not a gcd.
+    pub const gcd: ScaleTime?;
+    /// Returns the base milliseconds the action takes to cast.
+    pub const cast: ScaleTime = ScaleTime::zero();
+    /// Returns the human friendly name of the action.
+    pub const name: &'static str;
+    /// Returns the cooldown of the skill in milliseconds.
+    pub const cooldown: u32 = 0;
+    /// Returns the number of charges a skill has, or `1` if it is a single charge skill.
+    pub const cd_charges: u8 = 1;
+    /// Returns the delay in milliseconds for the damage/statuses to be applied.
+    pub const effect_delay: u32 = 0;
+    /// Returns the [`ActionCategory`] this action is part of.
+    pub const category: ActionCategory;
+
+    pub const skill for {
+        gcd = ScaleTime::skill(2500);
+        category = ActionCategory::Weaponskill;
+    }
+    pub const ability for {
+        category = ActionCategory::Ability;
+    }
+}]
+#[allow(missing_docs)] // no reason to document the variants.
+/// An action specific to the Machinist job.
+pub enum MchAction {
+    #[skill]
+    #[name = "Heated Split Shot"]
+    HeatedSplitShot,
+    #[skill]
+    #[name = "Heated Slug Shot"]
+    HeatedSlugShot,
+    #[skill]
+    #[name = "Heated Clean Shot"]
+    HeatedCleanShot,
+    #[skill]
+    #[name = "Scattergun"]
+    Scattergun,
+    #[skill]
+    #[cooldown = 20000]
+    #[cd_charges = 2]
+    #[name = "Drill"]
+    Drill,
+    #[skill]
+    #[cooldown = 40000]
+    #[name = "Air Anchor"]
+    AirAnchor,
+    #[skill]
+    #[cooldown = 60000]
+    #[name = "Chain Saw"]
+    ChainSaw,
+    #[skill]
+    #[name = "Excavator"]
+    Excavator,
+    #[skill]
+    #[name = "Full Metal Field"]
+    FullMetalField,
+    #[gcd = ScaleTime::none(1500)]
+    #[skill]
+    #[name = "Heat Blast"]
+    HeatBlast,
+    #[gcd = ScaleTime::none(1500)]
+    #[skill]
+    #[name = "Auto Crossbow"]
+    AutoCrossbow,
+    #[ability]
+    #[cooldown = 30000]
+    #[cd_charges = 3]
+    #[name = "Double Check"]
+    DoubleCheck,
+    #[ability]
+    #[cooldown = 30000]
+    #[cd_charges = 3]
+    #[name = "Checkmate"]
+    Checkmate,
+    #[ability]
+    #[cooldown = 55000]
+    #[cd_charges = 2]
+    #[name = "Reassemble"]
+    Reassemble,
+    #[ability]
+    #[cooldown = 10000]
+    #[name = "Hypercharge"]
+    Hypercharge,
+    #[ability]
+    #[cooldown = 120000]
+    #[name = "Barrel Stabilizer"]
+    BarrelStabilizer,
+    #[ability]
+    #[cooldown = 120000]
+    #[name = "Wildfire"]
+    Wildfire,
+    #[ability]
+    #[cooldown = 6000]
+    #[name = "Automaton Queen"]
+    AutomatonQueen,
+    #[gcd((v) => v.gcd())]
+    #[cast((v) => v.cast())]
+    #[name((v) => v.name())]
+    #[cooldown((v) => v.cooldown())]
+    #[cd_charges((v) => v.cd_charges())]
+    #[effect_delay((v) => v.effect_delay())]
+    #[category((v) => v.category())]
+    Role(RangedRoleAction),
+}
+
+impl JobAction for MchAction {
+    fn category(&self) -> ActionCategory {
+        self.category()
+    }
+
+    fn gcd(&self) -> bool {
+        self.gcd().is_some()
+    }
+}
+
+impl From<MchAction> for Action {
+    fn from(value: MchAction) -> Self {
+        Action::Job(value.into())
+    }
+}
+
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+/// The state of the Machinist job gauges, cooldowns, and combos.
+pub struct MchState {
+    /// The combos for Machinist.
+    pub combos: MchCombos,
+    /// The Heat gauge.
+    pub heat: GaugeU8<100>,
+    /// The Battery gauge.
+    pub battery: GaugeU8<100>,
+    /// The target of Wildfire and the number of weaponskills
+    /// that have hit it, or [`None`] if Wildfire is not active.
+    pub wildfire: Option<(ActorId, u8)>,
+    /// If the Automaton Queen is currently summoned.
+    pub queen: bool,
+}
+
+impl JobState for MchState {
+    fn advance(&mut self, time: u32) {
+        self.combos.advance(time);
+    }
+}
+
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+/// A custom event for Machinist.
+pub enum MchEvent {
+    /// Wildfire will detonate.
+    Wildfire,
+    /// The Automaton Queen will perform an attack.
+    Queen {
+        /// The attack performed.
+        attack: QueenAttack,
+        /// The amount of Battery the queen was summoned with.
+        battery: u8,
+    },
+}
+
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+/// An attack performed by the Automaton Queen.
+pub enum QueenAttack {
+    /// The opening attack.
+    RollerDash,
+    /// The repeated attack.
+    ArmPunch,
+    /// The first finishing attack.
+    PileBunker,
+    /// The second finishing attack.
+    CrownedCollider,
+}
+
+impl QueenAttack {
+    /// Returns the potency of the attack for the Battery the queen was summoned with.
+    ///
+    /// The finishing attacks scale with Battery, while the
+    /// other attacks scale through the duration of the queen.
+    pub const fn potency(&self, battery: u8) -> u64 {
+        match self {
+            Self::RollerDash => 480,
+            Self::ArmPunch => 240,
+            Self::PileBunker => 680 * battery as u64 / 100,
+            Self::CrownedCollider => 780 * battery as u64 / 100,
+        }
+    }
+}
+
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+/// The combos for Machinist.
+pub struct MchCombos {
+    /// The main combo.
+    pub main: ComboState<MainCombo>,
+}
+
+impl MchCombos {
+    /// Checks that the main combo prerequisite is met for a certain action.
+    pub fn check_main_for(&self, action: MchAction) -> bool {
+        let c = match action {
+            MchAction::HeatedSlugShot => MainCombo::SplitShot,
+            MchAction::HeatedCleanShot => MainCombo::SlugShot,
+            _ => return true,
+        };
+        self.main.check(c)
+    }
+
+    /// Advances the combos forward by a certain amount of time.
+    ///
+    /// See TODO: Advance Functions for more information.
+    pub fn advance(&mut self, time: u32) {
+        self.main.advance(time);
+    }
+}
+
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+/// The possible states the main combo can be in.
+pub enum MainCombo {
+    /// Combo Action: Heated Split Shot is met.
+    SplitShot,
+    /// Combo Action: Heated Slug Shot is met.
+    SlugShot,
+}
+
+job_cd_struct! {
+    MchAction =>
+
+    #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+    #[derive(Clone, Debug, Default)]
+    /// The cooldown map for Machinist actions.
+    pub MchCdMap
+
+    #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+    #[derive(Copy, Clone, Debug)]
+    /// The various cooldown groups a Machinist action can be part of.
+    pub MchCdGroup
+
+    "Drill"
+    drill Drill: Drill;
+    "Air Anchor"
+    air_anchor AirAnchor: AirAnchor;
+    "Chain Saw"
+    chain_saw ChainSaw: ChainSaw;
+    "Double Check"
+    double_check DoubleCheck: DoubleCheck;
+    "Checkmate"
+    checkmate Checkmate: Checkmate;
+    "Reassemble"
+    reassemble Reassemble: Reassemble;
+    "Hypercharge"
+    hypercharge Hypercharge: Hypercharge;
+    "Barrel Stabilizer"
+    barrel Barrel: BarrelStabilizer;
+    "Wildfire"
+    wildfire Wildfire: Wildfire;
+    "Automaton Queen"
+    queen Queen: AutomatonQueen;
+    "Second Wind"
+    second_wind SecondWind: Role(RangedRoleAction::SecondWind);
+    "Head Graze"
+    head_graze HeadGraze: Role(RangedRoleAction::HeadGraze);
+    "Peloton"
+    peloton Peloton: Role(RangedRoleAction::Peloton);
+    "Drill Charge"
+    drill_chg DrillChg;
+    "Double Check Charge"
+    double_check_chg DoubleCheckChg;
+    "Checkmate Charge"
+    checkmate_chg CheckmateChg;
+    "Reassemble Charge"
+    reassemble_chg ReassembleChg;
+}
+
+impl MchAction {
+    /// Returns the alternate [cooldown group] that this action is part of.
+    ///
+    /// Returns `None` if this action does not have an alternate cooldown.
+    /// This action is used for the 1s cooldown between uses of charged actions.
+    ///
+    /// [cooldown group]: MchCdGroup
+    pub const fn alt_cd_group(&self) -> Option<MchCdGroup> {
+        match self {
+            Self::Drill => Some(MchCdGroup::DrillChg),
+            Self::DoubleCheck => Some(MchCdGroup::DoubleCheckChg),
+            Self::Checkmate => Some(MchCdGroup::CheckmateChg),
+            Self::Reassemble => Some(MchCdGroup::ReassembleChg),
+            _ => None,
+        }
+    }
+}
@@ -0,0 +1,748 @@
+use core::{
+    error::Error,
+    fmt::{self, Display},
+};
+
+use macros::var_consts;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    bool_job_dist,
+    enums::{ActionCategory, DamageInstance},
+    err,
+    job::{role::MeleeRoleAction, CastInitInfo, Job, JobAction, JobState},
+    job_cd_struct, job_effect_wrapper, status_effect,
+    timing::{DurationInfo, EventCascade, ScaleTime},
+    util::{combo_pot, pos_pot, status_proc_error, ActionTargettingExt as _, ComboState, GaugeU8},
+    world::{
+        status::{consume_status, consume_status_stack, JobEffect, StatusEffect, StatusEventExt},
+        Action, ActionEventKind, ActionTargetting, ActorRef, DamageEventExt, Event, EventError,
+        EventSink, Faction, Positional, WorldRef,
+    },
+};
+
+/// The [`Job`] struct for Monk.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct MnkJob;
+
+/// The status effect "Perfect Balance".
+pub static PERFECT_BALANCE: StatusEffect = status_effect!("Perfect Balance" 20000);
+/// The status effect "Formless Fist".
+pub static FORMLESS_FIST: StatusEffect = status_effect!("Formless Fist" 30000);
+/// The status effect "Riddle of Fire".
+pub static RIDDLE_OF_FIRE: StatusEffect = status_effect!(
+    "Riddle of Fire" 20000 { damage { out = 115 / 100 } }
+);
+/// The status effect "Fire's Rumination".
+pub static FIRES_RUMINATION: StatusEffect = status_effect!("Fire's Rumination" 20000);
+/// The status effect "Wind's Rumination".
+pub static WINDS_RUMINATION: StatusEffect = status_effect!("Wind's Rumination" 15000);
+/// The status effect "Brotherhood".
+pub static BROTHERHOOD: StatusEffect = status_effect!(
+    "Brotherhood" 20000 { damage { out = 105 / 100 } }
+);
+/// The status effect "Meditative Brotherhood".
+pub static MEDITATIVE_BROTHERHOOD: StatusEffect =
+    status_effect!("Meditative Brotherhood" 20000 multi);
+
+impl Job for MnkJob {
+    type Action = MnkAction;
+    type State = MnkState;
+    type CastError = MnkError;
+    type Event = ();
+    type CdGroup = MnkCdGroup;
+    type CdMap<T> = MnkCdMap<T>;
+
+    fn check_cast<'w, W: WorldRef<'w>, E: EventSink<'w, W>>(
+        action: Self::Action,
+        state: &Self::State,
+        _: &'w W,
+        event_sink: &mut E,
+    ) -> Result<CastInitInfo<Self::CdGroup>, EventError> {
+        let this = event_sink.source();
+
+        let di = this.duration_info();
+
+        let gcd = action.gcd().map(|v| di.scale(v)).unwrap_or_default() as u16;
+        let (lock, snap) = di.get_cast(action.cast(), 600);
+
+        let cd = action
+            .cd_group()
+            .map(|v| (v, action.cooldown(), action.cd_charges()));
+
+        let alt_cd = action.alt_cd_group().map(|v| (v, 1000, 1));
+
+        use MnkAction::*;
+        if let Some(form) = action.form() {
+            // opo-opo form actions can always be used
+            if form != MnkForm::OpoOpo
+                && !state.form.check(form)
+                && !this.has_own_status(PERFECT_BALANCE)
+                && !this.has_own_status(FORMLESS_FIST)
+            {
+                err!(MnkError::Form(form));
+            }
+        }
+        match action {
+            ElixirBurst | RisingPhoenix | PhantomRush | CelestialRevolution => {
+                match state.blitz() {
+                    None => err!(MnkError::BeastChakra),
+                    Some(blitz) if blitz != action => err!(MnkError::Blitz(blitz)),
+                    _ => (),
+                }
+            }
+            TheForbiddenChakra | Enlightenment if state.chakra < 5 => err!(MnkError::Chakra(5)),
+            FiresReply if !this.has_own_status(FIRES_RUMINATION) => {
+                err!(MnkError::FiresRumination)
+            }
+            WindsReply if !this.has_own_status(WINDS_RUMINATION) => {
+                err!(MnkError::WindsRumination)
+            }
+            _ => (),
+        }
+
+        Ok(CastInitInfo {
+            gcd,
+            lock,
+            snap,
+            mp: 0,
+            cd,
+            alt_cd,
+        })
+    }
+
+    fn cast_snap<'w, W: WorldRef<'w>, E: EventSink<'w, W>>(
+        action: Self::Action,
+        state: &mut Self::State,
+        _: &'w W,
+        event_sink: &mut E,
+    ) -> Result<(), EventError> {
+        let this = event_sink.source();
+        let this_id = this.id();
+
+        use MnkAction::*;
+
+        let dl = action.effect_delay();
+
+        match action {
+            LeapingOpo => {
+                let t = this.target_enemy(MELEE)?.id();
+                let opo = Self::form_action(action, state, event_sink);
+                let fury = state.opo_fury.consume(1);
+                let mut damage = DamageInstance::new(combo_pot(260, 460, fury)).blunt();
+                if opo {
+                    damage = damage.force_crit();
+                }
+                event_sink.damage(action, damage, t, dl);
+                Self::chakra_proc(state, event_sink, opo);
+            }
+            DragonKick => {
+                let t = this.target_enemy(MELEE)?.id();
+                Self::form_action(action, state, event_sink);
+                state.opo_fury.set_max();
+                event_sink.damage(action, DamageInstance::new(320).blunt(), t, dl);
+                Self::chakra_proc(state, event_sink, false);
+            }
+            RisingRaptor => {
+                let t = this.target_enemy(MELEE)?.id();
+                Self::form_action(action, state, event_sink);
+                let fury = state.raptor_fury.consume(1);
+                event_sink.damage(
+                    action,
+                    DamageInstance::new(combo_pot(340, 540, fury)).blunt(),
+                    t,
+                    dl,
+                );
+                Self::chakra_proc(state, event_sink, false);
+            }
+            TwinSnakes => {
+                let t = this.target_enemy(MELEE)?.id();
+                Self::form_action(action, state, event_sink);
+                state.raptor_fury.set_max();
+                event_sink.damage(action, DamageInstance::new(420).blunt(), t, dl);
+                Self::chakra_proc(state, event_sink, false);
+            }
+            PouncingCoeurl => {
+                let t = this.target_enemy(MELEE)?.id();
+                Self::form_action(action, state, event_sink);
+                let fury = state.coeurl_fury.consume(1);
+                let pos = this.check_positional(Positional::Flank, t);
+                let potency = pos_pot(370, 430, pos) + if fury { 150 } else { 0 };
+                event_sink.damage(action, DamageInstance::new(potency).blunt(), t, dl);
+                Self::chakra_proc(state, event_sink, false);
+            }
+            Demolish => {
+                let t = this.target_enemy(MELEE)?.id();
+                Self::form_action(action, state, event_sink);
+                state.coeurl_fury.set_max();
+                let pos = this.check_positional(Positional::Rear, t);
+                event_sink.damage(
+                    action,
+                    DamageInstance::new(pos_pot(400, 460, pos)).blunt(),
+                    t,
+                    dl,
+                );
+                Self::chakra_proc(state, event_sink, false);
+            }
+            ShadowOfTheDestroyer | FourPointFury | Rockbreaker => {
+                let iter = this
+                    .target_enemy_aoe(CIRCLE, EventCascade::new(dl, 1))?
+                    .id();
+                Self::form_action(action, state, event_sink);
+                let potency = match action {
+                    ShadowOfTheDestroyer => 120,
+                    FourPointFury => 140,
+                    _ => 150,
+                };
+                for (t, d) in iter {
+                    event_sink.damage(action, DamageInstance::new(potency).blunt(), t, d);
+                }
+                Self::chakra_proc(state, event_sink, false);
+            }
+            ElixirBurst | RisingPhoenix | CelestialRevolution => {
+                let iter = if action == CelestialRevolution {
+                    this.target_enemy_aoe(MELEE, EventCascade::new(dl, 1))?
+                } else {
+                    this.target_enemy_aoe(CIRCLE, EventCascade::new(dl, 1))?
+                }
+                .id();
+                let potency = match action {
+                    CelestialRevolution => 600,
+                    _ => 900,
+                };
+                match action {
+                    ElixirBurst => state.lunar_nadi = true,
+                    RisingPhoenix => state.solar_nadi = true,
+                    _ if !state.lunar_nadi => state.lunar_nadi = true,
+                    _ => state.solar_nadi = true,
+                }
+                state.beast_chakra.clear();
+                for (t, d) in iter {
+                    event_sink.damage(action, DamageInstance::new(potency).blunt(), t, d);
+                }
+            }
+            PhantomRush => {
+                let iter = this
+                    .target_enemy_aoe(TG_CIRCLE, EventCascade::new(dl, 1))?
+                    .id()
+                    .falloff(50);
+                state.lunar_nadi = false;
+                state.solar_nadi = false;
+                state.beast_chakra.clear();
+                for (t, d, f) in iter {
+                    event_sink.damage(action, DamageInstance::new(1500).blunt().falloff(f), t, d);
+                }
+            }
+            TheForbiddenChakra => {
+                let t = this.target_enemy(MELEE)?.id();
+                if !state.chakra.consume(5) {
+                    err!(MnkError::Chakra(5));
+                }
+                event_sink.damage(action, DamageInstance::new(400).blunt(), t, dl);
+            }
+            Enlightenment => {
+                let iter = this.target_enemy_aoe(LINE, EventCascade::new(dl, 1))?.id();
+                if !state.chakra.consume(5) {
+                    err!(MnkError::Chakra(5));
+                }
+                for (t, d) in iter {
+                    event_sink.damage(action, DamageInstance::new(160).blunt(), t, d);
+                }
+            }
+            ForbiddenMeditation => {
+                if this.in_combat() {
+                    state.chakra += 1;
+                } else {
+                    state.chakra.set_max();
+                }
+            }
+            PerfectBalance => {
+                event_sink.apply_status(PERFECT_BALANCE, 3, this_id, 0);
+            }
+            RiddleOfFire => {
+                event_sink.apply_status(RIDDLE_OF_FIRE, 1, this_id, 0);
+                event_sink.apply_status(FIRES_RUMINATION, 1, this_id, 0);
+            }
+            RiddleOfWind => {
+                event_sink.apply_status(WINDS_RUMINATION, 1, this_id, 0);
+            }
+            FiresReply | WindsReply => {
+                let (targetting, potency, rumination) = if action == FiresReply {
+                    (TG_CIRCLE, 1200, FIRES_RUMINATION)
+                } else {
+                    (LINE, 900, WINDS_RUMINATION)
+                };
+                let iter = this
+                    .target_enemy_aoe(targetting, EventCascade::new(dl, 1))?
+                    .id()
+                    .falloff(50);
+                if !consume_status(event_sink, rumination, 0) {
+                    err!(if action == FiresReply {
+                        MnkError::FiresRumination
+                    } else {
+                        MnkError::WindsRumination
+                    });
+                }
+                for (t, d, f) in iter {
+                    event_sink.damage(
+                        action,
+                        DamageInstance::new(potency).blunt().falloff(f),
+                        t,
+                        d,
+                    );
+                }
+                if action == FiresReply {
+                    event_sink.apply_status(FORMLESS_FIST, 1, this_id, 0);
+                }
+            }
+            Brotherhood => {
+                for (t, d) in this
+                    .target_party_aoe(ActionTargetting::circle(30), EventCascade::new(dl, 3))?
+                    .id()
+                {
+                    event_sink.apply_status(BROTHERHOOD, 1, t, d);
+                    event_sink.apply_status(MEDITATIVE_BROTHERHOOD, 1, t, d);
+                }
+            }
+            Role(v) => v.cast_snap(action, event_sink)?,
+        }
+
+        Ok(())
+    }
+
+    fn event<'w, W: WorldRef<'w>, E: EventSink<'w, W>>(
+        state: &mut Self::State,
+        world: &'w W,
+        event: &Event,
+        event_sink: &mut E,
+    ) {
+        let this_id = event_sink.source().id();
+        if let Event::Action(event) = event {
+            if let Some(event_src) = world.actor(event.source) {
+                // party members under meditative brotherhood have a chance
+                // to open a chakra with each weaponskill and spell they cast
+                if event.kind == ActionEventKind::Cast
+                    && event_src.faction() == Faction::Party
+                    && event_src.has_status(MEDITATIVE_BROTHERHOOD, this_id)
+                    && event.action.category().skill_or_spell()
+                    && event_sink.random(MeditativeProc)
+                {
+                    state.chakra += 1;
+                }
+            }
+        }
+    }
+
+    fn effect(state: &Self::State) -> Option<&dyn JobEffect> {
+        Some(MnkJobEffect::new(state))
+    }
+}
+
+job_effect_wrapper! {
+    #[derive(Debug)]
+    struct MnkJobEffect(MnkState);
+}
+impl JobEffect for MnkJobEffect {
+    // greased lightning
+    fn haste(&self) -> u64 {
+        80
+    }
+}
+
+impl MnkJob {
+    // updates the form and beast chakra after a form action is used.
+    // returns `true` if the action was used in its form.
+    fn form_action<'w, W: WorldRef<'w>, E: EventSink<'w, W>>(
+        action: MnkAction,
+        state: &mut MnkState,
+        event_sink: &mut E,
+    ) -> bool {
+        let Some(form) = action.form() else {
+            return false;
+        };
+        let this = event_sink.source();
+        let in_form = state.form.check(form) || this.has_own_status(FORMLESS_FIST);
+        if consume_status_stack(event_sink, PERFECT_BALANCE, 0) {
+            state.beast_chakra.push(form);
+            true
+        } else {
+            consume_status(event_sink, FORMLESS_FIST, 0);
+            state.form.set(form.next());
+            in_form
+        }
+    }
+
+    // weaponskills that critically hit open a chakra.
+    fn chakra_proc<'w, W: WorldRef<'w>, E: EventSink<'w, W>>(
+        state: &mut MnkState,
+        event_sink: &mut E,
+        crit: bool,
+    ) {
+        if crit || event_sink.random(ChakraProc) {
+            state.chakra += 1;
+        }
+    }
+}
+
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Copy, Debug)]
+/// A custom cast error for Monk actions.
+pub enum MnkError {
+    /// Not in the required form.
+    Form(MnkForm),
+    /// Not enough Chakra.
+    Chakra(u8),
+    /// Not enough Beast Chakra to execute a Masterful Blitz.
+    BeastChakra,
+    /// The Beast Chakra resolves to a different Masterful Blitz.
+    Blitz(MnkAction),
+    /// Not under the effect of Fire's Rumination.
+    FiresRumination,
+    /// Not under the effect of Wind's Rumination.
+    WindsRumination,
+}
+
+impl From<MnkError> for EventError {
+    fn from(value: MnkError) -> Self {
+        Self::Job(value.into())
+    }
+}
+
+impl Display for MnkError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Form(form) => write!(f, "Not in {}.", form.name()),
+            Self::Chakra(v) => write!(f, "Not enough Chakra, needed at least {}.", v),
+            Self::BeastChakra => write!(f, "Not enough Beast Chakra, needed 3."),
+            Self::Blitz(ac) => write!(f, "The Beast Chakra resolves to '{}'.", ac.name()),
+            Self::FiresRumination => status_proc_error(f, FIRES_RUMINATION),
+            Self::WindsRumination => status_proc_error(f, WINDS_RUMINATION),
+        }
+    }
+}
+
+impl Error for MnkError {}
+
+bool_job_dist! {
+    /// The random event for a Chakra being opened by a weaponskill.
+    ///
+    /// This approximates the chance of a weaponskill critically hitting.
+    pub ChakraProc = 1 / 4;
+    /// The random event for a Chakra being opened by Meditative Brotherhood.
+    pub MeditativeProc = 1 / 5;
+}
+
+const MELEE: ActionTargetting = ActionTargetting::single(3);
+const CIRCLE: ActionTargetting = ActionTargetting::circle(5);
+const LINE: ActionTargetting = ActionTargetting::line(10);
+const TG_CIRCLE: ActionTargetting = ActionTargetting::target_circle(5, 3);
+
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(rename_all = "snake_case")
+)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[repr(u8)]
+#[var_consts {
+    /// Returns the form this action is part of, or `None` if it is not a form action.
+    pub const form: MnkForm?;
+    /// Returns the base GCD recast time, or `None` if the action is not a gcd.
+    pub const gcd: ScaleTime?;
+    /// Returns the base milliseconds the action takes to cast.
+    pub const cast: ScaleTime = ScaleTime::zero();
+    /// Returns the human friendly name of the action.
+    pub const name: &'static str;
+    /// Returns the cooldown of the skill in milliseconds.
+    pub const cooldown: u32 = 0;
+    /// Returns the number of charges a skill has, or `1` if it is a single charge skill.
+    pub const cd_charges: u8 = 1;
+    /// Returns the delay in milliseconds for the damage/statuses to be applied.
+    pub const effect_delay: u32 = 0;
+    /// Returns the [`ActionCategory`] this action is part of.
+    pub const category: ActionCategory;
+
+    pub const skill for {
+        gcd = ScaleTime::skill(2500);
+        category = ActionCategory::Weaponskill;
+    }
+    pub const ability for {
+        category = ActionCategory::Ability;
+    }
+}]
+#[allow(missing_docs)] // no reason to document the variants.
+/// An action specific to the Monk job.
+pub enum MnkAction {
+    #[skill]
+    #[form = MnkForm::OpoOpo]
+    #[name = "Leaping Opo"]
+    LeapingOpo,
+    #[skill]
+    #[form = MnkForm::OpoOpo]
+    #[name = "Dragon Kick"]
+    DragonKick,
+    #[skill]
+    #[form = MnkForm::OpoOpo]
+    #[name = "Shadow of the Destroyer"]
+    ShadowOfTheDestroyer,
+    #[skill]
+    #[form = MnkForm::Raptor]
+    #[name = "Rising Raptor"]
+    RisingRaptor,
+    #[skill]
+    #[form = MnkForm::Raptor]
+    #[name = "Twin Snakes"]
+    TwinSnakes,
+    #[skill]
+    #[form = MnkForm::Raptor]
+    #[name = "Four-point Fury"]
+    FourPointFury,
+    #[skill]
+    #[form = MnkForm::Coeurl]
+    #[name = "Pouncing Coeurl"]
+    PouncingCoeurl,
+    #[skill]
+    #[form = MnkForm::Coeurl]
+    #[name = "Demolish"]
+    Demolish,
+    #[skill]
+    #[form = MnkForm::Coeurl]
+    #[name = "Rockbreaker"]
+    Rockbreaker,
+    #[skill]
+    #[name = "Elixir Burst"]
+    ElixirBurst,
+    #[skill]
+    #[name = "Rising Phoenix"]
+    RisingPhoenix,
+    #[skill]
+    #[name = "Celestial Revolution"]
+    CelestialRevolution,
+    #[skill]
+    #[name = "Phantom Rush"]
+    PhantomRush,
+    #[ability]
+    #[cooldown = 1000]
+    #[name = "The Forbidden Chakra"]
+    TheForbiddenChakra,
+    #[ability]
+    #[cooldown = 1000]
+    #[name = "Enlightenment"]
+    Enlightenment,
+    #[ability]
+    #[name = "Forbidden Meditation"]
+    ForbiddenMeditation,
+    #[ability]
+    #[cooldown = 40000]
+    #[cd_charges = 2]
+    #[name = "Perfect Balance"]
+    PerfectBalance,
+    #[ability]
+    #[cooldown = 60000]
+    #[name = "Riddle of Fire"]
+    RiddleOfFire,
+    #[ability]
+    #[cooldown = 90000]
+    #[name = "Riddle of Wind"]
+    RiddleOfWind,
+    #[skill]
+    #[name = "Fire's Reply"]
+    FiresReply,
+    #[ability]
+    #[cooldown = 1000]
+    #[name = "Wind's Reply"]
+    WindsReply,
+    #[ability]
+    #[cooldown = 120000]
+    #[name = "Brotherhood"]
+    Brotherhood,
+    #[gcd((v) => v.gcd())]
+    #[cast((v) => v.cast())]
+    #[name((v) => v.name())]
+    #[cooldown((v) => v.cooldown())]
+    #[cd_charges((v) => v.cd_charges())]
+    #[effect_delay((v) => v.effect_delay())]
+    #[category((v) => v.category())]
+    Role(MeleeRoleAction),
+}
+
+impl JobAction for MnkAction {
+    fn category(&self) -> ActionCategory {
+        self.category()
+    }
+
+    fn gcd(&self) -> bool {
+        self.gcd().is_some()
+    }
+}
+
+impl From<MnkAction> for Action {
+    fn from(value: MnkAction) -> Self {
+        Action::Job(value.into())
+    }
+}
+
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+/// The state of the Monk job gauges, cooldowns, and forms.
+pub struct MnkState {
+    /// The current form.
+    pub form: ComboState<MnkForm>,
+    /// The Chakra gauge.
+    pub chakra: GaugeU8<5>,
+    /// The Opo-opo's Fury gauge.
+    pub opo_fury: GaugeU8<1>,
+    /// The Raptor's Fury gauge.
+    pub raptor_fury: GaugeU8<1>,
+    /// The Coeurl's Fury gauge.
+    pub coeurl_fury: GaugeU8<2>,
+    /// The Beast Chakra gauge.
+    pub beast_chakra: BeastChakra,
+    /// `true` if the Lunar Nadi is open.
+    pub lunar_nadi: bool,
+    /// `true` if the Solar Nadi is open.
+    pub solar_nadi: bool,
+}
+
+impl MnkState {
+    /// Returns the Masterful Blitz that the current Beast Chakra resolves to,
+    /// or `None` if there is not enough Beast Chakra.
+    pub fn blitz(&self) -> Option<MnkAction> {
+        let [a, b, c] = self.beast_chakra.get()?;
+        Some(if self.lunar_nadi && self.solar_nadi {
+            MnkAction::PhantomRush
+        } else if a == b && b == c {
+            MnkAction::ElixirBurst
+        } else if a != b && b != c && a != c {
+            MnkAction::RisingPhoenix
+        } else {
+            MnkAction::CelestialRevolution
+        })
+    }
+}
+
+impl JobState for MnkState {
+    fn advance(&mut self, time: u32) {
+        self.form.advance(time);
+    }
+}
+
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+/// The possible forms a Monk can be in.
+pub enum MnkForm {
+    /// Opo-opo Form.
+    OpoOpo,
+    /// Raptor Form.
+    Raptor,
+    /// Coeurl Form.
+    Coeurl,
+}
+
+impl MnkForm {
+    /// Returns the form that an action of this form transitions to.
+    pub const fn next(&self) -> Self {
+        match self {
+            Self::OpoOpo => Self::Raptor,
+            Self::Raptor => Self::Coeurl,
+            Self::Coeurl => Self::OpoOpo,
+        }
+    }
+    /// Returns the human friendly name of the form.
+    pub const fn name(&self) -> &'static str {
+        match self {
+            Self::OpoOpo => "Opo-opo Form",
+            Self::Raptor => "Raptor Form",
+            Self::Coeurl => "Coeurl Form",
+        }
+    }
+}
+
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+/// The Beast Chakra gauge.
+pub struct BeastChakra {
+    chakra: [Option<MnkForm>; 3],
+}
+impl BeastChakra {
+    /// Adds a Beast Chakra of the specified form.
+    ///
+    /// Does nothing if the gauge is already full.
+    pub fn push(&mut self, form: MnkForm) {
+        if let Some(slot) = self.chakra.iter_mut().find(|v| v.is_none()) {
+            *slot = Some(form);
+        }
+    }
+    /// Returns the Beast Chakra present, or `None` if there are less than 3.
+    pub fn get(&self) -> Option<[MnkForm; 3]> {
+        match self.chakra {
+            [Some(a), Some(b), Some(c)] => Some([a, b, c]),
+            _ => None,
+        }
+    }
+    /// Returns the number of Beast Chakra present.
+    pub fn count(&self) -> u8 {
+        self.chakra.iter().filter(|v| v.is_some()).count() as u8
+    }
+    /// Clears the Beast Chakra gauge.
+    pub fn clear(&mut self) {
+        self.chakra = [None; 3];
+    }
+}
+
+job_cd_struct! {
+    MnkAction =>
+
+    #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+    #[derive(Clone, Debug, Default)]
+    /// The cooldown map for Monk actions.
+    pub MnkCdMap
+
+    #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+    #[derive(Copy, Clone, Debug)]
+    /// The various cooldown groups a Monk action can be part of.
+    pub MnkCdGroup
+
+    "The Forbidden Chakra and Enlightenment"
+    chakra Chakra: TheForbiddenChakra Enlightenment;
+    "Perfect Balance"
+    perfect_balance PerfectBalance: PerfectBalance;
+    "Riddle of Fire"
+    riddle_fire RiddleOfFire: RiddleOfFire;
+    "Riddle of Wind"
+    riddle_wind RiddleOfWind: RiddleOfWind;
+    "Wind's Reply"
+    winds_reply WindsReply: WindsReply;
+    "Brotherhood"
+    brotherhood Brotherhood: Brotherhood;
+    "True North"
+    true_north TrueNorth: Role(MeleeRoleAction::TrueNorth);
+    "Feint"
+    feint Feint: Role(MeleeRoleAction::Feint);
+    "Bloodbath"
+    bloodbath Bloodbath: Role(MeleeRoleAction::Bloodbath);
+    "Second Wind"
+    second_wind SecondWind: Role(MeleeRoleAction::SecondWind);
+    "Perfect Balance Charge"
+    perfect_balance_chg PerfectBalanceChg;
+    "True North Charge"
+    true_north_chg TrueNorthChg;
+}
+
+impl MnkAction {
+    /// Returns the alternate [cooldown group] that this action is part of.
+    ///
+    /// Returns `None` if this action does not have an alternate cooldown.
+    /// This action is used for the 1s cooldown between uses of charged actions.
+    ///
+    /// [cooldown group]: MnkCdGroup
+    pub const fn alt_cd_group(&self) -> Option<MnkCdGroup> {
+        match self {
+            Self::PerfectBalance => Some(MnkCdGroup::PerfectBalanceChg),
+            Self::Role(MeleeRoleAction::TrueNorth) => Some(MnkCdGroup::TrueNorthChg),
+            _ => None,
+        }
+    }
+}
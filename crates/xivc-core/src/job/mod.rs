@@ -23,14 +23,50 @@ use crate::{
 /// Utilities for role actions.
 pub mod role;
 
+/// Logic and types for Astrologian.
+pub mod ast;
 /// Logic and types for Bard.
 pub mod brd;
+/// Logic and types for Black Mage.
+pub mod blm;
+/// Logic and types for Blue Mage.
+pub mod blu;
 /// Logic and types for Dancer.
 pub mod dnc;
-// /// Logic and types for Reaper.
-// pub mod rpr;
-// /// Logic and types for Samurai.
-// pub mod sam;
+/// Logic and types for Dragoon.
+pub mod drg;
+/// Logic and types for Dark Knight.
+pub mod drk;
+/// Logic and types for Gunbreaker.
+pub mod gnb;
+/// Logic and types for Machinist.
+pub mod mch;
+/// Logic and types for Monk.
+pub mod mnk;
+/// Logic and types for Ninja.
+pub mod nin;
+/// Logic and types for Pictomancer.
+pub mod pct;
+/// Logic and types for Paladin.
+pub mod pld;
+/// Logic and types for Red Mage.
+pub mod rdm;
+/// Logic and types for Reaper.
+pub mod rpr;
+/// Logic and types for Samurai.
+pub mod sam;
+/// Logic and types for Scholar.
+pub mod sch;
+/// Logic and types for Sage.
+pub mod sge;
+/// Logic and types for Summoner.
+pub mod smn;
+/// Logic and types for Viper.
+pub mod vpr;
+/// Logic and types for Warrior.
+pub mod war;
+/// Logic and types for White Mage.
+pub mod whm;
 
 /// A set of logic for working with jobs in a uniform way.
 ///
@@ -50,6 +86,7 @@ pub trait Job: 'static {
     /// This error should contain things like:
     /// * A gauge cost not being fulfilled.
     /// * An "(Action) Ready" status not being present.
+    ///
     /// or various other requirements action have to be cast.
     type CastError: Display + Debug + 'static;
     /// A custom event this job can use.
@@ -221,6 +258,8 @@ macro_rules! helper {
             /// Checks that a certain action may be casted, and returns
             /// cooldown information for that action.
             ///
+            /// Returns [`EventError::WrongJob`] if `action` is not an action of this job.
+            ///
             /// See [`Job::check_cast`] for more information.
             pub fn check_cast<'w, E: EventSink<'w, W>, W: WorldRef<'w>>(
                 &self,
@@ -229,6 +268,9 @@ macro_rules! helper {
                 world: &'w W,
                 event_sink: &mut E,
             ) -> Result<CastInitInfo<CdGroup>, EventError> {
+                if action.job() != self.job() {
+                    return Err(EventError::WrongJob(action));
+                }
                 Ok(match (self, action, state) {
                     $(
                         (
@@ -238,12 +280,14 @@ macro_rules! helper {
                         ) => <$job>::check_cast(action, state, world, event_sink)?
                                 .map_cd_group(CdGroup::$var_name),
                     )*
-                    _ => panic!("`action` and `state` do not match job type.")
+                    _ => panic!("`state` does not match job type.")
                 })
             }
 
             /// Executes the specified action.
             ///
+            /// Returns [`EventError::WrongJob`] if `action` is not an action of this job.
+            ///
             /// See [`Job::cast_snap`] for more information.
             pub fn cast_snap<'w, E: EventSink<'w, W>, W: WorldRef<'w>>(
                 &self,
@@ -252,6 +296,9 @@ macro_rules! helper {
                 world: &'w W,
                 event_sink: &mut E,
             ) -> Result<(), EventError> {
+                if action.job() != self.job() {
+                    return Err(EventError::WrongJob(action));
+                }
                 match (self, action, state) {
                     $(
                         (
@@ -260,7 +307,7 @@ macro_rules! helper {
                             State::$var_name(state),
                         ) => <$job>::cast_snap(action, state, world, event_sink),
                     )*
-                    _ => panic!("`action` and `state` do not match job type.")
+                    _ => panic!("`state` does not match job type.")
                 }
             }
 
@@ -599,7 +646,25 @@ macro_rules! helper {
 
 helper! {
     BRD brd Brd brd::BrdJob { "Bard" }
-    // SAM sam Sam sam::SamJob { "Samurai" }
+    SAM sam Sam sam::SamJob { "Samurai" }
     DNC dnc Dnc dnc::DncJob { "Dancer" }
-    // RPR rpr Rpr rpr::RprJob { "Reaper" }
+    RPR rpr Rpr rpr::RprJob { "Reaper" }
+    PLD pld Pld pld::PldJob { "Paladin" }
+    WAR war War war::WarJob { "Warrior" }
+    DRK drk Drk drk::DrkJob { "Dark Knight" }
+    GNB gnb Gnb gnb::GnbJob { "Gunbreaker" }
+    MNK mnk Mnk mnk::MnkJob { "Monk" }
+    DRG drg Drg drg::DrgJob { "Dragoon" }
+    NIN nin Nin nin::NinJob { "Ninja" }
+    VPR vpr Vpr vpr::VprJob { "Viper" }
+    MCH mch Mch mch::MchJob { "Machinist" }
+    BLM blm Blm blm::BlmJob { "Black Mage" }
+    SMN smn Smn smn::SmnJob { "Summoner" }
+    RDM rdm Rdm rdm::RdmJob { "Red Mage" }
+    PCT pct Pct pct::PctJob { "Pictomancer" }
+    WHM whm Whm whm::WhmJob { "White Mage" }
+    SCH sch Sch sch::SchJob { "Scholar" }
+    AST ast Ast ast::AstJob { "Astrologian" }
+    SGE sge Sge sge::SgeJob { "Sage" }
+    BLU blu Blu blu::BluJob { "Blue Mage" }
 }
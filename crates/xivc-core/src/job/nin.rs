@@ -0,0 +1,930 @@
+use core::{
+    error::Error,
+    fmt::{self, Display},
+};
+
+use macros::var_consts;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    enums::{ActionCategory, DamageInstance},
+    err,
+    job::{role::MeleeRoleAction, CastInitInfo, Job, JobAction, JobEvent, JobState},
+    job_cd_struct,
+    math::SpeedStat,
+    status_effect,
+    timing::{DurationInfo, EventCascade, ScaleTime},
+    util::{
+        combo_pos_pot, combo_pot, status_proc_error, ActionTargettingExt as _, ComboState, GaugeU8,
+    },
+    world::{
+        status::{consume_status, consume_status_stack, StatusEffect, StatusEventExt},
+        Action, ActionTargetting, ActorId, ActorRef, DamageEventExt, Event, EventError, EventSink,
+        Faction, Positional, WorldRef,
+    },
+};
+
+/// The [`Job`] struct for Ninja.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct NinJob;
+
+/// The status effect "Mudra".
+pub static MUDRA: StatusEffect = status_effect!("Mudra" 6000);
+/// The status effect "Kassatsu".
+pub static KASSATSU: StatusEffect = status_effect!("Kassatsu" 15000);
+/// The status effect "Ten Chi Jin".
+pub static TEN_CHI_JIN: StatusEffect = status_effect!("Ten Chi Jin" 6000);
+/// The status effect "Shadow Walker".
+pub static SHADOW_WALKER: StatusEffect = status_effect!("Shadow Walker" 20000);
+/// The status effect "Raiju Ready".
+pub static RAIJU_READY: StatusEffect = status_effect!("Raiju Ready" 30000);
+/// The status effect "Meisui".
+pub static MEISUI: StatusEffect = status_effect!("Meisui" 30000);
+/// The status effect "Bunshin".
+pub static BUNSHIN: StatusEffect = status_effect!("Bunshin" 30000);
+/// The status effect "Phantom Kamaitachi Ready".
+pub static PHANTOM_KAMAITACHI_READY: StatusEffect =
+    status_effect!("Phantom Kamaitachi Ready" 45000);
+/// The status effect "Dokumori".
+pub static DOKUMORI: StatusEffect = status_effect!(
+    "Dokumori" 20000 { damage { in = 105 / 100 } }
+);
+/// The ground effect "Doton".
+///
+/// This is never applied to a target, and is only used for its duration.
+pub static DOTON: StatusEffect = status_effect!("Doton" 18000 multi);
+
+impl Job for NinJob {
+    type Action = NinAction;
+    type State = NinState;
+    type CastError = NinError;
+    type Event = NinEvent;
+    type CdGroup = NinCdGroup;
+    type CdMap<T> = NinCdMap<T>;
+
+    fn check_cast<'w, W: WorldRef<'w>, E: EventSink<'w, W>>(
+        action: Self::Action,
+        state: &Self::State,
+        _: &'w W,
+        event_sink: &mut E,
+    ) -> Result<CastInitInfo<Self::CdGroup>, EventError> {
+        let this = event_sink.source();
+
+        let di = this.duration_info();
+
+        let mut gcd = action.gcd().map(|v| di.scale(v)).unwrap_or_default() as u16;
+        let (mut lock, snap) = di.get_cast(action.cast(), 600);
+
+        let mut cd = action
+            .cd_group()
+            .map(|v| (v, action.cooldown(), action.cd_charges()));
+
+        let alt_cd = action.alt_cd_group().map(|v| (v, 1000, 1));
+
+        let tcj = this.has_own_status(TEN_CHI_JIN);
+
+        use NinAction::*;
+        match action {
+            Ten | Chi | Jin => {
+                if tcj {
+                    if state.mudra.contains(action.mudra().unwrap()) {
+                        err!(NinError::TenChiJin);
+                    }
+                    gcd = 1000;
+                }
+                lock = lock.min(gcd);
+                // only the first mudra of a sequence uses a charge.
+                if tcj || this.has_own_status(MUDRA) || this.has_own_status(KASSATSU) {
+                    cd = None;
+                }
+            }
+            _ if action.ninjutsu() && !this.has_own_status(MUDRA) => err!(NinError::Mudra),
+            _ if action.ninjutsu() && tcj => err!(NinError::TenChiJin),
+            Kassatsu | TenChiJin if this.has_own_status(MUDRA) => err!(NinError::Mudra),
+            ForkedRaiju | FleetingRaiju if !this.has_own_status(RAIJU_READY) => {
+                err!(NinError::Raiju)
+            }
+            Meisui if !this.has_own_status(SHADOW_WALKER) => err!(NinError::ShadowWalker),
+            PhantomKamaitachi if !this.has_own_status(PHANTOM_KAMAITACHI_READY) => {
+                err!(NinError::PhantomKamaitachi)
+            }
+            Bhavacakra | HellfrogMedium | Bunshin if state.ninki < 50 => {
+                err!(NinError::Ninki(50))
+            }
+            _ => (),
+        }
+
+        Ok(CastInitInfo {
+            gcd,
+            lock,
+            snap,
+            mp: 0,
+            cd,
+            alt_cd,
+        })
+    }
+
+    fn cast_snap<'w, W: WorldRef<'w>, E: EventSink<'w, W>>(
+        action: Self::Action,
+        state: &mut Self::State,
+        _: &'w W,
+        event_sink: &mut E,
+    ) -> Result<(), EventError> {
+        let this = event_sink.source();
+        let this_id = this.id();
+
+        use NinAction::*;
+
+        let dl = action.effect_delay();
+
+        // phantom kamaitachi is performed by the shadow itself.
+        let bunshin = action.category() == ActionCategory::Weaponskill
+            && action != PhantomKamaitachi
+            && consume_status_stack(event_sink, BUNSHIN, 0);
+        if bunshin {
+            state.ninki += 5;
+        }
+        let shadow = match action {
+            DeathBlossom | HakkeMujinsatsu | ThrowingDagger => 80,
+            _ => 160,
+        };
+
+        match action {
+            SpinningEdge => {
+                let t = this.target_enemy(MELEE)?.id();
+                state.combos.main.set(MainCombo::SpinningEdge);
+                state.ninki += 5;
+                event_sink.damage(action, DamageInstance::new(300).slashing(), t, dl);
+                Self::shadow_hit(event_sink, bunshin, shadow, t, dl);
+            }
+            GustSlash => {
+                let t = this.target_enemy(MELEE)?.id();
+                let combo = state.combos.check_main_for(action);
+                if combo {
+                    state.combos.main.set(MainCombo::GustSlash);
+                    state.ninki += 5;
+                } else {
+                    state.combos.main.reset();
+                }
+                event_sink.damage(
+                    action,
+                    DamageInstance::new(combo_pot(240, 400, combo)).slashing(),
+                    t,
+                    dl,
+                );
+                Self::shadow_hit(event_sink, bunshin, shadow, t, dl);
+            }
+            AeolianEdge => {
+                let t = this.target_enemy(MELEE)?.id();
+                let combo = state.combos.check_main_for(action);
+                state.combos.main.reset();
+                if combo {
+                    state.ninki += 15;
+                }
+                let pos = this.check_positional(Positional::Rear, t);
+                let kazematoi = if state.kazematoi.consume(1) { 100 } else { 0 };
+                event_sink.damage(
+                    action,
+                    DamageInstance::new(combo_pos_pot(200, 260, 440, 500, combo, pos) + kazematoi)
+                        .slashing(),
+                    t,
+                    dl,
+                );
+                Self::shadow_hit(event_sink, bunshin, shadow, t, dl);
+            }
+            ArmorCrush => {
+                let t = this.target_enemy(MELEE)?.id();
+                let combo = state.combos.check_main_for(action);
+                state.combos.main.reset();
+                if combo {
+                    state.ninki += 15;
+                    state.kazematoi += 2;
+                }
+                let pos = this.check_positional(Positional::Flank, t);
+                event_sink.damage(
+                    action,
+                    DamageInstance::new(combo_pos_pot(200, 260, 420, 480, combo, pos)).slashing(),
+                    t,
+                    dl,
+                );
+                Self::shadow_hit(event_sink, bunshin, shadow, t, dl);
+            }
+            DeathBlossom | HakkeMujinsatsu => {
+                let iter = this
+                    .target_enemy_aoe(CIRCLE, EventCascade::new(dl, 1))?
+                    .id();
+                let combo = state.combos.check_aoe_for(action);
+                let potency = if action == DeathBlossom {
+                    100
+                } else {
+                    combo_pot(100, 130, combo)
+                };
+                let mut hit = false;
+                for (t, d) in iter {
+                    hit = true;
+                    event_sink.damage(action, DamageInstance::new(potency).slashing(), t, d);
+                    Self::shadow_hit(event_sink, bunshin, shadow, t, d);
+                }
+                if hit && combo {
+                    state.ninki += 5;
+                }
+                if hit && action == DeathBlossom {
+                    state.combos.aoe.set(AoeCombo::DeathBlossom);
+                } else {
+                    state.combos.aoe.reset();
+                }
+            }
+            ThrowingDagger => {
+                let t = this.target_enemy(RANGED)?.id();
+                state.ninki += 5;
+                event_sink.damage(action, DamageInstance::new(200).slashing(), t, dl);
+                Self::shadow_hit(event_sink, bunshin, shadow, t, dl);
+            }
+            ForkedRaiju | FleetingRaiju => {
+                let t = this
+                    .target_enemy(if action == ForkedRaiju { RANGED } else { MELEE })?
+                    .id();
+                if !consume_status_stack(event_sink, RAIJU_READY, 0) {
+                    err!(NinError::Raiju);
+                }
+                state.ninki += 5;
+                event_sink.damage(action, DamageInstance::new(700).slashing(), t, dl);
+                Self::shadow_hit(event_sink, bunshin, shadow, t, dl);
+            }
+            PhantomKamaitachi => {
+                let iter = this
+                    .target_enemy_aoe(TG_CIRCLE, EventCascade::new(dl, 1))?
+                    .id()
+                    .falloff(50);
+                if !consume_status(event_sink, PHANTOM_KAMAITACHI_READY, 0) {
+                    err!(NinError::PhantomKamaitachi);
+                }
+                state.ninki += 10;
+                for (t, d, f) in iter {
+                    event_sink.damage(action, DamageInstance::new(600).slashing().falloff(f), t, d);
+                }
+            }
+            Ten | Chi | Jin => {
+                let mudra = action.mudra().unwrap();
+                if this.has_own_status(TEN_CHI_JIN) {
+                    state.mudra.push(mudra);
+                    let ninjutsu = state.mudra.ninjutsu(false);
+                    if state.mudra.count() == 3 {
+                        state.mudra.clear();
+                        event_sink.remove_status(TEN_CHI_JIN, this_id, 0);
+                    }
+                    Self::ninjutsu(ninjutsu, 100, state, event_sink, dl)?;
+                } else {
+                    if !this.has_own_status(MUDRA) {
+                        state.mudra.clear();
+                        event_sink.apply_status(MUDRA, 1, this_id, 0);
+                    }
+                    state.mudra.push(mudra);
+                }
+            }
+            _ if action.ninjutsu() => {
+                if !consume_status(event_sink, MUDRA, 0) {
+                    err!(NinError::Mudra);
+                }
+                let kassatsu = this.has_own_status(KASSATSU);
+                let ninjutsu = state.mudra.ninjutsu(kassatsu);
+                state.mudra.clear();
+                let mul = if kassatsu && ninjutsu != RabbitMedium {
+                    consume_status(event_sink, KASSATSU, 0);
+                    130
+                } else {
+                    100
+                };
+                Self::ninjutsu(ninjutsu, mul, state, event_sink, dl)?;
+            }
+            Kassatsu => {
+                event_sink.apply_status(KASSATSU, 1, this_id, 0);
+            }
+            TenChiJin => {
+                state.mudra.clear();
+                event_sink.apply_status(TEN_CHI_JIN, 1, this_id, 0);
+            }
+            Dokumori => {
+                let t = this.target_enemy(MELEE)?.id();
+                state.ninki += 40;
+                event_sink.damage(action, DamageInstance::new(300).slashing(), t, dl);
+                event_sink.apply_status(DOKUMORI, 1, t, dl);
+            }
+            Bhavacakra => {
+                let t = this.target_enemy(MELEE)?.id();
+                if !state.ninki.consume(50) {
+                    err!(NinError::Ninki(50));
+                }
+                let meisui = if consume_status(event_sink, MEISUI, 0) {
+                    150
+                } else {
+                    0
+                };
+                event_sink.damage(action, DamageInstance::new(380 + meisui).slashing(), t, dl);
+            }
+            HellfrogMedium => {
+                let iter = this
+                    .target_enemy_aoe(TG_CIRCLE, EventCascade::new(dl, 1))?
+                    .id();
+                if !state.ninki.consume(50) {
+                    err!(NinError::Ninki(50));
+                }
+                for (t, d) in iter {
+                    event_sink.damage(action, DamageInstance::new(160).magical(), t, d);
+                }
+            }
+            Bunshin => {
+                if !state.ninki.consume(50) {
+                    err!(NinError::Ninki(50));
+                }
+                event_sink.apply_status(BUNSHIN, 5, this_id, 0);
+                event_sink.apply_status(PHANTOM_KAMAITACHI_READY, 1, this_id, 0);
+            }
+            Meisui => {
+                if !consume_status(event_sink, SHADOW_WALKER, 0) {
+                    err!(NinError::ShadowWalker);
+                }
+                state.ninki += 50;
+                event_sink.apply_status(MEISUI, 1, this_id, 0);
+            }
+            Role(v) => v.cast_snap(action, event_sink)?,
+            _ => (),
+        }
+
+        Ok(())
+    }
+
+    fn event<'w, W: WorldRef<'w>, E: EventSink<'w, W>>(
+        state: &mut Self::State,
+        _: &'w W,
+        event: &Event,
+        event_sink: &mut E,
+    ) {
+        let this = event_sink.source();
+        let this_id = this.id();
+        match event {
+            // positions are not part of the world, so the area is centered on the caster.
+            Event::Job(JobEvent::Nin(NinEvent::DotonTick { potency }), src_id)
+                if *src_id == this_id && state.doton > 0 =>
+            {
+                for target in this.actors_for_action(Some(Faction::Enemy), CIRCLE) {
+                    event_sink.dot_tick(
+                        NinAction::Doton,
+                        DamageInstance::new(*potency).magical(),
+                        SpeedStat::SkillSpeed,
+                        target.id(),
+                        0,
+                    );
+                }
+            }
+            _ => (),
+        }
+    }
+}
+
+impl NinJob {
+    // executes the effects of a resolved ninjutsu.
+    // `mul` is the percentage multiplier applied to the potency.
+    fn ninjutsu<'w, W: WorldRef<'w>, E: EventSink<'w, W>>(
+        ninjutsu: NinAction,
+        mul: u64,
+        state: &mut NinState,
+        event_sink: &mut E,
+        dl: u32,
+    ) -> Result<(), EventError> {
+        use NinAction::*;
+
+        let this = event_sink.source();
+        let this_id = this.id();
+
+        let dmg = |potency: u64| DamageInstance::new(potency * mul / 100).magical();
+
+        match ninjutsu {
+            FumaShuriken | Raiton | Hyoton | Suiton | HyoshoRanryu => {
+                let t = this.target_enemy(NINJUTSU)?.id();
+                let potency = match ninjutsu {
+                    FumaShuriken => 500,
+                    Raiton => {
+                        event_sink.apply_or_add_stacks(RAIJU_READY, 1, 3, this_id, dl);
+                        740
+                    }
+                    Hyoton => 350,
+                    Suiton => {
+                        event_sink.apply_status(SHADOW_WALKER, 1, this_id, dl);
+                        580
+                    }
+                    _ => 1300,
+                };
+                event_sink.damage(ninjutsu, dmg(potency), t, dl);
+            }
+            Katon | Huton | GokaMekkyaku => {
+                let iter = this
+                    .target_enemy_aoe(NINJUTSU_AOE, EventCascade::new(dl, 1))?
+                    .id();
+                let potency = match ninjutsu {
+                    Katon => 350,
+                    Huton => {
+                        event_sink.apply_status(SHADOW_WALKER, 1, this_id, dl);
+                        240
+                    }
+                    _ => 600,
+                };
+                for (t, d) in iter {
+                    event_sink.damage(ninjutsu, dmg(potency), t, d);
+                }
+            }
+            Doton => {
+                state.doton = DOTON.duration;
+                // doton is a ground effect, so the enemies hit
+                // are decided on every tick instead of when it is placed.
+                let potency = 80 * mul / 100;
+                let mut delay = dl;
+                while delay < dl + DOTON.duration {
+                    event_sink.event(
+                        JobEvent::nin(NinEvent::DotonTick { potency }, this_id),
+                        delay,
+                    );
+                    delay += 3000;
+                }
+            }
+            // rabbit medium does nothing.
+            _ => (),
+        }
+
+        Ok(())
+    }
+
+    // performs a bunshin shadow attack if a stack was consumed.
+    fn shadow_hit<'w, W: WorldRef<'w>, E: EventSink<'w, W>>(
+        event_sink: &mut E,
+        bunshin: bool,
+        potency: u64,
+        target: ActorId,
+        delay: u32,
+    ) {
+        if bunshin {
+            event_sink.damage(
+                NinAction::Bunshin,
+                DamageInstance::new(potency).slashing(),
+                target,
+                delay,
+            );
+        }
+    }
+}
+
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Copy, Debug)]
+/// A custom cast error for Ninja actions.
+pub enum NinError {
+    /// Not under the effect of Mudra.
+    Mudra,
+    /// The mudra was already used during Ten Chi Jin,
+    /// or a ninjutsu was used during Ten Chi Jin.
+    TenChiJin,
+    /// Not under the effect of Raiju Ready.
+    Raiju,
+    /// Not under the effect of Shadow Walker.
+    ShadowWalker,
+    /// Not under the effect of Phantom Kamaitachi Ready.
+    PhantomKamaitachi,
+    /// Not enough Ninki.
+    Ninki(u8),
+}
+
+impl From<NinError> for EventError {
+    fn from(value: NinError) -> Self {
+        Self::Job(value.into())
+    }
+}
+
+impl Display for NinError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Mudra => status_proc_error(f, MUDRA),
+            Self::TenChiJin => write!(
+                f,
+                "Action cannot be used in this sequence while under the effect of '{}'.",
+                TEN_CHI_JIN.name
+            ),
+            Self::Raiju => status_proc_error(f, RAIJU_READY),
+            Self::ShadowWalker => status_proc_error(f, SHADOW_WALKER),
+            Self::PhantomKamaitachi => status_proc_error(f, PHANTOM_KAMAITACHI_READY),
+            Self::Ninki(v) => write!(f, "Not enough Ninki, needed at least {}.", v),
+        }
+    }
+}
+
+impl Error for NinError {}
+
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+/// A custom event for Ninja.
+pub enum NinEvent {
+    /// Doton will deal damage to the enemies standing in it.
+    DotonTick {
+        /// The potency of the tick.
+        potency: u64,
+    },
+}
+
+const MELEE: ActionTargetting = ActionTargetting::single(3);
+const RANGED: ActionTargetting = ActionTargetting::single(20);
+const NINJUTSU: ActionTargetting = ActionTargetting::single(25);
+const NINJUTSU_AOE: ActionTargetting = ActionTargetting::target_circle(5, 25);
+const CIRCLE: ActionTargetting = ActionTargetting::circle(5);
+const TG_CIRCLE: ActionTargetting = ActionTargetting::target_circle(6, 20);
+
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(rename_all = "snake_case")
+)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[repr(u8)]
+#[var_consts {
+    /// Returns the base GCD recast time, or `None` if the action is not a gcd.
+    pub const gcd: ScaleTime?;
+    /// Returns the base milliseconds the action takes to cast.
+    pub const cast: ScaleTime = ScaleTime::zero();
+    /// Returns the human friendly name of the action.
+    pub const name: &'static str;
+    /// Returns the cooldown of the skill in milliseconds.
+    pub const cooldown: u32 = 0;
+    /// Returns the number of charges a skill has, or `1` if it is a single charge skill.
+    pub const cd_charges: u8 = 1;
+    /// Returns the delay in milliseconds for the damage/statuses to be applied.
+    pub const effect_delay: u32 = 0;
+    /// Returns the [`Mudra`] the action performs, or `None` if it is not a mudra.
+    pub const mudra: Mudra?;
+    /// Returns `true` if the action is a ninjutsu.
+    pub const ninjutsu: bool = false;
+    /// Returns the [`ActionCategory`] this action is part of.
+    pub const category: ActionCategory;
+
+    pub const skill for {
+        gcd = ScaleTime::skill(2500);
+        category = ActionCategory::Weaponskill;
+    }
+    pub const sign for {
+        gcd = ScaleTime::none(500);
+        cooldown = 20000;
+        cd_charges = 2;
+        category = ActionCategory::Ability;
+    }
+    pub const jutsu for {
+        gcd = ScaleTime::none(1500);
+        ninjutsu = true;
+        category = ActionCategory::Ability;
+    }
+    pub const ability for {
+        category = ActionCategory::Ability;
+    }
+}]
+#[allow(missing_docs)] // no reason to document the variants.
+/// An action specific to the Ninja job.
+///
+/// All of the ninjutsu actions share the same button in game,
+/// so casting any of them will execute whatever ninjutsu
+/// the current mudra sequence resolves to.
+pub enum NinAction {
+    #[skill]
+    #[name = "Spinning Edge"]
+    SpinningEdge,
+    #[skill]
+    #[name = "Gust Slash"]
+    GustSlash,
+    #[skill]
+    #[name = "Aeolian Edge"]
+    AeolianEdge,
+    #[skill]
+    #[name = "Armor Crush"]
+    ArmorCrush,
+    #[skill]
+    #[name = "Death Blossom"]
+    DeathBlossom,
+    #[skill]
+    #[name = "Hakke Mujinsatsu"]
+    HakkeMujinsatsu,
+    #[skill]
+    #[name = "Throwing Dagger"]
+    ThrowingDagger,
+    #[skill]
+    #[name = "Forked Raiju"]
+    ForkedRaiju,
+    #[skill]
+    #[name = "Fleeting Raiju"]
+    FleetingRaiju,
+    #[skill]
+    #[name = "Phantom Kamaitachi"]
+    PhantomKamaitachi,
+    #[sign]
+    #[mudra = Mudra::Ten]
+    #[name = "Ten"]
+    Ten,
+    #[sign]
+    #[mudra = Mudra::Chi]
+    #[name = "Chi"]
+    Chi,
+    #[sign]
+    #[mudra = Mudra::Jin]
+    #[name = "Jin"]
+    Jin,
+    #[jutsu]
+    #[name = "Fuma Shuriken"]
+    FumaShuriken,
+    #[jutsu]
+    #[name = "Katon"]
+    Katon,
+    #[jutsu]
+    #[name = "Raiton"]
+    Raiton,
+    #[jutsu]
+    #[name = "Hyoton"]
+    Hyoton,
+    #[jutsu]
+    #[name = "Huton"]
+    Huton,
+    #[jutsu]
+    #[name = "Doton"]
+    Doton,
+    #[jutsu]
+    #[name = "Suiton"]
+    Suiton,
+    #[jutsu]
+    #[name = "Goka Mekkyaku"]
+    GokaMekkyaku,
+    #[jutsu]
+    #[name = "Hyosho Ranryu"]
+    HyoshoRanryu,
+    #[jutsu]
+    #[name = "Rabbit Medium"]
+    RabbitMedium,
+    #[ability]
+    #[cooldown = 60000]
+    #[name = "Kassatsu"]
+    Kassatsu,
+    #[ability]
+    #[cooldown = 120000]
+    #[name = "Ten Chi Jin"]
+    TenChiJin,
+    #[ability]
+    #[cooldown = 120000]
+    #[name = "Dokumori"]
+    Dokumori,
+    #[ability]
+    #[cooldown = 1000]
+    #[name = "Bhavacakra"]
+    Bhavacakra,
+    #[ability]
+    #[cooldown = 1000]
+    #[name = "Hellfrog Medium"]
+    HellfrogMedium,
+    #[ability]
+    #[cooldown = 90000]
+    #[name = "Bunshin"]
+    Bunshin,
+    #[ability]
+    #[cooldown = 120000]
+    #[name = "Meisui"]
+    Meisui,
+    #[gcd((v) => v.gcd())]
+    #[cast((v) => v.cast())]
+    #[name((v) => v.name())]
+    #[cooldown((v) => v.cooldown())]
+    #[cd_charges((v) => v.cd_charges())]
+    #[effect_delay((v) => v.effect_delay())]
+    #[category((v) => v.category())]
+    Role(MeleeRoleAction),
+}
+
+impl JobAction for NinAction {
+    fn category(&self) -> ActionCategory {
+        self.category()
+    }
+
+    fn gcd(&self) -> bool {
+        self.gcd().is_some()
+    }
+}
+
+impl From<NinAction> for Action {
+    fn from(value: NinAction) -> Self {
+        Action::Job(value.into())
+    }
+}
+
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+/// The state of the Ninja job gauges, cooldowns, and combos.
+pub struct NinState {
+    /// The combos for Ninja.
+    pub combos: NinCombos,
+    /// The Ninki gauge.
+    pub ninki: GaugeU8<100>,
+    /// The Kazematoi stacks.
+    pub kazematoi: GaugeU8<5>,
+    /// The current mudra sequence.
+    pub mudra: MudraGauge,
+    /// The remaining time of Doton in milliseconds.
+    pub doton: u32,
+}
+
+impl JobState for NinState {
+    fn advance(&mut self, time: u32) {
+        self.combos.advance(time);
+        self.doton = self.doton.saturating_sub(time);
+    }
+}
+
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+/// The sequence of mudras that have been performed.
+pub struct MudraGauge {
+    /// The mudras performed, in order.
+    pub mudras: [Option<Mudra>; 3],
+    /// If the sequence is invalid and will result in Rabbit Medium.
+    pub failed: bool,
+}
+
+impl MudraGauge {
+    /// Performs the specified mudra.
+    ///
+    /// The sequence fails if the mudra was already performed,
+    /// or if three mudras have already been performed.
+    pub fn push(&mut self, mudra: Mudra) {
+        if self.contains(mudra) {
+            self.failed = true;
+            return;
+        }
+        match self.mudras.iter_mut().find(|v| v.is_none()) {
+            Some(slot) => *slot = Some(mudra),
+            None => self.failed = true,
+        }
+    }
+
+    /// Returns `true` if the mudra was already performed in this sequence.
+    pub fn contains(&self, mudra: Mudra) -> bool {
+        self.mudras.contains(&Some(mudra))
+    }
+
+    /// Returns the number of mudras performed.
+    pub fn count(&self) -> u8 {
+        self.mudras.iter().filter(|v| v.is_some()).count() as u8
+    }
+
+    /// Clears the mudra sequence.
+    pub fn clear(&mut self) {
+        *self = Self::default();
+    }
+
+    /// Returns the ninjutsu that the mudra sequence resolves to.
+    ///
+    /// The `kassatsu` parameter should be `true` if
+    /// the actor is under the effect of Kassatsu.
+    pub fn ninjutsu(&self, kassatsu: bool) -> NinAction {
+        use NinAction::*;
+
+        let count = self.count();
+        if self.failed || count == 0 {
+            return RabbitMedium;
+        }
+        let last = self.mudras[count as usize - 1].unwrap();
+        match (count, last) {
+            (1, _) => FumaShuriken,
+            (2, Mudra::Ten) if kassatsu => GokaMekkyaku,
+            (2, Mudra::Ten) => Katon,
+            (2, Mudra::Chi) => Raiton,
+            (2, Mudra::Jin) if kassatsu => HyoshoRanryu,
+            (2, Mudra::Jin) => Hyoton,
+            (_, Mudra::Ten) => Huton,
+            (_, Mudra::Chi) => Doton,
+            (_, Mudra::Jin) => Suiton,
+        }
+    }
+}
+
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[allow(missing_docs)]
+/// A mudra.
+pub enum Mudra {
+    Ten,
+    Chi,
+    Jin,
+}
+
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+/// The combos for Ninja.
+pub struct NinCombos {
+    /// The main combo.
+    pub main: ComboState<MainCombo>,
+    /// The AoE combo.
+    pub aoe: ComboState<AoeCombo>,
+}
+
+impl NinCombos {
+    /// Checks that the main combo prerequisite is met for a certain action.
+    pub fn check_main_for(&self, action: NinAction) -> bool {
+        let c = match action {
+            NinAction::GustSlash => MainCombo::SpinningEdge,
+            NinAction::AeolianEdge | NinAction::ArmorCrush => MainCombo::GustSlash,
+            _ => return true,
+        };
+        self.main.check(c)
+    }
+
+    /// Checks that the AoE combo prerequisite is met for a certain action.
+    pub fn check_aoe_for(&self, action: NinAction) -> bool {
+        let c = match action {
+            NinAction::HakkeMujinsatsu => AoeCombo::DeathBlossom,
+            _ => return true,
+        };
+        self.aoe.check(c)
+    }
+
+    /// Advances the combos forward by a certain amount of time.
+    ///
+    /// See TODO: Advance Functions for more information.
+    pub fn advance(&mut self, time: u32) {
+        self.main.advance(time);
+        self.aoe.advance(time);
+    }
+}
+
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+/// The possible states the main combo can be in.
+pub enum MainCombo {
+    /// Combo Action: Spinning Edge is met.
+    SpinningEdge,
+    /// Combo Action: Gust Slash is met.
+    GustSlash,
+}
+
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+/// The possible states the AoE combo can be in.
+pub enum AoeCombo {
+    /// Combo Action: Death Blossom is met.
+    DeathBlossom,
+}
+
+job_cd_struct! {
+    NinAction =>
+
+    #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+    #[derive(Clone, Debug, Default)]
+    /// The cooldown map for Ninja actions.
+    pub NinCdMap
+
+    #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+    #[derive(Copy, Clone, Debug)]
+    /// The various cooldown groups a Ninja action can be part of.
+    pub NinCdGroup
+
+    "Mudra"
+    mudra Mudra: Ten Chi Jin;
+    "Kassatsu"
+    kassatsu Kassatsu: Kassatsu;
+    "Ten Chi Jin"
+    tcj TenChiJin: TenChiJin;
+    "Dokumori"
+    dokumori Dokumori: Dokumori;
+    "Bhavacakra"
+    bhavacakra Bhavacakra: Bhavacakra;
+    "Hellfrog Medium"
+    hellfrog Hellfrog: HellfrogMedium;
+    "Bunshin"
+    bunshin Bunshin: Bunshin;
+    "Meisui"
+    meisui Meisui: Meisui;
+    "True North"
+    true_north TrueNorth: Role(MeleeRoleAction::TrueNorth);
+    "Feint"
+    feint Feint: Role(MeleeRoleAction::Feint);
+    "Bloodbath"
+    bloodbath Bloodbath: Role(MeleeRoleAction::Bloodbath);
+    "Second Wind"
+    second_wind SecondWind: Role(MeleeRoleAction::SecondWind);
+    "True North Charge"
+    true_north_chg TrueNorthChg;
+}
+
+impl NinAction {
+    /// Returns the alternate [cooldown group] that this action is part of.
+    ///
+    /// Returns `None` if this action does not have an alternate cooldown.
+    /// This action is used for the 1s cooldown between uses of charged actions.
+    ///
+    /// [cooldown group]: NinCdGroup
+    pub const fn alt_cd_group(&self) -> Option<NinCdGroup> {
+        match self {
+            Self::Role(MeleeRoleAction::TrueNorth) => Some(NinCdGroup::TrueNorthChg),
+            _ => None,
+        }
+    }
+}
@@ -0,0 +1,938 @@
+use core::{
+    error::Error,
+    fmt::{self, Display},
+};
+
+use macros::var_consts;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    enums::{ActionCategory, DamageInstance},
+    err,
+    job::{
+        role::{CasterRoleAction, SWIFTCAST},
+        CastInitInfo, Job, JobAction, JobState,
+    },
+    job_cd_struct, status_effect,
+    timing::{DurationInfo, EventCascade, ScaleTime},
+    util::{status_proc_error, ActionTargettingExt as _, ComboState, GaugeU8},
+    world::{
+        status::{consume_status, consume_status_stack, StatusEffect, StatusEventExt},
+        Action, ActionTargetting, ActorRef, DamageEventExt, EventError, EventSink, WorldRef,
+    },
+};
+
+/// The [`Job`] struct for Pictomancer.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct PctJob;
+
+/// The status effect "Aetherhues".
+pub static AETHERHUES: StatusEffect = status_effect!("Aetherhues" 30000);
+/// The status effect "Aetherhues II".
+pub static AETHERHUES_II: StatusEffect = status_effect!("Aetherhues II" 30000);
+/// The status effect "Subtractive Palette".
+pub static SUBTRACTIVE_PALETTE: StatusEffect = status_effect!("Subtractive Palette" 30000);
+/// The status effect "Subtractive Spectrum".
+pub static SUBTRACTIVE_SPECTRUM: StatusEffect = status_effect!("Subtractive Spectrum" 30000);
+/// The status effect "Monochrome Tones".
+pub static MONOCHROME_TONES: StatusEffect = status_effect!("Monochrome Tones" 30000);
+/// The status effect "Hammer Time".
+pub static HAMMER_TIME: StatusEffect = status_effect!("Hammer Time" 30000);
+/// The status effect "Starry Muse".
+pub static STARRY_MUSE: StatusEffect = status_effect!(
+    "Starry Muse" 20000 { damage { out = 105 / 100 } }
+);
+/// The status effect "Hyperphantasia".
+pub static HYPERPHANTASIA: StatusEffect = status_effect!("Hyperphantasia" 30000);
+/// The status effect "Inspiration".
+pub static INSPIRATION: StatusEffect = status_effect!(
+    "Inspiration" 30000 { haste { |_| 75 } }
+);
+/// The status effect "Rainbow Bright".
+pub static RAINBOW_BRIGHT: StatusEffect = status_effect!("Rainbow Bright" 30000);
+/// The status effect "Starstruck".
+pub static STARSTRUCK: StatusEffect = status_effect!("Starstruck" 20000);
+
+impl Job for PctJob {
+    type Action = PctAction;
+    type State = PctState;
+    type CastError = PctError;
+    type Event = ();
+    type CdGroup = PctCdGroup;
+    type CdMap<T> = PctCdMap<T>;
+
+    fn check_cast<'w, W: WorldRef<'w>, E: EventSink<'w, W>>(
+        action: Self::Action,
+        state: &Self::State,
+        _: &'w W,
+        event_sink: &mut E,
+    ) -> Result<CastInitInfo<Self::CdGroup>, EventError> {
+        let this = event_sink.source();
+
+        let di = this.duration_info();
+
+        use PctAction::*;
+
+        let rainbow = action == RainbowDrip && this.has_own_status(RAINBOW_BRIGHT);
+
+        let cast = if rainbow || this.has_own_status(SWIFTCAST) {
+            ScaleTime::zero()
+        } else {
+            action.cast()
+        };
+        let gcd = if rainbow {
+            Some(ScaleTime::spell(2500))
+        } else {
+            action.gcd()
+        };
+
+        let gcd = gcd.map(|v| di.scale(v)).unwrap_or_default() as u16;
+        let (lock, snap) = di.get_cast(cast, 600);
+
+        let cd = action
+            .cd_group()
+            .map(|v| (v, action.cooldown(), action.cd_charges()));
+
+        let alt_cd = action.alt_cd_group().map(|v| (v, 1000, 1));
+
+        if let Some(hue) = action.aetherhue() {
+            let subtractive = this.has_own_status(SUBTRACTIVE_PALETTE);
+            if action.subtractive() != subtractive {
+                err!(PctError::SubtractivePalette(subtractive));
+            }
+            if hue != aetherhue(&this) {
+                err!(PctError::Aetherhue(action.hue_action(aetherhue(&this))));
+            }
+        }
+
+        match action {
+            HolyInWhite | CometInBlack if state.paint == 0 => err!(PctError::WhitePaint),
+            HolyInWhite if this.has_own_status(MONOCHROME_TONES) => {
+                err!(PctError::MonochromeTones(true))
+            }
+            CometInBlack if !this.has_own_status(MONOCHROME_TONES) => {
+                err!(PctError::MonochromeTones(false))
+            }
+            SubtractivePalette if this.has_own_status(SUBTRACTIVE_PALETTE) => {
+                err!(PctError::SubtractivePalette(true))
+            }
+            SubtractivePalette
+                if state.palette < 50 && !this.has_own_status(SUBTRACTIVE_SPECTRUM) =>
+            {
+                err!(PctError::Palette(50))
+            }
+            PomMotif | WingMotif | ClawMotif | MawMotif => {
+                if state.creature.is_some() {
+                    err!(PctError::CanvasPainted(Canvas::Creature));
+                }
+                let next = state.next_creature();
+                if action != next.motif() {
+                    err!(PctError::Creature(next));
+                }
+            }
+            HammerMotif if state.weapon => err!(PctError::CanvasPainted(Canvas::Weapon)),
+            StarrySkyMotif if state.landscape => {
+                err!(PctError::CanvasPainted(Canvas::Landscape))
+            }
+            PomMuse | WingedMuse | ClawedMuse | FangedMuse => match state.creature {
+                Some(creature) if creature.muse() == action => (),
+                Some(creature) => err!(PctError::Creature(creature)),
+                None => err!(PctError::Canvas(Canvas::Creature)),
+            },
+            StrikingMuse if !state.weapon => err!(PctError::Canvas(Canvas::Weapon)),
+            StarryMuse if !state.landscape => err!(PctError::Canvas(Canvas::Landscape)),
+            MogOfTheAges if state.portrait != Some(Portrait::Moogle) => {
+                err!(PctError::Portrait(Portrait::Moogle))
+            }
+            RetributionOfTheMadeen if state.portrait != Some(Portrait::Madeen) => {
+                err!(PctError::Portrait(Portrait::Madeen))
+            }
+            HammerStamp | HammerBrush | PolishingHammer => {
+                if !this.has_own_status(HAMMER_TIME) {
+                    err!(PctError::HammerTime);
+                }
+                if !state.combos.check_hammer_for(action) {
+                    err!(PctError::Hammer(match action {
+                        HammerBrush => HammerStamp,
+                        _ => HammerBrush,
+                    }));
+                }
+            }
+            StarPrism if !this.has_own_status(STARSTRUCK) => err!(PctError::Starstruck),
+            _ => (),
+        }
+
+        Ok(CastInitInfo {
+            gcd,
+            lock,
+            snap,
+            mp: action.mp_cost(),
+            cd,
+            alt_cd,
+        })
+    }
+
+    fn cast_snap<'w, W: WorldRef<'w>, E: EventSink<'w, W>>(
+        action: Self::Action,
+        state: &mut Self::State,
+        _: &'w W,
+        event_sink: &mut E,
+    ) -> Result<(), EventError> {
+        let this = event_sink.source();
+        let this_id = this.id();
+
+        use PctAction::*;
+
+        let dl = action.effect_delay();
+
+        let dmg = |potency| DamageInstance::new(potency).magical();
+
+        // rainbow bright takes priority over swiftcast,
+        // and spells with no cast time don't use up either.
+        let rainbow = action == RainbowDrip && this.has_own_status(RAINBOW_BRIGHT);
+        if !rainbow && action.cast().duration() > 0 {
+            consume_status(event_sink, SWIFTCAST, 0);
+        }
+
+        if action.hyperphantasia() {
+            // using the last stack of hyperphantasia grants rainbow bright
+            if let Some(status) = this.get_own_status(HYPERPHANTASIA) {
+                consume_status_stack(event_sink, HYPERPHANTASIA, 0);
+                if status.stack == 1 {
+                    event_sink.remove_status(INSPIRATION, this_id, 0);
+                    event_sink.apply_status(RAINBOW_BRIGHT, 1, this_id, 0);
+                }
+            }
+        }
+
+        match action {
+            FireInRed | AeroInGreen | WaterInBlue | BlizzardInCyan | StoneInYellow
+            | ThunderInMagenta => {
+                let Some(hue) = action.aetherhue() else {
+                    unreachable!()
+                };
+                if action.subtractive() {
+                    consume_status_stack(event_sink, SUBTRACTIVE_PALETTE, 0);
+                }
+                match hue {
+                    0 => event_sink.apply_status(AETHERHUES, 1, this_id, 0),
+                    1 => {
+                        event_sink.remove_status(AETHERHUES, this_id, 0);
+                        event_sink.apply_status(AETHERHUES_II, 1, this_id, 0);
+                    }
+                    _ => {
+                        event_sink.remove_status(AETHERHUES_II, this_id, 0);
+                        if action == WaterInBlue {
+                            state.palette += 25;
+                        }
+                        state.paint += 1;
+                    }
+                }
+                let potency = match action {
+                    FireInRed => 440,
+                    AeroInGreen => 480,
+                    WaterInBlue => 520,
+                    BlizzardInCyan => 800,
+                    StoneInYellow => 840,
+                    _ => 880,
+                };
+                for (t, d, f) in this
+                    .target_enemy_aoe(TG_CIRCLE, EventCascade::new(dl, 1))?
+                    .id()
+                    .falloff(30)
+                {
+                    event_sink.damage(action, dmg(potency).falloff(f), t, d);
+                }
+            }
+            HolyInWhite | CometInBlack | StarPrism => {
+                let potency = match action {
+                    HolyInWhite => {
+                        if !state.paint.consume(1) {
+                            err!(PctError::WhitePaint);
+                        }
+                        520
+                    }
+                    CometInBlack => {
+                        if !state.paint.consume(1) {
+                            err!(PctError::WhitePaint);
+                        }
+                        if !consume_status(event_sink, MONOCHROME_TONES, 0) {
+                            err!(PctError::MonochromeTones(false));
+                        }
+                        880
+                    }
+                    _ => {
+                        if !consume_status(event_sink, STARSTRUCK, 0) {
+                            err!(PctError::Starstruck);
+                        }
+                        1100
+                    }
+                };
+                for (t, d, f) in this
+                    .target_enemy_aoe(TG_CIRCLE, EventCascade::new(dl, 1))?
+                    .id()
+                    .falloff(30)
+                {
+                    event_sink.damage(action, dmg(potency).falloff(f), t, d);
+                }
+            }
+            RainbowDrip => {
+                consume_status(event_sink, RAINBOW_BRIGHT, 0);
+                state.paint += 1;
+                for (t, d, f) in this
+                    .target_enemy_aoe(LINE, EventCascade::new(dl, 1))?
+                    .id()
+                    .falloff(15)
+                {
+                    event_sink.damage(action, dmg(1000).falloff(f), t, d);
+                }
+            }
+            HammerStamp | HammerBrush | PolishingHammer => {
+                if !state.combos.check_hammer_for(action) {
+                    state.combos.hammer.reset();
+                    err!(PctError::Hammer(match action {
+                        HammerBrush => HammerStamp,
+                        _ => HammerBrush,
+                    }));
+                }
+                if !consume_status_stack(event_sink, HAMMER_TIME, 0) {
+                    err!(PctError::HammerTime);
+                }
+                let potency = match action {
+                    HammerStamp => {
+                        state.combos.hammer.set(HammerCombo::Stamp);
+                        560
+                    }
+                    HammerBrush => {
+                        state.combos.hammer.set(HammerCombo::Brush);
+                        580
+                    }
+                    _ => {
+                        state.combos.hammer.reset();
+                        600
+                    }
+                };
+                for (t, d, f) in this
+                    .target_enemy_aoe(TG_CIRCLE, EventCascade::new(dl, 1))?
+                    .id()
+                    .falloff(30)
+                {
+                    event_sink.damage(
+                        action,
+                        dmg(potency).falloff(f).force_crit().force_dhit(),
+                        t,
+                        d,
+                    );
+                }
+            }
+            PomMotif | WingMotif | ClawMotif | MawMotif => {
+                state.creature = Some(state.next_creature());
+            }
+            HammerMotif => state.weapon = true,
+            StarrySkyMotif => state.landscape = true,
+            PomMuse | WingedMuse | ClawedMuse | FangedMuse => {
+                let Some(creature) = state.creature.take() else {
+                    err!(PctError::Canvas(Canvas::Creature));
+                };
+                state.creature_count = state.creature_count.wrapping_add(1);
+                match creature {
+                    Creature::Wing => state.portrait = Some(Portrait::Moogle),
+                    Creature::Maw => state.portrait = Some(Portrait::Madeen),
+                    _ => (),
+                }
+                for (t, d, f) in this
+                    .target_enemy_aoe(TG_CIRCLE, EventCascade::new(dl, 1))?
+                    .id()
+                    .falloff(30)
+                {
+                    event_sink.damage(action, dmg(creature.potency()).falloff(f), t, d);
+                }
+            }
+            MogOfTheAges | RetributionOfTheMadeen => {
+                state.portrait = None;
+                let potency = if action == MogOfTheAges { 1300 } else { 1400 };
+                for (t, d, f) in this
+                    .target_enemy_aoe(LINE, EventCascade::new(dl, 1))?
+                    .id()
+                    .falloff(30)
+                {
+                    event_sink.damage(action, dmg(potency).falloff(f), t, d);
+                }
+            }
+            StrikingMuse => {
+                state.weapon = false;
+                event_sink.apply_status(HAMMER_TIME, 3, this_id, 0);
+            }
+            StarryMuse => {
+                state.landscape = false;
+                event_sink.apply_status(HYPERPHANTASIA, 5, this_id, 0);
+                event_sink.apply_status(INSPIRATION, 1, this_id, 0);
+                event_sink.apply_status(SUBTRACTIVE_SPECTRUM, 1, this_id, 0);
+                event_sink.apply_status(STARSTRUCK, 1, this_id, 0);
+                for (t, d) in this
+                    .target_party_aoe(ActionTargetting::circle(30), EventCascade::new(dl, 3))?
+                    .id()
+                {
+                    event_sink.apply_status(STARRY_MUSE, 1, t, d);
+                }
+            }
+            SubtractivePalette => {
+                if !consume_status(event_sink, SUBTRACTIVE_SPECTRUM, 0)
+                    && !state.palette.consume(50)
+                {
+                    err!(PctError::Palette(50));
+                }
+                event_sink.apply_status(SUBTRACTIVE_PALETTE, 3, this_id, 0);
+                event_sink.apply_status(MONOCHROME_TONES, 1, this_id, 0);
+            }
+            Role(v) => v.cast_snap(action, event_sink)?,
+        }
+
+        Ok(())
+    }
+}
+
+/// Returns the current aetherhue of the actor.
+///
+/// This is `0` for Fire/Blizzard, `1` for Aero/Stone, and `2` for Water/Thunder.
+fn aetherhue<'w>(this: &impl ActorRef<'w>) -> u8 {
+    if this.has_own_status(AETHERHUES_II) {
+        2
+    } else if this.has_own_status(AETHERHUES) {
+        1
+    } else {
+        0
+    }
+}
+
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Copy, Debug)]
+/// A custom cast error for Pictomancer actions.
+pub enum PctError {
+    /// The current aetherhue only allows the specified action.
+    Aetherhue(PctAction),
+    /// The action requires Subtractive Palette to be active (`false`)
+    /// or inactive (`true`).
+    SubtractivePalette(bool),
+    /// Not enough Palette Gauge.
+    Palette(u8),
+    /// Not enough White Paint.
+    WhitePaint,
+    /// The action requires Monochrome Tones to be active (`false`)
+    /// or inactive (`true`).
+    MonochromeTones(bool),
+    /// The canvas has already been painted.
+    CanvasPainted(Canvas),
+    /// The canvas has not been painted.
+    Canvas(Canvas),
+    /// The specified creature is the next one in the sequence.
+    Creature(Creature),
+    /// The specified portrait is not available.
+    Portrait(Portrait),
+    /// Not under the effect of Hammer Time.
+    HammerTime,
+    /// The hammer combo action was not the specified action.
+    Hammer(PctAction),
+    /// Not under the effect of Starstruck.
+    Starstruck,
+}
+
+impl From<PctError> for EventError {
+    fn from(value: PctError) -> Self {
+        Self::Job(value.into())
+    }
+}
+
+impl Display for PctError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Aetherhue(action) => write!(
+                f,
+                "The current Aetherhue only allows '{}' to be used.",
+                action.name()
+            ),
+            Self::SubtractivePalette(true) => {
+                write!(f, "Cannot use action while Subtractive Palette is active.")
+            }
+            Self::SubtractivePalette(false) => status_proc_error(f, SUBTRACTIVE_PALETTE),
+            Self::Palette(v) => write!(f, "Not enough Palette Gauge, needed {}.", v),
+            Self::WhitePaint => write!(f, "Not enough White Paint, needed 1."),
+            Self::MonochromeTones(true) => {
+                write!(f, "Cannot use action while Monochrome Tones is active.")
+            }
+            Self::MonochromeTones(false) => status_proc_error(f, MONOCHROME_TONES),
+            Self::CanvasPainted(canvas) => {
+                write!(f, "The {} canvas is already painted.", canvas.name())
+            }
+            Self::Canvas(canvas) => write!(f, "The {} canvas is not painted.", canvas.name()),
+            Self::Creature(creature) => write!(
+                f,
+                "The next creature in the sequence is '{}'.",
+                creature.name()
+            ),
+            Self::Portrait(portrait) => {
+                write!(f, "The {} portrait is not painted.", portrait.name())
+            }
+            Self::HammerTime => status_proc_error(f, HAMMER_TIME),
+            Self::Hammer(action) => {
+                write!(f, "Hammer combo action was not '{}'.", action.name())
+            }
+            Self::Starstruck => status_proc_error(f, STARSTRUCK),
+        }
+    }
+}
+
+impl Error for PctError {}
+
+const TG_CIRCLE: ActionTargetting = ActionTargetting::target_circle(5, 25);
+const LINE: ActionTargetting = ActionTargetting::line(25);
+
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(rename_all = "snake_case")
+)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[repr(u8)]
+#[var_consts {
+    /// Returns `true` if the action is part of the Subtractive Palette.
+    pub const subtractive;
+    /// Returns `true` if the action consumes a stack of Hyperphantasia.
+    pub const hyperphantasia;
+    /// Returns the aetherhue the action requires, or `None` if the action
+    /// is not part of a palette.
+    pub const aetherhue: u8?;
+    /// Returns the base GCD recast time, or `None` if the action is not a gcd.
+    pub const gcd: ScaleTime?;
+    /// Returns the base milliseconds the action takes to cast.
+    pub const cast: ScaleTime = ScaleTime::zero();
+    /// Returns the human friendly name of the action.
+    pub const name: &'static str;
+    /// Returns the cooldown of the skill in milliseconds.
+    pub const cooldown: u32 = 0;
+    /// Returns the number of charges a skill has, or `1` if it is a single charge skill.
+    pub const cd_charges: u8 = 1;
+    /// Returns the delay in milliseconds for the damage/statuses to be applied.
+    pub const effect_delay: u32 = 0;
+    /// Returns the MP cost of the action.
+    pub const mp_cost: u16 = 0;
+    /// Returns the [`ActionCategory`] this action is part of.
+    pub const category: ActionCategory;
+
+    pub const spell for {
+        gcd = ScaleTime::spell(2500);
+        category = ActionCategory::Spell;
+    }
+    pub const additive_hue for {
+        gcd = ScaleTime::spell(2500);
+        cast = ScaleTime::spell(1500);
+        mp_cost = 300;
+        hyperphantasia = true;
+        category = ActionCategory::Spell;
+    }
+    pub const subtractive_hue for {
+        gcd = ScaleTime::spell(3300);
+        cast = ScaleTime::spell(2300);
+        mp_cost = 400;
+        subtractive = true;
+        hyperphantasia = true;
+        category = ActionCategory::Spell;
+    }
+    pub const motif for {
+        gcd = ScaleTime::spell(4000);
+        cast = ScaleTime::spell(3000);
+        category = ActionCategory::Spell;
+    }
+    pub const skill for {
+        gcd = ScaleTime::skill(2500);
+        category = ActionCategory::Weaponskill;
+    }
+    pub const ability for {
+        category = ActionCategory::Ability;
+    }
+}]
+#[allow(missing_docs)] // no reason to document the variants.
+/// An action specific to the Pictomancer job.
+pub enum PctAction {
+    #[additive_hue]
+    #[aetherhue = 0]
+    #[name = "Fire in Red"]
+    FireInRed,
+    #[additive_hue]
+    #[aetherhue = 1]
+    #[name = "Aero in Green"]
+    AeroInGreen,
+    #[additive_hue]
+    #[aetherhue = 2]
+    #[name = "Water in Blue"]
+    WaterInBlue,
+    #[subtractive_hue]
+    #[aetherhue = 0]
+    #[name = "Blizzard in Cyan"]
+    BlizzardInCyan,
+    #[subtractive_hue]
+    #[aetherhue = 1]
+    #[name = "Stone in Yellow"]
+    StoneInYellow,
+    #[subtractive_hue]
+    #[aetherhue = 2]
+    #[name = "Thunder in Magenta"]
+    ThunderInMagenta,
+    #[hyperphantasia]
+    #[spell]
+    #[mp_cost = 300]
+    #[name = "Holy in White"]
+    HolyInWhite,
+    #[hyperphantasia]
+    #[spell]
+    #[mp_cost = 400]
+    #[name = "Comet in Black"]
+    CometInBlack,
+    #[gcd = ScaleTime::spell(6000)]
+    #[cast = ScaleTime::spell(4000)]
+    #[spell]
+    #[mp_cost = 400]
+    #[name = "Rainbow Drip"]
+    RainbowDrip,
+    #[spell]
+    #[name = "Star Prism"]
+    StarPrism,
+    #[skill]
+    #[name = "Hammer Stamp"]
+    HammerStamp,
+    #[skill]
+    #[name = "Hammer Brush"]
+    HammerBrush,
+    #[skill]
+    #[name = "Polishing Hammer"]
+    PolishingHammer,
+    #[motif]
+    #[name = "Pom Motif"]
+    PomMotif,
+    #[motif]
+    #[name = "Wing Motif"]
+    WingMotif,
+    #[motif]
+    #[name = "Claw Motif"]
+    ClawMotif,
+    #[motif]
+    #[name = "Maw Motif"]
+    MawMotif,
+    #[motif]
+    #[name = "Hammer Motif"]
+    HammerMotif,
+    #[motif]
+    #[name = "Starry Sky Motif"]
+    StarrySkyMotif,
+    #[ability]
+    #[cooldown = 40000]
+    #[cd_charges = 3]
+    #[name = "Pom Muse"]
+    PomMuse,
+    #[ability]
+    #[cooldown = 40000]
+    #[cd_charges = 3]
+    #[name = "Winged Muse"]
+    WingedMuse,
+    #[ability]
+    #[cooldown = 40000]
+    #[cd_charges = 3]
+    #[name = "Clawed Muse"]
+    ClawedMuse,
+    #[ability]
+    #[cooldown = 40000]
+    #[cd_charges = 3]
+    #[name = "Fanged Muse"]
+    FangedMuse,
+    #[ability]
+    #[cooldown = 30000]
+    #[name = "Mog of the Ages"]
+    MogOfTheAges,
+    #[ability]
+    #[cooldown = 30000]
+    #[name = "Retribution of the Madeen"]
+    RetributionOfTheMadeen,
+    #[ability]
+    #[cooldown = 60000]
+    #[cd_charges = 2]
+    #[name = "Striking Muse"]
+    StrikingMuse,
+    #[ability]
+    #[cooldown = 120000]
+    #[name = "Starry Muse"]
+    StarryMuse,
+    #[ability]
+    #[cooldown = 1000]
+    #[name = "Subtractive Palette"]
+    SubtractivePalette,
+    #[gcd((v) => v.gcd())]
+    #[cast((v) => v.cast())]
+    #[name((v) => v.name())]
+    #[cooldown((v) => v.cooldown())]
+    #[cd_charges((v) => v.cd_charges())]
+    #[effect_delay((v) => v.effect_delay())]
+    #[mp_cost((v) => v.mp_cost())]
+    #[category((v) => v.category())]
+    Role(CasterRoleAction),
+}
+
+impl PctAction {
+    /// Returns the action in the same palette as this one for the specified aetherhue.
+    ///
+    /// This action should be part of either the additive or subtractive palette.
+    pub const fn hue_action(&self, hue: u8) -> Self {
+        match (self.subtractive(), hue) {
+            (false, 0) => Self::FireInRed,
+            (false, 1) => Self::AeroInGreen,
+            (false, _) => Self::WaterInBlue,
+            (true, 0) => Self::BlizzardInCyan,
+            (true, 1) => Self::StoneInYellow,
+            (true, _) => Self::ThunderInMagenta,
+        }
+    }
+}
+
+impl JobAction for PctAction {
+    fn category(&self) -> ActionCategory {
+        self.category()
+    }
+
+    fn gcd(&self) -> bool {
+        self.gcd().is_some()
+    }
+}
+
+impl From<PctAction> for Action {
+    fn from(value: PctAction) -> Self {
+        Action::Job(value.into())
+    }
+}
+
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+/// The state of the Pictomancer job gauges, cooldowns, and combos.
+pub struct PctState {
+    /// The combos for Pictomancer.
+    pub combos: PctCombos,
+    /// The Palette Gauge.
+    pub palette: GaugeU8<100>,
+    /// The White Paint gauge.
+    pub paint: GaugeU8<5>,
+    /// The creature painted on the creature canvas.
+    pub creature: Option<Creature>,
+    /// The number of Living Muses that have been used.
+    ///
+    /// This determines which creature will be painted next.
+    pub creature_count: u8,
+    /// If the weapon canvas is painted.
+    pub weapon: bool,
+    /// If the landscape canvas is painted.
+    pub landscape: bool,
+    /// The portrait that is painted.
+    pub portrait: Option<Portrait>,
+}
+
+impl PctState {
+    /// Returns the next creature that will be painted by a creature motif.
+    pub const fn next_creature(&self) -> Creature {
+        Creature::SEQUENCE[(self.creature_count % 4) as usize]
+    }
+}
+
+impl JobState for PctState {
+    fn advance(&mut self, time: u32) {
+        self.combos.advance(time);
+    }
+}
+
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+/// The canvases that can be painted.
+pub enum Canvas {
+    /// The creature canvas.
+    Creature,
+    /// The weapon canvas.
+    Weapon,
+    /// The landscape canvas.
+    Landscape,
+}
+
+impl Canvas {
+    /// Returns the human friendly name of the canvas.
+    pub const fn name(&self) -> &'static str {
+        match self {
+            Self::Creature => "Creature",
+            Self::Weapon => "Weapon",
+            Self::Landscape => "Landscape",
+        }
+    }
+}
+
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+/// The creatures that can be painted on the creature canvas.
+pub enum Creature {
+    /// The Pom creature.
+    Pom,
+    /// The Wing creature.
+    Wing,
+    /// The Claw creature.
+    Claw,
+    /// The Maw creature.
+    Maw,
+}
+
+impl Creature {
+    /// The order that creatures are painted in.
+    pub const SEQUENCE: [Self; 4] = [Self::Pom, Self::Wing, Self::Claw, Self::Maw];
+
+    /// Returns the human friendly name of the creature.
+    pub const fn name(&self) -> &'static str {
+        match self {
+            Self::Pom => "Pom",
+            Self::Wing => "Wing",
+            Self::Claw => "Claw",
+            Self::Maw => "Maw",
+        }
+    }
+
+    /// Returns the motif action that paints this creature.
+    pub const fn motif(&self) -> PctAction {
+        match self {
+            Self::Pom => PctAction::PomMotif,
+            Self::Wing => PctAction::WingMotif,
+            Self::Claw => PctAction::ClawMotif,
+            Self::Maw => PctAction::MawMotif,
+        }
+    }
+
+    /// Returns the Living Muse action for this creature.
+    pub const fn muse(&self) -> PctAction {
+        match self {
+            Self::Pom => PctAction::PomMuse,
+            Self::Wing => PctAction::WingedMuse,
+            Self::Claw => PctAction::ClawedMuse,
+            Self::Maw => PctAction::FangedMuse,
+        }
+    }
+
+    /// Returns the potency of the Living Muse for this creature.
+    pub const fn potency(&self) -> u64 {
+        match self {
+            Self::Maw => 1100,
+            _ => 1000,
+        }
+    }
+}
+
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+/// The portraits that can be painted.
+pub enum Portrait {
+    /// The Moogle portrait, for Mog of the Ages.
+    Moogle,
+    /// The Madeen portrait, for Retribution of the Madeen.
+    Madeen,
+}
+
+impl Portrait {
+    /// Returns the human friendly name of the portrait.
+    pub const fn name(&self) -> &'static str {
+        match self {
+            Self::Moogle => "Moogle",
+            Self::Madeen => "Madeen",
+        }
+    }
+}
+
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+/// The combos for Pictomancer.
+pub struct PctCombos {
+    /// The hammer combo.
+    pub hammer: ComboState<HammerCombo>,
+}
+
+impl PctCombos {
+    /// Checks that the hammer combo prerequisite is met for a certain action.
+    pub fn check_hammer_for(&self, action: PctAction) -> bool {
+        let c = match action {
+            PctAction::HammerBrush => HammerCombo::Stamp,
+            PctAction::PolishingHammer => HammerCombo::Brush,
+            _ => return true,
+        };
+        self.hammer.check(c)
+    }
+
+    /// Advances the combos forward by a certain amount of time.
+    ///
+    /// See TODO: Advance Functions for more information.
+    pub fn advance(&mut self, time: u32) {
+        self.hammer.advance(time);
+    }
+}
+
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+/// The possible states the hammer combo can be in.
+pub enum HammerCombo {
+    /// Combo Action: Hammer Stamp is met.
+    Stamp,
+    /// Combo Action: Hammer Brush is met.
+    Brush,
+}
+
+job_cd_struct! {
+    PctAction =>
+
+    #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+    #[derive(Clone, Debug, Default)]
+    /// The cooldown map for Pictomancer actions.
+    pub PctCdMap
+
+    #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+    #[derive(Copy, Clone, Debug)]
+    /// The various cooldown groups a Pictomancer action can be part of.
+    pub PctCdGroup
+
+    "Living Muse"
+    living_muse LivingMuse: PomMuse WingedMuse ClawedMuse FangedMuse;
+    "Mog of the Ages"
+    mog MogOfTheAges: MogOfTheAges RetributionOfTheMadeen;
+    "Striking Muse"
+    striking_muse StrikingMuse: StrikingMuse;
+    "Starry Muse"
+    starry_muse StarryMuse: StarryMuse;
+    "Subtractive Palette"
+    subtractive_palette SubtractivePalette: SubtractivePalette;
+    "Swiftcast"
+    swiftcast Swiftcast: Role(CasterRoleAction::Swiftcast);
+    "Lucid Dreaming"
+    lucid_dreaming LucidDreaming: Role(CasterRoleAction::LucidDreaming);
+    "Addle"
+    addle Addle: Role(CasterRoleAction::Addle);
+    "Living Muse Charge"
+    living_muse_chg LivingMuseChg;
+    "Striking Muse Charge"
+    striking_muse_chg StrikingMuseChg;
+}
+
+impl PctAction {
+    /// Returns the alternate [cooldown group] that this action is part of.
+    ///
+    /// Returns `None` if this action does not have an alternate cooldown.
+    /// This action is used for the 1s cooldown between uses of charged actions.
+    ///
+    /// [cooldown group]: PctCdGroup
+    pub const fn alt_cd_group(&self) -> Option<PctCdGroup> {
+        match self {
+            Self::PomMuse | Self::WingedMuse | Self::ClawedMuse | Self::FangedMuse => {
+                Some(PctCdGroup::LivingMuseChg)
+            }
+            Self::StrikingMuse => Some(PctCdGroup::StrikingMuseChg),
+            _ => None,
+        }
+    }
+}
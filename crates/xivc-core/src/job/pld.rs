@@ -0,0 +1,733 @@
+use core::{
+    error::Error,
+    fmt::{self, Display},
+};
+
+use macros::var_consts;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    enums::{ActionCategory, DamageInstance},
+    err,
+    job::{role::TankRoleAction, CastInitInfo, Job, JobAction, JobState},
+    job_cd_struct,
+    math::SpeedStat,
+    status_effect,
+    timing::{DurationInfo, EventCascade, ScaleTime},
+    util::{combo_pot, status_proc_error, ActionTargettingExt as _, ComboState},
+    world::{
+        status::{consume_status, consume_status_stack, StatusEffect, StatusEventExt},
+        Action, ActionTargetting, ActorRef, DamageEventExt, EventError, EventSink, WorldRef,
+    },
+};
+
+/// The [`Job`] struct for Paladin.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct PldJob;
+
+/// The status effect "Fight or Flight".
+pub static FIGHT_OR_FLIGHT: StatusEffect = status_effect!(
+    "Fight or Flight" 20000 { damage { out = 125 / 100 } }
+);
+/// The status effect "Goring Blade Ready".
+pub static GORING_BLADE_READY: StatusEffect = status_effect!("Goring Blade Ready" 30000);
+/// The status effect "Atonement Ready".
+pub static ATONEMENT_READY: StatusEffect = status_effect!("Atonement Ready" 30000);
+/// The status effect "Supplication Ready".
+pub static SUPPLICATION_READY: StatusEffect = status_effect!("Supplication Ready" 30000);
+/// The status effect "Sepulchre Ready".
+pub static SEPULCHRE_READY: StatusEffect = status_effect!("Sepulchre Ready" 30000);
+/// The status effect "Divine Might".
+pub static DIVINE_MIGHT: StatusEffect = status_effect!("Divine Might" 30000);
+/// The status effect "Requiescat".
+pub static REQUIESCAT: StatusEffect = status_effect!("Requiescat" 30000);
+/// The status effect "Confiteor Ready".
+pub static CONFITEOR_READY: StatusEffect = status_effect!("Confiteor Ready" 30000);
+/// The status effect "Blade of Honor Ready".
+pub static BLADE_OF_HONOR_READY: StatusEffect = status_effect!("Blade of Honor Ready" 30000);
+/// The DoT effect "Circle of Scorn".
+pub static CIRCLE_OF_SCORN: StatusEffect = status_effect!("Circle of Scorn" 15000 multi);
+
+impl Job for PldJob {
+    type Action = PldAction;
+    type State = PldState;
+    type CastError = PldError;
+    type Event = ();
+    type CdGroup = PldCdGroup;
+    type CdMap<T> = PldCdMap<T>;
+
+    fn check_cast<'w, W: WorldRef<'w>, E: EventSink<'w, W>>(
+        action: Self::Action,
+        state: &Self::State,
+        _: &'w W,
+        event_sink: &mut E,
+    ) -> Result<CastInitInfo<Self::CdGroup>, EventError> {
+        let this = event_sink.source();
+
+        let di = this.duration_info();
+
+        use PldAction::*;
+
+        let cast = match action {
+            HolySpirit | HolyCircle
+                if this.has_own_status(DIVINE_MIGHT) || this.has_own_status(REQUIESCAT) =>
+            {
+                ScaleTime::zero()
+            }
+            _ => action.cast(),
+        };
+
+        let gcd = action.gcd().map(|v| di.scale(v)).unwrap_or_default() as u16;
+        let (lock, snap) = di.get_cast(cast, 600);
+
+        let cd = action
+            .cd_group()
+            .map(|v| (v, action.cooldown(), action.cd_charges()));
+
+        let alt_cd = action.alt_cd_group().map(|v| (v, 1000, 1));
+
+        match action {
+            Atonement if !this.has_own_status(ATONEMENT_READY) => err!(PldError::Atonement),
+            Supplication if !this.has_own_status(SUPPLICATION_READY) => {
+                err!(PldError::Supplication)
+            }
+            Sepulchre if !this.has_own_status(SEPULCHRE_READY) => err!(PldError::Sepulchre),
+            GoringBlade if !this.has_own_status(GORING_BLADE_READY) => {
+                err!(PldError::GoringBlade)
+            }
+            Confiteor if !this.has_own_status(CONFITEOR_READY) => err!(PldError::Confiteor),
+            BladeOfFaith | BladeOfTruth | BladeOfValor
+                if !state.combos.check_confiteor_for(action) =>
+            {
+                err!(PldError::ConfiteorCombo(match action {
+                    BladeOfFaith => Confiteor,
+                    BladeOfTruth => BladeOfFaith,
+                    _ => BladeOfTruth,
+                }))
+            }
+            BladeOfHonor if !this.has_own_status(BLADE_OF_HONOR_READY) => {
+                err!(PldError::BladeOfHonor)
+            }
+            _ => (),
+        }
+
+        Ok(CastInitInfo {
+            gcd,
+            lock,
+            snap,
+            mp: action.mp_cost(),
+            cd,
+            alt_cd,
+        })
+    }
+
+    fn cast_snap<'w, W: WorldRef<'w>, E: EventSink<'w, W>>(
+        action: Self::Action,
+        state: &mut Self::State,
+        _: &'w W,
+        event_sink: &mut E,
+    ) -> Result<(), EventError> {
+        let this = event_sink.source();
+        let this_id = this.id();
+
+        use PldAction::*;
+
+        let dl = action.effect_delay();
+
+        match action {
+            FastBlade => {
+                let t = this.target_enemy(MELEE)?.id();
+                state.combos.main.set(MainCombo::FastBlade);
+                event_sink.damage(action, DamageInstance::new(220).slashing(), t, dl);
+            }
+            RiotBlade => {
+                let t = this.target_enemy(MELEE)?.id();
+                let combo = state.combos.check_main_for(action);
+                if combo {
+                    state.combos.main.set(MainCombo::RiotBlade);
+                } else {
+                    state.combos.main.reset();
+                }
+                event_sink.damage(
+                    action,
+                    DamageInstance::new(combo_pot(140, 330, combo)).slashing(),
+                    t,
+                    dl,
+                );
+            }
+            RoyalAuthority => {
+                let t = this.target_enemy(MELEE)?.id();
+                let combo = state.combos.check_main_for(action);
+                state.combos.main.reset();
+                if combo {
+                    event_sink.apply_status(ATONEMENT_READY, 1, this_id, dl);
+                    event_sink.apply_status(DIVINE_MIGHT, 1, this_id, dl);
+                }
+                event_sink.damage(
+                    action,
+                    DamageInstance::new(combo_pot(140, 460, combo)).slashing(),
+                    t,
+                    dl,
+                );
+            }
+            TotalEclipse => {
+                let mut hit = false;
+                for (t, d) in this
+                    .target_enemy_aoe(CIRCLE, EventCascade::new(dl, 1))?
+                    .id()
+                {
+                    hit = true;
+                    event_sink.damage(action, DamageInstance::new(100).slashing(), t, d);
+                }
+                if hit {
+                    state.combos.aoe.set(AoeCombo::TotalEclipse);
+                } else {
+                    state.combos.aoe.reset();
+                }
+            }
+            Prominence => {
+                let combo = state.combos.check_aoe_for(action);
+                state.combos.aoe.reset();
+                let mut hit = false;
+                for (t, d) in this
+                    .target_enemy_aoe(CIRCLE, EventCascade::new(dl, 1))?
+                    .id()
+                {
+                    hit = true;
+                    event_sink.damage(
+                        action,
+                        DamageInstance::new(combo_pot(100, 170, combo)).slashing(),
+                        t,
+                        d,
+                    );
+                }
+                if hit && combo {
+                    event_sink.apply_status(DIVINE_MIGHT, 1, this_id, dl);
+                }
+            }
+            ShieldLob => {
+                let t = this.target_enemy(RANGED)?.id();
+                event_sink.damage(action, DamageInstance::new(100).slashing(), t, dl);
+            }
+            Atonement | Supplication | Sepulchre => {
+                let t = this.target_enemy(MELEE)?.id();
+                let (ready, next, potency) = match action {
+                    Atonement => (ATONEMENT_READY, Some(SUPPLICATION_READY), 460),
+                    Supplication => (SUPPLICATION_READY, Some(SEPULCHRE_READY), 500),
+                    _ => (SEPULCHRE_READY, None, 540),
+                };
+                if !consume_status(event_sink, ready, 0) {
+                    err!(match action {
+                        Atonement => PldError::Atonement,
+                        Supplication => PldError::Supplication,
+                        _ => PldError::Sepulchre,
+                    });
+                }
+                if let Some(next) = next {
+                    event_sink.apply_status(next, 1, this_id, 0);
+                }
+                event_sink.damage(action, DamageInstance::new(potency).slashing(), t, dl);
+            }
+            GoringBlade => {
+                let t = this.target_enemy(MELEE)?.id();
+                if !consume_status(event_sink, GORING_BLADE_READY, 0) {
+                    err!(PldError::GoringBlade);
+                }
+                event_sink.damage(action, DamageInstance::new(700).slashing(), t, dl);
+            }
+            HolySpirit => {
+                let t = this.target_enemy(SPELL)?.id();
+                let potency = if consume_status(event_sink, DIVINE_MIGHT, 0) {
+                    500
+                } else if consume_status_stack(event_sink, REQUIESCAT, 0) {
+                    650
+                } else {
+                    350
+                };
+                event_sink.damage(action, DamageInstance::new(potency).magical(), t, dl);
+            }
+            HolyCircle => {
+                let iter = this
+                    .target_enemy_aoe(CIRCLE, EventCascade::new(dl, 1))?
+                    .id();
+                let potency = if consume_status(event_sink, DIVINE_MIGHT, 0) {
+                    200
+                } else if consume_status_stack(event_sink, REQUIESCAT, 0) {
+                    300
+                } else {
+                    100
+                };
+                for (t, d) in iter {
+                    event_sink.damage(action, DamageInstance::new(potency).magical(), t, d);
+                }
+            }
+            Confiteor | BladeOfFaith | BladeOfTruth | BladeOfValor => {
+                let iter = this
+                    .target_enemy_aoe(TG_CIRCLE, EventCascade::new(dl, 1))?
+                    .id()
+                    .falloff(50);
+                let (base, req_pot) = match action {
+                    Confiteor => {
+                        if !consume_status(event_sink, CONFITEOR_READY, 0) {
+                            err!(PldError::Confiteor);
+                        }
+                        state.combos.confiteor.set(ConfiteorCombo::Confiteor);
+                        (500, 1000)
+                    }
+                    BladeOfFaith => {
+                        state.combos.confiteor.set(ConfiteorCombo::BladeOfFaith);
+                        (260, 760)
+                    }
+                    BladeOfTruth => {
+                        state.combos.confiteor.set(ConfiteorCombo::BladeOfTruth);
+                        (380, 880)
+                    }
+                    _ => {
+                        state.combos.confiteor.reset();
+                        event_sink.apply_status(BLADE_OF_HONOR_READY, 1, this_id, 0);
+                        (500, 1000)
+                    }
+                };
+                let req = consume_status_stack(event_sink, REQUIESCAT, 0);
+                let potency = combo_pot(base, req_pot, req);
+                for (t, d, f) in iter {
+                    event_sink.damage(
+                        action,
+                        DamageInstance::new(potency).magical().falloff(f),
+                        t,
+                        d,
+                    );
+                }
+            }
+            FightOrFlight => {
+                event_sink.apply_status(FIGHT_OR_FLIGHT, 1, this_id, 0);
+                event_sink.apply_status(GORING_BLADE_READY, 1, this_id, 0);
+            }
+            Requiescat | Imperator => {
+                if action == Imperator {
+                    for (t, d, f) in this
+                        .target_enemy_aoe(TG_CIRCLE, EventCascade::new(dl, 1))?
+                        .id()
+                        .falloff(50)
+                    {
+                        event_sink.damage(
+                            action,
+                            DamageInstance::new(580).magical().falloff(f),
+                            t,
+                            d,
+                        );
+                    }
+                }
+                event_sink.apply_status(REQUIESCAT, 4, this_id, 0);
+                event_sink.apply_status(CONFITEOR_READY, 1, this_id, 0);
+            }
+            CircleOfScorn => {
+                for (t, d) in this
+                    .target_enemy_aoe(CIRCLE, EventCascade::new(dl, 1))?
+                    .id()
+                {
+                    event_sink.damage(action, DamageInstance::new(140).slashing(), t, d);
+                    event_sink.apply_dot(
+                        CIRCLE_OF_SCORN,
+                        DamageInstance::new(30).slashing(),
+                        SpeedStat::SkillSpeed,
+                        1,
+                        t,
+                        d,
+                    );
+                }
+            }
+            Expiacion => {
+                for (t, d, f) in this
+                    .target_enemy_aoe(EXPIACION, EventCascade::new(dl, 1))?
+                    .id()
+                    .falloff(40)
+                {
+                    event_sink.damage(action, DamageInstance::new(450).slashing().falloff(f), t, d);
+                }
+            }
+            Intervene => {
+                let t = this.target_enemy(INTERVENE)?.id();
+                event_sink.damage(action, DamageInstance::new(150).slashing(), t, dl);
+            }
+            BladeOfHonor => {
+                let iter = this
+                    .target_enemy_aoe(TG_CIRCLE, EventCascade::new(dl, 1))?
+                    .id()
+                    .falloff(50);
+                if !consume_status(event_sink, BLADE_OF_HONOR_READY, 0) {
+                    err!(PldError::BladeOfHonor);
+                }
+                for (t, d, f) in iter {
+                    event_sink.damage(action, DamageInstance::new(1000).magical().falloff(f), t, d);
+                }
+            }
+            Role(v) => v.cast_snap(action, event_sink)?,
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Copy, Debug)]
+/// A custom cast error for Paladin actions.
+pub enum PldError {
+    /// Not under the effect of Atonement Ready.
+    Atonement,
+    /// Not under the effect of Supplication Ready.
+    Supplication,
+    /// Not under the effect of Sepulchre Ready.
+    Sepulchre,
+    /// Not under the effect of Goring Blade Ready.
+    GoringBlade,
+    /// Not under the effect of Confiteor Ready.
+    Confiteor,
+    /// Not executed following the specified action.
+    ConfiteorCombo(PldAction),
+    /// Not under the effect of Blade of Honor Ready.
+    BladeOfHonor,
+}
+
+impl From<PldError> for EventError {
+    fn from(value: PldError) -> Self {
+        Self::Job(value.into())
+    }
+}
+
+impl Display for PldError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Atonement => status_proc_error(f, ATONEMENT_READY),
+            Self::Supplication => status_proc_error(f, SUPPLICATION_READY),
+            Self::Sepulchre => status_proc_error(f, SEPULCHRE_READY),
+            Self::GoringBlade => status_proc_error(f, GORING_BLADE_READY),
+            Self::Confiteor => status_proc_error(f, CONFITEOR_READY),
+            Self::ConfiteorCombo(ac) => write!(f, "Not executed following '{}'.", ac.name()),
+            Self::BladeOfHonor => status_proc_error(f, BLADE_OF_HONOR_READY),
+        }
+    }
+}
+
+impl Error for PldError {}
+
+const MELEE: ActionTargetting = ActionTargetting::single(3);
+const RANGED: ActionTargetting = ActionTargetting::single(20);
+const SPELL: ActionTargetting = ActionTargetting::single(25);
+const INTERVENE: ActionTargetting = ActionTargetting::single(20);
+const CIRCLE: ActionTargetting = ActionTargetting::circle(5);
+const TG_CIRCLE: ActionTargetting = ActionTargetting::target_circle(5, 25);
+const EXPIACION: ActionTargetting = ActionTargetting::target_circle(5, 3);
+
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(rename_all = "snake_case")
+)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[repr(u8)]
+#[var_consts {
+    /// Returns the base GCD recast time, or `None` if the action is not a gcd.
+    pub const gcd: ScaleTime?;
+    /// Returns the base milliseconds the action takes to cast.
+    pub const cast: ScaleTime = ScaleTime::zero();
+    /// Returns the human friendly name of the action.
+    pub const name: &'static str;
+    /// Returns the cooldown of the skill in milliseconds.
+    pub const cooldown: u32 = 0;
+    /// Returns the number of charges a skill has, or `1` if it is a single charge skill.
+    pub const cd_charges: u8 = 1;
+    /// Returns the delay in milliseconds for the damage/statuses to be applied.
+    pub const effect_delay: u32 = 0;
+    /// Returns the MP cost of the action.
+    pub const mp_cost: u16 = 0;
+    /// Returns the [`ActionCategory`] this action is part of.
+    pub const category: ActionCategory;
+
+    pub const skill for {
+        gcd = ScaleTime::skill(2500);
+        category = ActionCategory::Weaponskill;
+    }
+    pub const spell for {
+        gcd = ScaleTime::spell(2500);
+        category = ActionCategory::Spell;
+    }
+    pub const ability for {
+        category = ActionCategory::Ability;
+    }
+}]
+#[allow(missing_docs)] // no reason to document the variants.
+/// An action specific to the Paladin job.
+pub enum PldAction {
+    #[skill]
+    #[name = "Fast Blade"]
+    FastBlade,
+    #[ability]
+    #[cooldown = 60000]
+    #[name = "Fight or Flight"]
+    FightOrFlight,
+    #[skill]
+    #[name = "Riot Blade"]
+    RiotBlade,
+    #[skill]
+    #[name = "Total Eclipse"]
+    TotalEclipse,
+    #[skill]
+    #[name = "Shield Lob"]
+    ShieldLob,
+    #[ability]
+    #[cooldown = 30000]
+    #[name = "Circle of Scorn"]
+    CircleOfScorn,
+    #[skill]
+    #[name = "Royal Authority"]
+    RoyalAuthority,
+    #[skill]
+    #[name = "Prominence"]
+    Prominence,
+    #[ability]
+    #[cooldown = 60000]
+    #[name = "Requiescat"]
+    Requiescat,
+    #[spell]
+    #[cast = ScaleTime::spell(1500)]
+    #[mp_cost = 1000]
+    #[name = "Holy Spirit"]
+    HolySpirit,
+    #[spell]
+    #[cast = ScaleTime::spell(1500)]
+    #[mp_cost = 1000]
+    #[name = "Holy Circle"]
+    HolyCircle,
+    #[ability]
+    #[cooldown = 30000]
+    #[cd_charges = 2]
+    #[name = "Intervene"]
+    Intervene,
+    #[skill]
+    #[name = "Atonement"]
+    Atonement,
+    #[skill]
+    #[name = "Supplication"]
+    Supplication,
+    #[skill]
+    #[name = "Sepulchre"]
+    Sepulchre,
+    #[spell]
+    #[mp_cost = 1000]
+    #[name = "Confiteor"]
+    Confiteor,
+    #[ability]
+    #[cooldown = 30000]
+    #[name = "Expiacion"]
+    Expiacion,
+    #[spell]
+    #[mp_cost = 1000]
+    #[name = "Blade of Faith"]
+    BladeOfFaith,
+    #[spell]
+    #[mp_cost = 1000]
+    #[name = "Blade of Truth"]
+    BladeOfTruth,
+    #[spell]
+    #[mp_cost = 1000]
+    #[name = "Blade of Valor"]
+    BladeOfValor,
+    #[skill]
+    #[name = "Goring Blade"]
+    GoringBlade,
+    #[ability]
+    #[cooldown = 60000]
+    #[name = "Imperator"]
+    Imperator,
+    #[ability]
+    #[cooldown = 1000]
+    #[name = "Blade of Honor"]
+    BladeOfHonor,
+    #[gcd((v) => v.gcd())]
+    #[cast((v) => v.cast())]
+    #[name((v) => v.name())]
+    #[cooldown((v) => v.cooldown())]
+    #[cd_charges((v) => v.cd_charges())]
+    #[effect_delay((v) => v.effect_delay())]
+    #[mp_cost((v) => v.mp_cost())]
+    #[category((v) => v.category())]
+    Role(TankRoleAction),
+}
+
+impl JobAction for PldAction {
+    fn category(&self) -> ActionCategory {
+        self.category()
+    }
+
+    fn gcd(&self) -> bool {
+        self.gcd().is_some()
+    }
+}
+
+impl From<PldAction> for Action {
+    fn from(value: PldAction) -> Self {
+        Action::Job(value.into())
+    }
+}
+
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+/// The state of the Paladin job gauges, cooldowns, and combos.
+pub struct PldState {
+    /// The combos for Paladin.
+    pub combos: PldCombos,
+}
+
+impl JobState for PldState {
+    fn advance(&mut self, time: u32) {
+        self.combos.advance(time);
+    }
+}
+
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+/// The combos for Paladin.
+pub struct PldCombos {
+    /// The main combo.
+    ///
+    /// Includes the Riot Blade and Royal Authority combo.
+    pub main: ComboState<MainCombo>,
+    /// The AoE combo.
+    ///
+    /// Includes the Prominence combo.
+    pub aoe: ComboState<AoeCombo>,
+    /// The combo for the Confiteor chain.
+    pub confiteor: ComboState<ConfiteorCombo>,
+}
+
+impl PldCombos {
+    /// Checks that the main combo prerequisite is met for a certain action.
+    pub fn check_main_for(&self, action: PldAction) -> bool {
+        let c = match action {
+            PldAction::RiotBlade => MainCombo::FastBlade,
+            PldAction::RoyalAuthority => MainCombo::RiotBlade,
+            _ => return true,
+        };
+        self.main.check(c)
+    }
+
+    /// Checks that the AoE combo prerequisite is met for a certain action.
+    pub fn check_aoe_for(&self, action: PldAction) -> bool {
+        let c = match action {
+            PldAction::Prominence => AoeCombo::TotalEclipse,
+            _ => return true,
+        };
+        self.aoe.check(c)
+    }
+
+    /// Checks that the Confiteor combo prerequisite is met for a certain action.
+    pub fn check_confiteor_for(&self, action: PldAction) -> bool {
+        let c = match action {
+            PldAction::BladeOfFaith => ConfiteorCombo::Confiteor,
+            PldAction::BladeOfTruth => ConfiteorCombo::BladeOfFaith,
+            PldAction::BladeOfValor => ConfiteorCombo::BladeOfTruth,
+            _ => return true,
+        };
+        self.confiteor.check(c)
+    }
+
+    /// Advances the combos forward by a certain amount of time.
+    ///
+    /// See TODO: Advance Functions for more information.
+    pub fn advance(&mut self, time: u32) {
+        self.main.advance(time);
+        self.aoe.advance(time);
+        self.confiteor.advance(time);
+    }
+}
+
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+/// The possible states the main combo can be in.
+pub enum MainCombo {
+    /// Combo Action: Fast Blade is met.
+    FastBlade,
+    /// Combo Action: Riot Blade is met.
+    RiotBlade,
+}
+
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+/// The possible states the AoE combo can be in.
+pub enum AoeCombo {
+    /// Combo Action: Total Eclipse is met.
+    TotalEclipse,
+}
+
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+/// The possible states the Confiteor combo can be in.
+pub enum ConfiteorCombo {
+    /// Combo Action: Confiteor is met.
+    Confiteor,
+    /// Combo Action: Blade of Faith is met.
+    BladeOfFaith,
+    /// Combo Action: Blade of Truth is met.
+    BladeOfTruth,
+}
+
+job_cd_struct! {
+    PldAction =>
+
+    #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+    #[derive(Clone, Debug, Default)]
+    /// The cooldown map for Paladin actions.
+    pub PldCdMap
+
+    #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+    #[derive(Copy, Clone, Debug)]
+    /// The various cooldown groups a Paladin action can be part of.
+    pub PldCdGroup
+
+    "Fight or Flight"
+    fof FightOrFlight: FightOrFlight;
+    "Circle of Scorn"
+    scorn CircleOfScorn: CircleOfScorn;
+    "Requiescat and Imperator"
+    requiescat Requiescat: Requiescat Imperator;
+    "Intervene"
+    intervene Intervene: Intervene;
+    "Expiacion"
+    expiacion Expiacion: Expiacion;
+    "Blade of Honor"
+    honor BladeOfHonor: BladeOfHonor;
+    "Rampart"
+    rampart Rampart: Role(TankRoleAction::Rampart);
+    "Reprisal"
+    reprisal Reprisal: Role(TankRoleAction::Reprisal);
+    "Arm's Length"
+    arms_length ArmsLength: Role(TankRoleAction::ArmsLength);
+    "Provoke"
+    provoke Provoke: Role(TankRoleAction::Provoke);
+    "Shirk"
+    shirk Shirk: Role(TankRoleAction::Shirk);
+    "Interject"
+    interject Interject: Role(TankRoleAction::Interject);
+    "Low Blow"
+    low_blow LowBlow: Role(TankRoleAction::LowBlow);
+    "Intervene Charge"
+    intervene_chg InterveneChg;
+}
+
+impl PldAction {
+    /// Returns the alternate [cooldown group] that this action is part of.
+    ///
+    /// Returns `None` if this action does not have an alternate cooldown.
+    /// This action is used for the 1s cooldown between uses of charged actions.
+    ///
+    /// [cooldown group]: PldCdGroup
+    pub const fn alt_cd_group(&self) -> Option<PldCdGroup> {
+        match self {
+            Self::Intervene => Some(PldCdGroup::InterveneChg),
+            _ => None,
+        }
+    }
+}
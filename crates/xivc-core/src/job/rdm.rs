@@ -0,0 +1,761 @@
+use core::{
+    error::Error,
+    fmt::{self, Display},
+};
+
+use macros::var_consts;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    bool_job_dist,
+    enums::{ActionCategory, DamageInstance},
+    err,
+    job::{
+        role::{CasterRoleAction, SWIFTCAST},
+        CastInitInfo, Job, JobAction, JobState,
+    },
+    job_cd_struct, status_effect,
+    timing::{DurationInfo, EventCascade, ScaleTime},
+    util::{combo_pot, status_proc_error, ActionTargettingExt as _, ComboState, GaugeU8},
+    world::{
+        status::{consume_status, consume_status_stack, StatusEffect, StatusEventExt},
+        Action, ActionTargetting, ActorRef, DamageEventExt, EventError, EventSink, WorldRef,
+    },
+};
+
+/// The [`Job`] struct for Red Mage.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct RdmJob;
+
+/// The status effect "Dualcast".
+pub static DUALCAST: StatusEffect = status_effect!("Dualcast" 15000);
+/// The status effect "Verfire Ready".
+pub static VERFIRE_READY: StatusEffect = status_effect!("Verfire Ready" 30000);
+/// The status effect "Verstone Ready".
+pub static VERSTONE_READY: StatusEffect = status_effect!("Verstone Ready" 30000);
+/// The status effect "Acceleration".
+pub static ACCELERATION: StatusEffect = status_effect!("Acceleration" 20000);
+/// The status effect "Grand Impact Ready".
+pub static GRAND_IMPACT_READY: StatusEffect = status_effect!("Grand Impact Ready" 30000);
+/// The status effect "Embolden".
+pub static EMBOLDEN: StatusEffect = status_effect!(
+    "Embolden" 20000 { damage { out = 105 / 100 } }
+);
+/// The status effect "Thorned Flourish".
+pub static THORNED_FLOURISH: StatusEffect = status_effect!("Thorned Flourish" 30000);
+/// The status effect "Manafication".
+pub static MANAFICATION: StatusEffect = status_effect!(
+    "Manafication" 30000 { damage { out = 105 / 100 } }
+);
+/// The status effect "Magicked Swordplay".
+pub static MAGICKED_SWORDPLAY: StatusEffect = status_effect!("Magicked Swordplay" 30000);
+/// The status effect "Prefulgence Ready".
+pub static PREFULGENCE_READY: StatusEffect = status_effect!("Prefulgence Ready" 30000);
+
+impl Job for RdmJob {
+    type Action = RdmAction;
+    type State = RdmState;
+    type CastError = RdmError;
+    type Event = ();
+    type CdGroup = RdmCdGroup;
+    type CdMap<T> = RdmCdMap<T>;
+
+    fn check_cast<'w, W: WorldRef<'w>, E: EventSink<'w, W>>(
+        action: Self::Action,
+        state: &Self::State,
+        _: &'w W,
+        event_sink: &mut E,
+    ) -> Result<CastInitInfo<Self::CdGroup>, EventError> {
+        let this = event_sink.source();
+
+        let di = this.duration_info();
+
+        use RdmAction::*;
+
+        let cast = if this.has_own_status(DUALCAST)
+            || action.accelerated() && this.has_own_status(ACCELERATION)
+            || this.has_own_status(SWIFTCAST)
+        {
+            ScaleTime::zero()
+        } else {
+            action.cast()
+        };
+
+        let enchanted = action.enchant_cost() > 0
+            && (this.has_own_status(MAGICKED_SWORDPLAY) || state.has_mana(action.enchant_cost()));
+
+        let gcd = if enchanted {
+            action.enchanted_gcd()
+        } else {
+            action.gcd()
+        };
+        let gcd = gcd.map(|v| di.scale(v)).unwrap_or_default() as u16;
+        let (lock, snap) = di.get_cast(cast, 600);
+
+        let cd = action
+            .cd_group()
+            .map(|v| (v, action.cooldown(), action.cd_charges()));
+
+        let alt_cd = action.alt_cd_group().map(|v| (v, 1000, 1));
+
+        match action {
+            Verfire if !this.has_own_status(VERFIRE_READY) => err!(RdmError::Verfire),
+            Verstone if !this.has_own_status(VERSTONE_READY) => err!(RdmError::Verstone),
+            GrandImpact if !this.has_own_status(GRAND_IMPACT_READY) => {
+                err!(RdmError::GrandImpact)
+            }
+            Verflare | Verholy if state.mana_stacks < 3 => err!(RdmError::ManaStacks),
+            Scorch | Resolution if !state.combos.check_finisher_for(action) => {
+                err!(RdmError::Finisher(match action {
+                    Scorch => Verflare,
+                    _ => Scorch,
+                }))
+            }
+            ViceOfThorns if !this.has_own_status(THORNED_FLOURISH) => {
+                err!(RdmError::ViceOfThorns)
+            }
+            Prefulgence if !this.has_own_status(PREFULGENCE_READY) => {
+                err!(RdmError::Prefulgence)
+            }
+            _ => (),
+        }
+
+        Ok(CastInitInfo {
+            gcd,
+            lock,
+            snap,
+            mp: action.mp_cost(),
+            cd,
+            alt_cd,
+        })
+    }
+
+    fn cast_snap<'w, W: WorldRef<'w>, E: EventSink<'w, W>>(
+        action: Self::Action,
+        state: &mut Self::State,
+        _: &'w W,
+        event_sink: &mut E,
+    ) -> Result<(), EventError> {
+        let this = event_sink.source();
+        let this_id = this.id();
+
+        use RdmAction::*;
+
+        let dl = action.effect_delay();
+
+        // dualcast is used up before acceleration, which is used up before swiftcast,
+        // and casting a spell with a cast time grants dualcast.
+        let mut accelerated = false;
+        if action.cast().duration() > 0 {
+            if !consume_status(event_sink, DUALCAST, 0) {
+                accelerated = action.accelerated() && consume_status(event_sink, ACCELERATION, 0);
+                if !accelerated && !consume_status(event_sink, SWIFTCAST, 0) {
+                    event_sink.apply_status(DUALCAST, 1, this_id, 0);
+                }
+            }
+        } else if action.gcd().is_some() {
+            consume_status(event_sink, DUALCAST, 0);
+        }
+
+        if action.gcd().is_some() {
+            consume_status_stack(event_sink, MANAFICATION, 0);
+        }
+
+        let dmg = |potency| DamageInstance::new(potency).magical();
+
+        match action {
+            JoltIII | VerthunderIII | VeraeroIII | Verfire | Verstone => {
+                let t = this.target_enemy(SPELL)?.id();
+                let potency = match action {
+                    JoltIII => {
+                        state.add_mana(2, 2);
+                        360
+                    }
+                    VerthunderIII => {
+                        state.add_mana(6, 0);
+                        if accelerated || event_sink.random(VerfireProc) {
+                            event_sink.apply_status(VERFIRE_READY, 1, this_id, dl);
+                        }
+                        440
+                    }
+                    VeraeroIII => {
+                        state.add_mana(0, 6);
+                        if accelerated || event_sink.random(VerstoneProc) {
+                            event_sink.apply_status(VERSTONE_READY, 1, this_id, dl);
+                        }
+                        440
+                    }
+                    Verfire => {
+                        if !consume_status(event_sink, VERFIRE_READY, 0) {
+                            err!(RdmError::Verfire);
+                        }
+                        state.add_mana(5, 0);
+                        380
+                    }
+                    _ => {
+                        if !consume_status(event_sink, VERSTONE_READY, 0) {
+                            err!(RdmError::Verstone);
+                        }
+                        state.add_mana(0, 5);
+                        380
+                    }
+                };
+                event_sink.damage(action, dmg(potency), t, dl);
+            }
+            VerthunderII | VeraeroII | Impact | GrandImpact => {
+                let iter = this
+                    .target_enemy_aoe(TG_CIRCLE, EventCascade::new(dl, 1))?
+                    .id();
+                let (potency, falloff) = match action {
+                    VerthunderII => {
+                        state.add_mana(7, 0);
+                        (140, 100)
+                    }
+                    VeraeroII => {
+                        state.add_mana(0, 7);
+                        (140, 100)
+                    }
+                    Impact => {
+                        state.add_mana(3, 3);
+                        (210, 100)
+                    }
+                    _ => {
+                        if !consume_status(event_sink, GRAND_IMPACT_READY, 0) {
+                            err!(RdmError::GrandImpact);
+                        }
+                        state.add_mana(3, 3);
+                        (600, 40)
+                    }
+                };
+                for (t, d, f) in iter.falloff(falloff) {
+                    event_sink.damage(action, dmg(potency).falloff(f), t, d);
+                }
+            }
+            Riposte | Zwerchhau | Redoublement => {
+                let t = this.target_enemy(MELEE)?.id();
+                let combo = state.combos.check_melee_for(action);
+                let cost = action.enchant_cost();
+                let enchanted = consume_status_stack(event_sink, MAGICKED_SWORDPLAY, 0)
+                    || state.consume_mana(cost);
+                match action {
+                    Riposte => state.combos.melee.set(MeleeCombo::Riposte),
+                    Zwerchhau if combo => state.combos.melee.set(MeleeCombo::Zwerchhau),
+                    _ => state.combos.melee.reset(),
+                }
+                let damage = if enchanted {
+                    state.mana_stacks += 1;
+                    dmg(match action {
+                        Riposte => 300,
+                        Zwerchhau => combo_pot(170, 360, combo),
+                        _ => combo_pot(170, 540, combo),
+                    })
+                } else {
+                    DamageInstance::new(match action {
+                        Riposte => 130,
+                        Zwerchhau => combo_pot(100, 150, combo),
+                        _ => combo_pot(100, 230, combo),
+                    })
+                    .slashing()
+                };
+                event_sink.damage(action, damage, t, dl);
+            }
+            Verflare | Verholy | Scorch | Resolution => {
+                let targetting = if action == Resolution {
+                    LINE
+                } else {
+                    TG_CIRCLE
+                };
+                let iter = this
+                    .target_enemy_aoe(targetting, EventCascade::new(dl, 1))?
+                    .id()
+                    .falloff(40);
+                let potency = match action {
+                    Verflare | Verholy => {
+                        if !state.mana_stacks.consume(3) {
+                            err!(RdmError::ManaStacks);
+                        }
+                        state.combos.finisher.set(FinisherCombo::Verflare);
+                        // the proc is guaranteed if the opposite mana is higher
+                        if action == Verflare {
+                            let guaranteed = *state.white > *state.black;
+                            state.add_mana(11, 0);
+                            if guaranteed || event_sink.random(FinisherProc) {
+                                event_sink.apply_status(VERFIRE_READY, 1, this_id, dl);
+                            }
+                        } else {
+                            let guaranteed = *state.black > *state.white;
+                            state.add_mana(0, 11);
+                            if guaranteed || event_sink.random(FinisherProc) {
+                                event_sink.apply_status(VERSTONE_READY, 1, this_id, dl);
+                            }
+                        }
+                        620
+                    }
+                    Scorch => {
+                        let combo = state.combos.check_finisher_for(action);
+                        if combo {
+                            state.combos.finisher.set(FinisherCombo::Scorch);
+                        } else {
+                            state.combos.finisher.reset();
+                            err!(RdmError::Finisher(Verflare));
+                        }
+                        state.add_mana(4, 4);
+                        700
+                    }
+                    _ => {
+                        let combo = state.combos.check_finisher_for(action);
+                        state.combos.finisher.reset();
+                        if !combo {
+                            err!(RdmError::Finisher(Scorch));
+                        }
+                        state.add_mana(4, 4);
+                        800
+                    }
+                };
+                for (t, d, f) in iter {
+                    event_sink.damage(action, dmg(potency).falloff(f), t, d);
+                }
+            }
+            Fleche => {
+                let t = this.target_enemy(SPELL)?.id();
+                event_sink.damage(action, dmg(480), t, dl);
+            }
+            ContreSixte | ViceOfThorns | Prefulgence => {
+                let iter = this
+                    .target_enemy_aoe(TG_CIRCLE, EventCascade::new(dl, 1))?
+                    .id();
+                let (potency, falloff) = match action {
+                    ContreSixte => (420, 100),
+                    ViceOfThorns => {
+                        if !consume_status(event_sink, THORNED_FLOURISH, 0) {
+                            err!(RdmError::ViceOfThorns);
+                        }
+                        (700, 40)
+                    }
+                    _ => {
+                        if !consume_status(event_sink, PREFULGENCE_READY, 0) {
+                            err!(RdmError::Prefulgence);
+                        }
+                        (900, 40)
+                    }
+                };
+                for (t, d, f) in iter.falloff(falloff) {
+                    event_sink.damage(action, dmg(potency).falloff(f), t, d);
+                }
+            }
+            Acceleration => {
+                event_sink.apply_status(ACCELERATION, 1, this_id, 0);
+                event_sink.apply_status(GRAND_IMPACT_READY, 1, this_id, 0);
+            }
+            Manafication => {
+                event_sink.apply_status(MAGICKED_SWORDPLAY, 3, this_id, 0);
+                event_sink.apply_status(MANAFICATION, 6, this_id, 0);
+                event_sink.apply_status(PREFULGENCE_READY, 1, this_id, 0);
+            }
+            Embolden => {
+                event_sink.apply_status(THORNED_FLOURISH, 1, this_id, dl);
+                for (t, d) in this
+                    .target_party_aoe(ActionTargetting::circle(30), EventCascade::new(dl, 3))?
+                    .id()
+                {
+                    event_sink.apply_status(EMBOLDEN, 1, t, d);
+                }
+            }
+            Role(v) => v.cast_snap(action, event_sink)?,
+        }
+
+        Ok(())
+    }
+}
+
+bool_job_dist! {
+    /// The random event for a Verfire Ready proc from Verthunder III.
+    pub VerfireProc = 1 / 2;
+    /// The random event for a Verstone Ready proc from Veraero III.
+    pub VerstoneProc = 1 / 2;
+    /// The random event for a proc from Verflare or Verholy when
+    /// the opposite mana is not higher.
+    pub FinisherProc = 1 / 5;
+}
+
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Copy, Debug)]
+/// A custom cast error for Red Mage actions.
+pub enum RdmError {
+    /// Not under the effect of Verfire Ready.
+    Verfire,
+    /// Not under the effect of Verstone Ready.
+    Verstone,
+    /// Not under the effect of Grand Impact Ready.
+    GrandImpact,
+    /// Not enough Mana Stacks.
+    ManaStacks,
+    /// The finisher combo action was not the specified action.
+    Finisher(RdmAction),
+    /// Not under the effect of Thorned Flourish.
+    ViceOfThorns,
+    /// Not under the effect of Prefulgence Ready.
+    Prefulgence,
+}
+
+impl From<RdmError> for EventError {
+    fn from(value: RdmError) -> Self {
+        Self::Job(value.into())
+    }
+}
+
+impl Display for RdmError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Verfire => status_proc_error(f, VERFIRE_READY),
+            Self::Verstone => status_proc_error(f, VERSTONE_READY),
+            Self::GrandImpact => status_proc_error(f, GRAND_IMPACT_READY),
+            Self::ManaStacks => write!(f, "Not enough Mana Stacks, needed 3."),
+            Self::Finisher(action) => {
+                write!(f, "Finisher combo action was not '{}'.", action.name())
+            }
+            Self::ViceOfThorns => status_proc_error(f, THORNED_FLOURISH),
+            Self::Prefulgence => status_proc_error(f, PREFULGENCE_READY),
+        }
+    }
+}
+
+impl Error for RdmError {}
+
+const MELEE: ActionTargetting = ActionTargetting::single(3);
+const SPELL: ActionTargetting = ActionTargetting::single(25);
+const TG_CIRCLE: ActionTargetting = ActionTargetting::target_circle(5, 25);
+const LINE: ActionTargetting = ActionTargetting::line(25);
+
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(rename_all = "snake_case")
+)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[repr(u8)]
+#[var_consts {
+    /// Returns `true` if the action can be made instant by Acceleration.
+    pub const accelerated;
+    /// Returns the base GCD recast time, or `None` if the action is not a gcd.
+    pub const gcd: ScaleTime?;
+    /// Returns the base GCD recast time when the action is enchanted,
+    /// or `None` if the action cannot be enchanted.
+    pub const enchanted_gcd: ScaleTime?;
+    /// Returns the base milliseconds the action takes to cast.
+    pub const cast: ScaleTime = ScaleTime::zero();
+    /// Returns the human friendly name of the action.
+    pub const name: &'static str;
+    /// Returns the cooldown of the skill in milliseconds.
+    pub const cooldown: u32 = 0;
+    /// Returns the number of charges a skill has, or `1` if it is a single charge skill.
+    pub const cd_charges: u8 = 1;
+    /// Returns the delay in milliseconds for the damage/statuses to be applied.
+    pub const effect_delay: u32 = 0;
+    /// Returns the MP cost of the action.
+    pub const mp_cost: u16 = 0;
+    /// Returns the amount of both Black and White mana needed to enchant the action.
+    pub const enchant_cost: u8 = 0;
+    /// Returns the [`ActionCategory`] this action is part of.
+    pub const category: ActionCategory;
+
+    pub const spell for {
+        gcd = ScaleTime::spell(2500);
+        category = ActionCategory::Spell;
+    }
+    pub const skill for {
+        gcd = ScaleTime::skill(2500);
+        category = ActionCategory::Weaponskill;
+    }
+    pub const ability for {
+        category = ActionCategory::Ability;
+    }
+}]
+#[allow(missing_docs)] // no reason to document the variants.
+/// An action specific to the Red Mage job.
+pub enum RdmAction {
+    #[spell]
+    #[cast = ScaleTime::spell(2000)]
+    #[mp_cost = 200]
+    #[name = "Jolt III"]
+    JoltIII,
+    #[spell]
+    #[accelerated]
+    #[cast = ScaleTime::spell(5000)]
+    #[mp_cost = 300]
+    #[name = "Verthunder III"]
+    VerthunderIII,
+    #[spell]
+    #[accelerated]
+    #[cast = ScaleTime::spell(5000)]
+    #[mp_cost = 300]
+    #[name = "Veraero III"]
+    VeraeroIII,
+    #[spell]
+    #[cast = ScaleTime::spell(2000)]
+    #[mp_cost = 200]
+    #[name = "Verfire"]
+    Verfire,
+    #[spell]
+    #[cast = ScaleTime::spell(2000)]
+    #[mp_cost = 200]
+    #[name = "Verstone"]
+    Verstone,
+    #[spell]
+    #[cast = ScaleTime::spell(2000)]
+    #[mp_cost = 400]
+    #[name = "Verthunder II"]
+    VerthunderII,
+    #[spell]
+    #[cast = ScaleTime::spell(2000)]
+    #[mp_cost = 400]
+    #[name = "Veraero II"]
+    VeraeroII,
+    #[spell]
+    #[accelerated]
+    #[cast = ScaleTime::spell(5000)]
+    #[mp_cost = 400]
+    #[name = "Impact"]
+    Impact,
+    #[spell]
+    #[name = "Grand Impact"]
+    GrandImpact,
+    #[skill]
+    #[enchanted_gcd = ScaleTime::skill(1500)]
+    #[enchant_cost = 20]
+    #[name = "Riposte"]
+    Riposte,
+    #[skill]
+    #[enchanted_gcd = ScaleTime::skill(1500)]
+    #[enchant_cost = 15]
+    #[name = "Zwerchhau"]
+    Zwerchhau,
+    #[skill]
+    #[enchanted_gcd = ScaleTime::skill(2200)]
+    #[enchant_cost = 15]
+    #[name = "Redoublement"]
+    Redoublement,
+    #[spell]
+    #[name = "Verflare"]
+    Verflare,
+    #[spell]
+    #[name = "Verholy"]
+    Verholy,
+    #[spell]
+    #[name = "Scorch"]
+    Scorch,
+    #[spell]
+    #[name = "Resolution"]
+    Resolution,
+    #[ability]
+    #[cooldown = 25000]
+    #[name = "Fleche"]
+    Fleche,
+    #[ability]
+    #[cooldown = 45000]
+    #[name = "Contre Sixte"]
+    ContreSixte,
+    #[ability]
+    #[name = "Vice of Thorns"]
+    ViceOfThorns,
+    #[ability]
+    #[name = "Prefulgence"]
+    Prefulgence,
+    #[ability]
+    #[cooldown = 55000]
+    #[cd_charges = 2]
+    #[name = "Acceleration"]
+    Acceleration,
+    #[ability]
+    #[cooldown = 110000]
+    #[name = "Manafication"]
+    Manafication,
+    #[ability]
+    #[cooldown = 120000]
+    #[name = "Embolden"]
+    Embolden,
+    #[gcd((v) => v.gcd())]
+    #[cast((v) => v.cast())]
+    #[name((v) => v.name())]
+    #[cooldown((v) => v.cooldown())]
+    #[cd_charges((v) => v.cd_charges())]
+    #[effect_delay((v) => v.effect_delay())]
+    #[mp_cost((v) => v.mp_cost())]
+    #[category((v) => v.category())]
+    Role(CasterRoleAction),
+}
+
+impl JobAction for RdmAction {
+    fn category(&self) -> ActionCategory {
+        self.category()
+    }
+
+    fn gcd(&self) -> bool {
+        self.gcd().is_some()
+    }
+}
+
+impl From<RdmAction> for Action {
+    fn from(value: RdmAction) -> Self {
+        Action::Job(value.into())
+    }
+}
+
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+/// The state of the Red Mage job gauges, cooldowns, and combos.
+pub struct RdmState {
+    /// The combos for Red Mage.
+    pub combos: RdmCombos,
+    /// The Black Mana gauge.
+    pub black: GaugeU8<100>,
+    /// The White Mana gauge.
+    pub white: GaugeU8<100>,
+    /// The Mana Stacks gauge.
+    pub mana_stacks: GaugeU8<3>,
+}
+
+impl RdmState {
+    /// Adds some amount of Black and White mana to the gauge.
+    ///
+    /// If one type of mana is more than 30 higher than the other,
+    /// the amount gained for that type is halved.
+    pub fn add_mana(&mut self, black: u8, white: u8) {
+        let (b, w) = (*self.black, *self.white);
+        self.black += if b > w + 30 { black / 2 } else { black };
+        self.white += if w > b + 30 { white / 2 } else { white };
+    }
+
+    /// Returns `true` if there is at least `amount` of both Black and White mana.
+    pub fn has_mana(&self, amount: u8) -> bool {
+        self.black >= amount && self.white >= amount
+    }
+
+    /// Consumes `amount` of both Black and White mana.
+    ///
+    /// Returns `false` and leaves the gauge unchanged if there is not enough of either.
+    pub fn consume_mana(&mut self, amount: u8) -> bool {
+        if amount == 0 || !self.has_mana(amount) {
+            return false;
+        }
+        self.black -= amount;
+        self.white -= amount;
+        true
+    }
+}
+
+impl JobState for RdmState {
+    fn advance(&mut self, time: u32) {
+        self.combos.advance(time);
+    }
+}
+
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+/// The combos for Red Mage.
+pub struct RdmCombos {
+    /// The melee combo.
+    pub melee: ComboState<MeleeCombo>,
+    /// The finisher combo.
+    pub finisher: ComboState<FinisherCombo>,
+}
+
+impl RdmCombos {
+    /// Checks that the melee combo prerequisite is met for a certain action.
+    pub fn check_melee_for(&self, action: RdmAction) -> bool {
+        let c = match action {
+            RdmAction::Zwerchhau => MeleeCombo::Riposte,
+            RdmAction::Redoublement => MeleeCombo::Zwerchhau,
+            _ => return true,
+        };
+        self.melee.check(c)
+    }
+
+    /// Checks that the finisher combo prerequisite is met for a certain action.
+    pub fn check_finisher_for(&self, action: RdmAction) -> bool {
+        let c = match action {
+            RdmAction::Scorch => FinisherCombo::Verflare,
+            RdmAction::Resolution => FinisherCombo::Scorch,
+            _ => return true,
+        };
+        self.finisher.check(c)
+    }
+
+    /// Advances the combos forward by a certain amount of time.
+    ///
+    /// See TODO: Advance Functions for more information.
+    pub fn advance(&mut self, time: u32) {
+        self.melee.advance(time);
+        self.finisher.advance(time);
+    }
+}
+
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+/// The possible states the melee combo can be in.
+pub enum MeleeCombo {
+    /// Combo Action: Riposte is met.
+    Riposte,
+    /// Combo Action: Zwerchhau is met.
+    Zwerchhau,
+}
+
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+/// The possible states the finisher combo can be in.
+pub enum FinisherCombo {
+    /// Combo Action: Verflare or Verholy is met.
+    Verflare,
+    /// Combo Action: Scorch is met.
+    Scorch,
+}
+
+job_cd_struct! {
+    RdmAction =>
+
+    #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+    #[derive(Clone, Debug, Default)]
+    /// The cooldown map for Red Mage actions.
+    pub RdmCdMap
+
+    #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+    #[derive(Copy, Clone, Debug)]
+    /// The various cooldown groups a Red Mage action can be part of.
+    pub RdmCdGroup
+
+    "Fleche"
+    fleche Fleche: Fleche;
+    "Contre Sixte"
+    contre_sixte ContreSixte: ContreSixte;
+    "Acceleration"
+    acceleration Acceleration: Acceleration;
+    "Manafication"
+    manafication Manafication: Manafication;
+    "Embolden"
+    embolden Embolden: Embolden;
+    "Swiftcast"
+    swiftcast Swiftcast: Role(CasterRoleAction::Swiftcast);
+    "Lucid Dreaming"
+    lucid_dreaming LucidDreaming: Role(CasterRoleAction::LucidDreaming);
+    "Addle"
+    addle Addle: Role(CasterRoleAction::Addle);
+    "Acceleration Charge"
+    acceleration_chg AccelerationChg;
+}
+
+impl RdmAction {
+    /// Returns the alternate [cooldown group] that this action is part of.
+    ///
+    /// Returns `None` if this action does not have an alternate cooldown.
+    /// This action is used for the 1s cooldown between uses of charged actions.
+    ///
+    /// [cooldown group]: RdmCdGroup
+    pub const fn alt_cd_group(&self) -> Option<RdmCdGroup> {
+        match self {
+            Self::Acceleration => Some(RdmCdGroup::AccelerationChg),
+            _ => None,
+        }
+    }
+}
@@ -6,9 +6,11 @@ use serde::{Deserialize, Serialize};
 use crate::{
     enums::ActionCategory,
     status_effect,
+    timing::{EventCascade, ScaleTime},
+    util::ActionTargettingExt as _,
     world::{
         status::{StatusEffect, StatusEventExt},
-        ActorRef, EventSink, WorldRef,
+        Action, ActionTargetting, ActorRef, Event, EventError, EventSink, HealEventExt, WorldRef,
     },
 };
 
@@ -20,6 +22,10 @@ use crate::{
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
 #[repr(u8)]
 #[var_consts {
+    /// Returns the base GCD recast time, or `None` if the action is not a gcd.
+    pub const gcd: ScaleTime?;
+    /// Returns the base milliseconds the action takes to cast.
+    pub const cast: ScaleTime = ScaleTime::zero();
     /// Returns the human friendly name of the action.
     pub const name: &'static str;
     /// Returns the cooldown of the skill in milliseconds.
@@ -28,6 +34,107 @@ use crate::{
     pub const cd_charges: u8 = 1;
     /// Returns the delay in milliseconds for the damage/statuses to be applied.
     pub const effect_delay: u32 = 0;
+    /// Returns the MP cost of the action.
+    pub const mp_cost: u16 = 0;
+    /// Returns the [`ActionCategory`] this action is part of.
+    pub const category: ActionCategory = ActionCategory::Ability;
+}]
+#[allow(missing_docs)]
+/// A tank role action.
+pub enum TankRoleAction {
+    #[cooldown = 90000]
+    #[name = "Rampart"]
+    Rampart,
+    #[cooldown = 60000]
+    #[name = "Reprisal"]
+    Reprisal,
+    #[cooldown = 120000]
+    #[name = "Arm's Length"]
+    ArmsLength,
+    #[cooldown = 30000]
+    #[name = "Provoke"]
+    Provoke,
+    #[cooldown = 120000]
+    #[name = "Shirk"]
+    Shirk,
+    #[cooldown = 30000]
+    #[name = "Interject"]
+    Interject,
+    #[cooldown = 25000]
+    #[name = "Low Blow"]
+    LowBlow,
+}
+
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(rename_all = "snake_case")
+)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[repr(u8)]
+#[var_consts {
+    /// Returns the base GCD recast time, or `None` if the action is not a gcd.
+    pub const gcd: ScaleTime?;
+    /// Returns the base milliseconds the action takes to cast.
+    pub const cast: ScaleTime = ScaleTime::zero();
+    /// Returns the human friendly name of the action.
+    pub const name: &'static str;
+    /// Returns the cooldown of the skill in milliseconds.
+    pub const cooldown: u32 = 0;
+    /// Returns the number of charges a skill has, or `1` if it is a single charge skill.
+    pub const cd_charges: u8 = 1;
+    /// Returns the delay in milliseconds for the damage/statuses to be applied.
+    pub const effect_delay: u32 = 0;
+    /// Returns the MP cost of the action.
+    pub const mp_cost: u16 = 0;
+    /// Returns the [`ActionCategory`] this action is part of.
+    pub const category: ActionCategory = ActionCategory::Ability;
+}]
+#[allow(missing_docs)]
+/// A healer role action.
+pub enum HealerRoleAction {
+    #[cooldown = 60000]
+    #[name = "Swiftcast"]
+    Swiftcast,
+    #[cooldown = 60000]
+    #[name = "Lucid Dreaming"]
+    LucidDreaming,
+    #[cooldown = 120000]
+    #[name = "Surecast"]
+    Surecast,
+    #[cooldown = 120000]
+    #[name = "Rescue"]
+    Rescue,
+    #[gcd = ScaleTime::spell(2500)]
+    #[cast = ScaleTime::spell(1000)]
+    #[mp_cost = 400]
+    #[category = ActionCategory::Spell]
+    #[name = "Esuna"]
+    Esuna,
+}
+
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(rename_all = "snake_case")
+)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[repr(u8)]
+#[var_consts {
+    /// Returns the base GCD recast time, or `None` if the action is not a gcd.
+    pub const gcd: ScaleTime?;
+    /// Returns the base milliseconds the action takes to cast.
+    pub const cast: ScaleTime = ScaleTime::zero();
+    /// Returns the human friendly name of the action.
+    pub const name: &'static str;
+    /// Returns the cooldown of the skill in milliseconds.
+    pub const cooldown: u32 = 0;
+    /// Returns the number of charges a skill has, or `1` if it is a single charge skill.
+    pub const cd_charges: u8 = 1;
+    /// Returns the delay in milliseconds for the damage/statuses to be applied.
+    pub const effect_delay: u32 = 0;
+    /// Returns the MP cost of the action.
+    pub const mp_cost: u16 = 0;
     /// Returns the [`ActionCategory`] this action is part of.
     pub const category: ActionCategory = ActionCategory::Ability;
 }]
@@ -38,20 +145,303 @@ pub enum MeleeRoleAction {
     #[cd_charges = 2]
     #[name = "True North"]
     TrueNorth,
+    #[cooldown = 90000]
+    #[name = "Feint"]
+    Feint,
+    #[cooldown = 90000]
+    #[name = "Bloodbath"]
+    Bloodbath,
+    #[cooldown = 120000]
+    #[name = "Second Wind"]
+    SecondWind,
+}
+
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(rename_all = "snake_case")
+)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[repr(u8)]
+#[var_consts {
+    /// Returns the base GCD recast time, or `None` if the action is not a gcd.
+    pub const gcd: ScaleTime?;
+    /// Returns the base milliseconds the action takes to cast.
+    pub const cast: ScaleTime = ScaleTime::zero();
+    /// Returns the human friendly name of the action.
+    pub const name: &'static str;
+    /// Returns the cooldown of the skill in milliseconds.
+    pub const cooldown: u32 = 0;
+    /// Returns the number of charges a skill has, or `1` if it is a single charge skill.
+    pub const cd_charges: u8 = 1;
+    /// Returns the delay in milliseconds for the damage/statuses to be applied.
+    pub const effect_delay: u32 = 0;
+    /// Returns the MP cost of the action.
+    pub const mp_cost: u16 = 0;
+    /// Returns the [`ActionCategory`] this action is part of.
+    pub const category: ActionCategory = ActionCategory::Ability;
+}]
+#[allow(missing_docs)]
+/// A physical ranged DPS role action.
+pub enum RangedRoleAction {
+    #[cooldown = 120000]
+    #[name = "Second Wind"]
+    SecondWind,
+    #[cooldown = 30000]
+    #[name = "Head Graze"]
+    HeadGraze,
+    #[cooldown = 5000]
+    #[name = "Peloton"]
+    Peloton,
+}
+
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(rename_all = "snake_case")
+)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[repr(u8)]
+#[var_consts {
+    /// Returns the base GCD recast time, or `None` if the action is not a gcd.
+    pub const gcd: ScaleTime?;
+    /// Returns the base milliseconds the action takes to cast.
+    pub const cast: ScaleTime = ScaleTime::zero();
+    /// Returns the human friendly name of the action.
+    pub const name: &'static str;
+    /// Returns the cooldown of the skill in milliseconds.
+    pub const cooldown: u32 = 0;
+    /// Returns the number of charges a skill has, or `1` if it is a single charge skill.
+    pub const cd_charges: u8 = 1;
+    /// Returns the delay in milliseconds for the damage/statuses to be applied.
+    pub const effect_delay: u32 = 0;
+    /// Returns the MP cost of the action.
+    pub const mp_cost: u16 = 0;
+    /// Returns the [`ActionCategory`] this action is part of.
+    pub const category: ActionCategory = ActionCategory::Ability;
+}]
+#[allow(missing_docs)]
+/// A magical ranged DPS role action.
+pub enum CasterRoleAction {
+    #[cooldown = 60000]
+    #[name = "Swiftcast"]
+    Swiftcast,
+    #[cooldown = 60000]
+    #[name = "Lucid Dreaming"]
+    LucidDreaming,
+    #[cooldown = 90000]
+    #[name = "Addle"]
+    Addle,
 }
 
 /// The status effect "True North".
-pub const TRUE_NORTH: StatusEffect = status_effect!("True North" 10000);
+pub static TRUE_NORTH: StatusEffect = status_effect!("True North" 10000);
+/// The status effect "Rampart".
+pub static RAMPART: StatusEffect = status_effect!(
+    "Rampart" 20000 { damage { in = 80 / 100 } }
+);
+/// The status effect "Reprisal".
+pub static REPRISAL: StatusEffect = status_effect!(
+    "Reprisal" 15000 { damage { out = 90 / 100 } }
+);
+/// The status effect "Arm's Length".
+pub static ARMS_LENGTH: StatusEffect = status_effect!("Arm's Length" 6000);
+/// The status effect "Feint".
+pub static FEINT: StatusEffect = status_effect!(
+    "Feint" 15000 { damage { out = |_, d, ty, _| {
+        if ty.physical() {
+            d * 90 / 100
+        } else if ty.magical() {
+            d * 95 / 100
+        } else {
+            d
+        }
+    } } }
+);
+/// The status effect "Addle".
+pub static ADDLE: StatusEffect = status_effect!(
+    "Addle" 15000 { damage { out = |_, d, ty, _| {
+        if ty.magical() {
+            d * 90 / 100
+        } else if ty.physical() {
+            d * 95 / 100
+        } else {
+            d
+        }
+    } } }
+);
+/// The status effect "Bloodbath".
+pub static BLOODBATH: StatusEffect = status_effect!("Bloodbath" 20000);
+/// The status effect "Swiftcast".
+pub static SWIFTCAST: StatusEffect = status_effect!("Swiftcast" 10000);
+/// The status effect "Lucid Dreaming".
+pub static LUCID_DREAMING: StatusEffect = status_effect!("Lucid Dreaming" 21000);
+/// The status effect "Surecast".
+pub static SURECAST: StatusEffect = status_effect!("Surecast" 6000);
+/// The status effect "The Peloton".
+pub static PELOTON: StatusEffect = status_effect!("The Peloton" 30000);
+
+const MELEE: ActionTargetting = ActionTargetting::single(3);
+const FEINT_RANGE: ActionTargetting = ActionTargetting::single(10);
+const SPELL: ActionTargetting = ActionTargetting::single(25);
+const SINGLE_PARTY: ActionTargetting = ActionTargetting::single(30);
+
+impl TankRoleAction {
+    /// Casts the role action, submitting all events to the supplied event sink.
+    ///
+    /// The `action` is the job action this role action was cast as.
+    pub fn cast_snap<'w, W: WorldRef<'w>, E: EventSink<'w, W>>(
+        &self,
+        _: impl Into<Action>,
+        event_sink: &mut E,
+    ) -> Result<(), EventError> {
+        let this = event_sink.source();
+        let this_id = this.id();
+        let dl = self.effect_delay();
+        match self {
+            Self::Rampart => event_sink.apply_status(RAMPART, 1, this_id, dl),
+            Self::Reprisal => {
+                for (t, d) in this
+                    .target_enemy_aoe(ActionTargetting::circle(5), EventCascade::new(dl, 1))?
+                    .id()
+                {
+                    event_sink.apply_status(REPRISAL, 1, t, d);
+                }
+            }
+            Self::ArmsLength => event_sink.apply_status(ARMS_LENGTH, 1, this_id, dl),
+            // enmity, interrupts, and stuns are not simulated,
+            // so these only need a valid target.
+            Self::Provoke => {
+                this.target_enemy(SPELL)?;
+            }
+            Self::Shirk => {
+                this.target_party(ActionTargetting::single(25))?;
+            }
+            Self::Interject | Self::LowBlow => {
+                this.target_enemy(MELEE)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl HealerRoleAction {
+    /// Casts the role action, submitting all events to the supplied event sink.
+    ///
+    /// The `action` is the job action this role action was cast as.
+    pub fn cast_snap<'w, W: WorldRef<'w>, E: EventSink<'w, W>>(
+        &self,
+        _: impl Into<Action>,
+        event_sink: &mut E,
+    ) -> Result<(), EventError> {
+        let this = event_sink.source();
+        let this_id = this.id();
+        let dl = self.effect_delay();
+        match self {
+            Self::Swiftcast => event_sink.apply_status(SWIFTCAST, 1, this_id, dl),
+            Self::LucidDreaming => lucid_dreaming(event_sink, dl),
+            Self::Surecast => event_sink.apply_status(SURECAST, 1, this_id, dl),
+            // knockbacks are not simulated, so this only needs a valid target.
+            Self::Rescue => {
+                this.target_party(SINGLE_PARTY)?;
+            }
+            // debuff removal is not simulated, and with no party
+            // member targeted this will target the caster instead.
+            Self::Esuna => (),
+        }
+        Ok(())
+    }
+}
 
 impl MeleeRoleAction {
     /// Casts the role action, submitting all events to the supplied event sink.
-    pub fn cast<'w, W: WorldRef<'w>, E: EventSink<'w, W>>(&self, event_sink: &mut E) {
-        let this_id = event_sink.source().id();
+    ///
+    /// The `action` is the job action this role action was cast as.
+    ///
+    /// This function used to be called `cast`, but was renamed as that name is
+    /// taken by the cast time accessor every action enum has. Unlike the old `cast`,
+    /// it also reports targetting errors.
+    pub fn cast_snap<'w, W: WorldRef<'w>, E: EventSink<'w, W>>(
+        &self,
+        action: impl Into<Action>,
+        event_sink: &mut E,
+    ) -> Result<(), EventError> {
+        let this = event_sink.source();
+        let this_id = this.id();
+        let dl = self.effect_delay();
+        match self {
+            Self::TrueNorth => event_sink.apply_status(TRUE_NORTH, 1, this_id, dl),
+            Self::Feint => {
+                let t = this.target_enemy(FEINT_RANGE)?.id();
+                event_sink.apply_status(FEINT, 1, t, dl);
+            }
+            Self::Bloodbath => event_sink.apply_status(BLOODBATH, 1, this_id, dl),
+            Self::SecondWind => event_sink.heal(action, 800, this_id, dl),
+        }
+        Ok(())
+    }
+}
+
+impl RangedRoleAction {
+    /// Casts the role action, submitting all events to the supplied event sink.
+    ///
+    /// The `action` is the job action this role action was cast as.
+    pub fn cast_snap<'w, W: WorldRef<'w>, E: EventSink<'w, W>>(
+        &self,
+        action: impl Into<Action>,
+        event_sink: &mut E,
+    ) -> Result<(), EventError> {
+        let this = event_sink.source();
+        let this_id = this.id();
+        let dl = self.effect_delay();
+        match self {
+            Self::SecondWind => event_sink.heal(action, 800, this_id, dl),
+            // interrupts are not simulated, so this only needs a valid target.
+            Self::HeadGraze => {
+                this.target_enemy(SPELL)?;
+            }
+            Self::Peloton => {
+                for (t, d) in this
+                    .target_party_aoe(ActionTargetting::circle(30), EventCascade::new(dl, 1))?
+                    .id()
+                {
+                    event_sink.apply_status(PELOTON, 1, t, d);
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+impl CasterRoleAction {
+    /// Casts the role action, submitting all events to the supplied event sink.
+    ///
+    /// The `action` is the job action this role action was cast as.
+    pub fn cast_snap<'w, W: WorldRef<'w>, E: EventSink<'w, W>>(
+        &self,
+        _: impl Into<Action>,
+        event_sink: &mut E,
+    ) -> Result<(), EventError> {
+        let this = event_sink.source();
+        let this_id = this.id();
         let dl = self.effect_delay();
         match self {
-            Self::TrueNorth => {
-                event_sink.apply_status(TRUE_NORTH, 1, this_id, dl);
+            Self::Swiftcast => event_sink.apply_status(SWIFTCAST, 1, this_id, dl),
+            Self::LucidDreaming => lucid_dreaming(event_sink, dl),
+            Self::Addle => {
+                let t = this.target_enemy(SPELL)?.id();
+                event_sink.apply_status(ADDLE, 1, t, dl);
             }
         }
+        Ok(())
+    }
+}
+
+/// Applies Lucid Dreaming, restoring MP every 3 seconds for its duration.
+fn lucid_dreaming<'w, W: WorldRef<'w>, E: EventSink<'w, W>>(event_sink: &mut E, dl: u32) {
+    let this_id = event_sink.source().id();
+    event_sink.apply_status(LUCID_DREAMING, 1, this_id, dl);
+    for tick in 1..=7 {
+        event_sink.event(Event::AddMp(550, this_id), dl + tick * 3000);
     }
 }
@@ -1,4 +1,7 @@
-use core::fmt::{self, Display};
+use core::{
+    error::Error,
+    fmt::{self, Display},
+};
 
 use macros::var_consts;
 
@@ -7,10 +10,13 @@ use serde::{Deserialize, Serialize};
 
 use crate::{
     enums::{ActionCategory, DamageInstance},
-    job::{CastInitInfo, Job, JobAction, JobState},
-    job_cd_struct, need_target, status_effect,
+    err,
+    job::{role::MeleeRoleAction, CastInitInfo, Job, JobAction, JobState},
+    job_cd_struct, status_effect,
     timing::{DurationInfo, EventCascade, ScaleTime},
-    util::{combo_pos_pot, combo_pot, ComboState, GaugeU8},
+    util::{
+        combo_pos_pot, combo_pot, status_proc_error, ActionTargettingExt as _, ComboState, GaugeU8,
+    },
     world::{
         status::{consume_status, StatusEffect, StatusEventExt},
         Action, ActionTargetting, ActorRef, DamageEventExt, Event, EventError, EventSink, Faction,
@@ -23,35 +29,35 @@ use crate::{
 pub struct RprJob;
 
 /// The status effect "Death's Design".
-pub const DEATHS_DESIGN: StatusEffect = status_effect!(
+pub static DEATHS_DESIGN: StatusEffect = status_effect!(
     "Death's Design" 30000 multi { damage { in = 110 / 100 } }
 );
 /// The status effect "Arcane Circle".
-pub const ARCANE_CIRCLE: StatusEffect = status_effect!(
+pub static ARCANE_CIRCLE: StatusEffect = status_effect!(
     "Arcane Circle" 20000 { damage { out = 103 / 100 } }
 );
 /// The status effect "Circle of Sacrifice".
-pub const CIRCLE_SACRIFICE: StatusEffect = status_effect!("Circle of Sacrifice" 5000 multi);
+pub static CIRCLE_SACRIFICE: StatusEffect = status_effect!("Circle of Sacrifice" 5000 multi);
 /// The status effect "Bloodsown Sacrifice".
-pub const BLOODSOWN_SACRIFICE: StatusEffect = status_effect!("Bloodsown Sacrifice" 6000);
+pub static BLOODSOWN_SACRIFICE: StatusEffect = status_effect!("Bloodsown Sacrifice" 6000);
 /// The status effect "Immortal Sacrifice".
-pub const IMMORTAL_SACRIFICE: StatusEffect = status_effect!("Immortal Sacrifice" 30000);
+pub static IMMORTAL_SACRIFICE: StatusEffect = status_effect!("Immortal Sacrifice" 30000);
 /// The status effect "Soul Reaver".
-pub const SOUL_REAVER: StatusEffect = status_effect!("Soul Reaver" 30000);
+pub static SOUL_REAVER: StatusEffect = status_effect!("Soul Reaver" 30000);
 /// The status effect "Soulsow".
-pub const SOULSOW: StatusEffect = status_effect!("Soulsow" permanent);
+pub static SOULSOW: StatusEffect = status_effect!("Soulsow" permanent);
 /// The status effect "Enshroud".
-pub const ENSHROUD: StatusEffect = status_effect!("Enshroud" 30000);
+pub static ENSHROUD: StatusEffect = status_effect!("Enshroud" 30000);
 /// The status effect "Enhanced Harpe".
-pub const ENHARPE: StatusEffect = status_effect!("Enhanced Harpe" 20000);
+pub static ENHARPE: StatusEffect = status_effect!("Enhanced Harpe" 20000);
 /// The status effect "Enhanced Gibbet".
-pub const ENGIBBET: StatusEffect = status_effect!("Enhanced Gibbet" 60000);
+pub static ENGIBBET: StatusEffect = status_effect!("Enhanced Gibbet" 60000);
 /// The status effect "Enhanced Gallows".
-pub const ENGALLOWS: StatusEffect = status_effect!("Enhanced Gallows" 60000);
+pub static ENGALLOWS: StatusEffect = status_effect!("Enhanced Gallows" 60000);
 /// The status effect "Enhanced Void Reaping".
-pub const ENVOID: StatusEffect = status_effect!("Enhanced Void Reaping" 30000);
+pub static ENVOID: StatusEffect = status_effect!("Enhanced Void Reaping" 30000);
 /// The status effect "Enhanced Cross Reaping".
-pub const ENCROSS: StatusEffect = status_effect!("Enhanced Cross Reaping" 30000);
+pub static ENCROSS: StatusEffect = status_effect!("Enhanced Cross Reaping" 30000);
 
 impl Job for RprJob {
     type Action = RprAction;
@@ -66,7 +72,7 @@ impl Job for RprJob {
         state: &Self::State,
         _: &'w W,
         event_sink: &mut E,
-    ) -> CastInitInfo<Self::CdGroup> {
+    ) -> Result<CastInitInfo<Self::CdGroup>, EventError> {
         let this = event_sink.source();
 
         let di = this.duration_info();
@@ -78,72 +84,43 @@ impl Job for RprJob {
             .cd_group()
             .map(|v| (v, action.cooldown(), action.cd_charges()));
 
+        let alt_cd = action.alt_cd_group().map(|v| (v, 1000, 1));
+
         use RprAction::*;
         if state.lemure_shroud > 0 && action.enshroud_invalid() {
-            RprError::Enshroud(action).submit(event_sink);
+            err!(RprError::Enshroud(action));
         }
         match action {
-            BloodStalk | GrimSwathe => {
-                if state.soul < 50 {
-                    RprError::Soul(50).submit(event_sink);
-                }
-            }
-            UnveiledGibbet => {
-                if state.soul < 50 {
-                    RprError::Soul(50).submit(event_sink);
-                }
-                if !this.has_own_status(ENGIBBET) {
-                    RprError::UnvGibbet.submit(event_sink);
-                }
-            }
-            UnveiledGallows => {
-                if state.soul < 50 {
-                    RprError::Soul(50).submit(event_sink);
-                }
-                if !this.has_own_status(ENGALLOWS) {
-                    RprError::UnvGallows.submit(event_sink);
-                }
-            }
-            Gibbet | Gallows | Guillotine => {
-                if !this.has_own_status(SOUL_REAVER) {
-                    RprError::SoulReaver.submit(event_sink);
-                }
+            BloodStalk | GrimSwathe | Gluttony if state.soul < 50 => err!(RprError::Soul(50)),
+            UnveiledGibbet | UnveiledGallows if state.soul < 50 => err!(RprError::Soul(50)),
+            UnveiledGibbet if !this.has_own_status(ENGIBBET) => err!(RprError::UnvGibbet),
+            UnveiledGallows if !this.has_own_status(ENGALLOWS) => err!(RprError::UnvGallows),
+            Gibbet | Gallows | Guillotine if !this.has_own_status(SOUL_REAVER) => {
+                err!(RprError::SoulReaver)
             }
-            Enshroud if state.shroud < 50 => {
-                RprError::Shroud(50).submit(event_sink);
+            Enshroud if state.shroud < 50 => err!(RprError::Shroud(50)),
+            HarvestMoon if !this.has_own_status(SOULSOW) => err!(RprError::Soulsow),
+            VoidReaping | CrossReaping | GrimReaping | Communio if state.lemure_shroud == 0 => {
+                err!(RprError::Lemure(1))
             }
-            HarvestMoon if !this.has_own_status(SOULSOW) => {
-                RprError::Soulsow.submit(event_sink);
+            LemuresSlice | LemuresScythe if state.void_shroud < 2 => err!(RprError::Void(2)),
+            PlentifulHarvest if !this.has_own_status(IMMORTAL_SACRIFICE) => {
+                err!(RprError::Sacrifice)
             }
-            VoidReaping | CrossReaping | GrimReaping | Communio => {
-                if state.lemure_shroud == 0 {
-                    RprError::Lemure(1).submit(event_sink);
-                }
-            }
-            LemuresSlice | LemuresScythe => {
-                if state.void_shroud < 2 {
-                    RprError::Void(2).submit(event_sink);
-                }
-            }
-            PlentifulHarvest => {
-                if !this.has_own_status(IMMORTAL_SACRIFICE) {
-                    RprError::Sacrifice.submit(event_sink);
-                }
-                if this.has_own_status(BLOODSOWN_SACRIFICE) {
-                    RprError::Bloodsown.submit(event_sink);
-                }
+            PlentifulHarvest if this.has_own_status(BLOODSOWN_SACRIFICE) => {
+                err!(RprError::Bloodsown)
             }
             _ => (),
         };
 
-        CastInitInfo {
+        Ok(CastInitInfo {
             gcd,
             lock,
             snap,
             mp: 0,
             cd,
-            alt_cd: None,
-        }
+            alt_cd,
+        })
     }
 
     fn cast_snap<'w, W: WorldRef<'w>, E: EventSink<'w, W>>(
@@ -151,17 +128,12 @@ impl Job for RprJob {
         state: &mut Self::State,
         _: &'w W,
         event_sink: &mut E,
-    ) {
+    ) -> Result<(), EventError> {
         let this = event_sink.source();
         let this_id = this.id();
 
         use RprAction::*;
 
-        let target_enemy = |t: ActionTargetting| {
-            this.actors_for_action(Some(Faction::Enemy), t)
-                .map(|a| a.id())
-        };
-
         let dl = action.effect_delay();
 
         if action.gcd().is_some() {
@@ -175,17 +147,16 @@ impl Job for RprJob {
             }
         }
 
-        #[allow(clippy::match_single_binding)]
         match action {
             Slice => {
-                let t = need_target!(target_enemy(MELEE).next(), event_sink);
+                let t = this.target_enemy(MELEE)?.id();
                 state.combos.main.set(MainCombo::Slice);
                 state.soul += 10;
                 event_sink.damage(action, DamageInstance::new(320).slashing(), t, dl);
             }
             WaxingSlice => {
-                let t = need_target!(target_enemy(MELEE).next(), event_sink);
-                let combo = if state.combos.main.check(MainCombo::Slice) {
+                let t = this.target_enemy(MELEE)?.id();
+                let combo = if state.combos.check_main_for(action) {
                     state.combos.main.set(MainCombo::Waxing);
                     state.soul += 10;
                     true
@@ -201,12 +172,12 @@ impl Job for RprJob {
                 );
             }
             ShadowOfDeath => {
-                let t = need_target!(target_enemy(MELEE).next(), event_sink);
+                let t = this.target_enemy(MELEE)?.id();
                 event_sink.apply_or_extend_status(DEATHS_DESIGN, 1, 2, t, dl);
                 event_sink.damage(action, DamageInstance::new(300).slashing(), t, dl);
             }
             Harpe => {
-                let t = need_target!(target_enemy(RANGED).next(), event_sink);
+                let t = this.target_enemy(RANGED)?.id();
                 event_sink.damage(action, DamageInstance::new(300).slashing(), t, dl);
             }
             // it doesn't really matter
@@ -214,11 +185,13 @@ impl Job for RprJob {
                 event_sink.apply_status(ENHARPE, 1, this_id, dl);
             }
             SpinningScythe => {
-                let mut c = EventCascade::new(dl, 1);
                 let mut hit = false;
-                for t in target_enemy(CIRCLE) {
+                for (t, d) in this
+                    .target_enemy_aoe(CIRCLE, EventCascade::new(dl, 1))?
+                    .id()
+                {
                     hit = true;
-                    event_sink.damage(action, DamageInstance::new(140).slashing(), t, c.next());
+                    event_sink.damage(action, DamageInstance::new(140).slashing(), t, d);
                 }
                 if hit {
                     state.soul += 10;
@@ -228,13 +201,11 @@ impl Job for RprJob {
                 }
             }
             InfernalSlice => {
-                let t = need_target!(target_enemy(MELEE).next(), event_sink);
-                let combo = if state.combos.main.check(MainCombo::Waxing) {
+                let t = this.target_enemy(MELEE)?.id();
+                let combo = state.combos.check_main_for(action);
+                if combo {
                     state.soul += 10;
-                    true
-                } else {
-                    false
-                };
+                }
                 state.combos.main.reset();
                 event_sink.damage(
                     action,
@@ -244,119 +215,123 @@ impl Job for RprJob {
                 );
             }
             WhorlOfDeath => {
-                let mut c = EventCascade::new(dl, 1);
-                for t in target_enemy(CIRCLE) {
-                    let dl = c.next();
-                    event_sink.apply_or_extend_status(DEATHS_DESIGN, 1, 2, t, dl);
-                    event_sink.damage(action, DamageInstance::new(100).slashing(), t, dl);
+                for (t, d) in this
+                    .target_enemy_aoe(CIRCLE, EventCascade::new(dl, 1))?
+                    .id()
+                {
+                    event_sink.apply_or_extend_status(DEATHS_DESIGN, 1, 2, t, d);
+                    event_sink.damage(action, DamageInstance::new(100).slashing(), t, d);
                 }
             }
             NightmareScythe => {
-                let combo = state.combos.main.check(MainCombo::Spinning);
+                let combo = state.combos.check_main_for(action);
                 state.combos.main.reset();
-                let mut c = EventCascade::new(dl, 1);
                 let mut hit = false;
-                for t in target_enemy(CIRCLE) {
+                for (t, d) in this
+                    .target_enemy_aoe(CIRCLE, EventCascade::new(dl, 1))?
+                    .id()
+                {
                     hit = true;
                     event_sink.damage(
                         action,
                         DamageInstance::new(combo_pot(120, 180, combo)).slashing(),
                         t,
-                        c.next(),
+                        d,
                     );
                 }
                 if hit {
                     state.soul += 10;
                 }
             }
-            BloodStalk => {
-                let t = need_target!(target_enemy(MELEE).next(), event_sink);
-                state.soul -= 50;
-                event_sink.damage(action, DamageInstance::new(340).slashing(), t, dl);
+            BloodStalk | UnveiledGibbet | UnveiledGallows => {
+                let t = this.target_enemy(MELEE)?.id();
+                if !state.soul.consume(50) {
+                    err!(RprError::Soul(50));
+                }
+                let potency = if action == BloodStalk { 340 } else { 400 };
+                event_sink.damage(action, DamageInstance::new(potency).slashing(), t, dl);
                 // almost certain it is no delay of the soul reaver stack
                 event_sink.apply_status(SOUL_REAVER, 1, this_id, 0);
             }
             GrimSwathe => {
-                let mut c = EventCascade::new(dl, 1);
-                for t in need_target!(target_enemy(CONE), event_sink, uaoe) {
-                    event_sink.damage(action, DamageInstance::new(140).slashing(), t, c.next());
+                let iter = this.target_enemy_aoe(CONE, EventCascade::new(dl, 1))?.id();
+                if !state.soul.consume(50) {
+                    err!(RprError::Soul(50));
+                }
+                for (t, d) in iter {
+                    event_sink.damage(action, DamageInstance::new(140).slashing(), t, d);
                 }
-                state.soul -= 50;
                 event_sink.apply_status(SOUL_REAVER, 1, this_id, 0);
             }
             SoulSlice => {
-                let t = need_target!(target_enemy(MELEE).next(), event_sink);
-                state.soul -= 50;
+                let t = this.target_enemy(MELEE)?.id();
+                state.soul += 50;
                 event_sink.damage(action, DamageInstance::new(460).slashing(), t, dl);
             }
             SoulScythe => {
-                let mut c = EventCascade::new(dl, 1);
                 let mut hit = false;
-                for t in target_enemy(CIRCLE) {
+                for (t, d) in this
+                    .target_enemy_aoe(CIRCLE, EventCascade::new(dl, 1))?
+                    .id()
+                {
                     hit = true;
-                    event_sink.damage(action, DamageInstance::new(180).slashing(), t, c.next());
+                    event_sink.damage(action, DamageInstance::new(180).slashing(), t, d);
                 }
                 if hit {
                     state.soul += 50;
                 }
             }
-            Gibbet => {
-                let t = need_target!(target_enemy(MELEE).next(), event_sink);
-                let en = consume_status(event_sink, ENGIBBET, 0);
-                state.shroud += 10;
-                let pos = this.check_positional(Positional::Flank, t);
-                event_sink.damage(
-                    action,
-                    DamageInstance::new(combo_pos_pot(400, 460, 460, 520, en, pos)).slashing(),
-                    t,
-                    dl,
-                );
-                event_sink.apply_status(ENGALLOWS, 1, this_id, 0);
-            }
-            Gallows => {
-                let t = need_target!(target_enemy(MELEE).next(), event_sink);
-                let en = consume_status(event_sink, ENGALLOWS, 0);
+            Gibbet | Gallows => {
+                let t = this.target_enemy(MELEE)?.id();
+                let (en, pos, next) = if action == Gibbet {
+                    (ENGIBBET, Positional::Flank, ENGALLOWS)
+                } else {
+                    (ENGALLOWS, Positional::Rear, ENGIBBET)
+                };
+                let en = consume_status(event_sink, en, 0);
+                let pos = this.check_positional(pos, t);
                 state.shroud += 10;
-                let pos = this.check_positional(Positional::Rear, t);
                 event_sink.damage(
                     action,
                     DamageInstance::new(combo_pos_pot(400, 460, 460, 520, en, pos)).slashing(),
                     t,
                     dl,
                 );
-                event_sink.apply_status(ENGIBBET, 1, this_id, 0);
+                event_sink.apply_status(next, 1, this_id, 0);
             }
             Guillotine => {
-                let mut c = EventCascade::new(dl, 1);
-                for t in need_target!(target_enemy(CONE), event_sink, uaoe) {
-                    event_sink.damage(action, DamageInstance::new(200).slashing(), t, c.next());
+                for (t, d) in this.target_enemy_aoe(CONE, EventCascade::new(dl, 1))?.id() {
+                    event_sink.damage(action, DamageInstance::new(200).slashing(), t, d);
                 }
                 state.shroud += 10;
             }
             ArcaneCircle => {
-                let iter = this
-                    .actors_for_action(Some(Faction::Party), ActionTargetting::circle(30))
-                    .map(|a| a.id());
-                let mut c = EventCascade::new(dl, 3);
-                for t in iter {
-                    let dl = c.next();
-                    event_sink.apply_status(ARCANE_CIRCLE, 1, t, dl);
-                    event_sink.apply_status(CIRCLE_SACRIFICE, 1, t, dl);
+                for (t, d) in this
+                    .target_party_aoe(ActionTargetting::circle(30), EventCascade::new(dl, 3))?
+                    .id()
+                {
+                    event_sink.apply_status(ARCANE_CIRCLE, 1, t, d);
+                    event_sink.apply_status(CIRCLE_SACRIFICE, 1, t, d);
                 }
                 event_sink.apply_status(BLOODSOWN_SACRIFICE, 1, this_id, dl);
             }
             Gluttony => {
-                let (f, o) = need_target!(target_enemy(TARGET_CIRCLE), event_sink, aoe);
-                state.soul -= 50;
-                let mut c = EventCascade::new(dl, 1);
-                event_sink.damage(action, DamageInstance::new(520).magical(), f, c.next());
-                for t in o {
-                    event_sink.damage(action, DamageInstance::new(390).magical(), t, c.next());
+                let iter = this
+                    .target_enemy_aoe(TARGET_CIRCLE, EventCascade::new(dl, 1))?
+                    .id()
+                    .falloff(75);
+                if !state.soul.consume(50) {
+                    err!(RprError::Soul(50));
+                }
+                for (t, d, f) in iter {
+                    event_sink.damage(action, DamageInstance::new(520).magical().falloff(f), t, d);
                 }
                 event_sink.apply_status(SOUL_REAVER, 2, this_id, 0);
             }
             Enshroud => {
-                state.shroud -= 50;
+                if !state.shroud.consume(50) {
+                    err!(RprError::Shroud(50));
+                }
                 event_sink.apply_status(ENSHROUD, 1, this_id, 0);
                 state.lemure_shroud.set_max();
             }
@@ -364,82 +339,49 @@ impl Job for RprJob {
                 event_sink.apply_status(SOULSOW, 1, this_id, 0);
             }
             PlentifulHarvest => {
-                let (first, other) =
-                    need_target!(target_enemy(ActionTargetting::line(15)), event_sink, aoe);
+                let iter = this
+                    .target_enemy_aoe(ActionTargetting::line(15), EventCascade::new(dl, 1))?
+                    .id()
+                    .falloff(40);
                 let stacks = this
                     .get_own_status(IMMORTAL_SACRIFICE)
                     .map(|v| v.stack)
                     .unwrap_or_default();
                 state.shroud += 50;
-                let mut c = EventCascade::new(dl, 1);
-                event_sink.damage(
-                    action,
-                    DamageInstance::new(680 + 40 * stacks as u64).slashing(),
-                    first,
-                    c.next(),
-                );
-                for t in other {
-                    // 60% less
+                for (t, d, f) in iter {
                     event_sink.damage(
                         action,
-                        DamageInstance::new(272 + 16 * stacks as u64).slashing(),
+                        DamageInstance::new(680 + 40 * stacks as u64)
+                            .slashing()
+                            .falloff(f),
                         t,
-                        c.next(),
+                        d,
                     );
                 }
                 event_sink.remove_status(IMMORTAL_SACRIFICE, this_id, 0);
             }
             Communio => {
-                let (f, o) = need_target!(target_enemy(TARGET_CIRCLE), event_sink, aoe);
-                let mut c = EventCascade::new(dl, 1);
-                event_sink.damage(action, DamageInstance::new(1100).magical(), f, c.next());
-                for t in o {
-                    event_sink.damage(action, DamageInstance::new(440).magical(), t, c.next());
+                let iter = this
+                    .target_enemy_aoe(TARGET_CIRCLE, EventCascade::new(dl, 1))?
+                    .id()
+                    .falloff(40);
+                for (t, d, f) in iter {
+                    event_sink.damage(action, DamageInstance::new(1100).magical().falloff(f), t, d);
                 }
                 state.lemure_shroud.clear();
                 state.void_shroud.clear();
                 event_sink.remove_status(ENSHROUD, this_id, 0);
             }
-            UnveiledGibbet => {
-                let t = need_target!(target_enemy(MELEE).next(), event_sink);
-                state.soul -= 50;
-                event_sink.damage(action, DamageInstance::new(400).slashing(), t, dl);
-                event_sink.apply_status(SOUL_REAVER, 1, this_id, 0);
-            }
-            UnveiledGallows => {
-                let t = need_target!(target_enemy(MELEE).next(), event_sink);
-                state.soul -= 50;
-                event_sink.damage(action, DamageInstance::new(400).slashing(), t, dl);
-                event_sink.apply_status(SOUL_REAVER, 1, this_id, 0);
-            }
-            VoidReaping => {
-                let t = need_target!(target_enemy(MELEE).next(), event_sink);
-                let en = consume_status(event_sink, ENVOID, 0);
-                state.lemure_shroud -= 1;
-                if state.lemure_shroud == 0 {
-                    state.void_shroud.clear();
-                    event_sink.remove_status(ENSHROUD, this_id, 0);
-                } else {
-                    event_sink.apply_status(ENCROSS, 1, this_id, 0);
-                    state.void_shroud += 1;
-                }
-                event_sink.damage(
-                    action,
-                    DamageInstance::new(combo_pot(460, 520, en)).slashing(),
-                    t,
-                    dl,
-                );
-            }
-            CrossReaping => {
-                let t = need_target!(target_enemy(MELEE).next(), event_sink);
-                let en = consume_status(event_sink, ENCROSS, 0);
-                state.lemure_shroud -= 1;
-                if state.lemure_shroud == 0 {
-                    state.void_shroud.clear();
-                    event_sink.remove_status(ENSHROUD, this_id, 0);
+            VoidReaping | CrossReaping => {
+                let t = this.target_enemy(MELEE)?.id();
+                let (en, next) = if action == VoidReaping {
+                    (ENVOID, ENCROSS)
                 } else {
-                    event_sink.apply_status(ENVOID, 1, this_id, 0);
-                    state.void_shroud += 1;
+                    (ENCROSS, ENVOID)
+                };
+                let en = consume_status(event_sink, en, 0);
+                if Self::consume_lemure(state, event_sink) {
+                    event_sink.apply_status(next, 1, this_id, 0);
                 }
                 event_sink.damage(
                     action,
@@ -449,40 +391,44 @@ impl Job for RprJob {
                 );
             }
             GrimReaping => {
-                state.lemure_shroud -= 1;
-                if state.lemure_shroud == 0 {
-                    state.void_shroud.clear();
-                    event_sink.remove_status(ENSHROUD, this_id, 0);
-                } else {
-                    state.void_shroud += 1;
-                }
-                let mut c = EventCascade::new(dl, 1);
-                for t in need_target!(target_enemy(CONE), event_sink, uaoe) {
-                    event_sink.damage(action, DamageInstance::new(200).slashing(), t, c.next());
+                let iter = this.target_enemy_aoe(CONE, EventCascade::new(dl, 1))?.id();
+                Self::consume_lemure(state, event_sink);
+                for (t, d) in iter {
+                    event_sink.damage(action, DamageInstance::new(200).slashing(), t, d);
                 }
             }
             HarvestMoon => {
-                let (f, o) = need_target!(target_enemy(TARGET_CIRCLE), event_sink, aoe);
-                event_sink.remove_status(SOULSOW, this_id, 0);
-                let mut c = EventCascade::new(dl, 1);
-                event_sink.damage(action, DamageInstance::new(600).magical(), f, c.next());
-                for t in o {
-                    event_sink.damage(action, DamageInstance::new(300).magical(), t, c.next());
+                let iter = this
+                    .target_enemy_aoe(TARGET_CIRCLE, EventCascade::new(dl, 1))?
+                    .id()
+                    .falloff(50);
+                if !consume_status(event_sink, SOULSOW, 0) {
+                    err!(RprError::Soulsow);
+                }
+                for (t, d, f) in iter {
+                    event_sink.damage(action, DamageInstance::new(600).magical().falloff(f), t, d);
                 }
             }
             LemuresSlice => {
-                let t = need_target!(target_enemy(MELEE).next(), event_sink);
-                state.void_shroud -= 2;
+                let t = this.target_enemy(MELEE)?.id();
+                if !state.void_shroud.consume(2) {
+                    err!(RprError::Void(2));
+                }
                 event_sink.damage(action, DamageInstance::new(240).slashing(), t, dl);
             }
             LemuresScythe => {
-                let mut c = EventCascade::new(dl, 1);
-                for t in need_target!(target_enemy(CONE), event_sink, uaoe) {
-                    event_sink.damage(action, DamageInstance::new(100).slashing(), t, c.next());
+                let iter = this.target_enemy_aoe(CONE, EventCascade::new(dl, 1))?.id();
+                if !state.void_shroud.consume(2) {
+                    err!(RprError::Void(2));
+                }
+                for (t, d) in iter {
+                    event_sink.damage(action, DamageInstance::new(100).slashing(), t, d);
                 }
-                state.void_shroud -= 2;
             }
+            Role(v) => v.cast_snap(action, event_sink)?,
         }
+
+        Ok(())
     }
 
     fn event<'w, W: WorldRef<'w>, E: EventSink<'w, W>>(
@@ -509,9 +455,28 @@ impl Job for RprJob {
     }
 }
 
-/// A custom cast error for Reaper actions.
+impl RprJob {
+    // consumes a stack of lemure shroud, ending enshroud if it was the last one.
+    // returns `true` if enshroud is still active.
+    fn consume_lemure<'w, W: WorldRef<'w>, E: EventSink<'w, W>>(
+        state: &mut RprState,
+        event_sink: &mut E,
+    ) -> bool {
+        state.lemure_shroud -= 1;
+        if state.lemure_shroud == 0 {
+            state.void_shroud.clear();
+            event_sink.remove_status(ENSHROUD, event_sink.source().id(), 0);
+            false
+        } else {
+            state.void_shroud += 1;
+            true
+        }
+    }
+}
+
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[derive(Clone, Copy, Debug)]
+/// A custom cast error for Reaper actions.
 pub enum RprError {
     /// Not enough Soul gauge.
     Soul(u8),
@@ -536,12 +501,6 @@ pub enum RprError {
     /// Under the effect of Enshroud.
     Enshroud(RprAction),
 }
-impl RprError {
-    /// Submits the cast error into the [`EventSink`].
-    pub fn submit<'w, W: WorldRef<'w>>(self, p: &mut impl EventSink<'w, W>) {
-        p.error(self.into())
-    }
-}
 
 impl From<RprError> for EventError {
     fn from(value: RprError) -> Self {
@@ -554,7 +513,7 @@ impl Display for RprError {
         match self {
             Self::Soul(v) => write!(f, "Not enough Soul Gauge, needed at least {}.", v),
             Self::Shroud(v) => write!(f, "Not enough Shroud Gauge, needed at least {}.", v),
-            Self::Soulsow => write!(f, "Not under the effect of '{}'.", SOULSOW.name),
+            Self::Soulsow => status_proc_error(f, SOULSOW),
             Self::Lemure(v) => write!(
                 f,
                 "Not enough stacks of Lemure Shroud, needed at least {}.",
@@ -565,16 +524,16 @@ impl Display for RprError {
                 "Not enough stacks of Void Shroud, needed at least {}.",
                 v
             ),
-            Self::Sacrifice => write!(f, "Not under the effect of '{}'.", IMMORTAL_SACRIFICE.name),
+            Self::Sacrifice => status_proc_error(f, IMMORTAL_SACRIFICE),
             Self::Bloodsown => write!(
                 f,
                 "Cannot use action '{}' under the effect of '{}'.",
                 RprAction::PlentifulHarvest.name(),
                 BLOODSOWN_SACRIFICE.name,
             ),
-            Self::SoulReaver => write!(f, "Not under the effect of '{}'.", SOUL_REAVER.name),
-            Self::UnvGibbet => write!(f, "Not under the effect of '{}'.", ENGIBBET.name),
-            Self::UnvGallows => write!(f, "Not under the effect of '{}'.", ENGALLOWS.name),
+            Self::SoulReaver => status_proc_error(f, SOUL_REAVER),
+            Self::UnvGibbet => status_proc_error(f, ENGIBBET),
+            Self::UnvGallows => status_proc_error(f, ENGALLOWS),
             Self::Enshroud(ac) => write!(
                 f,
                 "Cannot use action '{}' under the effect of '{}'.",
@@ -585,6 +544,8 @@ impl Display for RprError {
     }
 }
 
+impl Error for RprError {}
+
 const MELEE: ActionTargetting = ActionTargetting::single(3);
 const RANGED: ActionTargetting = ActionTargetting::single(25);
 const CIRCLE: ActionTargetting = ActionTargetting::circle(5);
@@ -647,9 +608,11 @@ pub enum RprAction {
     #[name = "Harpe"]
     Harpe,
     #[ability]
+    #[cooldown = 20000]
     #[name = "Hell's Ingress"]
     HellsIngress,
     #[ability]
+    #[cooldown = 20000]
     #[name = "Hell's Egress"]
     HellsEgress,
     #[skill]
@@ -669,10 +632,12 @@ pub enum RprAction {
     NightmareScythe,
     #[ability]
     #[enshroud_invalid]
+    #[cooldown = 1000]
     #[name = "Blood Stalk"]
     BloodStalk,
     #[ability]
     #[enshroud_invalid]
+    #[cooldown = 1000]
     #[name = "Grim Swathe"]
     GrimSwathe,
     #[skill]
@@ -705,6 +670,7 @@ pub enum RprAction {
     ArcaneCircle,
     #[ability]
     #[enshroud_invalid]
+    #[cooldown = 60000]
     #[name = "Gluttony"]
     Gluttony,
     #[ability]
@@ -726,10 +692,12 @@ pub enum RprAction {
     Communio,
     #[ability]
     #[enshroud_invalid]
+    #[cooldown = 1000]
     #[name = "Unveiled Gibbet"]
     UnveiledGibbet,
     #[ability]
     #[enshroud_invalid]
+    #[cooldown = 1000]
     #[name = "Unveiled Gallows"]
     UnveiledGallows,
     // Regress,
@@ -756,6 +724,14 @@ pub enum RprAction {
     #[cooldown = 1000]
     #[name = "Lemure's Scythe"]
     LemuresScythe,
+    #[gcd((v) => v.gcd())]
+    #[cast((v) => v.cast())]
+    #[name((v) => v.name())]
+    #[cooldown((v) => v.cooldown())]
+    #[cd_charges((v) => v.cd_charges())]
+    #[effect_delay((v) => v.effect_delay())]
+    #[category((v) => v.category())]
+    Role(MeleeRoleAction),
 }
 
 impl JobAction for RprAction {
@@ -866,4 +842,29 @@ job_cd_struct! {
     enshroud Enshroud: Enshroud;
     "Lemure's Slice/Scythe"
     lemures Lemures: LemuresSlice LemuresScythe;
+    "True North"
+    true_north TrueNorth: Role(MeleeRoleAction::TrueNorth);
+    "Feint"
+    feint Feint: Role(MeleeRoleAction::Feint);
+    "Bloodbath"
+    bloodbath Bloodbath: Role(MeleeRoleAction::Bloodbath);
+    "Second Wind"
+    second_wind SecondWind: Role(MeleeRoleAction::SecondWind);
+    "True North Charge"
+    true_north_chg TrueNorthChg;
+}
+
+impl RprAction {
+    /// Returns the alternate [cooldown group] that this action is part of.
+    ///
+    /// Returns `None` if this action does not have an alternate cooldown.
+    /// This action is used for the 1s cooldown between uses of charged actions.
+    ///
+    /// [cooldown group]: RprCdGroup
+    pub const fn alt_cd_group(&self) -> Option<RprCdGroup> {
+        match self {
+            Self::Role(MeleeRoleAction::TrueNorth) => Some(RprCdGroup::TrueNorthChg),
+            _ => None,
+        }
+    }
 }
@@ -1,4 +1,7 @@
-use core::fmt::{self, Display};
+use core::{
+    error::Error,
+    fmt::{self, Display},
+};
 
 use macros::var_consts;
 
@@ -7,40 +10,42 @@ use serde::{Deserialize, Serialize};
 
 use crate::{
     enums::{ActionCategory, DamageInstance},
-    job::{CastInitInfo, Job, JobAction, JobState},
+    err,
+    job::{role::MeleeRoleAction, CastInitInfo, Job, JobAction, JobState},
     job_cd_struct,
     math::SpeedStat,
-    need_target, status_effect,
+    status_effect,
     timing::{DurationInfo, EventCascade, ScaleTime},
-    util::{combo_pos_pot, combo_pot, ComboState, GaugeU8},
+    util::{
+        combo_pos_pot, combo_pot, status_proc_error, ActionTargettingExt as _, ComboState, GaugeU8,
+    },
     world::{
         status::{consume_status, consume_status_stack, StatusEffect, StatusEventExt},
-        Action, ActionTargetting, ActorRef, DamageEventExt, EventError, EventSink, Faction,
-        Positional, WorldRef,
+        Action, ActionTargetting, ActorRef, DamageEventExt, EventError, EventSink, Positional,
+        WorldRef,
     },
 };
 
-// so i can be lazy with associated constant derives
-#[derive(Copy, Clone, Debug, Default)]
 /// The [`Job`] struct for Samurai.
+#[derive(Clone, Copy, Debug, Default)]
 pub struct SamJob;
 
-/// The status effect Fugetsu.
-pub const FUGETSU: StatusEffect = status_effect!(
+/// The status effect "Fugetsu".
+pub static FUGETSU: StatusEffect = status_effect!(
     "Fugetsu" 40000 { damage { out = 113 / 100 } }
 );
-/// The status effect Fuka.
-pub const FUKA: StatusEffect = status_effect!(
+/// The status effect "Fuka".
+pub static FUKA: StatusEffect = status_effect!(
     "Fuka" 40000 { haste { |_| 100 - 13 } }
 );
-/// The status effect Ogi Namikiri Ready.
-pub const OGI_READY: StatusEffect = status_effect!("Ogi Namikiri Ready" 30000);
-/// The status effect Meikyo Shisui.
-pub const MEIKYO: StatusEffect = status_effect!("Meikyo Shisui" 15000);
-/// The status effect Enhanced Enpi.
-pub const ENENPI: StatusEffect = status_effect!("Enhanced Enpi" 15000);
-/// The DoT effect Higanbana.
-pub const HIGANBANA: StatusEffect = status_effect!("Higanbana" 60000 multi);
+/// The status effect "Ogi Namikiri Ready".
+pub static OGI_READY: StatusEffect = status_effect!("Ogi Namikiri Ready" 30000);
+/// The status effect "Meikyo Shisui".
+pub static MEIKYO: StatusEffect = status_effect!("Meikyo Shisui" 15000);
+/// The status effect "Enhanced Enpi".
+pub static ENENPI: StatusEffect = status_effect!("Enhanced Enpi" 15000);
+/// The DoT effect "Higanbana".
+pub static HIGANBANA: StatusEffect = status_effect!("Higanbana" 60000 multi);
 
 impl Job for SamJob {
     type Action = SamAction;
@@ -55,7 +60,7 @@ impl Job for SamJob {
         state: &Self::State,
         _: &'w W,
         event_sink: &mut E,
-    ) -> CastInitInfo<Self::CdGroup> {
+    ) -> Result<CastInitInfo<Self::CdGroup>, EventError> {
         let this = event_sink.source();
 
         let di = this.duration_info();
@@ -71,44 +76,39 @@ impl Job for SamJob {
 
         use SamAction::*;
         if state.kenki < action.kenki_cost() {
-            SamError::Kenki(action.kenki_cost()).submit(event_sink);
+            err!(SamError::Kenki(action.kenki_cost()));
         }
         if let Some(sen_cost) = action.sen_cost() {
             if sen_cost != state.sen.count() {
-                SamError::IaiSen(sen_cost).submit(event_sink);
+                err!(SamError::IaiSen(sen_cost));
             }
         }
         match action {
-            Ikishoten if !this.in_combat() => event_sink.error(EventError::InCombat),
-            Namikiri if !this.has_own_status(OGI_READY) => {
-                SamError::OgiRdy.submit(event_sink);
-            }
-            Shoha | Shoha2 if state.meditation != 3 => SamError::Meditation.submit(event_sink),
-            Hagakure if state.sen.count() == 0 => SamError::HagaSen.submit(event_sink),
-            // really ugly if the if guard is in the match branch lol
-            KaeshiHiganbana | KaeshiGoken | KaeshiSetsugekka | KaeshiNamikiri => {
-                if !state.combos.check_kaeshi_for(action) {
-                    SamError::Kaeshi(match action {
-                        KaeshiHiganbana => Higanbana,
-                        KaeshiGoken => TenkaGoken,
-                        KaeshiSetsugekka => Midare,
-                        KaeshiNamikiri => Namikiri,
-                        _ => unreachable!(),
-                    })
-                    .submit(event_sink)
-                }
+            Ikishoten if !this.in_combat() => err!(EventError::InCombat),
+            Namikiri if !this.has_own_status(OGI_READY) => err!(SamError::OgiRdy),
+            Shoha | Shoha2 if state.meditation != 3 => err!(SamError::Meditation),
+            Hagakure if state.sen.count() == 0 => err!(SamError::HagaSen),
+            KaeshiHiganbana | KaeshiGoken | KaeshiSetsugekka | KaeshiNamikiri
+                if !state.combos.check_kaeshi_for(action) =>
+            {
+                err!(SamError::Kaeshi(match action {
+                    KaeshiHiganbana => Higanbana,
+                    KaeshiGoken => TenkaGoken,
+                    KaeshiSetsugekka => Midare,
+                    _ => Namikiri,
+                }))
             }
             _ => (),
         }
 
-        CastInitInfo {
+        Ok(CastInitInfo {
             gcd,
             lock,
             snap,
             mp: 0,
             cd,
             alt_cd,
-        }
+        })
     }
 
     fn cast_snap<'w, W: WorldRef<'w>, E: EventSink<'w, W>>(
@@ -116,15 +116,11 @@ impl Job for SamJob {
         state: &mut Self::State,
         _: &'w W,
         event_sink: &mut E,
-    ) {
+    ) -> Result<(), EventError> {
         let this = event_sink.source();
         let this_id = this.id();
 
         use SamAction::*;
-        let target_enemy = |t: ActionTargetting| {
-            this.actors_for_action(Some(Faction::Enemy), t)
-                .map(|a| a.id())
-        };
 
         let consume_meikyo = |p: &mut E| consume_status_stack(p, MEIKYO, 0);
 
@@ -132,7 +128,7 @@ impl Job for SamJob {
 
         match action {
             Hakaze => {
-                let t = need_target!(target_enemy(MELEE).next(), event_sink);
+                let t = this.target_enemy(MELEE)?.id();
                 state.combos.kaeshi.reset();
                 consume_meikyo(event_sink);
                 state.combos.main.set(MainCombo::Hakaze);
@@ -140,10 +136,10 @@ impl Job for SamJob {
                 event_sink.damage(action, DamageInstance::new(200).slashing(), t, dl);
             }
             Jinpu => {
-                let t = need_target!(target_enemy(MELEE).next(), event_sink);
+                let t = this.target_enemy(MELEE)?.id();
                 state.combos.kaeshi.reset();
                 let meikyo = consume_meikyo(event_sink);
-                let combo = if meikyo || state.combos.main.check(MainCombo::Hakaze) {
+                let combo = if meikyo || state.combos.check_main_for(action) {
                     state.combos.main.set(MainCombo::Jinpu);
                     state.kenki += 5;
                     event_sink.apply_status(FUGETSU, 1, this_id, dl);
@@ -160,10 +156,10 @@ impl Job for SamJob {
                 );
             }
             Shifu => {
-                let t = need_target!(target_enemy(MELEE).next(), event_sink);
+                let t = this.target_enemy(MELEE)?.id();
                 state.combos.kaeshi.reset();
                 let meikyo = consume_meikyo(event_sink);
-                let combo = if meikyo || state.combos.main.check(MainCombo::Hakaze) {
+                let combo = if meikyo || state.combos.check_main_for(action) {
                     state.combos.main.set(MainCombo::Shifu);
                     state.kenki += 5;
                     event_sink.apply_status(FUKA, 1, this_id, dl);
@@ -180,10 +176,10 @@ impl Job for SamJob {
                 );
             }
             Yukikaze => {
-                let t = need_target!(target_enemy(MELEE).next(), event_sink);
+                let t = this.target_enemy(MELEE)?.id();
                 state.combos.kaeshi.reset();
                 let meikyo = consume_meikyo(event_sink);
-                let combo = if meikyo || state.combos.main.check(MainCombo::Hakaze) {
+                let combo = if meikyo || state.combos.check_main_for(action) {
                     state.kenki += 15;
                     state.sen.grant_setsu();
                     true
@@ -198,44 +194,28 @@ impl Job for SamJob {
                 );
                 state.combos.main.reset();
             }
-            Gekko => {
-                let t = need_target!(target_enemy(MELEE).next(), event_sink);
+            Gekko | Kasha => {
+                let t = this.target_enemy(MELEE)?.id();
                 state.combos.kaeshi.reset();
                 let meikyo = consume_meikyo(event_sink);
-                let combo = if meikyo || state.combos.main.check(MainCombo::Jinpu) {
-                    state.kenki += 10;
-                    state.sen.grant_getsu();
-                    true
+                let combo = meikyo || state.combos.check_main_for(action);
+                let (pos, buff) = if action == Gekko {
+                    (Positional::Rear, FUGETSU)
                 } else {
-                    false
+                    (Positional::Flank, FUKA)
                 };
-                if meikyo {
-                    event_sink.apply_status(FUGETSU, 1, this_id, dl);
-                }
-                let pos = this.check_positional(Positional::Rear, t);
-                event_sink.damage(
-                    action,
-                    DamageInstance::new(combo_pos_pot(120, 170, 330, 380, combo, pos)).slashing(),
-                    t,
-                    dl,
-                );
-                state.combos.main.reset();
-            }
-            Kasha => {
-                let t = need_target!(target_enemy(MELEE).next(), event_sink);
-                state.combos.kaeshi.reset();
-                let meikyo = consume_meikyo(event_sink);
-                let combo = if meikyo || state.combos.main.check(MainCombo::Shifu) {
+                if combo {
                     state.kenki += 10;
-                    state.sen.grant_ka();
-                    true
-                } else {
-                    false
-                };
+                    if action == Gekko {
+                        state.sen.grant_getsu();
+                    } else {
+                        state.sen.grant_ka();
+                    }
+                }
                 if meikyo {
-                    event_sink.apply_status(FUKA, 1, this_id, dl);
+                    event_sink.apply_status(buff, 1, this_id, dl);
                 }
-                let pos = this.check_positional(Positional::Rear, t);
+                let pos = this.check_positional(pos, t);
                 event_sink.damage(
                     action,
                     DamageInstance::new(combo_pos_pot(120, 170, 330, 380, combo, pos)).slashing(),
@@ -247,11 +227,13 @@ impl Job for SamJob {
             Fuga | Fuko => {
                 state.combos.kaeshi.reset();
                 consume_meikyo(event_sink);
-                let mut c = EventCascade::new(dl, 1);
                 let mut hit = false;
-                for t in target_enemy(CIRCLE) {
+                for (t, d) in this
+                    .target_enemy_aoe(CIRCLE, EventCascade::new(dl, 1))?
+                    .id()
+                {
                     hit = true;
-                    event_sink.damage(action, DamageInstance::new(100).slashing(), t, c.next());
+                    event_sink.damage(action, DamageInstance::new(100).slashing(), t, d);
                 }
                 if hit {
                     state.combos.main.set(MainCombo::Fuko);
@@ -260,52 +242,35 @@ impl Job for SamJob {
                     state.combos.main.reset();
                 }
             }
-            Mangetsu => {
+            Mangetsu | Oka => {
                 state.combos.kaeshi.reset();
                 let meikyo = consume_meikyo(event_sink);
-                let combo = if meikyo || state.combos.main.check(MainCombo::Fuko) {
+                let combo = meikyo || state.combos.check_main_for(action);
+                if combo {
                     state.kenki += 10;
-                    state.sen.grant_getsu();
-                    event_sink.apply_status(FUGETSU, 1, this_id, dl);
-                    true
-                } else {
-                    false
-                };
-                let mut c = EventCascade::new(dl, 1);
-                for t in target_enemy(CIRCLE) {
-                    event_sink.damage(
-                        action,
-                        DamageInstance::new(combo_pot(100, 120, combo)).slashing(),
-                        t,
-                        c.next(),
-                    );
+                    if action == Mangetsu {
+                        state.sen.grant_getsu();
+                        event_sink.apply_status(FUGETSU, 1, this_id, dl);
+                    } else {
+                        state.sen.grant_ka();
+                        event_sink.apply_status(FUKA, 1, this_id, dl);
+                    }
                 }
-                state.combos.main.reset();
-            }
-            Oka => {
-                state.combos.kaeshi.reset();
-                let meikyo = consume_meikyo(event_sink);
-                let combo = if meikyo || state.combos.main.check(MainCombo::Fuko) {
-                    state.kenki += 10;
-                    state.sen.grant_ka();
-                    event_sink.apply_status(FUKA, 1, this_id, dl);
-                    true
-                } else {
-                    false
-                };
-                let mut c = EventCascade::new(dl, 1);
-                for t in target_enemy(CIRCLE) {
+                for (t, d) in this
+                    .target_enemy_aoe(CIRCLE, EventCascade::new(dl, 1))?
+                    .id()
+                {
                     event_sink.damage(
                         action,
                         DamageInstance::new(combo_pot(100, 120, combo)).slashing(),
                         t,
-                        c.next(),
+                        d,
                     );
                 }
                 state.combos.main.reset();
             }
             Enpi => {
-                let t = need_target!(target_enemy(RANGED).next(), event_sink);
+                let t = this.target_enemy(RANGED)?.id();
                 state.kenki += 10;
                 let en_enpi = consume_status(event_sink, ENENPI, 0);
                 event_sink.damage(
@@ -316,24 +281,26 @@ impl Job for SamJob {
                 );
             }
             Shinten => {
-                let t = need_target!(target_enemy(MELEE).next(), event_sink);
+                let t = this.target_enemy(MELEE)?.id();
                 state.kenki -= 25;
                 event_sink.damage(action, DamageInstance::new(250).slashing(), t, dl);
             }
             Kyuten => {
                 state.kenki -= 25;
-                let mut c = EventCascade::new(dl, 1);
-                for t in target_enemy(CIRCLE) {
-                    event_sink.damage(action, DamageInstance::new(120).slashing(), t, c.next());
+                for (t, d) in this
+                    .target_enemy_aoe(CIRCLE, EventCascade::new(dl, 1))?
+                    .id()
+                {
+                    event_sink.damage(action, DamageInstance::new(120).slashing(), t, d);
                 }
             }
             Gyoten => {
-                let t = need_target!(target_enemy(RANGED).next(), event_sink);
+                let t = this.target_enemy(RANGED)?.id();
                 state.kenki -= 10;
                 event_sink.damage(action, DamageInstance::new(100).slashing(), t, dl);
             }
             Yaten => {
-                let t = need_target!(target_enemy(MELEE).next(), event_sink);
+                let t = this.target_enemy(MELEE)?.id();
                 state.kenki -= 10;
                 event_sink.damage(action, DamageInstance::new(100).slashing(), t, dl);
                 event_sink.apply_status(ENENPI, 1, this_id, dl);
@@ -344,13 +311,13 @@ impl Job for SamJob {
                 state.kenki += sen_count * 10;
             }
             Guren => {
-                let (first, other) =
-                    need_target!(target_enemy(ActionTargetting::line(10)), event_sink, aoe);
+                let iter = this
+                    .target_enemy_aoe(ActionTargetting::line(10), EventCascade::new(dl, 1))?
+                    .id()
+                    .falloff(75);
                 state.kenki -= 25;
-                let mut c = EventCascade::new(dl, 1);
-                event_sink.damage(action, DamageInstance::new(500).slashing(), first, c.next());
-                for t in other {
-                    event_sink.damage(action, DamageInstance::new(375).slashing(), t, c.next());
+                for (t, d, f) in iter {
+                    event_sink.damage(action, DamageInstance::new(500).slashing().falloff(f), t, d);
                 }
             }
             Meikyo => {
@@ -359,7 +326,7 @@ impl Job for SamJob {
                 event_sink.apply_status(MEIKYO, 3, this_id, 0);
             }
             Senei => {
-                let t = need_target!(target_enemy(MELEE).next(), event_sink);
+                let t = this.target_enemy(MELEE)?.id();
                 state.kenki -= 25;
                 event_sink.damage(action, DamageInstance::new(860).slashing(), t, dl);
             }
@@ -368,82 +335,51 @@ impl Job for SamJob {
                 event_sink.apply_status(OGI_READY, 1, this_id, dl);
             }
             Shoha => {
-                let t = need_target!(target_enemy(MELEE).next(), event_sink);
+                let t = this.target_enemy(MELEE)?.id();
                 state.meditation.clear();
                 event_sink.damage(action, DamageInstance::new(560).slashing(), t, dl);
             }
             Shoha2 => {
+                let iter = this
+                    .target_enemy_aoe(CIRCLE, EventCascade::new(dl, 1))?
+                    .id();
                 state.meditation.clear();
-                let mut c = EventCascade::new(dl, 1);
-                for t in target_enemy(CIRCLE) {
-                    event_sink.damage(action, DamageInstance::new(200).slashing(), t, c.next());
+                for (t, d) in iter {
+                    event_sink.damage(action, DamageInstance::new(200).slashing(), t, d);
                 }
             }
-            Namikiri => {
-                let (first, other) = need_target!(
-                    target_enemy(ActionTargetting::cone(8, 135)),
-                    event_sink,
-                    aoe
-                );
+            Namikiri | KaeshiNamikiri => {
+                let iter = this
+                    .target_enemy_aoe(ActionTargetting::cone(8, 135), EventCascade::new(dl, 1))?
+                    .id()
+                    .falloff(25);
+                if action == Namikiri {
+                    if !consume_status(event_sink, OGI_READY, 0) {
+                        err!(SamError::OgiRdy);
+                    }
+                    state.combos.kaeshi.set(KaeshiCombo::Namikiri);
+                } else {
+                    state.combos.kaeshi.reset();
+                }
                 state.meditation += 1;
-                state.combos.kaeshi.set(KaeshiCombo::Namikiri);
-                event_sink.remove_status(OGI_READY, this_id, 0);
-                let mut c = EventCascade::new(dl, 1);
-                event_sink.damage(
-                    action,
-                    DamageInstance::new(860).slashing().force_crit(),
-                    first,
-                    c.next(),
-                );
-                for t in other {
+                for (t, d, f) in iter {
                     event_sink.damage(
                         action,
-                        DamageInstance::new(215).slashing().force_crit(),
+                        DamageInstance::new(860).slashing().force_crit().falloff(f),
                         t,
-                        c.next(),
+                        d,
                     );
                 }
             }
-            Higanbana => {
-                let t = need_target!(target_enemy(IAIJUTSU).next(), event_sink);
-                state.meditation += 1;
-                state.combos.kaeshi.set(KaeshiCombo::Higanbana);
-                state.sen.clear();
-                event_sink.damage(action, DamageInstance::new(200).slashing(), t, dl);
-                event_sink.apply_dot(
-                    HIGANBANA,
-                    DamageInstance::new(45).slashing(),
-                    SpeedStat::SkillSpeed,
-                    1,
-                    t,
-                    dl,
-                );
-            }
-            TenkaGoken => {
-                state.meditation += 1;
-                state.combos.kaeshi.set(KaeshiCombo::Goken);
-                state.sen.clear();
-                let mut c = EventCascade::new(dl, 1);
-                for t in target_enemy(ActionTargetting::circle(8)) {
-                    event_sink.damage(action, DamageInstance::new(300).slashing(), t, c.next());
+            Higanbana | KaeshiHiganbana => {
+                let t = this.target_enemy(IAIJUTSU)?.id();
+                if action == Higanbana {
+                    state.combos.kaeshi.set(KaeshiCombo::Higanbana);
+                    state.sen.clear();
+                } else {
+                    state.combos.kaeshi.reset();
                 }
-            }
-            Midare => {
-                let t = need_target!(target_enemy(IAIJUTSU).next(), event_sink);
                 state.meditation += 1;
-                state.combos.kaeshi.set(KaeshiCombo::Setsugekka);
-                state.sen.clear();
-                event_sink.damage(
-                    action,
-                    DamageInstance::new(640).slashing().force_crit(),
-                    t,
-                    dl,
-                );
-            }
-            KaeshiHiganbana => {
-                let t = need_target!(target_enemy(IAIJUTSU).next(), event_sink);
-                state.meditation += 1;
-                state.combos.kaeshi.reset();
                 event_sink.damage(action, DamageInstance::new(200).slashing(), t, dl);
                 event_sink.apply_dot(
                     HIGANBANA,
@@ -454,18 +390,30 @@ impl Job for SamJob {
                     dl,
                 );
             }
-            KaeshiGoken => {
+            TenkaGoken | KaeshiGoken => {
+                let iter = this
+                    .target_enemy_aoe(ActionTargetting::circle(8), EventCascade::new(dl, 1))?
+                    .id();
+                if action == TenkaGoken {
+                    state.combos.kaeshi.set(KaeshiCombo::Goken);
+                    state.sen.clear();
+                } else {
+                    state.combos.kaeshi.reset();
+                }
                 state.meditation += 1;
-                state.combos.kaeshi.reset();
-                let mut c = EventCascade::new(dl, 1);
-                for t in target_enemy(ActionTargetting::circle(8)) {
-                    event_sink.damage(action, DamageInstance::new(300).slashing(), t, c.next());
+                for (t, d) in iter {
+                    event_sink.damage(action, DamageInstance::new(300).slashing(), t, d);
                 }
             }
-            KaeshiSetsugekka => {
-                let t = need_target!(target_enemy(IAIJUTSU).next(), event_sink);
+            Midare | KaeshiSetsugekka => {
+                let t = this.target_enemy(IAIJUTSU)?.id();
+                if action == Midare {
+                    state.combos.kaeshi.set(KaeshiCombo::Setsugekka);
+                    state.sen.clear();
+                } else {
+                    state.combos.kaeshi.reset();
+                }
                 state.meditation += 1;
-                state.combos.kaeshi.reset();
                 event_sink.damage(
                     action,
                     DamageInstance::new(640).slashing().force_crit(),
@@ -473,37 +421,16 @@ impl Job for SamJob {
                     dl,
                 );
             }
-            KaeshiNamikiri => {
-                let (first, other) = need_target!(
-                    target_enemy(ActionTargetting::cone(8, 135)),
-                    event_sink,
-                    aoe
-                );
-                state.meditation += 1;
-                state.combos.kaeshi.reset();
-                let mut c = EventCascade::new(dl, 1);
-                event_sink.damage(
-                    action,
-                    DamageInstance::new(860).slashing().force_crit(),
-                    first,
-                    c.next(),
-                );
-                for t in other {
-                    event_sink.damage(
-                        action,
-                        DamageInstance::new(215).slashing().force_crit(),
-                        t,
-                        c.next(),
-                    );
-                }
-            }
+            Role(v) => v.cast_snap(action, event_sink)?,
         }
+
+        Ok(())
     }
 }
 
-/// A custom error for Samurai actions.
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[derive(Clone, Copy, Debug)]
+/// A custom cast error for Samurai actions.
 pub enum SamError {
     /// Not executed following the specified iaijutsu.
     Kaeshi(SamAction),
@@ -515,15 +442,9 @@ pub enum SamError {
     HagaSen,
     /// Not enough stacks of Meditation.
     Meditation,
-    /// Not under the effect Ogi Namikiri Ready.
+    /// Not under the effect of Ogi Namikiri Ready.
     OgiRdy,
 }
-impl SamError {
-    /// Submits the cast error into the [`EventSink`].
-    pub fn submit<'w, W: WorldRef<'w>>(self, p: &mut impl EventSink<'w, W>) {
-        p.error(self.into())
-    }
-}
 
 impl From<SamError> for EventError {
     fn from(value: SamError) -> Self {
@@ -534,16 +455,18 @@ impl From<SamError> for EventError {
 impl Display for SamError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
-            Self::Kaeshi(ac) => write!(f, "Not executed following {}", ac.name()),
-            Self::Kenki(k) => write!(f, "Not enough Kenki, needed at least {}", k),
-            Self::IaiSen(s) => write!(f, "Invalid Sen count, expected {}", s),
-            Self::HagaSen => write!(f, "Invalid Sen count, expected at least 1"),
-            Self::Meditation => write!(f, "Invalid Meditation count, expected 3"),
-            Self::OgiRdy => write!(f, "Not under the effect of 'Ogi Namikiri Ready'"),
+            Self::Kaeshi(ac) => write!(f, "Not executed following '{}'.", ac.name()),
+            Self::Kenki(k) => write!(f, "Not enough Kenki gauge, needed at least {}.", k),
+            Self::IaiSen(s) => write!(f, "Invalid Sen count, expected {}.", s),
+            Self::HagaSen => write!(f, "Invalid Sen count, expected at least 1."),
+            Self::Meditation => write!(f, "Not enough Meditation stacks, expected 3."),
+            Self::OgiRdy => status_proc_error(f, OGI_READY),
         }
     }
 }
 
+impl Error for SamError {}
+
 const MELEE: ActionTargetting = ActionTargetting::single(3);
 const RANGED: ActionTargetting = ActionTargetting::single(20);
 const IAIJUTSU: ActionTargetting = ActionTargetting::single(6);
@@ -587,7 +510,7 @@ const CIRCLE: ActionTargetting = ActionTargetting::circle(5);
     }
 }]
 #[allow(missing_docs)] // no reason to document the variants.
-/// An action specific to the Reaper job.
+/// An action specific to the Samurai job.
 pub enum SamAction {
     #[skill]
     #[name = "Hakaze"]
@@ -730,6 +653,14 @@ pub enum SamAction {
     #[gcd = ScaleTime::skill(2500)]
     #[name = "Kaeshi: Namikiri"]
     KaeshiNamikiri,
+    #[gcd((v) => v.gcd())]
+    #[cast((v) => v.cast())]
+    #[name((v) => v.name())]
+    #[cooldown((v) => v.cooldown())]
+    #[cd_charges((v) => v.cd_charges())]
+    #[effect_delay((v) => v.effect_delay())]
+    #[category((v) => v.category())]
+    Role(MeleeRoleAction),
 }
 
 impl JobAction for SamAction {
@@ -908,8 +839,18 @@ job_cd_struct! {
     shoha Shoha: Shoha Shoha2;
     "Tsubame-gaeshi"
     tsubame Tsubame: KaeshiHiganbana KaeshiGoken KaeshiSetsugekka;
+    "True North"
+    true_north TrueNorth: Role(MeleeRoleAction::TrueNorth);
+    "Feint"
+    feint Feint: Role(MeleeRoleAction::Feint);
+    "Bloodbath"
+    bloodbath Bloodbath: Role(MeleeRoleAction::Bloodbath);
+    "Second Wind"
+    second_wind SecondWind: Role(MeleeRoleAction::SecondWind);
     "Meikyo Shisui Charge"
     meikyo_chg MeikyoChg;
+    "True North Charge"
+    true_north_chg TrueNorthChg;
 }
 
 impl SamAction {
@@ -922,6 +863,7 @@ impl SamAction {
     pub const fn alt_cd_group(&self) -> Option<SamCdGroup> {
         match self {
             Self::Meikyo => Some(SamCdGroup::MeikyoChg),
+            Self::Role(MeleeRoleAction::TrueNorth) => Some(SamCdGroup::TrueNorthChg),
             _ => None,
         }
     }
@@ -0,0 +1,523 @@
+use core::{
+    error::Error,
+    fmt::{self, Display},
+};
+
+use macros::var_consts;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    enums::{ActionCategory, DamageInstance},
+    err,
+    job::{
+        role::{HealerRoleAction, SWIFTCAST},
+        CastInitInfo, Job, JobAction, JobEvent, JobState,
+    },
+    job_cd_struct,
+    math::SpeedStat,
+    status_effect,
+    timing::{DurationInfo, EventCascade, ScaleTime},
+    util::{status_proc_error, ActionTargettingExt as _, GaugeU8},
+    world::{
+        status::{consume_status, StatusEffect, StatusEventExt},
+        Action, ActionTargetting, ActorRef, DamageEventExt, Event, EventError, EventSink,
+        HealEventExt, WorldRef,
+    },
+};
+
+/// The [`Job`] struct for Scholar.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct SchJob;
+
+/// The damage over time status "Biolysis".
+pub static BIOLYSIS: StatusEffect = status_effect!("Biolysis" 30000 multi);
+/// The damage over time status "Baneful Impaction".
+pub static BANEFUL_IMPACTION: StatusEffect = status_effect!("Baneful Impaction" 15000 multi);
+/// The status effect "Chain Stratagem".
+pub static CHAIN_STRATAGEM: StatusEffect = status_effect!(
+    "Chain Stratagem" 20000 { crit { in = 100 } }
+);
+/// The status effect "Impact Imminent".
+pub static IMPACT_IMMINENT: StatusEffect = status_effect!("Impact Imminent" 30000);
+/// The barrier status "Galvanize".
+pub static GALVANIZE: StatusEffect = status_effect!("Galvanize" 30000);
+/// The status effect "Fey Illumination".
+pub static FEY_ILLUMINATION: StatusEffect = status_effect!(
+    "Fey Illumination" 20000 { damage { in = 95 / 100 } }
+);
+
+/// The delay in milliseconds between commanding the fairy
+/// and the fairy executing the ability.
+const FAIRY_DELAY: u32 = 1000;
+
+impl Job for SchJob {
+    type Action = SchAction;
+    type State = SchState;
+    type CastError = SchError;
+    type Event = SchEvent;
+    type CdGroup = SchCdGroup;
+    type CdMap<T> = SchCdMap<T>;
+
+    fn check_cast<'w, W: WorldRef<'w>, E: EventSink<'w, W>>(
+        action: Self::Action,
+        state: &Self::State,
+        _: &'w W,
+        event_sink: &mut E,
+    ) -> Result<CastInitInfo<Self::CdGroup>, EventError> {
+        let this = event_sink.source();
+
+        let di = this.duration_info();
+
+        use SchAction::*;
+
+        let cast = if this.has_own_status(SWIFTCAST) {
+            ScaleTime::zero()
+        } else {
+            action.cast()
+        };
+
+        let gcd = action.gcd().map(|v| di.scale(v)).unwrap_or_default() as u16;
+        let (lock, snap) = di.get_cast(cast, 600);
+
+        let cd = action
+            .cd_group()
+            .map(|v| (v, action.cooldown(), action.cd_charges()));
+
+        let alt_cd = action.alt_cd_group().map(|v| (v, 1000, 1));
+
+        match action {
+            EnergyDrain | Lustrate if state.aetherflow == 0 => err!(SchError::Aetherflow),
+            BanefulImpaction if !this.has_own_status(IMPACT_IMMINENT) => {
+                err!(SchError::ImpactImminent)
+            }
+            WhisperingDawn | FeyIllumination | FeyBlessing if !state.eos => {
+                err!(SchError::Fairy)
+            }
+            SummonEos if state.eos => err!(SchError::FairySummoned),
+            _ => (),
+        }
+
+        Ok(CastInitInfo {
+            gcd,
+            lock,
+            snap,
+            mp: action.mp_cost(),
+            cd,
+            alt_cd,
+        })
+    }
+
+    fn cast_snap<'w, W: WorldRef<'w>, E: EventSink<'w, W>>(
+        action: Self::Action,
+        state: &mut Self::State,
+        _: &'w W,
+        event_sink: &mut E,
+    ) -> Result<(), EventError> {
+        let this = event_sink.source();
+        let this_id = this.id();
+
+        use SchAction::*;
+
+        let dl = action.effect_delay();
+
+        // swiftcast is only used up by spells with a cast time.
+        if action.cast().duration() > 0 {
+            consume_status(event_sink, SWIFTCAST, 0);
+        }
+
+        let dmg = |potency| DamageInstance::new(potency).magical();
+
+        // single target heals will heal the caster if
+        // the current target is not a party member.
+        let heal_target = this.target_party(SINGLE_HEAL).map_or(this_id, |v| v.id());
+
+        match action {
+            BroilIV | RuinII | EnergyDrain => {
+                let t = this.target_enemy(SPELL)?.id();
+                let potency = match action {
+                    BroilIV => 310,
+                    RuinII => 220,
+                    _ => {
+                        if !state.aetherflow.consume(1) {
+                            err!(SchError::Aetherflow);
+                        }
+                        100
+                    }
+                };
+                event_sink.damage(action, dmg(potency), t, dl);
+            }
+            Biolysis => {
+                let t = this.target_enemy(SPELL)?.id();
+                event_sink.apply_dot(BIOLYSIS, dmg(80), SpeedStat::SpellSpeed, 1, t, dl);
+            }
+            ArtOfWarII => {
+                for (t, d) in this
+                    .target_enemy_aoe(ActionTargetting::circle(5), EventCascade::new(dl, 1))?
+                    .id()
+                {
+                    event_sink.damage(action, dmg(180), t, d);
+                }
+            }
+            BanefulImpaction => {
+                if !consume_status(event_sink, IMPACT_IMMINENT, 0) {
+                    err!(SchError::ImpactImminent);
+                }
+                for (t, d) in this
+                    .target_enemy_aoe(TG_CIRCLE, EventCascade::new(dl, 1))?
+                    .id()
+                {
+                    event_sink.apply_dot(
+                        BANEFUL_IMPACTION,
+                        dmg(140),
+                        SpeedStat::SpellSpeed,
+                        1,
+                        t,
+                        d,
+                    );
+                }
+            }
+            ChainStratagem => {
+                let t = this.target_enemy(SPELL)?.id();
+                event_sink.apply_status(CHAIN_STRATAGEM, 1, t, dl);
+                event_sink.apply_status(IMPACT_IMMINENT, 1, this_id, dl);
+            }
+            Aetherflow => {
+                state.aetherflow.set_max();
+                event_sink.event(Event::AddMp(2000, this_id), dl);
+            }
+            Adloquium | Lustrate => {
+                let potency = if action == Adloquium {
+                    event_sink.apply_status(GALVANIZE, 1, heal_target, dl);
+                    300
+                } else {
+                    if !state.aetherflow.consume(1) {
+                        err!(SchError::Aetherflow);
+                    }
+                    600
+                };
+                event_sink.heal(action, potency, heal_target, dl);
+            }
+            Succor => {
+                for (t, d) in this
+                    .target_party_aoe(ActionTargetting::circle(15), EventCascade::new(dl, 1))?
+                    .id()
+                {
+                    event_sink.heal(action, 200, t, d);
+                    event_sink.apply_status(GALVANIZE, 1, t, d);
+                }
+            }
+            SummonEos => state.eos = true,
+            WhisperingDawn | FeyIllumination | FeyBlessing => {
+                let ability = match action {
+                    WhisperingDawn => FairyAbility::WhisperingDawn,
+                    FeyIllumination => FairyAbility::FeyIllumination,
+                    _ => FairyAbility::FeyBlessing,
+                };
+                event_sink.event(
+                    JobEvent::sch(SchEvent::Fairy(ability), this_id),
+                    dl + FAIRY_DELAY,
+                );
+            }
+            Role(v) => v.cast_snap(action, event_sink)?,
+        }
+
+        Ok(())
+    }
+
+    fn event<'w, W: WorldRef<'w>, E: EventSink<'w, W>>(
+        state: &mut Self::State,
+        _: &'w W,
+        event: &Event,
+        event_sink: &mut E,
+    ) {
+        let this = event_sink.source();
+        let this_id = this.id();
+        match event {
+            Event::Job(JobEvent::Sch(SchEvent::Fairy(ability)), src_id)
+                if *src_id == this_id && state.eos =>
+            {
+                let action = ability.action();
+                // the fairy stays close to the scholar, so the area is centered on them.
+                let Ok(iter) =
+                    this.target_party_aoe(ActionTargetting::circle(20), EventCascade::new(0, 1))
+                else {
+                    return;
+                };
+                for (t, d) in iter.id() {
+                    match ability {
+                        FairyAbility::WhisperingDawn => {
+                            // the regen ticks every 3s for 21s
+                            for tick in 0..7 {
+                                event_sink.heal(action, 80, t, d + tick * 3000);
+                            }
+                        }
+                        FairyAbility::FeyIllumination => {
+                            event_sink.apply_status(FEY_ILLUMINATION, 1, t, d);
+                        }
+                        FairyAbility::FeyBlessing => event_sink.heal(action, 320, t, d),
+                    }
+                }
+            }
+            _ => (),
+        }
+    }
+}
+
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Copy, Debug)]
+/// A custom cast error for Scholar actions.
+pub enum SchError {
+    /// Not enough Aetherflow stacks.
+    Aetherflow,
+    /// Not under the effect of Impact Imminent.
+    ImpactImminent,
+    /// The fairy has not been summoned.
+    Fairy,
+    /// The fairy has already been summoned.
+    FairySummoned,
+}
+
+impl From<SchError> for EventError {
+    fn from(value: SchError) -> Self {
+        Self::Job(value.into())
+    }
+}
+
+impl Display for SchError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Aetherflow => write!(f, "Not enough Aetherflow stacks, needed 1."),
+            Self::ImpactImminent => status_proc_error(f, IMPACT_IMMINENT),
+            Self::Fairy => write!(f, "The fairy has not been summoned."),
+            Self::FairySummoned => write!(f, "The fairy has already been summoned."),
+        }
+    }
+}
+
+impl Error for SchError {}
+
+const SPELL: ActionTargetting = ActionTargetting::single(25);
+const SINGLE_HEAL: ActionTargetting = ActionTargetting::single(30);
+const TG_CIRCLE: ActionTargetting = ActionTargetting::target_circle(5, 25);
+
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(rename_all = "snake_case")
+)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[repr(u8)]
+#[var_consts {
+    /// Returns the base GCD recast time, or `None` if the action is not a gcd.
+    pub const gcd: ScaleTime?;
+    /// Returns the base milliseconds the action takes to cast.
+    pub const cast: ScaleTime = ScaleTime::zero();
+    /// Returns the human friendly name of the action.
+    pub const name: &'static str;
+    /// Returns the cooldown of the skill in milliseconds.
+    pub const cooldown: u32 = 0;
+    /// Returns the number of charges a skill has, or `1` if it is a single charge skill.
+    pub const cd_charges: u8 = 1;
+    /// Returns the delay in milliseconds for the damage/statuses to be applied.
+    pub const effect_delay: u32 = 0;
+    /// Returns the MP cost of the action.
+    pub const mp_cost: u16 = 0;
+    /// Returns the [`ActionCategory`] this action is part of.
+    pub const category: ActionCategory;
+
+    pub const spell for {
+        gcd = ScaleTime::spell(2500);
+        category = ActionCategory::Spell;
+    }
+    pub const ability for {
+        category = ActionCategory::Ability;
+    }
+}]
+#[allow(missing_docs)] // no reason to document the variants.
+/// An action specific to the Scholar job.
+pub enum SchAction {
+    #[spell]
+    #[cast = ScaleTime::spell(1500)]
+    #[mp_cost = 400]
+    #[name = "Broil IV"]
+    BroilIV,
+    #[spell]
+    #[mp_cost = 300]
+    #[name = "Ruin II"]
+    RuinII,
+    #[spell]
+    #[mp_cost = 300]
+    #[name = "Biolysis"]
+    Biolysis,
+    #[spell]
+    #[mp_cost = 400]
+    #[name = "Art of War II"]
+    ArtOfWarII,
+    #[spell]
+    #[cast = ScaleTime::spell(2000)]
+    #[mp_cost = 900]
+    #[name = "Adloquium"]
+    Adloquium,
+    #[spell]
+    #[cast = ScaleTime::spell(2000)]
+    #[mp_cost = 900]
+    #[name = "Succor"]
+    Succor,
+    #[spell]
+    #[cast = ScaleTime::spell(1500)]
+    #[mp_cost = 200]
+    #[name = "Summon Eos"]
+    SummonEos,
+    #[ability]
+    #[cooldown = 60000]
+    #[name = "Aetherflow"]
+    Aetherflow,
+    #[ability]
+    #[cooldown = 1000]
+    #[name = "Energy Drain"]
+    EnergyDrain,
+    #[ability]
+    #[cooldown = 1000]
+    #[name = "Lustrate"]
+    Lustrate,
+    #[ability]
+    #[cooldown = 120000]
+    #[name = "Chain Stratagem"]
+    ChainStratagem,
+    #[ability]
+    #[name = "Baneful Impaction"]
+    BanefulImpaction,
+    #[ability]
+    #[cooldown = 60000]
+    #[name = "Whispering Dawn"]
+    WhisperingDawn,
+    #[ability]
+    #[cooldown = 120000]
+    #[name = "Fey Illumination"]
+    FeyIllumination,
+    #[ability]
+    #[cooldown = 60000]
+    #[name = "Fey Blessing"]
+    FeyBlessing,
+    #[gcd((v) => v.gcd())]
+    #[cast((v) => v.cast())]
+    #[name((v) => v.name())]
+    #[cooldown((v) => v.cooldown())]
+    #[cd_charges((v) => v.cd_charges())]
+    #[effect_delay((v) => v.effect_delay())]
+    #[mp_cost((v) => v.mp_cost())]
+    #[category((v) => v.category())]
+    Role(HealerRoleAction),
+}
+
+impl JobAction for SchAction {
+    fn category(&self) -> ActionCategory {
+        self.category()
+    }
+
+    fn gcd(&self) -> bool {
+        self.gcd().is_some()
+    }
+}
+
+impl From<SchAction> for Action {
+    fn from(value: SchAction) -> Self {
+        Action::Job(value.into())
+    }
+}
+
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+/// The state of the Scholar job gauges and cooldowns.
+pub struct SchState {
+    /// The Aetherflow gauge.
+    pub aetherflow: GaugeU8<3>,
+    /// If Eos is summoned.
+    pub eos: bool,
+}
+
+impl JobState for SchState {
+    fn advance(&mut self, _: u32) {}
+}
+
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+/// A custom event for Scholar.
+pub enum SchEvent {
+    /// The fairy will execute an ability.
+    Fairy(FairyAbility),
+}
+
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+/// An ability executed by the fairy on command.
+pub enum FairyAbility {
+    /// The fairy's "Whispering Dawn".
+    WhisperingDawn,
+    /// The fairy's "Fey Illumination".
+    FeyIllumination,
+    /// The fairy's "Fey Blessing".
+    FeyBlessing,
+}
+
+impl FairyAbility {
+    /// Returns the action that commands the fairy to execute this ability.
+    pub const fn action(&self) -> SchAction {
+        match self {
+            Self::WhisperingDawn => SchAction::WhisperingDawn,
+            Self::FeyIllumination => SchAction::FeyIllumination,
+            Self::FeyBlessing => SchAction::FeyBlessing,
+        }
+    }
+}
+
+job_cd_struct! {
+    SchAction =>
+
+    #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+    #[derive(Clone, Debug, Default)]
+    /// The cooldown map for Scholar actions.
+    pub SchCdMap
+
+    #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+    #[derive(Copy, Clone, Debug)]
+    /// The various cooldown groups a Scholar action can be part of.
+    pub SchCdGroup
+
+    "Aetherflow"
+    aetherflow Aetherflow: Aetherflow;
+    "Energy Drain"
+    energy_drain EnergyDrain: EnergyDrain;
+    "Lustrate"
+    lustrate Lustrate: Lustrate;
+    "Chain Stratagem"
+    chain_stratagem ChainStratagem: ChainStratagem;
+    "Whispering Dawn"
+    whispering_dawn WhisperingDawn: WhisperingDawn;
+    "Fey Illumination"
+    fey_illumination FeyIllumination: FeyIllumination;
+    "Fey Blessing"
+    fey_blessing FeyBlessing: FeyBlessing;
+    "Swiftcast"
+    swiftcast Swiftcast: Role(HealerRoleAction::Swiftcast);
+    "Lucid Dreaming"
+    lucid_dreaming LucidDreaming: Role(HealerRoleAction::LucidDreaming);
+    "Surecast"
+    surecast Surecast: Role(HealerRoleAction::Surecast);
+    "Rescue"
+    rescue Rescue: Role(HealerRoleAction::Rescue);
+}
+
+impl SchAction {
+    /// Returns the alternate [cooldown group] that this action is part of.
+    ///
+    /// Returns `None` if this action does not have an alternate cooldown.
+    /// This action is used for the 1s cooldown between uses of charged actions.
+    ///
+    /// [cooldown group]: SchCdGroup
+    pub const fn alt_cd_group(&self) -> Option<SchCdGroup> {
+        None
+    }
+}
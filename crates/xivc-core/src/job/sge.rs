@@ -0,0 +1,485 @@
+use core::{
+    error::Error,
+    fmt::{self, Display},
+};
+
+use macros::var_consts;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    enums::{ActionCategory, DamageInstance},
+    err,
+    job::{
+        role::{HealerRoleAction, SWIFTCAST},
+        CastInitInfo, Job, JobAction, JobState,
+    },
+    job_cd_struct,
+    math::SpeedStat,
+    status_effect,
+    timing::{DurationInfo, EventCascade, ScaleTime},
+    util::{ActionTargettingExt as _, GaugeU8},
+    world::{
+        status::{consume_status, StatusEffect, StatusEventExt},
+        Action, ActionTargetting, ActorId, ActorRef, DamageEventExt, Event, EventError, EventSink,
+        HealEventExt, WorldRef,
+    },
+};
+
+/// The [`Job`] struct for Sage.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct SgeJob;
+
+/// The status effect "Eukrasia".
+pub static EUKRASIA: StatusEffect = status_effect!("Eukrasia" permanent);
+/// The damage over time status "Eukrasian Dosis III".
+pub static EUKRASIAN_DOSIS_III: StatusEffect = status_effect!("Eukrasian Dosis III" 30000 multi);
+/// The damage over time status "Eukrasian Dyskrasia".
+pub static EUKRASIAN_DYSKRASIA: StatusEffect = status_effect!("Eukrasian Dyskrasia" 30000 multi);
+/// The barrier status "Eukrasian Diagnosis".
+pub static EUKRASIAN_DIAGNOSIS: StatusEffect = status_effect!("Eukrasian Diagnosis" 30000);
+/// The barrier status "Eukrasian Prognosis".
+pub static EUKRASIAN_PROGNOSIS: StatusEffect = status_effect!("Eukrasian Prognosis" 30000);
+/// The status effect "Kardia".
+pub static KARDIA: StatusEffect = status_effect!("Kardia" permanent);
+/// The status effect "Kardion".
+pub static KARDION: StatusEffect = status_effect!("Kardion" permanent);
+
+/// The time in milliseconds it takes to gain a stack of Addersgall.
+const ADDERSGALL_TIME: u32 = 20000;
+
+impl Job for SgeJob {
+    type Action = SgeAction;
+    type State = SgeState;
+    type CastError = SgeError;
+    type Event = ();
+    type CdGroup = SgeCdGroup;
+    type CdMap<T> = SgeCdMap<T>;
+
+    fn check_cast<'w, W: WorldRef<'w>, E: EventSink<'w, W>>(
+        action: Self::Action,
+        state: &Self::State,
+        _: &'w W,
+        event_sink: &mut E,
+    ) -> Result<CastInitInfo<Self::CdGroup>, EventError> {
+        let this = event_sink.source();
+
+        let di = this.duration_info();
+
+        use SgeAction::*;
+
+        // all of the eukrasian versions of actions are instant.
+        let cast = if action.eukrasian() && this.has_own_status(EUKRASIA)
+            || this.has_own_status(SWIFTCAST)
+        {
+            ScaleTime::zero()
+        } else {
+            action.cast()
+        };
+
+        let gcd = action.gcd().map(|v| di.scale(v)).unwrap_or_default() as u16;
+        let (lock, snap) = di.get_cast(cast, 600);
+
+        let cd = action
+            .cd_group()
+            .map(|v| (v, action.cooldown(), action.cd_charges()));
+
+        let alt_cd = action.alt_cd_group().map(|v| (v, 1000, 1));
+
+        match action {
+            Druochole if state.addersgall == 0 => err!(SgeError::Addersgall),
+            ToxikonII if state.addersting == 0 => err!(SgeError::Addersting),
+            _ => (),
+        }
+
+        Ok(CastInitInfo {
+            gcd,
+            lock,
+            snap,
+            mp: action.mp_cost(),
+            cd,
+            alt_cd,
+        })
+    }
+
+    fn cast_snap<'w, W: WorldRef<'w>, E: EventSink<'w, W>>(
+        action: Self::Action,
+        state: &mut Self::State,
+        _: &'w W,
+        event_sink: &mut E,
+    ) -> Result<(), EventError> {
+        let this = event_sink.source();
+        let this_id = this.id();
+
+        use SgeAction::*;
+
+        let dl = action.effect_delay();
+
+        let dmg = |potency| DamageInstance::new(potency).magical();
+
+        // single target heals will heal the caster if
+        // the current target is not a party member.
+        let heal_target = this.target_party(SINGLE_HEAL).map_or(this_id, |v| v.id());
+
+        // eukrasia is used up before swiftcast.
+        let eukrasia = action.eukrasian() && consume_status(event_sink, EUKRASIA, 0);
+        if !eukrasia && action.cast().duration() > 0 {
+            consume_status(event_sink, SWIFTCAST, 0);
+        }
+
+        match action {
+            Eukrasia => event_sink.apply_status(EUKRASIA, 1, this_id, dl),
+            DosisIII => {
+                let t = this.target_enemy(SPELL)?.id();
+                if eukrasia {
+                    event_sink.apply_dot(
+                        EUKRASIAN_DOSIS_III,
+                        dmg(80),
+                        SpeedStat::SpellSpeed,
+                        1,
+                        t,
+                        dl,
+                    );
+                } else {
+                    event_sink.damage(action, dmg(330), t, dl);
+                }
+            }
+            DyskrasiaII => {
+                for (t, d) in this
+                    .target_enemy_aoe(ActionTargetting::circle(5), EventCascade::new(dl, 1))?
+                    .id()
+                {
+                    if eukrasia {
+                        event_sink.apply_dot(
+                            EUKRASIAN_DYSKRASIA,
+                            dmg(40),
+                            SpeedStat::SpellSpeed,
+                            1,
+                            t,
+                            d,
+                        );
+                    } else {
+                        event_sink.damage(action, dmg(170), t, d);
+                    }
+                }
+            }
+            PhlegmaIII | ToxikonII | Pneuma | Psyche => {
+                let (targetting, potency, falloff) = match action {
+                    PhlegmaIII => (PHLEGMA, 600, 50),
+                    ToxikonII => {
+                        if !state.addersting.consume(1) {
+                            err!(SgeError::Addersting);
+                        }
+                        (TG_CIRCLE, 330, 100)
+                    }
+                    Pneuma => (ActionTargetting::line(25), 330, 60),
+                    _ => (TG_CIRCLE, 600, 50),
+                };
+                for (t, d, f) in this
+                    .target_enemy_aoe(targetting, EventCascade::new(dl, 1))?
+                    .id()
+                    .falloff(falloff)
+                {
+                    event_sink.damage(action, dmg(potency).falloff(f), t, d);
+                }
+                if action == Pneuma {
+                    for (t, d) in this
+                        .target_party_aoe(ActionTargetting::circle(20), EventCascade::new(dl, 1))?
+                        .id()
+                    {
+                        event_sink.heal(action, 600, t, d);
+                    }
+                }
+            }
+            Diagnosis => {
+                if eukrasia {
+                    event_sink.heal(action, 300, heal_target, dl);
+                    event_sink.apply_status(EUKRASIAN_DIAGNOSIS, 1, heal_target, dl);
+                    // barriers are not simulated, so the addersting
+                    // is granted as soon as the barrier is applied.
+                    state.addersting += 1;
+                } else {
+                    event_sink.heal(action, 450, heal_target, dl);
+                }
+            }
+            Prognosis => {
+                for (t, d) in this
+                    .target_party_aoe(ActionTargetting::circle(15), EventCascade::new(dl, 1))?
+                    .id()
+                {
+                    if eukrasia {
+                        event_sink.heal(action, 100, t, d);
+                        event_sink.apply_status(EUKRASIAN_PROGNOSIS, 1, t, d);
+                    } else {
+                        event_sink.heal(action, 300, t, d);
+                    }
+                }
+            }
+            Kardia => {
+                // only one party member can be the kardion at a time.
+                if let Some(kardion) = state.kardion {
+                    event_sink.remove_status(KARDION, kardion, 0);
+                }
+                event_sink.apply_status(KARDIA, 1, this_id, dl);
+                event_sink.apply_status(KARDION, 1, heal_target, dl);
+                state.kardion = Some(heal_target);
+            }
+            Druochole => {
+                if !state.addersgall.consume(1) {
+                    err!(SgeError::Addersgall);
+                }
+                event_sink.heal(action, 600, heal_target, dl);
+                event_sink.event(Event::AddMp(700, this_id), dl);
+            }
+            Rhizomata => state.addersgall += 1,
+            Role(v) => v.cast_snap(action, event_sink)?,
+        }
+
+        if action.kardia() {
+            if let Some(kardion) = state.kardion {
+                event_sink.heal(Kardia, 170, kardion, dl);
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Copy, Debug)]
+/// A custom cast error for Sage actions.
+pub enum SgeError {
+    /// Not enough Addersgall stacks.
+    Addersgall,
+    /// Not enough Addersting stacks.
+    Addersting,
+}
+
+impl From<SgeError> for EventError {
+    fn from(value: SgeError) -> Self {
+        Self::Job(value.into())
+    }
+}
+
+impl Display for SgeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Addersgall => write!(f, "Not enough Addersgall stacks, needed 1."),
+            Self::Addersting => write!(f, "Not enough Addersting stacks, needed 1."),
+        }
+    }
+}
+
+impl Error for SgeError {}
+
+const SPELL: ActionTargetting = ActionTargetting::single(25);
+const SINGLE_HEAL: ActionTargetting = ActionTargetting::single(30);
+const TG_CIRCLE: ActionTargetting = ActionTargetting::target_circle(5, 25);
+const PHLEGMA: ActionTargetting = ActionTargetting::target_circle(5, 6);
+
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(rename_all = "snake_case")
+)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[repr(u8)]
+#[var_consts {
+    /// Returns the base GCD recast time, or `None` if the action is not a gcd.
+    pub const gcd: ScaleTime?;
+    /// Returns the base milliseconds the action takes to cast.
+    pub const cast: ScaleTime = ScaleTime::zero();
+    /// Returns the human friendly name of the action.
+    pub const name: &'static str;
+    /// Returns the cooldown of the skill in milliseconds.
+    pub const cooldown: u32 = 0;
+    /// Returns the number of charges a skill has, or `1` if it is a single charge skill.
+    pub const cd_charges: u8 = 1;
+    /// Returns the delay in milliseconds for the damage/statuses to be applied.
+    pub const effect_delay: u32 = 0;
+    /// Returns the MP cost of the action.
+    pub const mp_cost: u16 = 0;
+    /// Returns the [`ActionCategory`] this action is part of.
+    pub const category: ActionCategory;
+    /// Returns `true` if the action is transformed by Eukrasia.
+    pub const eukrasian;
+    /// Returns `true` if the action triggers a Kardia heal.
+    pub const kardia;
+
+    pub const spell for {
+        gcd = ScaleTime::spell(2500);
+        category = ActionCategory::Spell;
+    }
+    pub const ability for {
+        category = ActionCategory::Ability;
+    }
+}]
+#[allow(missing_docs)] // no reason to document the variants.
+/// An action specific to the Sage job.
+pub enum SgeAction {
+    #[gcd = ScaleTime::none(1000)]
+    #[spell]
+    #[name = "Eukrasia"]
+    Eukrasia,
+    #[spell]
+    #[eukrasian]
+    #[kardia]
+    #[cast = ScaleTime::spell(1500)]
+    #[mp_cost = 400]
+    #[name = "Dosis III"]
+    DosisIII,
+    #[spell]
+    #[eukrasian]
+    #[kardia]
+    #[mp_cost = 400]
+    #[name = "Dyskrasia II"]
+    DyskrasiaII,
+    #[spell]
+    #[kardia]
+    #[cooldown = 40000]
+    #[cd_charges = 2]
+    #[mp_cost = 400]
+    #[name = "Phlegma III"]
+    PhlegmaIII,
+    #[spell]
+    #[kardia]
+    #[name = "Toxikon II"]
+    ToxikonII,
+    #[spell]
+    #[kardia]
+    #[cast = ScaleTime::spell(1500)]
+    #[cooldown = 120000]
+    #[mp_cost = 700]
+    #[name = "Pneuma"]
+    Pneuma,
+    #[spell]
+    #[eukrasian]
+    #[cast = ScaleTime::spell(1500)]
+    #[mp_cost = 400]
+    #[name = "Diagnosis"]
+    Diagnosis,
+    #[spell]
+    #[eukrasian]
+    #[cast = ScaleTime::spell(2000)]
+    #[mp_cost = 800]
+    #[name = "Prognosis"]
+    Prognosis,
+    #[ability]
+    #[cooldown = 5000]
+    #[name = "Kardia"]
+    Kardia,
+    #[ability]
+    #[cooldown = 60000]
+    #[name = "Psyche"]
+    Psyche,
+    #[ability]
+    #[cooldown = 1000]
+    #[name = "Druochole"]
+    Druochole,
+    #[ability]
+    #[cooldown = 90000]
+    #[name = "Rhizomata"]
+    Rhizomata,
+    #[gcd((v) => v.gcd())]
+    #[cast((v) => v.cast())]
+    #[name((v) => v.name())]
+    #[cooldown((v) => v.cooldown())]
+    #[cd_charges((v) => v.cd_charges())]
+    #[effect_delay((v) => v.effect_delay())]
+    #[mp_cost((v) => v.mp_cost())]
+    #[category((v) => v.category())]
+    Role(HealerRoleAction),
+}
+
+impl JobAction for SgeAction {
+    fn category(&self) -> ActionCategory {
+        self.category()
+    }
+
+    fn gcd(&self) -> bool {
+        self.gcd().is_some()
+    }
+}
+
+impl From<SgeAction> for Action {
+    fn from(value: SgeAction) -> Self {
+        Action::Job(value.into())
+    }
+}
+
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+/// The state of the Sage job gauges and cooldowns.
+pub struct SgeState {
+    /// The Addersgall gauge.
+    pub addersgall: GaugeU8<3>,
+    /// The time in milliseconds since the last Addersgall was gained.
+    pub addersgall_timer: u32,
+    /// The Addersting gauge.
+    pub addersting: GaugeU8<3>,
+    /// The party member that is the current Kardion target.
+    pub kardion: Option<ActorId>,
+}
+
+impl JobState for SgeState {
+    fn advance(&mut self, time: u32) {
+        self.addersgall_timer += time;
+        while self.addersgall_timer >= ADDERSGALL_TIME {
+            self.addersgall_timer -= ADDERSGALL_TIME;
+            self.addersgall += 1;
+        }
+    }
+}
+
+job_cd_struct! {
+    SgeAction =>
+
+    #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+    #[derive(Clone, Debug, Default)]
+    /// The cooldown map for Sage actions.
+    pub SgeCdMap
+
+    #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+    #[derive(Copy, Clone, Debug)]
+    /// The various cooldown groups a Sage action can be part of.
+    pub SgeCdGroup
+
+    "Phlegma III"
+    phlegma Phlegma: PhlegmaIII;
+    "Pneuma"
+    pneuma Pneuma: Pneuma;
+    "Kardia"
+    kardia Kardia: Kardia;
+    "Psyche"
+    psyche Psyche: Psyche;
+    "Druochole"
+    druochole Druochole: Druochole;
+    "Rhizomata"
+    rhizomata Rhizomata: Rhizomata;
+    "Swiftcast"
+    swiftcast Swiftcast: Role(HealerRoleAction::Swiftcast);
+    "Lucid Dreaming"
+    lucid_dreaming LucidDreaming: Role(HealerRoleAction::LucidDreaming);
+    "Surecast"
+    surecast Surecast: Role(HealerRoleAction::Surecast);
+    "Rescue"
+    rescue Rescue: Role(HealerRoleAction::Rescue);
+    "Phlegma III Charge"
+    phlegma_chg PhlegmaChg;
+}
+
+impl SgeAction {
+    /// Returns the alternate [cooldown group] that this action is part of.
+    ///
+    /// Returns `None` if this action does not have an alternate cooldown.
+    /// This action is used for the 1s cooldown between uses of charged actions.
+    ///
+    /// [cooldown group]: SgeCdGroup
+    pub const fn alt_cd_group(&self) -> Option<SgeCdGroup> {
+        match self {
+            Self::PhlegmaIII => Some(SgeCdGroup::PhlegmaChg),
+            _ => None,
+        }
+    }
+}
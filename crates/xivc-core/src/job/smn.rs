@@ -0,0 +1,880 @@
+use core::{
+    error::Error,
+    fmt::{self, Display},
+};
+
+use macros::var_consts;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    enums::{ActionCategory, DamageInstance},
+    err,
+    job::{
+        self,
+        role::{CasterRoleAction, SWIFTCAST},
+        CastInitInfo, Job, JobAction, JobState,
+    },
+    job_cd_struct, status_effect,
+    timing::{DurationInfo, EventCascade, ScaleTime},
+    util::{status_proc_error, ActionTargettingExt as _, GaugeU8},
+    world::{
+        status::{consume_status, StatusEffect, StatusEventExt},
+        Action, ActionEvent, ActionEventKind, ActionTargetting, ActorRef, DamageEventExt, Event,
+        EventError, EventSink, WorldRef,
+    },
+};
+
+/// The [`Job`] struct for Summoner.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct SmnJob;
+
+/// The status effect "Searing Light".
+pub static SEARING_LIGHT: StatusEffect = status_effect!(
+    "Searing Light" 20000 { damage { out = 105 / 100 } }
+);
+/// The status effect "Ruby's Glimmer".
+pub static RUBYS_GLIMMER: StatusEffect = status_effect!("Ruby's Glimmer" 30000);
+/// The status effect "Further Ruin".
+pub static FURTHER_RUIN: StatusEffect = status_effect!("Further Ruin" 60000);
+/// The status effect "Ifrit's Favor".
+pub static IFRITS_FAVOR: StatusEffect = status_effect!("Ifrit's Favor" 30000);
+/// The status effect "Titan's Favor".
+pub static TITANS_FAVOR: StatusEffect = status_effect!("Titan's Favor" 30000);
+/// The status effect "Garuda's Favor".
+pub static GARUDAS_FAVOR: StatusEffect = status_effect!("Garuda's Favor" 30000);
+
+/// The duration of a demi-summon in milliseconds.
+const DEMI_DURATION: u32 = 15000;
+
+impl Job for SmnJob {
+    type Action = SmnAction;
+    type State = SmnState;
+    type CastError = SmnError;
+    type Event = ();
+    type CdGroup = SmnCdGroup;
+    type CdMap<T> = SmnCdMap<T>;
+
+    fn check_cast<'w, W: WorldRef<'w>, E: EventSink<'w, W>>(
+        action: Self::Action,
+        state: &Self::State,
+        _: &'w W,
+        event_sink: &mut E,
+    ) -> Result<CastInitInfo<Self::CdGroup>, EventError> {
+        let this = event_sink.source();
+
+        let di = this.duration_info();
+
+        use SmnAction::*;
+
+        let (gcd, cast) = state.timing(action);
+        let cast = if this.has_own_status(SWIFTCAST) {
+            ScaleTime::zero()
+        } else {
+            cast
+        };
+
+        let gcd = gcd.map(|v| di.scale(v)).unwrap_or_default() as u16;
+        let (lock, snap) = di.get_cast(cast, 600);
+
+        let cd = action
+            .cd_group()
+            .map(|v| (v, action.cooldown(), action.cd_charges()));
+
+        let alt_cd = action.alt_cd_group().map(|v| (v, 1000, 1));
+
+        match action {
+            SummonBahamut | SummonPhoenix | SummonSolarBahamut => {
+                if state.demi.is_some() {
+                    err!(SmnError::DemiActive);
+                }
+                let next = state.next_demi();
+                if action != next.action() {
+                    err!(SmnError::Demi(next));
+                }
+            }
+            SummonIfrit | SummonTitan | SummonGaruda => {
+                if state.demi.is_some() {
+                    err!(SmnError::DemiActive);
+                }
+                let primal = match action {
+                    SummonIfrit => Primal::Ifrit,
+                    SummonTitan => Primal::Titan,
+                    _ => Primal::Garuda,
+                };
+                if !state.arcanum(primal) {
+                    err!(SmnError::Primal(primal));
+                }
+            }
+            Enkindle if !state.enkindle => err!(SmnError::DemiAction),
+            AstralFlow if !state.astral_flow => err!(SmnError::DemiAction),
+            Gemshine | PreciousBrilliance if state.attunement.is_none() => {
+                err!(SmnError::Attunement)
+            }
+            CrimsonCyclone if !this.has_own_status(IFRITS_FAVOR) => err!(SmnError::IfritsFavor),
+            CrimsonStrike if !state.crimson_strike => err!(SmnError::CrimsonStrike),
+            MountainBuster if !this.has_own_status(TITANS_FAVOR) => err!(SmnError::TitansFavor),
+            Slipstream if !this.has_own_status(GARUDAS_FAVOR) => err!(SmnError::GarudasFavor),
+            RuinIV if !this.has_own_status(FURTHER_RUIN) => err!(SmnError::FurtherRuin),
+            Fester | Necrotize | Painflare if state.aetherflow == 0 => {
+                err!(SmnError::Aetherflow)
+            }
+            SearingFlash if !this.has_own_status(RUBYS_GLIMMER) => err!(SmnError::RubysGlimmer),
+            _ => (),
+        }
+
+        let mp = match (action, state.attunement) {
+            (Gemshine | PreciousBrilliance, Some((Primal::Ifrit, _))) => 300,
+            (RuinIII | Tridisaster, _) if state.demi.is_some() => 0,
+            _ => action.mp_cost(),
+        };
+
+        Ok(CastInitInfo {
+            gcd,
+            lock,
+            snap,
+            mp,
+            cd,
+            alt_cd,
+        })
+    }
+
+    fn cast_snap<'w, W: WorldRef<'w>, E: EventSink<'w, W>>(
+        action: Self::Action,
+        state: &mut Self::State,
+        _: &'w W,
+        event_sink: &mut E,
+    ) -> Result<(), EventError> {
+        let this = event_sink.source();
+        let this_id = this.id();
+
+        use SmnAction::*;
+
+        let dl = action.effect_delay();
+
+        // swiftcast is only used up by spells with a cast time.
+        if state.timing(action).1.duration() > 0 {
+            consume_status(event_sink, SWIFTCAST, 0);
+        }
+
+        let dmg = |potency| DamageInstance::new(potency).magical();
+
+        match action {
+            RuinIII | RuinIV | Gemshine | CrimsonStrike | Slipstream => {
+                let t = this.target_enemy(SPELL)?.id();
+                let potency = match action {
+                    RuinIII => match state.demi {
+                        Some((demi, _)) => demi.impulse_potency(),
+                        None => 360,
+                    },
+                    RuinIV => {
+                        if !consume_status(event_sink, FURTHER_RUIN, 0) {
+                            err!(SmnError::FurtherRuin);
+                        }
+                        490
+                    }
+                    Gemshine => {
+                        let Some(primal) = state.consume_attunement() else {
+                            err!(SmnError::Attunement);
+                        };
+                        if primal == Primal::Titan {
+                            event_sink.apply_status(TITANS_FAVOR, 1, this_id, dl);
+                        }
+                        primal.gemshine_potency()
+                    }
+                    CrimsonStrike => {
+                        state.crimson_strike = false;
+                        490
+                    }
+                    _ => {
+                        if !consume_status(event_sink, GARUDAS_FAVOR, 0) {
+                            err!(SmnError::GarudasFavor);
+                        }
+                        490
+                    }
+                };
+                event_sink.damage(action, dmg(potency), t, dl);
+            }
+            CrimsonCyclone => {
+                let t = this.target_enemy(CRIMSON_CYCLONE)?.id();
+                if !consume_status(event_sink, IFRITS_FAVOR, 0) {
+                    err!(SmnError::IfritsFavor);
+                }
+                state.crimson_strike = true;
+                event_sink.damage(action, dmg(490), t, dl);
+            }
+            Tridisaster | PreciousBrilliance | Fester | Necrotize | Painflare | EnergyDrain
+            | EnergySiphon | MountainBuster | SearingFlash => {
+                let iter = match action {
+                    Fester | Necrotize | EnergyDrain => {
+                        this.target_enemy_aoe(SPELL, EventCascade::new(dl, 1))?
+                    }
+                    _ => this.target_enemy_aoe(TG_CIRCLE, EventCascade::new(dl, 1))?,
+                }
+                .id();
+                let (potency, falloff) = match action {
+                    Tridisaster => match state.demi {
+                        Some((demi, _)) => (demi.flare_potency(), 100),
+                        None => (120, 100),
+                    },
+                    PreciousBrilliance => {
+                        let Some(primal) = state.consume_attunement() else {
+                            err!(SmnError::Attunement);
+                        };
+                        if primal == Primal::Titan {
+                            event_sink.apply_status(TITANS_FAVOR, 1, this_id, dl);
+                        }
+                        (primal.catastrophe_potency(), 100)
+                    }
+                    Fester | Necrotize | Painflare => {
+                        if !state.aetherflow.consume(1) {
+                            err!(SmnError::Aetherflow);
+                        }
+                        match action {
+                            Fester => (340, 100),
+                            Necrotize => (440, 100),
+                            _ => (150, 100),
+                        }
+                    }
+                    EnergyDrain | EnergySiphon => {
+                        state.aetherflow.set_max();
+                        event_sink.apply_status(FURTHER_RUIN, 1, this_id, dl);
+                        if action == EnergyDrain {
+                            (200, 100)
+                        } else {
+                            (100, 100)
+                        }
+                    }
+                    MountainBuster => {
+                        if !consume_status(event_sink, TITANS_FAVOR, 0) {
+                            err!(SmnError::TitansFavor);
+                        }
+                        (160, 30)
+                    }
+                    _ => {
+                        if !consume_status(event_sink, RUBYS_GLIMMER, 0) {
+                            err!(SmnError::RubysGlimmer);
+                        }
+                        (600, 40)
+                    }
+                };
+                for (t, d, f) in iter.falloff(falloff) {
+                    event_sink.damage(action, dmg(potency).falloff(f), t, d);
+                }
+            }
+            SummonBahamut | SummonPhoenix | SummonSolarBahamut => {
+                let demi = state.next_demi();
+                state.demi = Some((demi, DEMI_DURATION));
+                state.demi_count = state.demi_count.wrapping_add(1);
+                state.enkindle = true;
+                state.astral_flow = true;
+                state.attunement = None;
+                state.ifrit = true;
+                state.titan = true;
+                state.garuda = true;
+            }
+            SummonIfrit | SummonTitan | SummonGaruda => {
+                let primal = match action {
+                    SummonIfrit => Primal::Ifrit,
+                    SummonTitan => Primal::Titan,
+                    _ => Primal::Garuda,
+                };
+                *state.arcanum_mut(primal) = false;
+                state.attunement = Some((primal, primal.attunement()));
+                match primal {
+                    Primal::Ifrit => event_sink.apply_status(IFRITS_FAVOR, 1, this_id, dl),
+                    Primal::Titan => (),
+                    Primal::Garuda => event_sink.apply_status(GARUDAS_FAVOR, 1, this_id, dl),
+                }
+                for (t, d, f) in this
+                    .target_enemy_aoe(TG_CIRCLE, EventCascade::new(dl, 1))?
+                    .id()
+                    .falloff(40)
+                {
+                    event_sink.damage(action, dmg(800).falloff(f), t, d);
+                }
+            }
+            Enkindle | AstralFlow => {
+                let Some((demi, _)) = state.demi else {
+                    err!(SmnError::DemiAction);
+                };
+                let potency = if action == Enkindle {
+                    state.enkindle = false;
+                    demi.enkindle_potency()
+                } else {
+                    state.astral_flow = false;
+                    demi.astral_flow_potency()
+                };
+                // rekindle is a heal, so there is nothing to do for phoenix
+                if potency > 0 {
+                    for (t, d, f) in this
+                        .target_enemy_aoe(TG_CIRCLE, EventCascade::new(dl, 1))?
+                        .id()
+                        .falloff(40)
+                    {
+                        event_sink.damage(action, dmg(potency).falloff(f), t, d);
+                    }
+                }
+            }
+            SearingLight => {
+                event_sink.apply_status(RUBYS_GLIMMER, 1, this_id, dl);
+                for (t, d) in this
+                    .target_party_aoe(ActionTargetting::circle(30), EventCascade::new(dl, 3))?
+                    .id()
+                {
+                    event_sink.apply_status(SEARING_LIGHT, 1, t, d);
+                }
+            }
+            Role(v) => v.cast_snap(action, event_sink)?,
+        }
+
+        Ok(())
+    }
+
+    fn event<'w, W: WorldRef<'w>, E: EventSink<'w, W>>(
+        state: &mut Self::State,
+        _: &'w W,
+        event: &Event,
+        event_sink: &mut E,
+    ) {
+        let this = event_sink.source();
+        let this_id = this.id();
+        // demi-summons attack every time the summoner casts a gcd.
+        // failed casts are never passed along as events, so this is
+        // only reached for gcds that actually went through.
+        if let Event::Action(ActionEvent {
+            action: Action::Job(job::Action::Smn(action)),
+            source,
+            kind: ActionEventKind::Cast,
+        }) = event
+        {
+            if *source != this_id || action.gcd().is_none() || action.demi() {
+                return;
+            }
+            let Some((demi, _)) = state.demi else {
+                return;
+            };
+            if let Ok(target) = this.target_enemy(SPELL) {
+                event_sink.damage(
+                    demi.action(),
+                    DamageInstance::new(demi.pet_potency()).magical(),
+                    target.id(),
+                    0,
+                );
+            }
+        }
+    }
+}
+
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Copy, Debug)]
+/// A custom cast error for Summoner actions.
+pub enum SmnError {
+    /// The demi-summon is not the next in the cycle.
+    ///
+    /// Contains the demi-summon that is next to be summoned.
+    Demi(Demi),
+    /// A demi-summon is already active.
+    DemiActive,
+    /// No demi-summon is active, or the action was already
+    /// used for the active demi-summon.
+    DemiAction,
+    /// The primal has already been summoned.
+    Primal(Primal),
+    /// Not attuned to any primal.
+    Attunement,
+    /// Not under the effect of Ifrit's Favor.
+    IfritsFavor,
+    /// Crimson Cyclone was not used.
+    CrimsonStrike,
+    /// Not under the effect of Titan's Favor.
+    TitansFavor,
+    /// Not under the effect of Garuda's Favor.
+    GarudasFavor,
+    /// Not under the effect of Further Ruin.
+    FurtherRuin,
+    /// Not enough Aetherflow.
+    Aetherflow,
+    /// Not under the effect of Ruby's Glimmer.
+    RubysGlimmer,
+}
+
+impl From<SmnError> for EventError {
+    fn from(value: SmnError) -> Self {
+        Self::Job(value.into())
+    }
+}
+
+impl Display for SmnError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Demi(demi) => write!(f, "The next demi-summon is '{}'.", demi.action().name()),
+            Self::DemiActive => write!(f, "A demi-summon is already active."),
+            Self::DemiAction => write!(f, "Not available for the current demi-summon."),
+            Self::Primal(primal) => {
+                write!(f, "'{}' has already been summoned.", primal.action().name())
+            }
+            Self::Attunement => write!(f, "Not attuned to any primal."),
+            Self::IfritsFavor => status_proc_error(f, IFRITS_FAVOR),
+            Self::CrimsonStrike => write!(f, "Crimson Cyclone was not used."),
+            Self::TitansFavor => status_proc_error(f, TITANS_FAVOR),
+            Self::GarudasFavor => status_proc_error(f, GARUDAS_FAVOR),
+            Self::FurtherRuin => status_proc_error(f, FURTHER_RUIN),
+            Self::Aetherflow => write!(f, "Not enough Aetherflow."),
+            Self::RubysGlimmer => status_proc_error(f, RUBYS_GLIMMER),
+        }
+    }
+}
+
+impl Error for SmnError {}
+
+const SPELL: ActionTargetting = ActionTargetting::single(25);
+const CRIMSON_CYCLONE: ActionTargetting = ActionTargetting::single(20);
+const TG_CIRCLE: ActionTargetting = ActionTargetting::target_circle(5, 25);
+
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(rename_all = "snake_case")
+)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[repr(u8)]
+#[var_consts {
+    /// Returns `true` if the action summons a demi-summon.
+    pub const demi;
+    /// Returns the base GCD recast time, or `None` if the action is not a gcd.
+    pub const gcd: ScaleTime?;
+    /// Returns the base milliseconds the action takes to cast.
+    pub const cast: ScaleTime = ScaleTime::zero();
+    /// Returns the human friendly name of the action.
+    pub const name: &'static str;
+    /// Returns the cooldown of the skill in milliseconds.
+    pub const cooldown: u32 = 0;
+    /// Returns the number of charges a skill has, or `1` if it is a single charge skill.
+    pub const cd_charges: u8 = 1;
+    /// Returns the delay in milliseconds for the damage/statuses to be applied.
+    pub const effect_delay: u32 = 0;
+    /// Returns the MP cost of the action.
+    pub const mp_cost: u16 = 0;
+    /// Returns the [`ActionCategory`] this action is part of.
+    pub const category: ActionCategory;
+
+    pub const spell for {
+        gcd = ScaleTime::spell(2500);
+        category = ActionCategory::Spell;
+    }
+    pub const ability for {
+        category = ActionCategory::Ability;
+    }
+}]
+#[allow(missing_docs)] // no reason to document the variants.
+/// An action specific to the Summoner job.
+pub enum SmnAction {
+    #[spell]
+    #[cast = ScaleTime::spell(1500)]
+    #[mp_cost = 300]
+    #[name = "Ruin III"]
+    RuinIII,
+    #[spell]
+    #[name = "Ruin IV"]
+    RuinIV,
+    #[spell]
+    #[cast = ScaleTime::spell(1500)]
+    #[mp_cost = 300]
+    #[name = "Tri-disaster"]
+    Tridisaster,
+    #[spell]
+    #[name = "Gemshine"]
+    Gemshine,
+    #[spell]
+    #[name = "Precious Brilliance"]
+    PreciousBrilliance,
+    #[spell]
+    #[demi]
+    #[cooldown = 60000]
+    #[name = "Summon Bahamut"]
+    SummonBahamut,
+    #[spell]
+    #[demi]
+    #[cooldown = 60000]
+    #[name = "Summon Phoenix"]
+    SummonPhoenix,
+    #[spell]
+    #[demi]
+    #[cooldown = 60000]
+    #[name = "Summon Solar Bahamut"]
+    SummonSolarBahamut,
+    #[spell]
+    #[name = "Summon Ifrit II"]
+    SummonIfrit,
+    #[spell]
+    #[name = "Summon Titan II"]
+    SummonTitan,
+    #[spell]
+    #[name = "Summon Garuda II"]
+    SummonGaruda,
+    #[gcd = ScaleTime::spell(3500)]
+    #[spell]
+    #[name = "Crimson Cyclone"]
+    CrimsonCyclone,
+    #[spell]
+    #[name = "Crimson Strike"]
+    CrimsonStrike,
+    #[gcd = ScaleTime::spell(3500)]
+    #[spell]
+    #[cast = ScaleTime::spell(3000)]
+    #[name = "Slipstream"]
+    Slipstream,
+    #[ability]
+    #[name = "Mountain Buster"]
+    MountainBuster,
+    #[ability]
+    #[cooldown = 20000]
+    #[name = "Enkindle"]
+    Enkindle,
+    #[ability]
+    #[cooldown = 20000]
+    #[name = "Astral Flow"]
+    AstralFlow,
+    #[ability]
+    #[cooldown = 60000]
+    #[name = "Energy Drain"]
+    EnergyDrain,
+    #[ability]
+    #[cooldown = 60000]
+    #[name = "Energy Siphon"]
+    EnergySiphon,
+    #[ability]
+    #[cooldown = 1000]
+    #[name = "Fester"]
+    Fester,
+    #[ability]
+    #[cooldown = 1000]
+    #[name = "Necrotize"]
+    Necrotize,
+    #[ability]
+    #[cooldown = 1000]
+    #[name = "Painflare"]
+    Painflare,
+    #[ability]
+    #[cooldown = 120000]
+    #[name = "Searing Light"]
+    SearingLight,
+    #[ability]
+    #[name = "Searing Flash"]
+    SearingFlash,
+    #[gcd((v) => v.gcd())]
+    #[cast((v) => v.cast())]
+    #[name((v) => v.name())]
+    #[cooldown((v) => v.cooldown())]
+    #[cd_charges((v) => v.cd_charges())]
+    #[effect_delay((v) => v.effect_delay())]
+    #[mp_cost((v) => v.mp_cost())]
+    #[category((v) => v.category())]
+    Role(CasterRoleAction),
+}
+
+impl JobAction for SmnAction {
+    fn category(&self) -> ActionCategory {
+        self.category()
+    }
+
+    fn gcd(&self) -> bool {
+        self.gcd().is_some()
+    }
+}
+
+impl From<SmnAction> for Action {
+    fn from(value: SmnAction) -> Self {
+        Action::Job(value.into())
+    }
+}
+
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+/// A demi-summon.
+pub enum Demi {
+    /// Demi-Bahamut.
+    Bahamut,
+    /// Demi-Phoenix.
+    Phoenix,
+    /// Solar Bahamut.
+    SolarBahamut,
+}
+
+impl Demi {
+    /// The order that the demi-summons are summoned in.
+    pub const CYCLE: [Self; 4] = [
+        Self::SolarBahamut,
+        Self::Bahamut,
+        Self::SolarBahamut,
+        Self::Phoenix,
+    ];
+
+    /// Returns the action that summons the demi-summon.
+    pub const fn action(&self) -> SmnAction {
+        match self {
+            Self::Bahamut => SmnAction::SummonBahamut,
+            Self::Phoenix => SmnAction::SummonPhoenix,
+            Self::SolarBahamut => SmnAction::SummonSolarBahamut,
+        }
+    }
+
+    /// Returns the potency of the attack the demi-summon
+    /// performs when the summoner casts a GCD.
+    pub const fn pet_potency(&self) -> u64 {
+        match self {
+            // wyrmwave
+            Self::Bahamut => 150,
+            // scarlet flame
+            Self::Phoenix => 150,
+            // luxwave
+            Self::SolarBahamut => 160,
+        }
+    }
+
+    /// Returns the potency of Ruin III under the demi-summon.
+    pub const fn impulse_potency(&self) -> u64 {
+        match self {
+            // astral impulse
+            Self::Bahamut => 440,
+            // fountain of fire
+            Self::Phoenix => 540,
+            // umbral impulse
+            Self::SolarBahamut => 620,
+        }
+    }
+
+    /// Returns the potency of Tri-disaster under the demi-summon.
+    pub const fn flare_potency(&self) -> u64 {
+        match self {
+            // astral flare
+            Self::Bahamut => 180,
+            // brand of purgatory
+            Self::Phoenix => 240,
+            // umbral flare
+            Self::SolarBahamut => 280,
+        }
+    }
+
+    /// Returns the potency of Enkindle under the demi-summon.
+    pub const fn enkindle_potency(&self) -> u64 {
+        match self {
+            // akh morn
+            Self::Bahamut => 1300,
+            // revelation
+            Self::Phoenix => 1300,
+            // exodus
+            Self::SolarBahamut => 1400,
+        }
+    }
+
+    /// Returns the potency of Astral Flow under the demi-summon.
+    pub const fn astral_flow_potency(&self) -> u64 {
+        match self {
+            // deathflare
+            Self::Bahamut => 500,
+            // rekindle
+            Self::Phoenix => 0,
+            // sunflare
+            Self::SolarBahamut => 800,
+        }
+    }
+}
+
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+/// A primal that can be summoned for attunement.
+pub enum Primal {
+    /// Ifrit-Egi.
+    Ifrit,
+    /// Titan-Egi.
+    Titan,
+    /// Garuda-Egi.
+    Garuda,
+}
+
+impl Primal {
+    /// Returns the action that summons the primal.
+    pub const fn action(&self) -> SmnAction {
+        match self {
+            Self::Ifrit => SmnAction::SummonIfrit,
+            Self::Titan => SmnAction::SummonTitan,
+            Self::Garuda => SmnAction::SummonGaruda,
+        }
+    }
+
+    /// Returns the number of attunement stacks granted by summoning the primal.
+    pub const fn attunement(&self) -> u8 {
+        match self {
+            Self::Ifrit => 2,
+            Self::Titan | Self::Garuda => 4,
+        }
+    }
+
+    /// Returns the GCD recast time and cast time of Gemshine
+    /// and Precious Brilliance while attuned to the primal.
+    pub const fn gemshine_timing(&self) -> (Option<ScaleTime>, ScaleTime) {
+        match self {
+            Self::Ifrit => (Some(ScaleTime::spell(3000)), ScaleTime::spell(2800)),
+            Self::Titan => (Some(ScaleTime::spell(2500)), ScaleTime::zero()),
+            Self::Garuda => (Some(ScaleTime::spell(1500)), ScaleTime::zero()),
+        }
+    }
+
+    /// Returns the potency of Gemshine while attuned to the primal.
+    pub const fn gemshine_potency(&self) -> u64 {
+        match self {
+            // ruby rite
+            Self::Ifrit => 540,
+            // topaz rite
+            Self::Titan => 340,
+            // emerald rite
+            Self::Garuda => 240,
+        }
+    }
+
+    /// Returns the potency of Precious Brilliance while attuned to the primal.
+    pub const fn catastrophe_potency(&self) -> u64 {
+        match self {
+            Self::Ifrit => 210,
+            Self::Titan => 140,
+            Self::Garuda => 100,
+        }
+    }
+}
+
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+/// The state of the Summoner job gauges and cooldowns.
+pub struct SmnState {
+    /// The active demi-summon and the time in milliseconds until it leaves.
+    pub demi: Option<(Demi, u32)>,
+    /// The number of demi-summons that have been summoned.
+    ///
+    /// This is used to determine the next demi-summon in the [cycle].
+    ///
+    /// [cycle]: Demi::CYCLE
+    pub demi_count: u8,
+    /// If Enkindle can be used on the active demi-summon.
+    pub enkindle: bool,
+    /// If Astral Flow can be used on the active demi-summon.
+    pub astral_flow: bool,
+    /// If Ifrit-Egi can be summoned.
+    pub ifrit: bool,
+    /// If Titan-Egi can be summoned.
+    pub titan: bool,
+    /// If Garuda-Egi can be summoned.
+    pub garuda: bool,
+    /// The primal attuned to and the number of attunement stacks left.
+    pub attunement: Option<(Primal, u8)>,
+    /// If Crimson Strike can be used.
+    pub crimson_strike: bool,
+    /// The Aetherflow gauge.
+    pub aetherflow: GaugeU8<2>,
+}
+
+impl SmnState {
+    /// Returns the GCD recast time and cast time of an action,
+    /// after the modifiers from attunement and demi-summons.
+    pub fn timing(&self, action: SmnAction) -> (Option<ScaleTime>, ScaleTime) {
+        use SmnAction::*;
+        match (action, self.attunement) {
+            // gemshine and precious brilliance take on the timings of the attuned primal
+            (Gemshine | PreciousBrilliance, Some((primal, _))) => primal.gemshine_timing(),
+            // ruin III and tri-disaster are instant during a demi-summon
+            (RuinIII | Tridisaster, _) if self.demi.is_some() => (action.gcd(), ScaleTime::zero()),
+            _ => (action.gcd(), action.cast()),
+        }
+    }
+
+    /// Returns the next demi-summon to be summoned.
+    pub fn next_demi(&self) -> Demi {
+        Demi::CYCLE[self.demi_count as usize % Demi::CYCLE.len()]
+    }
+
+    /// Returns `true` if the primal can be summoned.
+    pub fn arcanum(&self, primal: Primal) -> bool {
+        match primal {
+            Primal::Ifrit => self.ifrit,
+            Primal::Titan => self.titan,
+            Primal::Garuda => self.garuda,
+        }
+    }
+
+    fn arcanum_mut(&mut self, primal: Primal) -> &mut bool {
+        match primal {
+            Primal::Ifrit => &mut self.ifrit,
+            Primal::Titan => &mut self.titan,
+            Primal::Garuda => &mut self.garuda,
+        }
+    }
+
+    /// Consumes a stack of attunement, returning the attuned primal.
+    ///
+    /// Returns `None` if there is no attunement.
+    pub fn consume_attunement(&mut self) -> Option<Primal> {
+        let (primal, stacks) = self.attunement?;
+        self.attunement = (stacks > 1).then_some((primal, stacks - 1));
+        Some(primal)
+    }
+}
+
+impl JobState for SmnState {
+    fn advance(&mut self, time: u32) {
+        if let Some((_, remaining)) = &mut self.demi {
+            *remaining = remaining.saturating_sub(time);
+            if *remaining == 0 {
+                self.demi = None;
+                self.enkindle = false;
+                self.astral_flow = false;
+            }
+        }
+    }
+}
+
+job_cd_struct! {
+    SmnAction =>
+
+    #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+    #[derive(Clone, Debug, Default)]
+    /// The cooldown map for Summoner actions.
+    pub SmnCdMap
+
+    #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+    #[derive(Copy, Clone, Debug)]
+    /// The various cooldown groups a Summoner action can be part of.
+    pub SmnCdGroup
+
+    "Aethercharge"
+    aethercharge Aethercharge: SummonBahamut SummonPhoenix SummonSolarBahamut;
+    "Enkindle"
+    enkindle Enkindle: Enkindle;
+    "Astral Flow"
+    astral_flow AstralFlow: AstralFlow;
+    "Energy Drain"
+    energy_drain EnergyDrain: EnergyDrain EnergySiphon;
+    "Fester"
+    fester Fester: Fester Necrotize Painflare;
+    "Searing Light"
+    searing_light SearingLight: SearingLight;
+    "Swiftcast"
+    swiftcast Swiftcast: Role(CasterRoleAction::Swiftcast);
+    "Lucid Dreaming"
+    lucid_dreaming LucidDreaming: Role(CasterRoleAction::LucidDreaming);
+    "Addle"
+    addle Addle: Role(CasterRoleAction::Addle);
+}
+
+impl SmnAction {
+    /// Returns the alternate [cooldown group] that this action is part of.
+    ///
+    /// Returns `None` if this action does not have an alternate cooldown.
+    /// This action is used for the 1s cooldown between uses of charged actions.
+    ///
+    /// [cooldown group]: SmnCdGroup
+    pub const fn alt_cd_group(&self) -> Option<SmnCdGroup> {
+        None
+    }
+}
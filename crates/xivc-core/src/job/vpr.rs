@@ -0,0 +1,900 @@
+use core::{
+    error::Error,
+    fmt::{self, Display},
+};
+
+use macros::var_consts;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    enums::{ActionCategory, DamageInstance},
+    err,
+    job::{role::MeleeRoleAction, CastInitInfo, Job, JobAction, JobState},
+    job_cd_struct, status_effect,
+    timing::{DurationInfo, EventCascade, ScaleTime},
+    util::{pos_pot, ActionTargettingExt as _, ComboState, GaugeU8},
+    world::{
+        status::{consume_status, StatusEffect, StatusEventExt},
+        Action, ActionTargetting, ActorId, ActorRef, DamageEventExt, EventError, EventSink,
+        Positional, WorldRef,
+    },
+};
+
+/// The [`Job`] struct for Viper.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct VprJob;
+
+/// The status effect "Hunter's Instinct".
+pub static HUNTERS_INSTINCT: StatusEffect = status_effect!(
+    "Hunter's Instinct" 40000 { damage { out = 110 / 100 } }
+);
+/// The status effect "Swiftscaled".
+pub static SWIFTSCALED: StatusEffect = status_effect!(
+    "Swiftscaled" 40000 { haste { |_| 85 } }
+);
+/// The status effect "Honed Steel".
+pub static HONED_STEEL: StatusEffect = status_effect!("Honed Steel" 60000);
+/// The status effect "Honed Reavers".
+pub static HONED_REAVERS: StatusEffect = status_effect!("Honed Reavers" 60000);
+/// The status effect "Flankstung Venom".
+pub static FLANKSTUNG_VENOM: StatusEffect = status_effect!("Flankstung Venom" 60000);
+/// The status effect "Flanksbane Venom".
+pub static FLANKSBANE_VENOM: StatusEffect = status_effect!("Flanksbane Venom" 60000);
+/// The status effect "Hindstung Venom".
+pub static HINDSTUNG_VENOM: StatusEffect = status_effect!("Hindstung Venom" 60000);
+/// The status effect "Hindsbane Venom".
+pub static HINDSBANE_VENOM: StatusEffect = status_effect!("Hindsbane Venom" 60000);
+/// The status effect "Grimhunter's Venom".
+pub static GRIMHUNTERS_VENOM: StatusEffect = status_effect!("Grimhunter's Venom" 30000);
+/// The status effect "Grimskin's Venom".
+pub static GRIMSKINS_VENOM: StatusEffect = status_effect!("Grimskin's Venom" 30000);
+/// The status effect "Poised for Twinfang".
+pub static POISED_FOR_TWINFANG: StatusEffect = status_effect!("Poised for Twinfang" 60000);
+/// The status effect "Poised for Twinblood".
+pub static POISED_FOR_TWINBLOOD: StatusEffect = status_effect!("Poised for Twinblood" 60000);
+/// The status effect "Ready to Reawaken".
+pub static READY_TO_REAWAKEN: StatusEffect = status_effect!("Ready to Reawaken" 30000);
+/// The status effect "Reawakened".
+pub static REAWAKENED: StatusEffect = status_effect!("Reawakened" 30000);
+/// The status effect "Noxious Gnash".
+///
+/// This increases the damage taken from the actor that applied it,
+/// so it is accounted for in the potency of Viper actions instead
+/// of as a damage modifier.
+pub static NOXIOUS_GNASH: StatusEffect = status_effect!("Noxious Gnash" 20000 multi);
+
+impl Job for VprJob {
+    type Action = VprAction;
+    type State = VprState;
+    type CastError = VprError;
+    type Event = ();
+    type CdGroup = VprCdGroup;
+    type CdMap<T> = VprCdMap<T>;
+
+    fn check_cast<'w, W: WorldRef<'w>, E: EventSink<'w, W>>(
+        action: Self::Action,
+        state: &Self::State,
+        _: &'w W,
+        event_sink: &mut E,
+    ) -> Result<CastInitInfo<Self::CdGroup>, EventError> {
+        let this = event_sink.source();
+
+        let di = this.duration_info();
+
+        let gcd = action.gcd().map(|v| di.scale(v)).unwrap_or_default() as u16;
+        let (lock, snap) = di.get_cast(action.cast(), 600);
+
+        let cd = action
+            .cd_group()
+            .map(|v| (v, action.cooldown(), action.cd_charges()));
+
+        let alt_cd = action.alt_cd_group().map(|v| (v, 1000, 1));
+
+        use VprAction::*;
+        match action {
+            _ if !state.combos.check_for(action) => {
+                err!(VprError::Combo(action.combo_from().unwrap()))
+            }
+            DeathRattle | LastLash | FirstLegacy | SecondLegacy | ThirdLegacy | FourthLegacy
+                if state.serpents_tail != Some(action) =>
+            {
+                err!(VprError::Unavailable(action))
+            }
+            TwinfangBite if !state.twinfang => err!(VprError::Unavailable(action)),
+            TwinbloodBite if !state.twinblood => err!(VprError::Unavailable(action)),
+            UncoiledTwinfang if !this.has_own_status(POISED_FOR_TWINFANG) => {
+                err!(VprError::Unavailable(action))
+            }
+            UncoiledTwinblood if !this.has_own_status(POISED_FOR_TWINBLOOD) => {
+                err!(VprError::Unavailable(action))
+            }
+            FirstGeneration | SecondGeneration | ThirdGeneration | FourthGeneration | Ouroboros
+                if !this.has_own_status(REAWAKENED) =>
+            {
+                err!(VprError::Unavailable(action))
+            }
+            UncoiledFury if state.coil < 1 => err!(VprError::Coil(1)),
+            Reawaken if state.offering < 50 && !this.has_own_status(READY_TO_REAWAKEN) => {
+                err!(VprError::Offering(50))
+            }
+            _ => (),
+        }
+
+        Ok(CastInitInfo {
+            gcd,
+            lock,
+            snap,
+            mp: 0,
+            cd,
+            alt_cd,
+        })
+    }
+
+    fn cast_snap<'w, W: WorldRef<'w>, E: EventSink<'w, W>>(
+        action: Self::Action,
+        state: &mut Self::State,
+        world: &'w W,
+        event_sink: &mut E,
+    ) -> Result<(), EventError> {
+        let this = event_sink.source();
+        let this_id = this.id();
+
+        use VprAction::*;
+
+        let dl = action.effect_delay();
+
+        let gnash = |potency: u64, target: ActorId| {
+            if world
+                .actor(target)
+                .is_some_and(|v| v.has_status(NOXIOUS_GNASH, this_id))
+            {
+                potency * 110 / 100
+            } else {
+                potency
+            }
+        };
+
+        if !state.combos.check_for(action) {
+            err!(VprError::Combo(action.combo_from().unwrap()));
+        }
+
+        if action.category() == ActionCategory::Weaponskill {
+            // the follow-up oGCDs are only available until the next weaponskill.
+            state.serpents_tail = None;
+            state.twinfang = false;
+            state.twinblood = false;
+        }
+
+        match action {
+            SteelFangs | ReavingFangs => {
+                let t = this.target_enemy(MELEE)?.id();
+                let (honed, next) = if action == SteelFangs {
+                    (HONED_STEEL, HONED_REAVERS)
+                } else {
+                    (HONED_REAVERS, HONED_STEEL)
+                };
+                let potency = if consume_status(event_sink, honed, 0) {
+                    300
+                } else {
+                    200
+                };
+                event_sink.apply_status(next, 1, this_id, dl);
+                state.combos.main.set(MainCombo::Fangs);
+                event_sink.damage(
+                    action,
+                    DamageInstance::new(gnash(potency, t)).slashing(),
+                    t,
+                    dl,
+                );
+            }
+            HuntersSting | SwiftskinsSting => {
+                let t = this.target_enemy(MELEE)?.id();
+                if action == HuntersSting {
+                    event_sink.apply_status(HUNTERS_INSTINCT, 1, this_id, dl);
+                    state.combos.main.set(MainCombo::HuntersSting);
+                } else {
+                    event_sink.apply_status(SWIFTSCALED, 1, this_id, dl);
+                    state.combos.main.set(MainCombo::SwiftskinsSting);
+                }
+                event_sink.damage(action, DamageInstance::new(gnash(300, t)).slashing(), t, dl);
+            }
+            FlankstingStrike | FlanksbaneFang | HindstingStrike | HindsbaneFang => {
+                let t = this.target_enemy(MELEE)?.id();
+                let (positional, venom, next) = match action {
+                    FlankstingStrike => (Positional::Flank, FLANKSTUNG_VENOM, HINDSTUNG_VENOM),
+                    FlanksbaneFang => (Positional::Flank, FLANKSBANE_VENOM, HINDSBANE_VENOM),
+                    HindstingStrike => (Positional::Rear, HINDSTUNG_VENOM, FLANKSBANE_VENOM),
+                    _ => (Positional::Rear, HINDSBANE_VENOM, FLANKSTUNG_VENOM),
+                };
+                let pos = this.check_positional(positional, t);
+                let venom = if consume_status(event_sink, venom, 0) {
+                    100
+                } else {
+                    0
+                };
+                event_sink.apply_status(next, 1, this_id, dl);
+                state.combos.main.reset();
+                state.offering += 10;
+                state.serpents_tail = Some(DeathRattle);
+                event_sink.damage(
+                    action,
+                    DamageInstance::new(gnash(pos_pot(340, 400, pos) + venom, t)).slashing(),
+                    t,
+                    dl,
+                );
+            }
+            SteelMaw | ReavingMaw => {
+                let iter = this
+                    .target_enemy_aoe(CIRCLE, EventCascade::new(dl, 1))?
+                    .id();
+                let (honed, next) = if action == SteelMaw {
+                    (HONED_STEEL, HONED_REAVERS)
+                } else {
+                    (HONED_REAVERS, HONED_STEEL)
+                };
+                let potency = if consume_status(event_sink, honed, 0) {
+                    120
+                } else {
+                    100
+                };
+                event_sink.apply_status(next, 1, this_id, dl);
+                state.combos.aoe.set(AoeCombo::Maw);
+                for (t, d) in iter {
+                    event_sink.damage(
+                        action,
+                        DamageInstance::new(gnash(potency, t)).slashing(),
+                        t,
+                        d,
+                    );
+                }
+            }
+            HuntersBite | SwiftskinsBite => {
+                let iter = this
+                    .target_enemy_aoe(CIRCLE, EventCascade::new(dl, 1))?
+                    .id();
+                if action == HuntersBite {
+                    event_sink.apply_status(HUNTERS_INSTINCT, 1, this_id, dl);
+                    state.combos.aoe.set(AoeCombo::HuntersBite);
+                } else {
+                    event_sink.apply_status(SWIFTSCALED, 1, this_id, dl);
+                    state.combos.aoe.set(AoeCombo::SwiftskinsBite);
+                }
+                for (t, d) in iter {
+                    event_sink.damage(action, DamageInstance::new(gnash(130, t)).slashing(), t, d);
+                }
+            }
+            JaggedMaw | BloodiedMaw => {
+                let iter = this
+                    .target_enemy_aoe(CIRCLE, EventCascade::new(dl, 1))?
+                    .id();
+                let (venom, next) = if action == JaggedMaw {
+                    (GRIMHUNTERS_VENOM, GRIMSKINS_VENOM)
+                } else {
+                    (GRIMSKINS_VENOM, GRIMHUNTERS_VENOM)
+                };
+                let potency = if consume_status(event_sink, venom, 0) {
+                    160
+                } else {
+                    140
+                };
+                event_sink.apply_status(next, 1, this_id, dl);
+                state.combos.aoe.reset();
+                state.offering += 10;
+                state.serpents_tail = Some(LastLash);
+                for (t, d) in iter {
+                    event_sink.damage(
+                        action,
+                        DamageInstance::new(gnash(potency, t)).slashing(),
+                        t,
+                        d,
+                    );
+                }
+            }
+            Vicewinder => {
+                let t = this.target_enemy(MELEE)?.id();
+                state.coil += 1;
+                state.combos.coil.set(CoilCombo::Vicewinder);
+                event_sink.apply_or_extend_status(NOXIOUS_GNASH, 1, 2, t, dl);
+                event_sink.damage(action, DamageInstance::new(gnash(500, t)).slashing(), t, dl);
+            }
+            HuntersCoil | SwiftskinsCoil => {
+                let t = this.target_enemy(MELEE)?.id();
+                let positional = if action == HuntersCoil {
+                    event_sink.apply_status(HUNTERS_INSTINCT, 1, this_id, dl);
+                    Positional::Flank
+                } else {
+                    event_sink.apply_status(SWIFTSCALED, 1, this_id, dl);
+                    Positional::Rear
+                };
+                if state.combos.coil.check(CoilCombo::Vicewinder) {
+                    state.combos.coil.set(if action == HuntersCoil {
+                        CoilCombo::HuntersCoil
+                    } else {
+                        CoilCombo::SwiftskinsCoil
+                    });
+                } else {
+                    state.combos.coil.reset();
+                }
+                state.offering += 5;
+                state.twinfang = true;
+                state.twinblood = true;
+                let pos = this.check_positional(positional, t);
+                event_sink.damage(
+                    action,
+                    DamageInstance::new(gnash(pos_pot(570, 620, pos), t)).slashing(),
+                    t,
+                    dl,
+                );
+            }
+            TwinfangBite | TwinbloodBite => {
+                let t = this.target_enemy(MELEE)?.id();
+                if action == TwinfangBite {
+                    state.twinfang = false;
+                } else {
+                    state.twinblood = false;
+                }
+                event_sink.damage(action, DamageInstance::new(gnash(120, t)).slashing(), t, dl);
+            }
+            UncoiledFury => {
+                let iter = this
+                    .target_enemy_aoe(RANGED_AOE, EventCascade::new(dl, 1))?
+                    .id()
+                    .falloff(50);
+                if !state.coil.consume(1) {
+                    err!(VprError::Coil(1));
+                }
+                event_sink.apply_status(POISED_FOR_TWINFANG, 1, this_id, dl);
+                event_sink.apply_status(POISED_FOR_TWINBLOOD, 1, this_id, dl);
+                for (t, d, f) in iter {
+                    event_sink.damage(
+                        action,
+                        DamageInstance::new(gnash(680, t)).slashing().falloff(f),
+                        t,
+                        d,
+                    );
+                }
+            }
+            UncoiledTwinfang | UncoiledTwinblood => {
+                let iter = this
+                    .target_enemy_aoe(RANGED_AOE, EventCascade::new(dl, 1))?
+                    .id()
+                    .falloff(50);
+                let poised = if action == UncoiledTwinfang {
+                    POISED_FOR_TWINFANG
+                } else {
+                    POISED_FOR_TWINBLOOD
+                };
+                if !consume_status(event_sink, poised, 0) {
+                    err!(VprError::Unavailable(action));
+                }
+                for (t, d, f) in iter {
+                    event_sink.damage(
+                        action,
+                        DamageInstance::new(gnash(120, t)).slashing().falloff(f),
+                        t,
+                        d,
+                    );
+                }
+            }
+            WrithingSnap => {
+                let t = this.target_enemy(RANGED)?.id();
+                event_sink.damage(action, DamageInstance::new(gnash(200, t)).slashing(), t, dl);
+            }
+            DeathRattle => {
+                let t = this.target_enemy(MELEE)?.id();
+                event_sink.damage(action, DamageInstance::new(gnash(280, t)).slashing(), t, dl);
+            }
+            LastLash => {
+                for (t, d) in this
+                    .target_enemy_aoe(CIRCLE, EventCascade::new(dl, 1))?
+                    .id()
+                {
+                    event_sink.damage(action, DamageInstance::new(gnash(100, t)).slashing(), t, d);
+                }
+            }
+            SerpentsIre => {
+                state.coil += 1;
+                event_sink.apply_status(READY_TO_REAWAKEN, 1, this_id, 0);
+            }
+            Reawaken => {
+                let iter = this
+                    .target_enemy_aoe(CIRCLE, EventCascade::new(dl, 1))?
+                    .id()
+                    .falloff(40);
+                if !consume_status(event_sink, READY_TO_REAWAKEN, 0) && !state.offering.consume(50)
+                {
+                    err!(VprError::Offering(50));
+                }
+                state.anguine.set_max();
+                state.combos.generation = Some(Reawaken);
+                event_sink.apply_status(REAWAKENED, 1, this_id, 0);
+                for (t, d, f) in iter {
+                    event_sink.damage(
+                        action,
+                        DamageInstance::new(gnash(750, t)).slashing().falloff(f),
+                        t,
+                        d,
+                    );
+                }
+            }
+            FirstGeneration | SecondGeneration | ThirdGeneration | FourthGeneration | Ouroboros => {
+                let iter = this
+                    .target_enemy_aoe(TG_CIRCLE, EventCascade::new(dl, 1))?
+                    .id()
+                    .falloff(40);
+                state.anguine.consume(1);
+                state.combos.generation = if action == Ouroboros {
+                    None
+                } else {
+                    Some(action)
+                };
+                state.serpents_tail = match action {
+                    FirstGeneration => Some(FirstLegacy),
+                    SecondGeneration => Some(SecondLegacy),
+                    ThirdGeneration => Some(ThirdLegacy),
+                    FourthGeneration => Some(FourthLegacy),
+                    _ => None,
+                };
+                let potency = if action == Ouroboros {
+                    event_sink.remove_status(REAWAKENED, this_id, 0);
+                    1050
+                } else {
+                    680
+                };
+                for (t, d, f) in iter {
+                    event_sink.damage(
+                        action,
+                        DamageInstance::new(gnash(potency, t)).slashing().falloff(f),
+                        t,
+                        d,
+                    );
+                }
+            }
+            FirstLegacy | SecondLegacy | ThirdLegacy | FourthLegacy => {
+                state.serpents_tail = None;
+                for (t, d, f) in this
+                    .target_enemy_aoe(TG_CIRCLE, EventCascade::new(dl, 1))?
+                    .id()
+                    .falloff(40)
+                {
+                    event_sink.damage(
+                        action,
+                        DamageInstance::new(gnash(320, t)).slashing().falloff(f),
+                        t,
+                        d,
+                    );
+                }
+            }
+            Role(v) => v.cast_snap(action, event_sink)?,
+        }
+
+        if matches!(action, DeathRattle | LastLash) {
+            state.serpents_tail = None;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Copy, Debug)]
+/// A custom cast error for Viper actions.
+pub enum VprError {
+    /// Not executed following the specified action.
+    Combo(VprAction),
+    /// The follow-up action is not currently available.
+    Unavailable(VprAction),
+    /// Not enough Rattling Coils.
+    Coil(u8),
+    /// Not enough Serpent Offering.
+    Offering(u8),
+}
+
+impl From<VprError> for EventError {
+    fn from(value: VprError) -> Self {
+        Self::Job(value.into())
+    }
+}
+
+impl Display for VprError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Combo(v) => write!(f, "Not executed following '{}'.", v.name()),
+            Self::Unavailable(v) => write!(f, "'{}' is not available.", v.name()),
+            Self::Coil(v) => write!(f, "Not enough Rattling Coils, needed at least {}.", v),
+            Self::Offering(v) => {
+                write!(f, "Not enough Serpent Offering, needed at least {}.", v)
+            }
+        }
+    }
+}
+
+impl Error for VprError {}
+
+const MELEE: ActionTargetting = ActionTargetting::single(3);
+const RANGED: ActionTargetting = ActionTargetting::single(20);
+const CIRCLE: ActionTargetting = ActionTargetting::circle(5);
+const TG_CIRCLE: ActionTargetting = ActionTargetting::target_circle(5, 3);
+const RANGED_AOE: ActionTargetting = ActionTargetting::target_circle(5, 20);
+
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(rename_all = "snake_case")
+)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[repr(u8)]
+#[var_consts {
+    /// Returns the base GCD recast time, or `None` if the action is not a gcd.
+    pub const gcd: ScaleTime?;
+    /// Returns the base milliseconds the action takes to cast.
+    pub const cast: ScaleTime = ScaleTime::zero();
+    /// Returns the human friendly name of the action.
+    pub const name: &'static str;
+    /// Returns the cooldown of the skill in milliseconds.
+    pub const cooldown: u32 = 0;
+    /// Returns the number of charges a skill has, or `1` if it is a single charge skill.
+    pub const cd_charges: u8 = 1;
+    /// Returns the delay in milliseconds for the damage/statuses to be applied.
+    pub const effect_delay: u32 = 0;
+    /// Returns the action that must directly precede this action in a combo,
+    /// or `None` if the action can be used outside of a combo.
+    pub const combo_from: VprAction?;
+    /// Returns the [`ActionCategory`] this action is part of.
+    pub const category: ActionCategory;
+
+    pub const skill for {
+        gcd = ScaleTime::skill(2500);
+        category = ActionCategory::Weaponskill;
+    }
+    pub const twinblade for {
+        gcd = ScaleTime::skill(3000);
+        category = ActionCategory::Weaponskill;
+    }
+    pub const reawaken for {
+        gcd = ScaleTime::skill(2000);
+        category = ActionCategory::Weaponskill;
+    }
+    pub const ability for {
+        category = ActionCategory::Ability;
+    }
+}]
+#[allow(missing_docs)] // no reason to document the variants.
+/// An action specific to the Viper job.
+pub enum VprAction {
+    #[skill]
+    #[name = "Steel Fangs"]
+    SteelFangs,
+    #[skill]
+    #[name = "Reaving Fangs"]
+    ReavingFangs,
+    #[skill]
+    #[name = "Hunter's Sting"]
+    #[combo_from = VprAction::SteelFangs]
+    HuntersSting,
+    #[skill]
+    #[name = "Swiftskin's Sting"]
+    #[combo_from = VprAction::SteelFangs]
+    SwiftskinsSting,
+    #[skill]
+    #[name = "Flanksting Strike"]
+    #[combo_from = VprAction::HuntersSting]
+    FlankstingStrike,
+    #[skill]
+    #[name = "Flanksbane Fang"]
+    #[combo_from = VprAction::HuntersSting]
+    FlanksbaneFang,
+    #[skill]
+    #[name = "Hindsting Strike"]
+    #[combo_from = VprAction::SwiftskinsSting]
+    HindstingStrike,
+    #[skill]
+    #[name = "Hindsbane Fang"]
+    #[combo_from = VprAction::SwiftskinsSting]
+    HindsbaneFang,
+    #[skill]
+    #[name = "Steel Maw"]
+    SteelMaw,
+    #[skill]
+    #[name = "Reaving Maw"]
+    ReavingMaw,
+    #[skill]
+    #[name = "Hunter's Bite"]
+    #[combo_from = VprAction::SteelMaw]
+    HuntersBite,
+    #[skill]
+    #[name = "Swiftskin's Bite"]
+    #[combo_from = VprAction::SteelMaw]
+    SwiftskinsBite,
+    #[skill]
+    #[name = "Jagged Maw"]
+    #[combo_from = VprAction::HuntersBite]
+    JaggedMaw,
+    #[skill]
+    #[name = "Bloodied Maw"]
+    #[combo_from = VprAction::SwiftskinsBite]
+    BloodiedMaw,
+    #[skill]
+    #[name = "Writhing Snap"]
+    WrithingSnap,
+    #[twinblade]
+    #[cooldown = 40000]
+    #[cd_charges = 2]
+    #[name = "Vicewinder"]
+    Vicewinder,
+    #[twinblade]
+    #[name = "Hunter's Coil"]
+    #[combo_from = VprAction::Vicewinder]
+    HuntersCoil,
+    #[twinblade]
+    #[name = "Swiftskin's Coil"]
+    #[combo_from = VprAction::Vicewinder]
+    SwiftskinsCoil,
+    #[gcd = ScaleTime::skill(3500)]
+    #[skill]
+    #[name = "Uncoiled Fury"]
+    UncoiledFury,
+    #[gcd = ScaleTime::skill(2200)]
+    #[reawaken]
+    #[name = "Reawaken"]
+    Reawaken,
+    #[reawaken]
+    #[name = "First Generation"]
+    #[combo_from = VprAction::Reawaken]
+    FirstGeneration,
+    #[reawaken]
+    #[name = "Second Generation"]
+    #[combo_from = VprAction::FirstGeneration]
+    SecondGeneration,
+    #[reawaken]
+    #[name = "Third Generation"]
+    #[combo_from = VprAction::SecondGeneration]
+    ThirdGeneration,
+    #[reawaken]
+    #[name = "Fourth Generation"]
+    #[combo_from = VprAction::ThirdGeneration]
+    FourthGeneration,
+    #[gcd = ScaleTime::skill(3000)]
+    #[reawaken]
+    #[name = "Ouroboros"]
+    #[combo_from = VprAction::FourthGeneration]
+    Ouroboros,
+    #[ability]
+    #[cooldown = 1000]
+    #[name = "Death Rattle"]
+    DeathRattle,
+    #[ability]
+    #[cooldown = 1000]
+    #[name = "Last Lash"]
+    LastLash,
+    #[ability]
+    #[cooldown = 1000]
+    #[name = "Twinfang Bite"]
+    TwinfangBite,
+    #[ability]
+    #[cooldown = 1000]
+    #[name = "Twinblood Bite"]
+    TwinbloodBite,
+    #[ability]
+    #[cooldown = 1000]
+    #[name = "Uncoiled Twinfang"]
+    UncoiledTwinfang,
+    #[ability]
+    #[cooldown = 1000]
+    #[name = "Uncoiled Twinblood"]
+    UncoiledTwinblood,
+    #[ability]
+    #[cooldown = 1000]
+    #[name = "First Legacy"]
+    FirstLegacy,
+    #[ability]
+    #[cooldown = 1000]
+    #[name = "Second Legacy"]
+    SecondLegacy,
+    #[ability]
+    #[cooldown = 1000]
+    #[name = "Third Legacy"]
+    ThirdLegacy,
+    #[ability]
+    #[cooldown = 1000]
+    #[name = "Fourth Legacy"]
+    FourthLegacy,
+    #[ability]
+    #[cooldown = 120000]
+    #[name = "Serpent's Ire"]
+    SerpentsIre,
+    #[gcd((v) => v.gcd())]
+    #[cast((v) => v.cast())]
+    #[name((v) => v.name())]
+    #[cooldown((v) => v.cooldown())]
+    #[cd_charges((v) => v.cd_charges())]
+    #[effect_delay((v) => v.effect_delay())]
+    #[category((v) => v.category())]
+    Role(MeleeRoleAction),
+}
+
+impl JobAction for VprAction {
+    fn category(&self) -> ActionCategory {
+        self.category()
+    }
+
+    fn gcd(&self) -> bool {
+        self.gcd().is_some()
+    }
+}
+
+impl From<VprAction> for Action {
+    fn from(value: VprAction) -> Self {
+        Action::Job(value.into())
+    }
+}
+
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+/// The state of the Viper job gauges, cooldowns, and combos.
+pub struct VprState {
+    /// The combos for Viper.
+    pub combos: VprCombos,
+    /// The Serpent Offering gauge.
+    pub offering: GaugeU8<100>,
+    /// The Rattling Coil gauge.
+    pub coil: GaugeU8<3>,
+    /// The Anguine Tribute gauge.
+    pub anguine: GaugeU8<5>,
+    /// The action currently available through Serpent's Tail.
+    ///
+    /// This is Death Rattle, Last Lash, or one of the Legacy actions.
+    pub serpents_tail: Option<VprAction>,
+    /// If Twinfang Bite is available.
+    pub twinfang: bool,
+    /// If Twinblood Bite is available.
+    pub twinblood: bool,
+}
+
+impl JobState for VprState {
+    fn advance(&mut self, time: u32) {
+        self.combos.advance(time);
+    }
+}
+
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+/// The combos for Viper.
+pub struct VprCombos {
+    /// The main dual wield combo.
+    pub main: ComboState<MainCombo>,
+    /// The AoE dual wield combo.
+    pub aoe: ComboState<AoeCombo>,
+    /// The Vicewinder twinblade combo.
+    pub coil: ComboState<CoilCombo>,
+    /// The last Generation executed during Reawaken.
+    ///
+    /// This does not expire, as Reawaken ends when
+    /// the Anguine Tribute gauge is emptied.
+    pub generation: Option<VprAction>,
+}
+
+impl VprCombos {
+    /// Checks that the combo prerequisite is met for a certain action.
+    pub fn check_for(&self, action: VprAction) -> bool {
+        use VprAction::*;
+        match action {
+            HuntersSting | SwiftskinsSting => self.main.check(MainCombo::Fangs),
+            FlankstingStrike | FlanksbaneFang => self.main.check(MainCombo::HuntersSting),
+            HindstingStrike | HindsbaneFang => self.main.check(MainCombo::SwiftskinsSting),
+            HuntersBite | SwiftskinsBite => self.aoe.check(AoeCombo::Maw),
+            JaggedMaw => self.aoe.check(AoeCombo::HuntersBite),
+            BloodiedMaw => self.aoe.check(AoeCombo::SwiftskinsBite),
+            HuntersCoil => {
+                self.coil.check(CoilCombo::Vicewinder) || self.coil.check(CoilCombo::SwiftskinsCoil)
+            }
+            SwiftskinsCoil => {
+                self.coil.check(CoilCombo::Vicewinder) || self.coil.check(CoilCombo::HuntersCoil)
+            }
+            FirstGeneration | SecondGeneration | ThirdGeneration | FourthGeneration | Ouroboros => {
+                self.generation == action.combo_from()
+            }
+            _ => true,
+        }
+    }
+
+    /// Advances the combos forward by a certain amount of time.
+    ///
+    /// See TODO: Advance Functions for more information.
+    pub fn advance(&mut self, time: u32) {
+        self.main.advance(time);
+        self.aoe.advance(time);
+        self.coil.advance(time);
+    }
+}
+
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+/// The possible states the main combo can be in.
+pub enum MainCombo {
+    /// Combo Action: Steel Fangs or Reaving Fangs is met.
+    Fangs,
+    /// Combo Action: Hunter's Sting is met.
+    HuntersSting,
+    /// Combo Action: Swiftskin's Sting is met.
+    SwiftskinsSting,
+}
+
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+/// The possible states the AoE combo can be in.
+pub enum AoeCombo {
+    /// Combo Action: Steel Maw or Reaving Maw is met.
+    Maw,
+    /// Combo Action: Hunter's Bite is met.
+    HuntersBite,
+    /// Combo Action: Swiftskin's Bite is met.
+    SwiftskinsBite,
+}
+
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+/// The possible states the Vicewinder combo can be in.
+pub enum CoilCombo {
+    /// Combo Action: Vicewinder is met.
+    Vicewinder,
+    /// Combo Action: Hunter's Coil is met.
+    HuntersCoil,
+    /// Combo Action: Swiftskin's Coil is met.
+    SwiftskinsCoil,
+}
+
+job_cd_struct! {
+    VprAction =>
+
+    #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+    #[derive(Clone, Debug, Default)]
+    /// The cooldown map for Viper actions.
+    pub VprCdMap
+
+    #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+    #[derive(Copy, Clone, Debug)]
+    /// The various cooldown groups a Viper action can be part of.
+    pub VprCdGroup
+
+    "Vicewinder"
+    vicewinder Vicewinder: Vicewinder;
+    "Serpent's Tail"
+    serpents_tail SerpentsTail: DeathRattle LastLash FirstLegacy SecondLegacy ThirdLegacy FourthLegacy;
+    "Twinfang"
+    twinfang Twinfang: TwinfangBite UncoiledTwinfang;
+    "Twinblood"
+    twinblood Twinblood: TwinbloodBite UncoiledTwinblood;
+    "Serpent's Ire"
+    serpents_ire SerpentsIre: SerpentsIre;
+    "True North"
+    true_north TrueNorth: Role(MeleeRoleAction::TrueNorth);
+    "Feint"
+    feint Feint: Role(MeleeRoleAction::Feint);
+    "Bloodbath"
+    bloodbath Bloodbath: Role(MeleeRoleAction::Bloodbath);
+    "Second Wind"
+    second_wind SecondWind: Role(MeleeRoleAction::SecondWind);
+    "Vicewinder Charge"
+    vicewinder_chg VicewinderChg;
+    "True North Charge"
+    true_north_chg TrueNorthChg;
+}
+
+impl VprAction {
+    /// Returns the alternate [cooldown group] that this action is part of.
+    ///
+    /// Returns `None` if this action does not have an alternate cooldown.
+    /// This action is used for the 1s cooldown between uses of charged actions.
+    ///
+    /// [cooldown group]: VprCdGroup
+    pub const fn alt_cd_group(&self) -> Option<VprCdGroup> {
+        match self {
+            Self::Vicewinder => Some(VprCdGroup::VicewinderChg),
+            Self::Role(MeleeRoleAction::TrueNorth) => Some(VprCdGroup::TrueNorthChg),
+            _ => None,
+        }
+    }
+}
@@ -0,0 +1,671 @@
+use core::{
+    error::Error,
+    fmt::{self, Display},
+};
+
+use macros::var_consts;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    enums::{ActionCategory, DamageInstance},
+    err,
+    job::{role::TankRoleAction, CastInitInfo, Job, JobAction, JobState},
+    job_cd_struct, status_effect,
+    timing::{DurationInfo, EventCascade, ScaleTime},
+    util::{combo_pot, status_proc_error, ActionTargettingExt as _, ComboState, GaugeU8},
+    world::{
+        status::{
+            consume_status, consume_status_stack, StatusEffect, StatusEvent, StatusEventExt,
+            StatusEventKind,
+        },
+        Action, ActionTargetting, ActorRef, DamageEventExt, Event, EventError, EventSink, WorldRef,
+    },
+};
+
+/// The [`Job`] struct for Warrior.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct WarJob;
+
+/// The status effect "Surging Tempest".
+pub static SURGING_TEMPEST: StatusEffect = status_effect!(
+    "Surging Tempest" 30000 { damage { out = 110 / 100 } }
+);
+/// The status effect "Inner Release".
+pub static INNER_RELEASE: StatusEffect = status_effect!("Inner Release" 30000);
+/// The status effect "Nascent Chaos".
+pub static NASCENT_CHAOS: StatusEffect = status_effect!("Nascent Chaos" 30000);
+/// The status effect "Primal Rend Ready".
+pub static PRIMAL_REND_READY: StatusEffect = status_effect!("Primal Rend Ready" 30000);
+/// The status effect "Primal Ruination Ready".
+pub static PRIMAL_RUINATION_READY: StatusEffect = status_effect!("Primal Ruination Ready" 30000);
+/// The status effect "Wrathful".
+pub static WRATHFUL: StatusEffect = status_effect!("Wrathful" 30000);
+
+impl Job for WarJob {
+    type Action = WarAction;
+    type State = WarState;
+    type CastError = WarError;
+    type Event = ();
+    type CdGroup = WarCdGroup;
+    type CdMap<T> = WarCdMap<T>;
+
+    fn check_cast<'w, W: WorldRef<'w>, E: EventSink<'w, W>>(
+        action: Self::Action,
+        state: &Self::State,
+        _: &'w W,
+        event_sink: &mut E,
+    ) -> Result<CastInitInfo<Self::CdGroup>, EventError> {
+        let this = event_sink.source();
+
+        let di = this.duration_info();
+
+        let gcd = action.gcd().map(|v| di.scale(v)).unwrap_or_default() as u16;
+        let (lock, snap) = di.get_cast(action.cast(), 600);
+
+        let cd = action
+            .cd_group()
+            .map(|v| (v, action.cooldown(), action.cd_charges()));
+
+        let alt_cd = action.alt_cd_group().map(|v| (v, 1000, 1));
+
+        use WarAction::*;
+        match action {
+            FellCleave | Decimate if state.beast < 50 && !this.has_own_status(INNER_RELEASE) => {
+                err!(WarError::Beast(50))
+            }
+            InnerChaos | ChaoticCyclone if !this.has_own_status(NASCENT_CHAOS) => {
+                err!(WarError::NascentChaos)
+            }
+            InnerChaos | ChaoticCyclone if state.beast < 50 => err!(WarError::Beast(50)),
+            Infuriate if !this.in_combat() => err!(EventError::InCombat),
+            PrimalRend if !this.has_own_status(PRIMAL_REND_READY) => err!(WarError::PrimalRend),
+            PrimalRuination if !this.has_own_status(PRIMAL_RUINATION_READY) => {
+                err!(WarError::PrimalRuination)
+            }
+            PrimalWrath if !this.has_own_status(WRATHFUL) => err!(WarError::Wrathful),
+            _ => (),
+        }
+
+        Ok(CastInitInfo {
+            gcd,
+            lock,
+            snap,
+            mp: 0,
+            cd,
+            alt_cd,
+        })
+    }
+
+    fn cast_snap<'w, W: WorldRef<'w>, E: EventSink<'w, W>>(
+        action: Self::Action,
+        state: &mut Self::State,
+        _: &'w W,
+        event_sink: &mut E,
+    ) -> Result<(), EventError> {
+        let this = event_sink.source();
+        let this_id = this.id();
+
+        use WarAction::*;
+
+        let dl = action.effect_delay();
+
+        match action {
+            HeavySwing => {
+                let t = this.target_enemy(MELEE)?.id();
+                state.combos.main.set(MainCombo::HeavySwing);
+                event_sink.damage(action, DamageInstance::new(220).slashing(), t, dl);
+            }
+            Maim => {
+                let t = this.target_enemy(MELEE)?.id();
+                let combo = if state.combos.check_main_for(action) {
+                    state.combos.main.set(MainCombo::Maim);
+                    state.beast += 10;
+                    true
+                } else {
+                    state.combos.main.reset();
+                    false
+                };
+                event_sink.damage(
+                    action,
+                    DamageInstance::new(combo_pot(190, 340, combo)).slashing(),
+                    t,
+                    dl,
+                );
+            }
+            StormsPath | StormsEye => {
+                let t = this.target_enemy(MELEE)?.id();
+                let combo = state.combos.check_main_for(action);
+                state.combos.main.reset();
+                if combo {
+                    if action == StormsPath {
+                        state.beast += 20;
+                    } else {
+                        state.beast += 10;
+                        event_sink.apply_or_extend_status(SURGING_TEMPEST, 1, 2, this_id, dl);
+                    }
+                }
+                event_sink.damage(
+                    action,
+                    DamageInstance::new(combo_pot(200, 480, combo)).slashing(),
+                    t,
+                    dl,
+                );
+            }
+            Tomahawk => {
+                let t = this.target_enemy(RANGED)?.id();
+                event_sink.damage(action, DamageInstance::new(150).slashing(), t, dl);
+            }
+            Overpower => {
+                let mut hit = false;
+                for (t, d) in this.target_enemy_aoe(CONE, EventCascade::new(dl, 1))?.id() {
+                    hit = true;
+                    event_sink.damage(action, DamageInstance::new(110).slashing(), t, d);
+                }
+                if hit {
+                    state.combos.aoe.set(AoeCombo::Overpower);
+                } else {
+                    state.combos.aoe.reset();
+                }
+            }
+            MythrilTempest => {
+                let combo = state.combos.check_aoe_for(action);
+                state.combos.aoe.reset();
+                let mut hit = false;
+                for (t, d) in this
+                    .target_enemy_aoe(CIRCLE, EventCascade::new(dl, 1))?
+                    .id()
+                {
+                    hit = true;
+                    event_sink.damage(
+                        action,
+                        DamageInstance::new(combo_pot(100, 140, combo)).slashing(),
+                        t,
+                        d,
+                    );
+                }
+                if hit && combo {
+                    state.beast += 20;
+                    event_sink.apply_or_extend_status(SURGING_TEMPEST, 1, 2, this_id, dl);
+                }
+            }
+            FellCleave => {
+                let t = this.target_enemy(MELEE)?.id();
+                let ir = Self::consume_beast(state, event_sink)?;
+                let mut damage = DamageInstance::new(580).slashing();
+                if ir {
+                    damage = damage.force_crit().force_dhit();
+                }
+                event_sink.damage(action, damage, t, dl);
+                Self::reduce_infuriate(event_sink);
+            }
+            Decimate => {
+                let iter = this
+                    .target_enemy_aoe(CIRCLE, EventCascade::new(dl, 1))?
+                    .id();
+                let ir = Self::consume_beast(state, event_sink)?;
+                let mut damage = DamageInstance::new(180).slashing();
+                if ir {
+                    damage = damage.force_crit().force_dhit();
+                }
+                for (t, d) in iter {
+                    event_sink.damage(action, damage, t, d);
+                }
+                Self::reduce_infuriate(event_sink);
+            }
+            InnerChaos => {
+                let t = this.target_enemy(MELEE)?.id();
+                if !consume_status(event_sink, NASCENT_CHAOS, 0) {
+                    err!(WarError::NascentChaos);
+                }
+                if !state.beast.consume(50) {
+                    err!(WarError::Beast(50));
+                }
+                event_sink.damage(
+                    action,
+                    DamageInstance::new(660)
+                        .slashing()
+                        .force_crit()
+                        .force_dhit(),
+                    t,
+                    dl,
+                );
+                Self::reduce_infuriate(event_sink);
+            }
+            ChaoticCyclone => {
+                let iter = this
+                    .target_enemy_aoe(CIRCLE, EventCascade::new(dl, 1))?
+                    .id();
+                if !consume_status(event_sink, NASCENT_CHAOS, 0) {
+                    err!(WarError::NascentChaos);
+                }
+                if !state.beast.consume(50) {
+                    err!(WarError::Beast(50));
+                }
+                for (t, d) in iter {
+                    event_sink.damage(
+                        action,
+                        DamageInstance::new(300)
+                            .slashing()
+                            .force_crit()
+                            .force_dhit(),
+                        t,
+                        d,
+                    );
+                }
+                Self::reduce_infuriate(event_sink);
+            }
+            Infuriate => {
+                state.beast += 50;
+                event_sink.apply_status(NASCENT_CHAOS, 1, this_id, 0);
+            }
+            InnerRelease => {
+                event_sink.apply_status(INNER_RELEASE, 3, this_id, 0);
+                event_sink.apply_status(PRIMAL_REND_READY, 1, this_id, 0);
+                // inner release only extends surging tempest, it will not apply it
+                if this.has_own_status(SURGING_TEMPEST) {
+                    event_sink.event(
+                        StatusEvent {
+                            status: SURGING_TEMPEST,
+                            source: this_id,
+                            target: this_id,
+                            kind: StatusEventKind::ApplyOrExtend {
+                                duration: 10000,
+                                stacks: 1,
+                                max: SURGING_TEMPEST.duration * 2,
+                            },
+                        }
+                        .into(),
+                        0,
+                    );
+                }
+            }
+            Upheaval => {
+                let t = this.target_enemy(MELEE)?.id();
+                event_sink.damage(action, DamageInstance::new(400).slashing(), t, dl);
+            }
+            Orogeny => {
+                for (t, d) in this
+                    .target_enemy_aoe(CIRCLE, EventCascade::new(dl, 1))?
+                    .id()
+                {
+                    event_sink.damage(action, DamageInstance::new(150).slashing(), t, d);
+                }
+            }
+            Onslaught => {
+                let t = this.target_enemy(ONSLAUGHT)?.id();
+                event_sink.damage(action, DamageInstance::new(150).slashing(), t, dl);
+            }
+            PrimalRend | PrimalRuination | PrimalWrath => {
+                let (targetting, potency, falloff, ready) = match action {
+                    PrimalRend => (PRIMAL_REND, 700, 30, PRIMAL_REND_READY),
+                    PrimalRuination => (TG_CIRCLE, 740, 40, PRIMAL_RUINATION_READY),
+                    _ => (TG_CIRCLE, 700, 30, WRATHFUL),
+                };
+                let iter = this
+                    .target_enemy_aoe(targetting, EventCascade::new(dl, 1))?
+                    .id()
+                    .falloff(falloff);
+                if !consume_status(event_sink, ready, 0) {
+                    err!(match action {
+                        PrimalRend => WarError::PrimalRend,
+                        PrimalRuination => WarError::PrimalRuination,
+                        _ => WarError::Wrathful,
+                    });
+                }
+                for (t, d, f) in iter {
+                    event_sink.damage(
+                        action,
+                        DamageInstance::new(potency)
+                            .slashing()
+                            .force_crit()
+                            .force_dhit()
+                            .falloff(f),
+                        t,
+                        d,
+                    );
+                }
+                if action == PrimalRend {
+                    event_sink.apply_status(PRIMAL_RUINATION_READY, 1, this_id, 0);
+                }
+            }
+            Role(v) => v.cast_snap(action, event_sink)?,
+        }
+
+        Ok(())
+    }
+}
+
+impl WarJob {
+    // consumes a stack of inner release, or 50 beast gauge if it is not active.
+    // returns `true` if a stack of inner release was consumed.
+    fn consume_beast<'w, W: WorldRef<'w>, E: EventSink<'w, W>>(
+        state: &mut WarState,
+        event_sink: &mut E,
+    ) -> Result<bool, EventError> {
+        let this = event_sink.source();
+        match this.get_own_status(INNER_RELEASE) {
+            Some(ir) => {
+                consume_status_stack(event_sink, INNER_RELEASE, 0);
+                if ir.stack == 1 {
+                    event_sink.apply_status(WRATHFUL, 1, this.id(), 0);
+                }
+                Ok(true)
+            }
+            None if state.beast.consume(50) => Ok(false),
+            None => err!(WarError::Beast(50)),
+        }
+    }
+
+    // every beast gauge spender recovers 5s of the infuriate cooldown.
+    fn reduce_infuriate<'w, W: WorldRef<'w>, E: EventSink<'w, W>>(event_sink: &mut E) {
+        let this_id = event_sink.source().id();
+        event_sink.event(Event::AdvCd(WarCdGroup::Infuriate.into(), 5000, this_id), 0);
+    }
+}
+
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Copy, Debug)]
+/// A custom cast error for Warrior actions.
+pub enum WarError {
+    /// Not enough Beast gauge.
+    Beast(u8),
+    /// Not under the effect of Nascent Chaos.
+    NascentChaos,
+    /// Not under the effect of Primal Rend Ready.
+    PrimalRend,
+    /// Not under the effect of Primal Ruination Ready.
+    PrimalRuination,
+    /// Not under the effect of Wrathful.
+    Wrathful,
+}
+
+impl From<WarError> for EventError {
+    fn from(value: WarError) -> Self {
+        Self::Job(value.into())
+    }
+}
+
+impl Display for WarError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Beast(v) => write!(f, "Not enough Beast Gauge, needed at least {}.", v),
+            Self::NascentChaos => status_proc_error(f, NASCENT_CHAOS),
+            Self::PrimalRend => status_proc_error(f, PRIMAL_REND_READY),
+            Self::PrimalRuination => status_proc_error(f, PRIMAL_RUINATION_READY),
+            Self::Wrathful => status_proc_error(f, WRATHFUL),
+        }
+    }
+}
+
+impl Error for WarError {}
+
+const MELEE: ActionTargetting = ActionTargetting::single(3);
+const RANGED: ActionTargetting = ActionTargetting::single(20);
+const ONSLAUGHT: ActionTargetting = ActionTargetting::single(20);
+const CIRCLE: ActionTargetting = ActionTargetting::circle(5);
+const CONE: ActionTargetting = ActionTargetting::cone(8, 120);
+const TG_CIRCLE: ActionTargetting = ActionTargetting::target_circle(5, 3);
+const PRIMAL_REND: ActionTargetting = ActionTargetting::target_circle(5, 20);
+
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(rename_all = "snake_case")
+)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[repr(u8)]
+#[var_consts {
+    /// Returns the base GCD recast time, or `None` if the action is not a gcd.
+    pub const gcd: ScaleTime?;
+    /// Returns the base milliseconds the action takes to cast.
+    pub const cast: ScaleTime = ScaleTime::zero();
+    /// Returns the human friendly name of the action.
+    pub const name: &'static str;
+    /// Returns the cooldown of the skill in milliseconds.
+    pub const cooldown: u32 = 0;
+    /// Returns the number of charges a skill has, or `1` if it is a single charge skill.
+    pub const cd_charges: u8 = 1;
+    /// Returns the delay in milliseconds for the damage/statuses to be applied.
+    pub const effect_delay: u32 = 0;
+    /// Returns the [`ActionCategory`] this action is part of.
+    pub const category: ActionCategory;
+
+    pub const skill for {
+        gcd = ScaleTime::skill(2500);
+        category = ActionCategory::Weaponskill;
+    }
+    pub const ability for {
+        category = ActionCategory::Ability;
+    }
+}]
+#[allow(missing_docs)] // no reason to document the variants.
+/// An action specific to the Warrior job.
+pub enum WarAction {
+    #[skill]
+    #[name = "Heavy Swing"]
+    HeavySwing,
+    #[skill]
+    #[name = "Maim"]
+    Maim,
+    #[skill]
+    #[name = "Storm's Path"]
+    StormsPath,
+    #[skill]
+    #[name = "Storm's Eye"]
+    StormsEye,
+    #[skill]
+    #[name = "Tomahawk"]
+    Tomahawk,
+    #[skill]
+    #[name = "Overpower"]
+    Overpower,
+    #[skill]
+    #[name = "Mythril Tempest"]
+    MythrilTempest,
+    #[skill]
+    #[name = "Fell Cleave"]
+    FellCleave,
+    #[skill]
+    #[name = "Decimate"]
+    Decimate,
+    #[skill]
+    #[name = "Inner Chaos"]
+    InnerChaos,
+    #[skill]
+    #[name = "Chaotic Cyclone"]
+    ChaoticCyclone,
+    #[ability]
+    #[cooldown = 60000]
+    #[cd_charges = 2]
+    #[name = "Infuriate"]
+    Infuriate,
+    #[ability]
+    #[cooldown = 60000]
+    #[name = "Inner Release"]
+    InnerRelease,
+    #[ability]
+    #[cooldown = 30000]
+    #[name = "Upheaval"]
+    Upheaval,
+    #[ability]
+    #[cooldown = 30000]
+    #[name = "Orogeny"]
+    Orogeny,
+    #[ability]
+    #[cooldown = 30000]
+    #[cd_charges = 3]
+    #[name = "Onslaught"]
+    Onslaught,
+    #[skill]
+    #[name = "Primal Rend"]
+    PrimalRend,
+    #[skill]
+    #[name = "Primal Ruination"]
+    PrimalRuination,
+    #[ability]
+    #[cooldown = 1000]
+    #[name = "Primal Wrath"]
+    PrimalWrath,
+    #[gcd((v) => v.gcd())]
+    #[cast((v) => v.cast())]
+    #[name((v) => v.name())]
+    #[cooldown((v) => v.cooldown())]
+    #[cd_charges((v) => v.cd_charges())]
+    #[effect_delay((v) => v.effect_delay())]
+    #[category((v) => v.category())]
+    Role(TankRoleAction),
+}
+
+impl JobAction for WarAction {
+    fn category(&self) -> ActionCategory {
+        self.category()
+    }
+
+    fn gcd(&self) -> bool {
+        self.gcd().is_some()
+    }
+}
+
+impl From<WarAction> for Action {
+    fn from(value: WarAction) -> Self {
+        Action::Job(value.into())
+    }
+}
+
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+/// The state of the Warrior job gauges, cooldowns, and combos.
+pub struct WarState {
+    /// The combos for Warrior.
+    pub combos: WarCombos,
+    /// The Beast gauge.
+    pub beast: GaugeU8<100>,
+}
+
+impl JobState for WarState {
+    fn advance(&mut self, time: u32) {
+        self.combos.advance(time);
+    }
+}
+
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+/// The combos for Warrior.
+pub struct WarCombos {
+    /// The main combo.
+    ///
+    /// Includes the Storm's Path and Storm's Eye combos.
+    pub main: ComboState<MainCombo>,
+    /// The AoE combo.
+    ///
+    /// Includes the Mythril Tempest combo.
+    pub aoe: ComboState<AoeCombo>,
+}
+
+impl WarCombos {
+    /// Checks that the main combo prerequisite is met for a certain action.
+    pub fn check_main_for(&self, action: WarAction) -> bool {
+        let c = match action {
+            WarAction::Maim => MainCombo::HeavySwing,
+            WarAction::StormsPath | WarAction::StormsEye => MainCombo::Maim,
+            _ => return true,
+        };
+        self.main.check(c)
+    }
+
+    /// Checks that the AoE combo prerequisite is met for a certain action.
+    pub fn check_aoe_for(&self, action: WarAction) -> bool {
+        let c = match action {
+            WarAction::MythrilTempest => AoeCombo::Overpower,
+            _ => return true,
+        };
+        self.aoe.check(c)
+    }
+
+    /// Advances the combos forward by a certain amount of time.
+    ///
+    /// See TODO: Advance Functions for more information.
+    pub fn advance(&mut self, time: u32) {
+        self.main.advance(time);
+        self.aoe.advance(time);
+    }
+}
+
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+/// The possible states the main combo can be in.
+pub enum MainCombo {
+    /// Combo Action: Heavy Swing is met.
+    HeavySwing,
+    /// Combo Action: Maim is met.
+    Maim,
+}
+
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+/// The possible states the AoE combo can be in.
+pub enum AoeCombo {
+    /// Combo Action: Overpower is met.
+    Overpower,
+}
+
+job_cd_struct! {
+    WarAction =>
+
+    #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+    #[derive(Clone, Debug, Default)]
+    /// The cooldown map for Warrior actions.
+    pub WarCdMap
+
+    #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+    #[derive(Copy, Clone, Debug)]
+    /// The various cooldown groups a Warrior action can be part of.
+    pub WarCdGroup
+
+    "Infuriate"
+    infuriate Infuriate: Infuriate;
+    "Inner Release"
+    inner_release InnerRelease: InnerRelease;
+    "Upheaval and Orogeny"
+    upheaval Upheaval: Upheaval Orogeny;
+    "Onslaught"
+    onslaught Onslaught: Onslaught;
+    "Primal Wrath"
+    primal_wrath PrimalWrath: PrimalWrath;
+    "Rampart"
+    rampart Rampart: Role(TankRoleAction::Rampart);
+    "Reprisal"
+    reprisal Reprisal: Role(TankRoleAction::Reprisal);
+    "Arm's Length"
+    arms_length ArmsLength: Role(TankRoleAction::ArmsLength);
+    "Provoke"
+    provoke Provoke: Role(TankRoleAction::Provoke);
+    "Shirk"
+    shirk Shirk: Role(TankRoleAction::Shirk);
+    "Interject"
+    interject Interject: Role(TankRoleAction::Interject);
+    "Low Blow"
+    low_blow LowBlow: Role(TankRoleAction::LowBlow);
+    "Infuriate Charge"
+    infuriate_chg InfuriateChg;
+    "Onslaught Charge"
+    onslaught_chg OnslaughtChg;
+}
+
+impl WarAction {
+    /// Returns the alternate [cooldown group] that this action is part of.
+    ///
+    /// Returns `None` if this action does not have an alternate cooldown.
+    /// This action is used for the 1s cooldown between uses of charged actions.
+    ///
+    /// [cooldown group]: WarCdGroup
+    pub const fn alt_cd_group(&self) -> Option<WarCdGroup> {
+        match self {
+            Self::Infuriate => Some(WarCdGroup::InfuriateChg),
+            Self::Onslaught => Some(WarCdGroup::OnslaughtChg),
+            _ => None,
+        }
+    }
+}
@@ -0,0 +1,442 @@
+use core::{
+    error::Error,
+    fmt::{self, Display},
+};
+
+use macros::var_consts;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    enums::{ActionCategory, DamageInstance},
+    err,
+    job::{
+        role::{HealerRoleAction, SWIFTCAST},
+        CastInitInfo, Job, JobAction, JobState,
+    },
+    job_cd_struct,
+    math::SpeedStat,
+    status_effect,
+    timing::{DurationInfo, EventCascade, ScaleTime},
+    util::{status_proc_error, ActionTargettingExt as _, GaugeU8},
+    world::{
+        status::{consume_status, consume_status_stack, StatusEffect, StatusEventExt},
+        Action, ActionTargetting, ActorRef, DamageEventExt, Event, EventError, EventSink,
+        HealEventExt, WorldRef,
+    },
+};
+
+/// The [`Job`] struct for White Mage.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct WhmJob;
+
+/// The damage over time status "Dia".
+pub static DIA: StatusEffect = status_effect!("Dia" 30000 multi);
+/// The status effect "Presence of Mind".
+pub static PRESENCE_OF_MIND: StatusEffect = status_effect!(
+    "Presence of Mind" 15000 { haste { |_| 80 } }
+);
+/// The status effect "Sacred Sight".
+pub static SACRED_SIGHT: StatusEffect = status_effect!("Sacred Sight" 30000);
+
+/// The time in milliseconds it takes for a Lily to bloom.
+const LILY_TIME: u32 = 20000;
+
+impl Job for WhmJob {
+    type Action = WhmAction;
+    type State = WhmState;
+    type CastError = WhmError;
+    type Event = ();
+    type CdGroup = WhmCdGroup;
+    type CdMap<T> = WhmCdMap<T>;
+
+    fn check_cast<'w, W: WorldRef<'w>, E: EventSink<'w, W>>(
+        action: Self::Action,
+        state: &Self::State,
+        _: &'w W,
+        event_sink: &mut E,
+    ) -> Result<CastInitInfo<Self::CdGroup>, EventError> {
+        let this = event_sink.source();
+
+        let di = this.duration_info();
+
+        use WhmAction::*;
+
+        let cast = if this.has_own_status(SWIFTCAST) {
+            ScaleTime::zero()
+        } else {
+            action.cast()
+        };
+
+        let gcd = action.gcd().map(|v| di.scale(v)).unwrap_or_default() as u16;
+        let (lock, snap) = di.get_cast(cast, 600);
+
+        let cd = action
+            .cd_group()
+            .map(|v| (v, action.cooldown(), action.cd_charges()));
+
+        let alt_cd = action.alt_cd_group().map(|v| (v, 1000, 1));
+
+        match action {
+            AfflatusSolace | AfflatusRapture if state.lily == 0 => err!(WhmError::Lily),
+            AfflatusMisery if state.blood_lily < 3 => err!(WhmError::BloodLily),
+            GlareIV if !this.has_own_status(SACRED_SIGHT) => err!(WhmError::SacredSight),
+            _ => (),
+        }
+
+        Ok(CastInitInfo {
+            gcd,
+            lock,
+            snap,
+            mp: action.mp_cost(),
+            cd,
+            alt_cd,
+        })
+    }
+
+    fn cast_snap<'w, W: WorldRef<'w>, E: EventSink<'w, W>>(
+        action: Self::Action,
+        state: &mut Self::State,
+        _: &'w W,
+        event_sink: &mut E,
+    ) -> Result<(), EventError> {
+        let this = event_sink.source();
+        let this_id = this.id();
+
+        use WhmAction::*;
+
+        let dl = action.effect_delay();
+
+        // swiftcast is only used up by spells with a cast time.
+        if action.cast().duration() > 0 {
+            consume_status(event_sink, SWIFTCAST, 0);
+        }
+
+        let dmg = |potency| DamageInstance::new(potency).magical();
+
+        // single target heals will heal the caster if
+        // the current target is not a party member.
+        let heal_target = this.target_party(SINGLE_HEAL).map_or(this_id, |v| v.id());
+
+        match action {
+            GlareIII => {
+                let t = this.target_enemy(SPELL)?.id();
+                event_sink.damage(action, dmg(310), t, dl);
+            }
+            Dia => {
+                let t = this.target_enemy(SPELL)?.id();
+                event_sink.damage(action, dmg(85), t, dl);
+                event_sink.apply_dot(DIA, dmg(85), SpeedStat::SpellSpeed, 1, t, dl);
+            }
+            GlareIV | AfflatusMisery => {
+                let (potency, falloff) = if action == GlareIV {
+                    if !consume_status_stack(event_sink, SACRED_SIGHT, 0) {
+                        err!(WhmError::SacredSight);
+                    }
+                    (640, 60)
+                } else {
+                    if !state.blood_lily.consume(3) {
+                        err!(WhmError::BloodLily);
+                    }
+                    (1320, 50)
+                };
+                for (t, d, f) in this
+                    .target_enemy_aoe(TG_CIRCLE, EventCascade::new(dl, 1))?
+                    .id()
+                    .falloff(falloff)
+                {
+                    event_sink.damage(action, dmg(potency).falloff(f), t, d);
+                }
+            }
+            HolyIII => {
+                for (t, d) in this
+                    .target_enemy_aoe(ActionTargetting::circle(8), EventCascade::new(dl, 1))?
+                    .id()
+                {
+                    event_sink.damage(action, dmg(150), t, d);
+                }
+            }
+            CureII | AfflatusSolace | Tetragrammaton => {
+                let potency = match action {
+                    CureII => 800,
+                    AfflatusSolace => {
+                        state.consume_lily()?;
+                        800
+                    }
+                    _ => 700,
+                };
+                event_sink.heal(action, potency, heal_target, dl);
+            }
+            Benediction => event_sink.full_heal(action, heal_target, dl),
+            Medica | AfflatusRapture => {
+                let (potency, radius) = if action == Medica {
+                    (400, 15)
+                } else {
+                    state.consume_lily()?;
+                    (400, 20)
+                };
+                for (t, d) in this
+                    .target_party_aoe(ActionTargetting::circle(radius), EventCascade::new(dl, 1))?
+                    .id()
+                {
+                    event_sink.heal(action, potency, t, d);
+                }
+            }
+            Assize => {
+                for (t, d) in this
+                    .target_enemy_aoe(ActionTargetting::circle(15), EventCascade::new(dl, 1))?
+                    .id()
+                {
+                    event_sink.damage(action, dmg(400), t, d);
+                }
+                for (t, d) in this
+                    .target_party_aoe(ActionTargetting::circle(15), EventCascade::new(dl, 1))?
+                    .id()
+                {
+                    event_sink.heal(action, 400, t, d);
+                }
+                event_sink.event(Event::AddMp(500, this_id), dl);
+            }
+            PresenceOfMind => {
+                event_sink.apply_status(PRESENCE_OF_MIND, 1, this_id, 0);
+                event_sink.apply_status(SACRED_SIGHT, 3, this_id, 0);
+            }
+            Role(v) => v.cast_snap(action, event_sink)?,
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Copy, Debug)]
+/// A custom cast error for White Mage actions.
+pub enum WhmError {
+    /// Not enough Lilies.
+    Lily,
+    /// Not enough Blood Lilies.
+    BloodLily,
+    /// Not under the effect of Sacred Sight.
+    SacredSight,
+}
+
+impl From<WhmError> for EventError {
+    fn from(value: WhmError) -> Self {
+        Self::Job(value.into())
+    }
+}
+
+impl Display for WhmError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Lily => write!(f, "Not enough Lilies, needed 1."),
+            Self::BloodLily => write!(f, "Not enough Blood Lilies, needed 3."),
+            Self::SacredSight => status_proc_error(f, SACRED_SIGHT),
+        }
+    }
+}
+
+impl Error for WhmError {}
+
+const SPELL: ActionTargetting = ActionTargetting::single(25);
+const SINGLE_HEAL: ActionTargetting = ActionTargetting::single(30);
+const TG_CIRCLE: ActionTargetting = ActionTargetting::target_circle(5, 25);
+
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(rename_all = "snake_case")
+)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[repr(u8)]
+#[var_consts {
+    /// Returns the base GCD recast time, or `None` if the action is not a gcd.
+    pub const gcd: ScaleTime?;
+    /// Returns the base milliseconds the action takes to cast.
+    pub const cast: ScaleTime = ScaleTime::zero();
+    /// Returns the human friendly name of the action.
+    pub const name: &'static str;
+    /// Returns the cooldown of the skill in milliseconds.
+    pub const cooldown: u32 = 0;
+    /// Returns the number of charges a skill has, or `1` if it is a single charge skill.
+    pub const cd_charges: u8 = 1;
+    /// Returns the delay in milliseconds for the damage/statuses to be applied.
+    pub const effect_delay: u32 = 0;
+    /// Returns the MP cost of the action.
+    pub const mp_cost: u16 = 0;
+    /// Returns the [`ActionCategory`] this action is part of.
+    pub const category: ActionCategory;
+
+    pub const spell for {
+        gcd = ScaleTime::spell(2500);
+        category = ActionCategory::Spell;
+    }
+    pub const ability for {
+        category = ActionCategory::Ability;
+    }
+}]
+#[allow(missing_docs)] // no reason to document the variants.
+/// An action specific to the White Mage job.
+pub enum WhmAction {
+    #[spell]
+    #[cast = ScaleTime::spell(1500)]
+    #[mp_cost = 400]
+    #[name = "Glare III"]
+    GlareIII,
+    #[spell]
+    #[mp_cost = 400]
+    #[name = "Glare IV"]
+    GlareIV,
+    #[spell]
+    #[mp_cost = 400]
+    #[name = "Dia"]
+    Dia,
+    #[spell]
+    #[cast = ScaleTime::spell(2500)]
+    #[mp_cost = 400]
+    #[name = "Holy III"]
+    HolyIII,
+    #[spell]
+    #[name = "Afflatus Misery"]
+    AfflatusMisery,
+    #[spell]
+    #[cast = ScaleTime::spell(2000)]
+    #[mp_cost = 1000]
+    #[name = "Cure II"]
+    CureII,
+    #[spell]
+    #[cast = ScaleTime::spell(2000)]
+    #[mp_cost = 900]
+    #[name = "Medica"]
+    Medica,
+    #[spell]
+    #[name = "Afflatus Solace"]
+    AfflatusSolace,
+    #[spell]
+    #[name = "Afflatus Rapture"]
+    AfflatusRapture,
+    #[ability]
+    #[cooldown = 60000]
+    #[cd_charges = 2]
+    #[name = "Tetragrammaton"]
+    Tetragrammaton,
+    #[ability]
+    #[cooldown = 180000]
+    #[name = "Benediction"]
+    Benediction,
+    #[ability]
+    #[cooldown = 40000]
+    #[name = "Assize"]
+    Assize,
+    #[ability]
+    #[cooldown = 120000]
+    #[name = "Presence of Mind"]
+    PresenceOfMind,
+    #[gcd((v) => v.gcd())]
+    #[cast((v) => v.cast())]
+    #[name((v) => v.name())]
+    #[cooldown((v) => v.cooldown())]
+    #[cd_charges((v) => v.cd_charges())]
+    #[effect_delay((v) => v.effect_delay())]
+    #[mp_cost((v) => v.mp_cost())]
+    #[category((v) => v.category())]
+    Role(HealerRoleAction),
+}
+
+impl JobAction for WhmAction {
+    fn category(&self) -> ActionCategory {
+        self.category()
+    }
+
+    fn gcd(&self) -> bool {
+        self.gcd().is_some()
+    }
+}
+
+impl From<WhmAction> for Action {
+    fn from(value: WhmAction) -> Self {
+        Action::Job(value.into())
+    }
+}
+
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+/// The state of the White Mage job gauges and cooldowns.
+pub struct WhmState {
+    /// The Lily gauge.
+    pub lily: GaugeU8<3>,
+    /// The time in milliseconds since the last Lily bloomed.
+    pub lily_timer: u32,
+    /// The Blood Lily gauge.
+    pub blood_lily: GaugeU8<3>,
+}
+
+impl WhmState {
+    /// Consumes a Lily, causing the Blood Lily to grow.
+    pub fn consume_lily(&mut self) -> Result<(), EventError> {
+        if !self.lily.consume(1) {
+            err!(WhmError::Lily);
+        }
+        self.blood_lily += 1;
+        Ok(())
+    }
+}
+
+impl JobState for WhmState {
+    fn advance(&mut self, time: u32) {
+        self.lily_timer += time;
+        while self.lily_timer >= LILY_TIME {
+            self.lily_timer -= LILY_TIME;
+            self.lily += 1;
+        }
+    }
+}
+
+job_cd_struct! {
+    WhmAction =>
+
+    #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+    #[derive(Clone, Debug, Default)]
+    /// The cooldown map for White Mage actions.
+    pub WhmCdMap
+
+    #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+    #[derive(Copy, Clone, Debug)]
+    /// The various cooldown groups a White Mage action can be part of.
+    pub WhmCdGroup
+
+    "Tetragrammaton"
+    tetragrammaton Tetragrammaton: Tetragrammaton;
+    "Benediction"
+    benediction Benediction: Benediction;
+    "Assize"
+    assize Assize: Assize;
+    "Presence of Mind"
+    presence_of_mind PresenceOfMind: PresenceOfMind;
+    "Swiftcast"
+    swiftcast Swiftcast: Role(HealerRoleAction::Swiftcast);
+    "Lucid Dreaming"
+    lucid_dreaming LucidDreaming: Role(HealerRoleAction::LucidDreaming);
+    "Surecast"
+    surecast Surecast: Role(HealerRoleAction::Surecast);
+    "Rescue"
+    rescue Rescue: Role(HealerRoleAction::Rescue);
+    "Tetragrammaton Charge"
+    tetragrammaton_chg TetragrammatonChg;
+}
+
+impl WhmAction {
+    /// Returns the alternate [cooldown group] that this action is part of.
+    ///
+    /// Returns `None` if this action does not have an alternate cooldown.
+    /// This action is used for the 1s cooldown between uses of charged actions.
+    ///
+    /// [cooldown group]: WhmCdGroup
+    pub const fn alt_cd_group(&self) -> Option<WhmCdGroup> {
+        match self {
+            Self::Tetragrammaton => Some(WhmCdGroup::TetragrammatonChg),
+            _ => None,
+        }
+    }
+}
@@ -13,6 +13,7 @@
 extern crate alloc;
 
 pub mod enums;
+pub mod item;
 pub mod job;
 pub mod math;
 pub mod timing;
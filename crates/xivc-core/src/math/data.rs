@@ -62,16 +62,30 @@ pub enum ClanField {
     MND,
 }
 
+/// Returns the healing magic potency modifier at a specific level.
+///
+/// Currently, only levels `80` and `100` are supported.
+pub const fn heal_mod(level: u8) -> u64 {
+    match level {
+        // the level cap of limited jobs.
+        // from `floor(100 * (HMP - 340) / 264) + 100`, scaled to the level main stat.
+        80 => 129,
+        100 => 164,
+        _ => panic!("only lvl 80 and 100 are supported currently."),
+    }
+}
+
 // Omega cursed function
 /// Returns the attack modifier for a job at a specific level.
 ///
-/// Currently, only level `90` is supported.
+/// Currently, only levels `80` and `100` are supported.
 pub const fn atk_mod(job: Job, level: u8) -> u64 {
-    const NM_MOD: u64 = 237;
-    const TK_MOD: u64 = 190;
-    if level != 100 {
-        panic!("only lvl 100 is supported currently.");
-    }
+    let (nm_mod, tk_mod) = match level {
+        // the level cap of limited jobs
+        80 => (165, 115),
+        100 => (237, 190),
+        _ => panic!("only lvl 80 and 100 are supported currently."),
+    };
     // for the future
     // let out = match level {
     //     0..=50 => 75,
@@ -80,9 +94,9 @@ pub const fn atk_mod(job: Job, level: u8) -> u64 {
     //     _ => (level as u64 - 80) * 3 + 165,
     // };
     if job.tank() {
-        TK_MOD
+        tk_mod
     } else {
-        NM_MOD
+        nm_mod
     }
 }
 
@@ -110,9 +124,14 @@ pub const fn job(job: Job, field: JobField) -> u64 {
 }
 
 /// Returns the entry for the field of a leve in the level datasheet.
+///
+/// Currently, only levels `80` and `100` are supported,
+/// and the [`HP`] modifier is only supported for level `100`.
+///
+/// [`HP`]: LevelField::HP
 pub const fn level(level: u8, field: LevelField) -> u64 {
-    if level != 100 {
-        panic!("only lvl 100 is supported currently.");
+    if level != 80 && level != 100 {
+        panic!("only lvl 80 and 100 are supported currently.");
     }
     // just forcing level 90 for now, not like anyone actually gives a shit about lower levels
     // fuck you ucob speedrunners i guess
@@ -125,6 +144,8 @@ pub const fn level(level: u8, field: LevelField) -> u64 {
         LevelField::MAIN => main,
         LevelField::SUB => sub,
         LevelField::DIV => div,
+        // a value of `0` means the modifier is not known for the level
+        LevelField::HP if hp == 0 => panic!("the HP modifier is not known for this level."),
         LevelField::HP => hp,
     }
 }
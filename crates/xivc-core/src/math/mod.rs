@@ -91,6 +91,120 @@ impl PlayerStats {
     }
 }
 
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+/// A bonus to a stat, as a percentage of the stat up to a maximum value.
+pub struct StatBonus {
+    /// The percentage of the stat to add.
+    pub percent: u16,
+    /// The maximum value that can be added to the stat.
+    pub cap: u16,
+}
+
+impl StatBonus {
+    /// A bonus that does not change the stat.
+    pub const NONE: Self = Self::new(0, 0);
+
+    /// Creates a new bonus of `percent` of the stat, up to `cap`.
+    pub const fn new(percent: u16, cap: u16) -> Self {
+        Self { percent, cap }
+    }
+
+    /// Returns the value of `stat` after the bonus is added.
+    ///
+    /// # Examples
+    /// ```
+    /// # use xivc_core::math::StatBonus;
+    /// let bonus = StatBonus::new(10, 100);
+    ///
+    /// // 10% of 500 is below the cap.
+    /// assert_eq!(bonus.apply(500), 550);
+    /// // 10% of 3000 is above the cap.
+    /// assert_eq!(bonus.apply(3000), 3100);
+    /// // The stat will never overflow.
+    /// assert_eq!(StatBonus::new(1000, 1000).apply(u16::MAX), u16::MAX);
+    /// ```
+    pub const fn apply(&self, stat: u16) -> u16 {
+        let bonus = stat as u32 * self.percent as u32 / 100;
+        let cap = self.cap as u32;
+        // the bonus is at most `cap`, so it always fits in a `u16`
+        stat.saturating_add(if bonus > cap { cap } else { bonus } as u16)
+    }
+}
+
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+/// The stat bonuses given by a food buff.
+///
+/// Food lasts for the whole fight, so rather than being a status effect,
+/// it is applied directly to the [`PlayerStats`] of a player.
+/// In the reference world, this is done with `Actor::food`.
+///
+/// # Examples
+/// ```
+/// # use xivc_core::math::{Food, PlayerStats, StatBonus};
+/// let food = Food {
+///     vit: StatBonus::new(10, 186),
+///     crt: StatBonus::new(10, 112),
+///     det: StatBonus::new(10, 71),
+///     ..Food::NONE
+/// };
+///
+/// let stats = PlayerStats { crt: 2800, det: 600, ..PlayerStats::default(100) };
+/// let fed = food.apply(stats);
+///
+/// assert_eq!(fed.crt, 2912);
+/// assert_eq!(fed.det, 660);
+/// assert_eq!(fed.dh, stats.dh);
+/// ```
+pub struct Food {
+    /// The bonus to Vitality.
+    pub vit: StatBonus,
+    /// The bonus to Determination.
+    pub det: StatBonus,
+    /// The bonus to Critical Hit.
+    pub crt: StatBonus,
+    /// The bonus to Direct Hit.
+    pub dh: StatBonus,
+    /// The bonus to Skill Speed.
+    pub sks: StatBonus,
+    /// The bonus to Spell Speed.
+    pub sps: StatBonus,
+    /// The bonus to Tenacity.
+    pub ten: StatBonus,
+    /// The bonus to Piety.
+    pub pie: StatBonus,
+}
+
+impl Food {
+    /// A food that does not give any bonuses.
+    pub const NONE: Self = Self {
+        vit: StatBonus::NONE,
+        det: StatBonus::NONE,
+        crt: StatBonus::NONE,
+        dh: StatBonus::NONE,
+        sks: StatBonus::NONE,
+        sps: StatBonus::NONE,
+        ten: StatBonus::NONE,
+        pie: StatBonus::NONE,
+    };
+
+    /// Returns the player stats after the bonuses of the food are added.
+    pub const fn apply(&self, stats: PlayerStats) -> PlayerStats {
+        PlayerStats {
+            vit: self.vit.apply(stats.vit),
+            det: self.det.apply(stats.det),
+            crt: self.crt.apply(stats.crt),
+            dh: self.dh.apply(stats.dh),
+            sks: self.sks.apply(stats.sks),
+            sps: self.sps.apply(stats.sps),
+            ten: self.ten.apply(stats.ten),
+            pie: self.pie.apply(stats.pie),
+            ..stats
+        }
+    }
+}
+
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
 /// Information about the player that is not tied to gear
@@ -229,7 +343,14 @@ impl XivMath {
     const PIE_MOD: u64 = 150;
 
     /// Creates a new `XivMath` instance based on the player's stats.
-    pub const fn new(stats: PlayerStats, weapon: WeaponInfo, player: PlayerInfo) -> Self {
+    ///
+    /// The level of the player is capped to the [maximum level] of their job.
+    ///
+    /// [maximum level]: Job::max_level
+    pub const fn new(stats: PlayerStats, weapon: WeaponInfo, mut player: PlayerInfo) -> Self {
+        if player.lvl > player.job.max_level() {
+            player.lvl = player.job.max_level();
+        }
         XivMath {
             stats,
             weapon,
@@ -291,6 +412,11 @@ impl XivMath {
         self.info.job.attack_stat()
     }
 
+    /// Returns the stat used to heal for the player's job.
+    pub fn job_heal_stat(&self) -> ActionStat {
+        self.info.job.heal_stat()
+    }
+
     /// The crit multiplied based on the handling.  
     /// Output is scaled by `10000000` to allow for greater accuracy for [`HitTypeHandle::Avg`].
     pub fn crit_mod(&self, handle: HitTypeHandle, buffs: &impl Buffs) -> u64 {
@@ -348,6 +474,7 @@ impl XivMath {
     /// * When using attack power, the weapon's Physical Damage will be used
     /// * When using attack magic or healing magic, the weapon's Magic Damage will be used.
     ///   Additionally, the job stat modifier will be chosen depending on the main stat being used.
+    ///
     /// The output of this function is a multiplier scaled by `100`.
     pub const fn wd_mod(&self, stat: ActionStat) -> u64 {
         let stat_field = match stat {
@@ -367,7 +494,10 @@ impl XivMath {
         // self.main_stat(stat) can be under lvl_main bc of job modifiers
         // so we handle that here
         let main = self.main_stat(stat);
-        let atk_mod = data::atk_mod(self.info.job, self.info.lvl);
+        let atk_mod = match stat {
+            ActionStat::HealingMagic => data::heal_mod(self.info.lvl),
+            _ => data::atk_mod(self.info.job, self.info.lvl),
+        };
         if main < lvl_main {
             // seems to work. pretty sure div_ceil is correct here.
             100 - (atk_mod * (lvl_main - main)).div_ceil(lvl_main)
@@ -512,6 +642,31 @@ impl XivMath {
         buffs.damage(prebuff, dmg_ty, dmg_el)
     }
 
+    /// Calculates the healing a direct heal action with a certain `potency` will do.
+    /// The healing depends on the type of `stat` used, the job `traits`, whether or not the
+    /// action `crit`, and a random modifier `rand` between `9700` and `10300` inclusive.
+    ///
+    /// Heals can never direct hit, and are not affected by damage buffs.
+    pub fn action_heal(
+        &self,
+        potency: u64,
+        stat: ActionStat,
+        crit: HitTypeHandle,
+        // Scaled by 10000
+        rand: u64,
+        buffs: &impl Buffs,
+    ) -> u64 {
+        let this = self.with_stats(buffs);
+        #[rustfmt::skip]
+        let prerand = potency
+            * this.atk_damage(stat) / 100
+            * this.det_damage(false) / 1000
+            * this.ten_damage() / 1000
+            * this.wd_mod(stat) / 100
+            * this.job_trait_mod() / 100;
+        prerand * this.crit_mod(crit, buffs) / 1000000 * rand / 10000
+    }
+
     /// Calculates the damage a damage over time tick with a certain `potency` will do.
     /// The damage depends on the type of `stat` used, the job `traits`,
     /// the type of `speed_stat` that the action was modified by,
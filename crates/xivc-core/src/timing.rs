@@ -189,6 +189,11 @@ impl ActionCd {
 ///     Action6,
 ///     Action7,
 ///     Action8,
+///     Role(ExampleRoleActions),
+/// }
+///
+/// pub enum ExampleRoleActions {
+///     RoleAction1,
 /// }
 ///
 /// job_cd_struct! {
@@ -222,6 +227,10 @@ impl ActionCd {
 ///     // group description. For example, "Lemure's Slice/Scythe".
 ///     "Action4/6"
 ///     cd_group_b CdGroupB: Action4 Action6;
+///     // actions with a single field, such as embedded role actions,
+///     // have the value of that field written in parentheses.
+///     "Role Action 1"
+///     role_action_1 RoleAction1: Role(ExampleRoleActions::RoleAction1);
 /// }
 /// ```
 ///
@@ -245,7 +254,7 @@ macro_rules! job_cd_struct {
             $(#[$cdsf_meta:meta])*
             $cdsf_name:ident
             $(#[$cdgv_meta:meta])*
-            $cdgv_name:ident $(: $($aci:ident)+)?;
+            $cdgv_name:ident $(: $($aci:ident $(($acr:path))?)+)?;
         )*
     ) => {
         $(#[$cds_meta])*
@@ -273,7 +282,7 @@ macro_rules! job_cd_struct {
             pub fn actions(&self) -> &'static [$acty] {
                 match self {
                     $(
-                        Self::$cdgv_name => &[ $( $(<$acty>::$aci),+ )? ],
+                        Self::$cdgv_name => &[ $( $(<$acty>::$aci $(($acr))?),+ )? ],
                     )*
                 }
             }
@@ -332,7 +341,7 @@ macro_rules! job_cd_struct {
             pub fn cd_group(self) -> Option<$cdg_id> {
                 Some(match self {
                     $(
-                        $($(Self::$aci)|+ => $cdg_id::$cdgv_name,)?
+                        $($(Self::$aci $(($acr))?)|+ => $cdg_id::$cdgv_name,)?
                     )*
                     _ => return None
                 })
@@ -341,11 +350,15 @@ macro_rules! job_cd_struct {
     };
 }
 
+/// The type-erased getter function used by [`CdMapIter`] and [`CdMapIterMut`].
+type CdMapGetFn<T, G> = fn(NonNull<()>, usize) -> Option<(NonNull<T>, G)>;
+
+#[allow(unpredictable_function_pointer_comparisons)]
 #[derive(Clone, Debug, PartialEq, Eq)]
 /// An iterator over the values in a cooldown map.
 pub struct CdMapIter<'a, T: 'a, G> {
     map: NonNull<()>,
-    get: fn(NonNull<()>, usize) -> Option<(NonNull<T>, G)>,
+    get: CdMapGetFn<T, G>,
     index: usize,
     marker: PhantomData<&'a T>,
 }
@@ -373,11 +386,12 @@ impl<'a, T: 'a, G> Iterator for CdMapIter<'a, T, G> {
     }
 }
 
+#[allow(unpredictable_function_pointer_comparisons)]
 #[derive(Clone, Debug, PartialEq, Eq)]
 /// An iterator over the values in a cooldown map.
 pub struct CdMapIterMut<'a, T: 'a, G> {
     map: NonNull<()>,
-    get: fn(NonNull<()>, usize) -> Option<(NonNull<T>, G)>,
+    get: CdMapGetFn<T, G>,
     index: usize,
     marker: PhantomData<&'a mut T>,
 }
@@ -9,6 +9,11 @@
 //!
 //! Also of note is the [`status`] submodule. This module contains
 //! all of the logic for status effect handling.
+//!
+//! With the `alloc` feature enabled, the `reference` submodule contains
+//! an in-memory implementation of these traits that can be used to drive
+//! the job modules directly, and the `simulation` submodule contains
+//! the event loop that runs on top of it.
 
 use core::{error::Error, fmt};
 
@@ -19,10 +24,17 @@ pub mod status;
 
 pub mod queue;
 
+#[cfg(feature = "alloc")]
+pub mod reference;
+
+#[cfg(feature = "alloc")]
+pub mod simulation;
+
 use rand::distributions::Distribution;
 
 use crate::{
     enums::{ActionCategory, DamageInstance},
+    item::Item,
     job,
     math::{EotSnapshot, HitTypeHandle, SpeedStat},
     timing::DurationInfo,
@@ -80,6 +92,10 @@ pub trait ActorRef<'w>: Clone + Sized {
     ) -> EotSnapshot;
     /// Returns the calculated damage for an auto attack.
     fn auto_damage<R>(&self, target: ActorId, rng: &mut R) -> u64
+    where
+        R: EventRng;
+    /// Returns the calculated healing of a heal with the specified `potency`.
+    fn heal_amount<R>(&self, potency: u64, target: ActorId, rng: &mut R) -> u64
     where
         R: EventRng;
 
@@ -142,20 +158,109 @@ pub enum EventError {
     /// The cast lock is still active.
     Lock,
     /// The action is still on cooldown.
-    Cooldown(job::Action),
+    Cooldown(Action),
     /// A job specific error.
     Job(job::CastError),
     /// The actor is not in combat.
     InCombat,
     /// No target exists.
     NoTarget,
+    /// The actor does not have enough MP.
+    ///
+    /// Contains the amount of MP that was needed.
+    Mp(u16),
+    /// The action does not belong to the job of the actor.
+    WrongJob(job::Action),
 }
 
 impl Error for EventError {}
 
 impl fmt::Display for EventError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        todo!()
+        match self {
+            Self::Gcd => write!(f, "The GCD is still active."),
+            Self::Lock => write!(f, "The cast lock is still active."),
+            Self::Cooldown(action) => write!(f, "'{}' is still on cooldown.", action.name()),
+            Self::Job(err) => err.fmt(f),
+            Self::InCombat => write!(f, "The actor is not in combat."),
+            Self::NoTarget => write!(f, "No valid target could be found."),
+            Self::Mp(mp) => write!(f, "Not enough MP, needed at least {}.", mp),
+            Self::WrongJob(action) => {
+                write!(f, "'{}' cannot be used by the actor's job.", action.name())
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+/// A report of an action that could not be cast.
+///
+/// This wraps an [`EventError`] with the action and actor that caused it,
+/// along with the time remaining until the action could have been cast
+/// if the error was caused by the GCD, cast lock, or a cooldown.
+///
+/// # Examples
+/// ```
+/// # use xivc_core::{
+/// #     job::brd::BrdAction,
+/// #     world::{ActorId, CastFailure, EventError},
+/// # };
+/// let failure = CastFailure::new(BrdAction::BurstShot.into(), ActorId(0), EventError::Gcd)
+///     .remaining(1250);
+///
+/// assert_eq!(
+///     failure.to_string(),
+///     "Could not cast 'Burst Shot': The GCD is still active. (1.25s remaining)",
+/// );
+/// ```
+pub struct CastFailure {
+    /// The action that failed to cast.
+    pub action: Action,
+    /// The actor that tried to cast the action.
+    pub source: ActorId,
+    /// The error that caused the action to fail.
+    pub error: EventError,
+    /// The time in milliseconds until the action could have been cast.
+    ///
+    /// This will be [`None`] if the error does not depend on time.
+    pub remaining: Option<u32>,
+}
+
+impl CastFailure {
+    /// Creates a new `CastFailure` without a remaining time.
+    pub const fn new(action: Action, source: ActorId, error: EventError) -> Self {
+        Self {
+            action,
+            source,
+            error,
+            remaining: None,
+        }
+    }
+    /// Sets the time in milliseconds until the action could have been cast.
+    pub const fn remaining(mut self, remaining: u32) -> Self {
+        self.remaining = Some(remaining);
+        self
+    }
+}
+
+impl Error for CastFailure {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        Some(&self.error)
+    }
+}
+
+impl fmt::Display for CastFailure {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Could not cast '{}': {}", self.action.name(), self.error)?;
+        if let Some(remaining) = self.remaining {
+            write!(
+                f,
+                " ({}.{:02}s remaining)",
+                remaining / 1000,
+                remaining % 1000 / 10
+            )?;
+        }
+        Ok(())
     }
 }
 
@@ -273,6 +378,7 @@ pub enum Faction {
 pub enum Event {
     Action(ActionEvent),
     Damage(DamageEvent),
+    Heal(HealEvent),
     Status(StatusEvent),
     Job(job::JobEvent, ActorId),
     AdvCd(job::CdGroup, u32, ActorId),
@@ -354,6 +460,53 @@ impl From<DamageEvent> for Event {
     }
 }
 
+/// A heal application event.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct HealEvent {
+    /// The amount of HP restored by the event.
+    ///
+    /// This is `0` if the heal is a [`full`] heal.
+    ///
+    /// [`full`]: HealEvent::full
+    pub heal: u64,
+    /// If `true`, the heal restores all of the target's HP.
+    pub full: bool,
+    /// The actor healing.
+    pub source: ActorId,
+    /// The target being healed.
+    pub target: ActorId,
+    /// The action doing the healing.
+    pub action: Action,
+}
+impl HealEvent {
+    /// Creates a new heal event.
+    pub const fn new(heal: u64, source: ActorId, target: ActorId, action: Action) -> Self {
+        Self {
+            heal,
+            full: false,
+            source,
+            target,
+            action,
+        }
+    }
+    /// Creates a new heal event that restores all of the target's HP.
+    pub const fn new_full(source: ActorId, target: ActorId, action: Action) -> Self {
+        Self {
+            heal: 0,
+            full: true,
+            source,
+            target,
+            action,
+        }
+    }
+}
+impl From<HealEvent> for Event {
+    fn from(value: HealEvent) -> Self {
+        Event::Heal(value)
+    }
+}
+
 /// A random event determining whether an instance of damage
 /// will critically hit.
 pub struct CriticalHit {
@@ -431,6 +584,29 @@ impl Default for DamageVariance {
     }
 }
 
+/// A random event determining the +/-3% healing variance of
+/// a heal.
+pub struct HealVariance(());
+
+impl HealVariance {
+    /// Creates a new instance of this `struct`.
+    pub const fn new() -> Self {
+        Self(())
+    }
+}
+
+impl Distribution<u64> for HealVariance {
+    fn sample<R: rand::Rng + ?Sized>(&self, rng: &mut R) -> u64 {
+        rng.gen_range(9700..=10300)
+    }
+}
+
+impl Default for HealVariance {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 /// A helper trait for easily submitting damage events on to an event sink.
 pub trait DamageEventExt<'w, W: WorldRef<'w>>: EventSink<'w, W> {
     /// Deals damage to the target after the specified delay.
@@ -448,15 +624,63 @@ pub trait DamageEventExt<'w, W: WorldRef<'w>>: EventSink<'w, W> {
             delay,
         )
     }
+
+    /// Deals a single tick of damage over time to the target after the specified delay.
+    ///
+    /// This is for damage over time that is not tied to a status on the target,
+    /// like ground effects, which hit whoever is standing in them on each tick.
+    fn dot_tick(
+        &mut self,
+        action: impl Into<Action>,
+        damage: DamageInstance,
+        stat: SpeedStat,
+        target: ActorId,
+        delay: u32,
+    ) {
+        let actor = self.source();
+        let snapshot = actor.dot_damage_snapshot(damage, stat, target);
+        let crit: HitTypeHandle = self.random(CriticalHit::new(snapshot.crit_chance));
+        let dhit: HitTypeHandle = self.random(DirectHit::new(snapshot.dhit_chance));
+        let rand: u64 = self.random(DamageVariance::new());
+        let damage = snapshot.eot_result(crit, dhit, rand);
+        self.event(
+            DamageEvent::new(damage, actor.id(), target, action.into()).into(),
+            delay,
+        )
+    }
 }
 impl<'w, W: WorldRef<'w>, E: EventSink<'w, W>> DamageEventExt<'w, W> for E {}
 
+/// A helper trait for easily submitting heal events on to an event sink.
+pub trait HealEventExt<'w, W: WorldRef<'w>>: EventSink<'w, W> {
+    /// Heals the target with some potency after the specified delay.
+    fn heal(&mut self, action: impl Into<Action>, potency: u64, target: ActorId, delay: u32) {
+        let actor = self.source();
+        let heal = actor.heal_amount(potency, target, self.rng());
+        self.event(
+            HealEvent::new(heal, actor.id(), target, action.into()).into(),
+            delay,
+        )
+    }
+    /// Restores all of the target's HP after the specified delay.
+    fn full_heal(&mut self, action: impl Into<Action>, target: ActorId, delay: u32) {
+        let actor = self.source();
+        self.event(
+            HealEvent::new_full(actor.id(), target, action.into()).into(),
+            delay,
+        )
+    }
+}
+impl<'w, W: WorldRef<'w>, E: EventSink<'w, W>> HealEventExt<'w, W> for E {}
+
 /// An action that an actor can cast.
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum Action {
     /// An action used by a job.
     Job(job::Action),
+    /// A consumable item.
+    Item(Item),
 }
 
 impl Action {
@@ -464,18 +688,21 @@ impl Action {
     pub fn category(&self) -> ActionCategory {
         match self {
             Self::Job(v) => v.category(),
+            Self::Item(v) => v.category(),
         }
     }
     /// Returns `true` if this action is a GCD.
     pub fn gcd(&self) -> bool {
         match self {
             Self::Job(v) => v.gcd(),
+            Self::Item(_) => false,
         }
     }
     /// Returns the name of the action.
     pub fn name(&self) -> &'static str {
         match self {
             Self::Job(v) => v.name(),
+            Self::Item(v) => v.name(),
         }
     }
 }
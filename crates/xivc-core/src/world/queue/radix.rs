@@ -211,6 +211,41 @@ impl<E> RadixEventQueue<E> {
         self.time
     }
 
+    /// Returns the time of the next event in the queue without removing it,
+    /// or [`None`] if the queue is empty.
+    ///
+    /// Unlike [`pop`], this does not change the [current time] of the queue.
+    ///
+    /// # Examples
+    /// ```
+    /// # use xivc_core::world::queue::RadixEventQueue;
+    /// let mut queue = RadixEventQueue::new();
+    /// assert_eq!(queue.peek_time(), None);
+    ///
+    /// queue.push(10, ());
+    /// queue.push(5, ());
+    ///
+    /// assert_eq!(queue.peek_time(), Some(5));
+    /// assert_eq!(queue.time(), 0);
+    ///
+    /// queue.pop();
+    /// assert_eq!(queue.peek_time(), Some(10));
+    /// ```
+    ///
+    /// [`pop`]: Self::pop
+    /// [current time]: crate::world::queue#current
+    pub fn peek_time(&self) -> Option<u32> {
+        if !self.head.is_empty() {
+            return Some(self.time);
+        }
+        if self.filled == 0 {
+            return None;
+        }
+        // every event in a bucket is earlier than the events in any of the buckets after it,
+        // so the minimum of the first non-empty bucket is the time of the next event
+        Some(self.buckets[self.filled.trailing_zeros() as usize].min)
+    }
+
     /// Returns `true` if the event queue is empty.
     ///
     /// # Examples
@@ -0,0 +1,923 @@
+//! A reference in-memory implementation of a world.
+//!
+//! This module contains the [`World`] type, which stores every actor in a simulation
+//! along with their [status effects], MP, targets, factions, and [job state].
+//!
+//! A [`World`] is read through its [`WorldRef`] implementation on `&World`, which
+//! hands out [`ActorHandle`]s implementing [`ActorRef`]. Events are submitted through
+//! a [`WorldSink`], which collects them until they are submitted to a [`RadixEventQueue`].
+//!
+//! # Geometry
+//!
+//! Every actor has a [`Position`] and a hitbox radius, both in yalms scaled by `100`.
+//! Ranges are measured from the center of the acting actor to the edge of the hitbox
+//! of the other actor, like they are in game.
+//!
+//! An actor always faces its current [target]. An actor without a target
+//! (or one standing on top of its target) faces towards positive `y`.
+//!
+//! # Examples
+//! ```
+//! # use xivc_core::{
+//! #     enums::{Clan, Job},
+//! #     math::{PlayerInfo, PlayerStats, WeaponInfo, XivMath},
+//! #     world::{reference::{Actor, Position, World}, ActorRef, Faction, WorldRef},
+//! # };
+//! let math = XivMath::new(
+//!     PlayerStats::default(100),
+//!     WeaponInfo { wd: 132, auto: 13200, delay: 300 },
+//!     PlayerInfo { clan: Clan::Wildwood, job: Job::BRD, lvl: 100 },
+//! );
+//!
+//! let mut world = World::new();
+//! let dummy = world.add_actor(Actor::new(Faction::Enemy).hitbox(500));
+//! let bard = world.add_actor(Actor::player(math).position(Position::new(0, -1000)).target(dummy));
+//!
+//! let world = &world;
+//! let actor = world.actor(bard).unwrap();
+//! assert_eq!(actor.target().map(|v| v.id()), Some(dummy));
+//! assert_eq!(actor.mp(), 10000);
+//! ```
+//!
+//! [status effects]: StatusInstance
+//! [job state]: job::State
+//! [target]: Actor::target
+
+use alloc::vec::Vec;
+use core::f64::consts::PI;
+
+use rand::{distributions::Distribution, RngCore};
+
+use crate::{
+    enums::{DamageElement, DamageInstance},
+    job::{self, role::TRUE_NORTH, CdMap, DynJob},
+    math::{Buffs, EotSnapshot, Food, HitTypeHandle, PlayerStats, SpeedStat, XivMath},
+    timing::{ActionCd, DurationInfo, ScaleTime},
+};
+
+use super::{
+    queue::RadixEventQueue,
+    status::{
+        JobEffect, StatusEffect, StatusEvent, StatusEventKind, StatusInstance, StatusSnapshot,
+    },
+    ActionTargetting, ActorId, ActorRef, CriticalHit, DamageVariance, DirectHit, Event, EventRng,
+    EventSink, Faction, HealVariance, Positional, WorldRef,
+};
+
+/// The maximum amount of MP a player can have.
+pub const MAX_MP: u16 = 10000;
+
+/// An in-memory world that stores every actor in a simulation.
+///
+/// Actors are added with [`add_actor`], and are never removed.
+/// Read access to the world for jobs is done through the [`WorldRef`]
+/// implementation on `&World`.
+///
+/// [`add_actor`]: World::add_actor
+#[derive(Clone, Debug, Default)]
+pub struct World {
+    actors: Vec<Actor>,
+}
+
+impl World {
+    /// Creates a new empty world.
+    pub const fn new() -> Self {
+        Self { actors: Vec::new() }
+    }
+
+    /// Adds an actor to the world, returning the [`ActorId`] for it.
+    pub fn add_actor(&mut self, actor: Actor) -> ActorId {
+        let id = ActorId(
+            self.actors
+                .len()
+                .try_into()
+                .expect("too many actors in the world"),
+        );
+        self.actors.push(actor);
+        id
+    }
+
+    /// Returns a reference to the actor with the specified `id`.
+    pub fn get(&self, id: ActorId) -> Option<&Actor> {
+        self.actors.get(id.0 as usize)
+    }
+
+    /// Returns a mutable reference to the actor with the specified `id`.
+    pub fn get_mut(&mut self, id: ActorId) -> Option<&mut Actor> {
+        self.actors.get_mut(id.0 as usize)
+    }
+
+    /// Returns an iterator over the actors in the world and their ids.
+    pub fn actors(&self) -> impl Iterator<Item = (ActorId, &Actor)> + '_ {
+        self.actors
+            .iter()
+            .enumerate()
+            .map(|(i, v)| (ActorId(i as u16), v))
+    }
+
+    /// Returns a mutable iterator over the actors in the world and their ids.
+    pub fn actors_mut(&mut self) -> impl Iterator<Item = (ActorId, &mut Actor)> + '_ {
+        self.actors
+            .iter_mut()
+            .enumerate()
+            .map(|(i, v)| (ActorId(i as u16), v))
+    }
+
+    /// Advances every actor in the world forward by a certain amount of time.
+    ///
+    /// See [`Actor::advance`] for more information.
+    pub fn advance(&mut self, time: u32) {
+        for actor in &mut self.actors {
+            actor.advance(time);
+        }
+    }
+
+    /// Applies a [`StatusEvent`] to the target of the event.
+    ///
+    /// Does nothing if the target does not exist.
+    pub fn apply_status_event(&mut self, event: &StatusEvent) {
+        if let Some(target) = self.get_mut(event.target) {
+            target.apply_status_event(event);
+        }
+    }
+}
+
+/// A position in the world.
+///
+/// Both coordinates are in yalms scaled by `100`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Position {
+    /// The `x` coordinate of the position.
+    pub x: i32,
+    /// The `y` coordinate of the position.
+    pub y: i32,
+}
+
+impl Position {
+    /// Creates a new position.
+    pub const fn new(x: i32, y: i32) -> Self {
+        Self { x, y }
+    }
+
+    // the vector pointing from `self` to `other`
+    const fn to(self, other: Self) -> (i64, i64) {
+        (
+            other.x as i64 - self.x as i64,
+            other.y as i64 - self.y as i64,
+        )
+    }
+}
+
+/// An actor stored inside of a [`World`].
+#[derive(Clone, Debug)]
+pub struct Actor {
+    /// The faction the actor is part of.
+    pub faction: Faction,
+    /// The position of the actor.
+    pub position: Position,
+    /// The radius of the actor's hitbox, in yalms scaled by `100`.
+    pub hitbox: u32,
+    /// The current target of the actor.
+    pub target: Option<ActorId>,
+    /// If `true`, the actor is in combat.
+    pub in_combat: bool,
+    /// The status effects currently present on the actor.
+    pub statuses: Vec<StatusEntry>,
+    /// The player information for the actor, or [`None`] if the actor is not a player.
+    pub player: Option<Player>,
+}
+
+impl Actor {
+    /// Creates a new non-player actor in the specified faction.
+    ///
+    /// This is what should be used for things like target dummies.
+    pub const fn new(faction: Faction) -> Self {
+        Self {
+            faction,
+            position: Position::new(0, 0),
+            hitbox: 50,
+            target: None,
+            in_combat: false,
+            statuses: Vec::new(),
+            player: None,
+        }
+    }
+
+    /// Creates a new player actor in the party.
+    ///
+    /// The job of the player will be the job in the [`PlayerInfo`] of `math`.
+    ///
+    /// # Panics
+    /// Panics if the job of the player is not yet implemented.
+    ///
+    /// [`PlayerInfo`]: crate::math::PlayerInfo
+    pub fn player(math: XivMath) -> Self {
+        Self {
+            player: Some(Player::new(math)),
+            ..Self::new(Faction::Party)
+        }
+    }
+
+    /// Applies the bonuses of a [`Food`] to the stats of the actor.
+    ///
+    /// Does nothing if the actor is not a player.
+    ///
+    /// # Examples
+    /// ```
+    /// # use xivc_core::{
+    /// #     enums::{Clan, Job},
+    /// #     math::{Food, PlayerInfo, PlayerStats, StatBonus, WeaponInfo, XivMath},
+    /// #     world::reference::Actor,
+    /// # };
+    /// let stats = PlayerStats { crt: 2800, ..PlayerStats::default(100) };
+    /// let math = XivMath::new(
+    ///     stats,
+    ///     WeaponInfo { wd: 132, auto: 13200, delay: 300 },
+    ///     PlayerInfo { clan: Clan::Wildwood, job: Job::BRD, lvl: 100 },
+    /// );
+    /// let food = Food { crt: StatBonus::new(10, 112), ..Food::NONE };
+    ///
+    /// let actor = Actor::player(math).food(food);
+    /// assert_eq!(actor.player.unwrap().math.stats.crt, 2912);
+    /// ```
+    pub fn food(mut self, food: Food) -> Self {
+        if let Some(player) = &mut self.player {
+            player.math.stats = food.apply(player.math.stats);
+        }
+        self
+    }
+
+    /// Sets the position of the actor.
+    pub const fn position(mut self, position: Position) -> Self {
+        self.position = position;
+        self
+    }
+
+    /// Sets the hitbox radius of the actor, in yalms scaled by `100`.
+    pub const fn hitbox(mut self, hitbox: u32) -> Self {
+        self.hitbox = hitbox;
+        self
+    }
+
+    /// Sets the target of the actor.
+    pub const fn target(mut self, target: ActorId) -> Self {
+        self.target = Some(target);
+        self
+    }
+
+    /// Returns an iterator over the status instances present on the actor.
+    pub fn status_instances(&self) -> impl Iterator<Item = StatusInstance> + Clone + '_ {
+        self.statuses
+            .iter()
+            .filter(|v| v.instance.stack > 0)
+            .map(|v| v.instance)
+    }
+
+    /// Returns the job effect for the actor, if there is one.
+    pub fn job_effect(&self) -> Option<&dyn JobEffect> {
+        self.player.as_ref().and_then(|p| p.job.effect(&p.state))
+    }
+
+    /// Advances the actor forward by a certain amount of time.
+    ///
+    /// Status effects that run out will be removed from the actor.
+    pub fn advance(&mut self, time: u32) {
+        for entry in &mut self.statuses {
+            entry.instance.advance(time);
+        }
+        self.statuses.retain(|v| v.instance.stack > 0);
+        if let Some(player) = &mut self.player {
+            player.advance(time);
+        }
+    }
+
+    fn find_status(&self, effect: StatusEffect, source: ActorId) -> Option<usize> {
+        self.statuses.iter().position(|v| v.matches(effect, source))
+    }
+
+    /// Applies a [`StatusEvent`] to the actor.
+    ///
+    /// A status effect that is [`unique`] will only ever have one instance on an
+    /// actor, and any application will overwrite the existing instance, regardless
+    /// of the source of the instance.
+    ///
+    /// [`unique`]: super::status::StatusVTable::unique
+    pub fn apply_status_event(&mut self, event: &StatusEvent) {
+        let StatusEvent {
+            status,
+            source,
+            kind,
+            ..
+        } = *event;
+        let existing = self.find_status(status, source);
+        let entry = |duration, stacks, snapshot| StatusEntry {
+            instance: StatusInstance {
+                source,
+                effect: status,
+                time: duration,
+                stack: stacks,
+            },
+            snapshot,
+        };
+        match kind {
+            StatusEventKind::Apply { duration, stacks } => {
+                self.insert_status(existing, entry(duration, stacks, None))
+            }
+            StatusEventKind::ApplyDot {
+                duration,
+                snapshot,
+                stacks,
+            } => self.insert_status(existing, entry(duration, stacks, Some(snapshot))),
+            StatusEventKind::Remove => {
+                if let Some(i) = existing {
+                    self.statuses.remove(i);
+                }
+            }
+            StatusEventKind::RemoveStacks { stacks } => {
+                if let Some(i) = existing {
+                    self.statuses[i].instance.sub_stacks(stacks);
+                    if self.statuses[i].instance.stack == 0 {
+                        self.statuses.remove(i);
+                    }
+                }
+            }
+            StatusEventKind::AddStacks { stacks, max } => {
+                if let Some(i) = existing {
+                    self.statuses[i].instance.add_stacks(stacks, max);
+                }
+            }
+            StatusEventKind::ApplyOrExtend {
+                duration,
+                stacks,
+                max,
+            } => match existing {
+                Some(i) => {
+                    let instance = &mut self.statuses[i].instance;
+                    instance.source = source;
+                    instance.time = (instance.time + duration).min(max);
+                    instance.stack = stacks;
+                }
+                None => self.insert_status(None, entry(duration, stacks, None)),
+            },
+            StatusEventKind::ApplyOrAddStacks {
+                duration,
+                stacks,
+                max,
+            } => match existing {
+                Some(i) => self.statuses[i].instance.add_stacks(stacks, max),
+                None => self.insert_status(None, entry(duration, stacks.min(max), None)),
+            },
+        }
+    }
+
+    fn insert_status(&mut self, existing: Option<usize>, entry: StatusEntry) {
+        match existing {
+            Some(i) => self.statuses[i] = entry,
+            None => self.statuses.push(entry),
+        }
+    }
+}
+
+/// A status effect instance stored on an [`Actor`].
+#[derive(Clone, Copy, Debug)]
+pub struct StatusEntry {
+    /// The status instance.
+    pub instance: StatusInstance,
+    /// The damage snapshot for the status, if it is a damage over time effect.
+    pub snapshot: Option<EotSnapshot>,
+}
+
+impl StatusEntry {
+    // returns `true` if applying `effect` from `source` should affect this entry
+    fn matches(&self, effect: StatusEffect, source: ActorId) -> bool {
+        self.instance.effect == effect && (effect.unique || self.instance.source == source)
+    }
+}
+
+/// The player specific information of an [`Actor`].
+#[derive(Clone, Debug)]
+pub struct Player {
+    /// The stats and math for the player.
+    pub math: XivMath,
+    /// The job of the player.
+    pub job: DynJob,
+    /// The job gauge state and combos of the player.
+    pub state: job::State,
+    /// The action cooldowns of the player.
+    pub cds: CdMap<ActionCd>,
+    /// The cooldown shared by every item.
+    pub item_cd: ActionCd,
+    /// The amount of MP the player has.
+    pub mp: u16,
+    /// The time remaining on the GCD of the player.
+    pub gcd: u32,
+    /// The time remaining on the cast or animation lock of the player.
+    pub lock: u32,
+}
+
+impl Player {
+    /// Creates a new player with a default job state and full MP.
+    ///
+    /// # Panics
+    /// Panics if the job of the player is not yet implemented.
+    pub fn new(math: XivMath) -> Self {
+        let job = math.info.job;
+        Self {
+            math,
+            job: DynJob::from_job(job),
+            state: job::State::default_for(job),
+            cds: CdMap::default_for(job),
+            item_cd: ActionCd::new(),
+            mp: MAX_MP,
+            gcd: 0,
+            lock: 0,
+        }
+    }
+
+    /// Advances the job state, cooldowns, and timers of the player
+    /// forward by a certain amount of time.
+    pub fn advance(&mut self, time: u32) {
+        self.state.advance(time);
+        for (cd, _) in self.cds.iter_mut() {
+            cd.advance(time);
+        }
+        self.item_cd.advance(time);
+        self.gcd = self.gcd.saturating_sub(time);
+        self.lock = self.lock.saturating_sub(time);
+    }
+}
+
+impl<'w> WorldRef<'w> for &'w World {
+    type Actor = ActorHandle<'w>;
+    type DurationInfo = ActorDurationInfo;
+
+    fn actor(&self, id: ActorId) -> Option<Self::Actor> {
+        self.get(id).map(|_| ActorHandle { world: self, id })
+    }
+}
+
+/// A handle to an actor in a [`World`].
+///
+/// This is the [`ActorRef`] for `&World`.
+#[derive(Clone, Copy, Debug)]
+pub struct ActorHandle<'w> {
+    world: &'w World,
+    id: ActorId,
+}
+
+impl<'w> ActorHandle<'w> {
+    /// Returns the actor data this handle refers to.
+    pub fn data(&self) -> &'w Actor {
+        &self.world.actors[self.id.0 as usize]
+    }
+
+    // the direction this actor is facing
+    fn facing(&self) -> (i64, i64) {
+        let data = self.data();
+        data.target
+            .and_then(|v| self.world.get(v))
+            .map(|v| data.position.to(v.position))
+            .filter(|&v| v != (0, 0))
+            .unwrap_or((0, 1))
+    }
+
+    // returns `true` if `other` is in the area an action with `targetting` would hit
+    fn in_area(&self, other: &Actor, targetting: ActionTargetting) -> bool {
+        let this = self.data();
+        let hitbox = other.hitbox as i64;
+        let reach = |range: u8| range as i64 * 100 + hitbox;
+        let v = this.position.to(other.position);
+        let dist_sq = v.0 * v.0 + v.1 * v.1;
+
+        match targetting {
+            ActionTargetting::Single { range } | ActionTargetting::Circle { radius: range } => {
+                dist_sq <= reach(range).pow(2)
+            }
+            ActionTargetting::TargetCircle { range, radius } => {
+                let Some(target) = this.target.and_then(|v| self.world.get(v)) else {
+                    return false;
+                };
+                let t = this.position.to(target.position);
+                let t_reach = range as i64 * 100 + target.hitbox as i64;
+                let o = target.position.to(other.position);
+                t.0 * t.0 + t.1 * t.1 <= t_reach.pow(2)
+                    && o.0 * o.0 + o.1 * o.1 <= reach(radius).pow(2)
+            }
+            // lines and cones never hit the actor using them
+            ActionTargetting::Line { .. } | ActionTargetting::Cone { .. }
+                if core::ptr::eq(this, other) =>
+            {
+                false
+            }
+            ActionTargetting::Line { range } => {
+                let dir = self.facing();
+                let len = (dir.0 * dir.0 + dir.1 * dir.1).isqrt();
+                let proj = v.0 * dir.0 + v.1 * dir.1;
+                let perp = (v.0 * dir.1 - v.1 * dir.0).abs();
+                // lines are 2.5y wide on either side
+                proj >= -hitbox * len && proj <= reach(range) * len && perp <= (250 + hitbox) * len
+            }
+            ActionTargetting::Cone { range, angle } => {
+                if dist_sq > reach(range).pow(2) {
+                    return false;
+                }
+                if dist_sq == 0 {
+                    return true;
+                }
+                let dir = self.facing();
+                let dir_len = (dir.0 * dir.0 + dir.1 * dir.1).isqrt();
+                let v_len = dist_sq.isqrt();
+                let dot = v.0 * dir.0 + v.1 * dir.1;
+                dot * 1000 >= v_len * dir_len * cos_milli(angle as u32 / 2)
+            }
+        }
+    }
+}
+
+// the cosine of an angle in degrees, scaled by `1000`.
+// `core` has no trig functions, so this is a taylor series.
+fn cos_milli(deg: u32) -> i64 {
+    let x = deg.min(180) as f64 * PI / 180.0;
+    let x2 = x * x;
+    let mut term = 1.0;
+    let mut sum = 1.0;
+    for n in 1..12 {
+        term *= -x2 / ((2 * n - 1) * (2 * n)) as f64;
+        sum += term;
+    }
+    (sum * 1000.0) as i64
+}
+
+impl<'w> ActorRef<'w> for ActorHandle<'w> {
+    type World = &'w World;
+
+    fn id(&self) -> ActorId {
+        self.id
+    }
+
+    fn world(&self) -> Self::World {
+        self.world
+    }
+
+    fn attack_damage<R>(&self, damage: DamageInstance, target: ActorId, rng: &mut R) -> u64
+    where
+        R: EventRng,
+    {
+        let this = self.data();
+        let Some(player) = &this.player else {
+            return 0;
+        };
+        let buffs = self.snapshot(target);
+        let math = player.math.with_stats(&buffs);
+        let (crit, dhit) = roll_hit_types(&math, &buffs, damage, rng);
+        player.math.action_damage(
+            damage.potency,
+            damage.dmg_ty,
+            damage.dmg_el,
+            math.job_attack_stat(),
+            crit,
+            dhit,
+            damage.falloff as u64,
+            rng.random(DamageVariance::new()),
+            &buffs,
+        )
+    }
+
+    fn dot_damage_snapshot(
+        &self,
+        damage: DamageInstance,
+        stat: SpeedStat,
+        target: ActorId,
+    ) -> EotSnapshot {
+        let Some(player) = &self.data().player else {
+            return EotSnapshot::default();
+        };
+        player.math.dot_damage_snapshot(
+            damage.potency,
+            damage.dmg_ty,
+            damage.dmg_el,
+            player.math.job_attack_stat(),
+            stat,
+            &self.snapshot(target),
+        )
+    }
+
+    fn auto_damage<R>(&self, target: ActorId, rng: &mut R) -> u64
+    where
+        R: EventRng,
+    {
+        let Some(player) = &self.data().player else {
+            return 0;
+        };
+        let job = player.math.info.job;
+        let damage = DamageInstance::new(job.aa_potency() as u64);
+        let buffs = self.snapshot(target);
+        let math = player.math.with_stats(&buffs);
+        let (crit, dhit) = roll_hit_types(&math, &buffs, damage, rng);
+        player.math.aa_damage(
+            damage.potency,
+            job.aa_type(),
+            DamageElement::None,
+            crit,
+            dhit,
+            rng.random(DamageVariance::new()),
+            &buffs,
+        )
+    }
+
+    fn heal_amount<R>(&self, potency: u64, target: ActorId, rng: &mut R) -> u64
+    where
+        R: EventRng,
+    {
+        let Some(player) = &self.data().player else {
+            return 0;
+        };
+        let buffs = self.snapshot(target);
+        let math = player.math.with_stats(&buffs);
+        let crit = rng.random(CriticalHit::new(
+            buffs.crit_chance(math.crit_chance()) as u16
+        ));
+        player.math.action_heal(
+            potency,
+            math.job_heal_stat(),
+            crit,
+            rng.random(HealVariance::new()),
+            &buffs,
+        )
+    }
+
+    fn statuses(&self) -> impl Iterator<Item = StatusInstance> + 'w {
+        self.data().status_instances()
+    }
+
+    fn target(&self) -> Option<Self> {
+        self.data().target.and_then(|id| self.world.actor(id))
+    }
+
+    fn actors_for_action(
+        &self,
+        faction: Option<Faction>,
+        targetting: ActionTargetting,
+    ) -> impl Iterator<Item = Self> + 'w {
+        let in_faction = |v: &Actor| faction.is_none_or(|f| v.faction == f);
+        let mut out = Vec::new();
+        let target = self.data().target;
+
+        if let Some(range) = targetting.requires_target() {
+            let Some(t) = target.and_then(|v| self.world.get(v)) else {
+                return out.into_iter();
+            };
+            // the main target is always hit first
+            if in_faction(t) && self.in_area(t, ActionTargetting::Single { range }) {
+                out.push(ActorHandle {
+                    world: self.world,
+                    id: target.unwrap(),
+                });
+            }
+        }
+        if !matches!(targetting, ActionTargetting::Single { .. }) {
+            out.extend(
+                self.world
+                    .actors()
+                    .filter(|&(id, _)| Some(id) != target || targetting.requires_target().is_none())
+                    .filter(|(_, v)| in_faction(v) && self.in_area(v, targetting))
+                    .map(|(id, _)| ActorHandle {
+                        world: self.world,
+                        id,
+                    }),
+            );
+        }
+        out.into_iter()
+    }
+
+    fn within_range(&self, other: ActorId, targetting: ActionTargetting) -> bool {
+        self.world
+            .get(other)
+            .is_some_and(|v| self.in_area(v, targetting))
+    }
+
+    fn mp(&self) -> u16 {
+        self.data()
+            .player
+            .as_ref()
+            .map(|v| v.mp)
+            .unwrap_or_default()
+    }
+
+    fn faction(&self) -> Faction {
+        self.data().faction
+    }
+
+    fn check_positional(&self, positional: Positional, actor: ActorId) -> bool {
+        if self.has_own_status(TRUE_NORTH) {
+            return true;
+        }
+        let Some(other) = self.world.actor(actor) else {
+            return false;
+        };
+        let facing = other.facing();
+        let v = other.data().position.to(self.data().position);
+        let dot = v.0 * facing.0 + v.1 * facing.1;
+        let cross = (v.0 * facing.1 - v.1 * facing.0).abs();
+        match positional {
+            Positional::Front => dot > 0 && cross <= dot,
+            Positional::Rear => dot < 0 && cross <= -dot,
+            Positional::Flank => cross >= dot.abs(),
+        }
+    }
+
+    fn in_combat(&self) -> bool {
+        self.data().in_combat
+    }
+
+    fn duration_info(&self) -> ActorDurationInfo {
+        let this = self.data();
+        let haste = StatusSnapshot::new(
+            this.status_instances(),
+            core::iter::empty(),
+            this.job_effect(),
+        )
+        .haste(100);
+        ActorDurationInfo {
+            math: this.player.as_ref().map(|v| v.math),
+            haste,
+        }
+    }
+}
+
+impl<'w> ActorHandle<'w> {
+    /// Returns the [`StatusSnapshot`] for an event from this actor
+    /// targetting the specified actor.
+    pub fn snapshot(
+        &self,
+        target: ActorId,
+    ) -> StatusSnapshot<
+        impl Iterator<Item = StatusInstance> + Clone + 'w,
+        impl Iterator<Item = StatusInstance> + Clone + 'w,
+        &'w dyn JobEffect,
+    > {
+        let this = self.data();
+        let target = self
+            .world
+            .get(target)
+            .map(|v| v.statuses.as_slice())
+            .unwrap_or_default();
+        StatusSnapshot::new(
+            this.status_instances(),
+            target
+                .iter()
+                .filter(|v| v.instance.stack > 0)
+                .map(|v| v.instance),
+            this.job_effect(),
+        )
+    }
+}
+
+// rolls for critical and direct hits based on the stats and buffs of an actor
+fn roll_hit_types<R: EventRng>(
+    math: &XivMath,
+    buffs: &impl Buffs,
+    damage: DamageInstance,
+    rng: &mut R,
+) -> (HitTypeHandle, HitTypeHandle) {
+    let crit = if damage.force_crit {
+        HitTypeHandle::Force
+    } else {
+        rng.random(CriticalHit::new(
+            buffs.crit_chance(math.crit_chance()) as u16
+        ))
+    };
+    let dhit = if damage.force_dhit {
+        HitTypeHandle::Force
+    } else {
+        rng.random(DirectHit::new(buffs.dhit_chance(math.dhit_chance()) as u16))
+    };
+    (crit, dhit)
+}
+
+/// The [`DurationInfo`] for an actor in a [`World`].
+#[derive(Clone, Copy, Debug)]
+pub struct ActorDurationInfo {
+    math: Option<XivMath>,
+    // the haste multiplier, scaled by `100`
+    haste: u64,
+}
+
+impl DurationInfo for ActorDurationInfo {
+    fn extra_ani_lock(&self) -> u16 {
+        self.math.map(|v| v.ex_lock).unwrap_or_default()
+    }
+
+    fn scale(&self, duration: ScaleTime) -> u32 {
+        let haste = Haste(if duration.haste() { self.haste } else { 100 });
+        match &self.math {
+            Some(math) => {
+                math.action_cast_length(duration.duration() as u64, duration.stat(), &haste) as u32
+            }
+            None => haste.haste(duration.duration() as u64) as u32,
+        }
+    }
+}
+
+// a set of buffs that only modifies haste
+struct Haste(u64);
+
+impl Buffs for Haste {
+    fn damage(&self, base: u64, _: crate::enums::DamageType, _: DamageElement) -> u64 {
+        base
+    }
+    fn crit_chance(&self, base: u64) -> u64 {
+        base
+    }
+    fn dhit_chance(&self, base: u64) -> u64 {
+        base
+    }
+    fn stats(&self, base: PlayerStats) -> PlayerStats {
+        base
+    }
+    fn haste(&self, base: u64) -> u64 {
+        base * self.0 / 100
+    }
+}
+
+/// An [`EventSink`] for a [`World`].
+///
+/// Events submitted to this sink are held until they are [submitted] to an event queue,
+/// which allows the events of a cast that turns out to be invalid to be thrown away.
+///
+/// [submitted]: WorldSink::submit
+pub struct WorldSink<'w, 'q, R> {
+    source: ActorHandle<'w>,
+    rng: &'q mut R,
+    // the events along with their delays
+    events: Vec<(u32, Event)>,
+    dots: Vec<StatusEffect>,
+}
+
+impl<'w, 'q, R> WorldSink<'w, 'q, R> {
+    /// Creates a new event sink for events coming from the `source` actor.
+    ///
+    /// # Panics
+    /// Panics if the `source` actor does not exist.
+    pub fn new(world: &'w World, source: ActorId, rng: &'q mut R) -> Self {
+        Self {
+            source: world.actor(source).expect("source actor does not exist"),
+            rng,
+            events: Vec::new(),
+            dots: Vec::new(),
+        }
+    }
+
+    /// Pushes every event submitted to this sink into an event queue,
+    /// relative to the [current time] of the queue.
+    ///
+    /// [current time]: super::queue#current
+    pub fn submit(self, queue: &mut RadixEventQueue<Event>) {
+        for (delay, event) in self.events {
+            queue.push(queue.time() + delay, event);
+        }
+    }
+
+    /// Returns the damage over time effects that have been applied through this sink.
+    pub fn applied_dots(&self) -> &[StatusEffect] {
+        &self.dots
+    }
+}
+
+impl<'w, R: EventRng> EventSink<'w, &'w World> for WorldSink<'w, '_, R> {
+    type Rng = R;
+
+    fn source(&self) -> ActorHandle<'w> {
+        self.source
+    }
+
+    fn event(&mut self, event: Event, delay: u32) {
+        if let Event::Status(StatusEvent {
+            status,
+            kind: StatusEventKind::ApplyDot { .. },
+            ..
+        }) = event
+        {
+            self.dots.push(status);
+        }
+        self.events.push((delay, event));
+    }
+
+    fn rng(&mut self) -> &mut Self::Rng {
+        self.rng
+    }
+}
+
+/// An [`EventRng`] that samples every distribution with a [`RngCore`].
+#[derive(Clone, Debug, Default)]
+pub struct SampleRng<R>(pub R);
+
+impl<R: RngCore> EventRng for SampleRng<R> {
+    fn random<D, T>(&mut self, distr: D) -> T
+    where
+        D: Distribution<T> + 'static,
+        T: 'static,
+    {
+        distr.sample(&mut self.0)
+    }
+}
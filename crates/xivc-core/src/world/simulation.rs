@@ -0,0 +1,454 @@
+//! A simulation driver for the reference [`World`].
+//!
+//! The [`Simulation`] type owns a [`World`] and a [`RadixEventQueue`], and
+//! executes events from the queue one by one. Before an event is executed,
+//! every actor in the world is [advanced] by the time between the previous event
+//! and the current one, following the [current time] contract of the event queue.
+//!
+//! Every event is also passed along to the [`Job::event`] function of each player
+//! in the world, with that player as the source of the event sink.
+//!
+//! [advanced]: World::advance
+//! [current time]: super::queue#current
+//! [`Job::event`]: crate::job::Job::event
+
+use alloc::vec::Vec;
+
+use crate::{
+    item::{ITEM_COOLDOWN, ITEM_LOCK},
+    job,
+    math::HitTypeHandle,
+    world::{
+        queue::RadixEventQueue,
+        reference::{World, WorldSink, MAX_MP},
+        status::StatusEffect,
+        Action, ActionEvent, ActionEventKind, ActorId, CastFailure, CriticalHit, DamageEvent,
+        DamageVariance, DirectHit, Event, EventError, EventRng,
+    },
+};
+
+/// The time in milliseconds between each actor and MP tick.
+pub const TICK: u32 = 3000;
+
+/// A simulation over a [`World`].
+///
+/// # Examples
+/// ```
+/// # use xivc_core::{
+/// #     enums::{Clan, Job},
+/// #     job::brd::BrdAction,
+/// #     math::{PlayerInfo, PlayerStats, WeaponInfo, XivMath},
+/// #     world::{
+/// #         reference::{Actor, Position, SampleRng, World},
+/// #         simulation::Simulation,
+/// #         Faction,
+/// #     },
+/// # };
+/// # use rand::rngs::mock::StepRng;
+/// # let math = XivMath::new(
+/// #     PlayerStats::default(100),
+/// #     WeaponInfo { wd: 132, auto: 13200, delay: 300 },
+/// #     PlayerInfo { clan: Clan::Wildwood, job: Job::BRD, lvl: 100 },
+/// # );
+/// let mut world = World::new();
+/// let dummy = world.add_actor(Actor::new(Faction::Enemy).hitbox(500));
+/// let bard = world.add_actor(Actor::player(math).position(Position::new(0, -1000)).target(dummy));
+///
+/// let mut sim = Simulation::new(world, SampleRng(StepRng::new(0, 0x9E3779B97F4A7C15)));
+/// // cast Burst Shot right away
+/// sim.cast(bard, BrdAction::BurstShot, 0);
+/// // run the simulation for 10 seconds
+/// sim.run(10000, |_, _, result| assert!(result.is_ok()));
+///
+/// assert_eq!(sim.time(), 10000);
+/// ```
+///
+/// Every enemy caught in an area of effect action takes damage, not just the target.
+/// ```
+/// # use xivc_core::{
+/// #     enums::{Clan, Job},
+/// #     job::{blm::BlmAction, rdm::RdmAction, sge::SgeAction, smn::SmnAction, Action},
+/// #     math::{PlayerInfo, PlayerStats, WeaponInfo, XivMath},
+/// #     world::{
+/// #         self,
+/// #         reference::{Actor, Position, SampleRng, World},
+/// #         simulation::Simulation,
+/// #         Event, Faction,
+/// #     },
+/// # };
+/// # use rand::rngs::mock::StepRng;
+/// let casts: [(Job, Action); 4] = [
+///     (Job::BLM, BlmAction::HighFireII.into()),
+///     (Job::SMN, SmnAction::Tridisaster.into()),
+///     (Job::RDM, RdmAction::VerthunderII.into()),
+///     (Job::SGE, SgeAction::Psyche.into()),
+/// ];
+/// for (job, action) in casts {
+/// #   let math = XivMath::new(
+/// #       PlayerStats::default(100),
+/// #       WeaponInfo { wd: 132, auto: 13200, delay: 300 },
+/// #       PlayerInfo { clan: Clan::Wildwood, job, lvl: 100 },
+/// #   );
+///     let mut world = World::new();
+///     let dummy = world.add_actor(Actor::new(Faction::Enemy).hitbox(500));
+///     let other = world.add_actor(Actor::new(Faction::Enemy).position(Position::new(200, 0)));
+///     let caster = world.add_actor(Actor::player(math).position(Position::new(0, -1000)).target(dummy));
+///
+///     let mut sim = Simulation::new(world, SampleRng(StepRng::new(0, 0x9E3779B97F4A7C15)));
+///     sim.cast(caster, world::Action::Job(action), 0);
+///     let mut damage = 0;
+///     sim.run(5000, |_, event, result| {
+///         assert!(result.is_ok());
+///         match event {
+///             Event::Damage(event) if event.target == other => damage += event.damage,
+///             _ => (),
+///         }
+///     });
+///
+///     assert!(damage > 0, "'{}' did not damage the second enemy", action.name());
+/// }
+/// ```
+#[derive(Clone, Debug)]
+pub struct Simulation<R> {
+    world: World,
+    queue: RadixEventQueue<Event>,
+    rng: R,
+    // the current time of the simulation.
+    // this can be ahead of the time of the queue if `run` stopped before an event.
+    time: u32,
+    // the action that last applied a damage over time effect
+    dots: Vec<(ActorId, StatusEffect, Action)>,
+}
+
+impl<R: EventRng> Simulation<R> {
+    /// Creates a new simulation over a world.
+    ///
+    /// The actor and MP ticks for every actor will be scheduled at
+    /// the start of the simulation, and will then repeat every [`TICK`].
+    pub fn new(world: World, rng: R) -> Self {
+        let mut queue = RadixEventQueue::new();
+        for (id, actor) in world.actors() {
+            queue.push(0, Event::ActorTick(id));
+            if actor.player.is_some() {
+                queue.push(0, Event::MpTick(id));
+            }
+        }
+        Self {
+            world,
+            queue,
+            rng,
+            time: 0,
+            dots: Vec::new(),
+        }
+    }
+
+    /// Returns a reference to the world of the simulation.
+    pub fn world(&self) -> &World {
+        &self.world
+    }
+
+    /// Returns a mutable reference to the world of the simulation.
+    pub fn world_mut(&mut self) -> &mut World {
+        &mut self.world
+    }
+
+    /// Returns the [current time] of the simulation.
+    ///
+    /// [current time]: super::queue#current
+    pub fn time(&self) -> u32 {
+        self.time
+    }
+
+    /// Submits an event to be executed after a delay relative to the current time.
+    pub fn event(&mut self, event: Event, delay: u32) {
+        self.queue.push(self.time + delay, event);
+    }
+
+    /// Submits an action to be cast by an actor after a delay relative to the current time.
+    ///
+    /// If the action does not belong to the job of the actor,
+    /// the cast will fail with [`EventError::WrongJob`].
+    pub fn cast(&mut self, actor: ActorId, action: impl Into<Action>, delay: u32) {
+        self.event(
+            ActionEvent::new(action.into(), actor, ActionEventKind::Prepare).into(),
+            delay,
+        );
+    }
+
+    /// Executes the next event in the queue.
+    ///
+    /// Returns the time and the event that was executed, along with the
+    /// result of executing it, or [`None`] if the queue is empty.
+    pub fn step(&mut self) -> Option<(u32, Event, Result<(), CastFailure>)> {
+        let (time, event) = self.queue.pop()?;
+        let result = self.execute(time, &event);
+        Some((time, event, result))
+    }
+
+    /// Executes events until the queue is empty or the next event would happen after `limit`.
+    ///
+    /// The function `f` is called with the time, event, and result of every executed event.
+    /// Afterwards, the current time of the simulation will be `limit`.
+    pub fn run<F>(&mut self, limit: u32, mut f: F)
+    where
+        F: FnMut(u32, &Event, Result<(), CastFailure>),
+    {
+        while self.queue.peek_time().is_some_and(|time| time <= limit) {
+            // the queue was just checked to not be empty
+            let (time, event) = self.queue.pop().unwrap();
+            let result = self.execute(time, &event);
+            f(time, &event, result);
+        }
+        if limit > self.time {
+            self.world.advance(limit - self.time);
+            self.time = limit;
+        }
+    }
+
+    fn execute(&mut self, time: u32, event: &Event) -> Result<(), CastFailure> {
+        self.world.advance(time - self.time);
+        self.time = time;
+
+        let result = match event {
+            Event::Action(event) => match event.kind {
+                ActionEventKind::Prepare => self.prepare(event),
+                ActionEventKind::Cast => self
+                    .cast_snap(event)
+                    .map_err(|e| CastFailure::new(event.action, event.source, e)),
+            },
+            Event::Damage(event) => {
+                for id in [event.source, event.target] {
+                    if let Some(actor) = self.world.get_mut(id) {
+                        actor.in_combat = true;
+                    }
+                }
+                Ok(())
+            }
+            Event::Heal(_) => Ok(()),
+            Event::Status(event) => {
+                self.world.apply_status_event(event);
+                Ok(())
+            }
+            Event::Job(..) => Ok(()),
+            Event::AdvCd(group, time, id) => {
+                if let Some(cd) = self
+                    .world
+                    .get_mut(*id)
+                    .and_then(|v| v.player.as_mut())
+                    .and_then(|v| v.cds.get_mut(*group))
+                {
+                    cd.advance(*time);
+                }
+                Ok(())
+            }
+            Event::AddMp(mp, id) => {
+                if let Some(player) = self.world.get_mut(*id).and_then(|v| v.player.as_mut()) {
+                    player.mp = player.mp.saturating_add(*mp).min(MAX_MP);
+                }
+                Ok(())
+            }
+            Event::MpTick(id) => {
+                if let Some(player) = self.world.get_mut(*id).and_then(|v| v.player.as_mut()) {
+                    let regen = player.math.mp_regen();
+                    let regen = match player.job.effect(&player.state) {
+                        Some(effect) => effect.mp_regen(regen),
+                        None => regen,
+                    } as u16;
+                    player.mp = player.mp.saturating_add(regen).min(MAX_MP);
+                }
+                self.event(Event::MpTick(*id), TICK);
+                Ok(())
+            }
+            Event::ActorTick(id) => {
+                self.dot_tick(*id);
+                self.event(Event::ActorTick(*id), TICK);
+                Ok(())
+            }
+        };
+
+        // jobs only get to see casts that actually happened
+        if result.is_ok() {
+            self.broadcast(event);
+        }
+        result
+    }
+
+    fn prepare(&mut self, event: &ActionEvent) -> Result<(), CastFailure> {
+        let action = match event.action {
+            Action::Job(action) => action,
+            Action::Item(_) => return self.prepare_item(event),
+        };
+        let world = &self.world;
+        let Some(player) = world.get(event.source).and_then(|v| v.player.as_ref()) else {
+            return Ok(());
+        };
+        let failure = |error| CastFailure::new(event.action, event.source, error);
+        if player.lock > 0 {
+            return Err(failure(EventError::Lock).remaining(player.lock));
+        }
+        if action.gcd() && player.gcd > 0 {
+            return Err(failure(EventError::Gcd).remaining(player.gcd));
+        }
+
+        // the events are only submitted once the cast is known to go through
+        let mut sink = WorldSink::new(world, event.source, &mut self.rng);
+        let info = player
+            .job
+            .check_cast(action, &player.state, &world, &mut sink)
+            .map_err(failure)?;
+        for (group, cd, charges) in info.cd.iter().chain(&info.alt_cd) {
+            match player.cds.get(*group).map(|v| v.cd_until(*cd, *charges)) {
+                Some(0) => (),
+                Some(remaining) => {
+                    return Err(failure(EventError::Cooldown(event.action)).remaining(remaining))
+                }
+                None => return Err(failure(EventError::Cooldown(event.action))),
+            }
+        }
+        if player.mp < info.mp {
+            return Err(failure(EventError::Mp(info.mp)));
+        }
+        sink.submit(&mut self.queue);
+
+        let player = self
+            .world
+            .get_mut(event.source)
+            .and_then(|v| v.player.as_mut())
+            .unwrap();
+        if info.gcd > 0 {
+            player.gcd = info.gcd as u32;
+        }
+        player.lock = info.lock as u32;
+        player.mp = player.mp.saturating_sub(info.mp);
+        for (group, cd, charges) in info.cd.into_iter().chain(info.alt_cd) {
+            if let Some(v) = player.cds.get_mut(group) {
+                v.apply(cd, charges);
+            }
+        }
+
+        self.event(
+            ActionEvent::new(event.action, event.source, ActionEventKind::Cast).into(),
+            info.snap as u32,
+        );
+        Ok(())
+    }
+
+    // items do not go through the job of the player,
+    // and instead share a single cooldown between all of them.
+    fn prepare_item(&mut self, event: &ActionEvent) -> Result<(), CastFailure> {
+        let Some(player) = self
+            .world
+            .get_mut(event.source)
+            .and_then(|v| v.player.as_mut())
+        else {
+            return Ok(());
+        };
+        let failure = |error| CastFailure::new(event.action, event.source, error);
+        if player.lock > 0 {
+            return Err(failure(EventError::Lock).remaining(player.lock));
+        }
+        match player.item_cd.cd_until(ITEM_COOLDOWN, 1) {
+            0 => (),
+            remaining => {
+                return Err(failure(EventError::Cooldown(event.action)).remaining(remaining))
+            }
+        }
+
+        player.lock = (ITEM_LOCK + player.math.ex_lock) as u32;
+        player.item_cd.apply(ITEM_COOLDOWN, 1);
+
+        self.event(
+            ActionEvent::new(event.action, event.source, ActionEventKind::Cast).into(),
+            0,
+        );
+        Ok(())
+    }
+
+    fn cast_snap(&mut self, event: &ActionEvent) -> Result<(), EventError> {
+        let world = &self.world;
+        let Some(player) = world.get(event.source).and_then(|v| v.player.as_ref()) else {
+            return Ok(());
+        };
+        let mut sink = WorldSink::new(world, event.source, &mut self.rng);
+        let action = match event.action {
+            Action::Job(action) => action,
+            Action::Item(item) => {
+                item.cast_snap(&mut sink);
+                sink.submit(&mut self.queue);
+                return Ok(());
+            }
+        };
+
+        // the state is taken out of the world so that the job can mutate it
+        // while still having read access to the rest of the world.
+        // if the cast fails, both the new state and the events are thrown away.
+        let mut state = player.state.clone();
+        player
+            .job
+            .cast_snap(action, &mut state, &world, &mut sink)?;
+
+        for &status in sink.applied_dots() {
+            let key = (event.source, status);
+            match self.dots.iter_mut().find(|v| (v.0, v.1) == key) {
+                Some(v) => v.2 = event.action,
+                None => self.dots.push((event.source, status, event.action)),
+            }
+        }
+        sink.submit(&mut self.queue);
+        self.set_state(event.source, state);
+        Ok(())
+    }
+
+    // deals the damage for the damage over time effects on an actor
+    fn dot_tick(&mut self, id: ActorId) {
+        let Some(actor) = self.world.get(id) else {
+            return;
+        };
+        for entry in &actor.statuses {
+            let Some(snapshot) = entry.snapshot else {
+                continue;
+            };
+            let source = entry.instance.source;
+            let Some(&(.., action)) = self
+                .dots
+                .iter()
+                .find(|v| v.0 == source && v.1 == entry.instance.effect)
+            else {
+                continue;
+            };
+            let crit: HitTypeHandle = self.rng.random(CriticalHit::new(snapshot.crit_chance));
+            let dhit: HitTypeHandle = self.rng.random(DirectHit::new(snapshot.dhit_chance));
+            let rand: u64 = self.rng.random(DamageVariance::new());
+            let damage = snapshot.eot_result(crit, dhit, rand);
+            self.queue.push(
+                self.time,
+                DamageEvent::new(damage, source, id, action).into(),
+            );
+        }
+    }
+
+    // passes an event along to the jobs of every player in the world
+    fn broadcast(&mut self, event: &Event) {
+        let players: Vec<_> = self
+            .world
+            .actors()
+            .filter(|(_, v)| v.player.is_some())
+            .map(|(id, _)| id)
+            .collect();
+        for id in players {
+            let world = &self.world;
+            let player = world.get(id).and_then(|v| v.player.as_ref()).unwrap();
+            let mut state = player.state.clone();
+            let mut sink = WorldSink::new(world, id, &mut self.rng);
+            player.job.event(&mut state, &world, event, &mut sink);
+            sink.submit(&mut self.queue);
+            self.set_state(id, state);
+        }
+    }
+
+    fn set_state(&mut self, id: ActorId, state: job::State) {
+        if let Some(player) = self.world.get_mut(id).and_then(|v| v.player.as_mut()) {
+            player.state = state;
+        }
+    }
+}
@@ -226,20 +226,27 @@ pub trait JobEffect: Debug {
     fn haste(&self) -> u64 {
         100
     }
+    /// The modifier for the MP regenerated on each natural MP tick.
+    fn mp_regen(&self, regen: u64) -> u64 {
+        regen
+    }
 }
 
-impl<'a> JobEffect for &'a dyn JobEffect {
+impl JobEffect for &dyn JobEffect {
     fn damage(&self, damage: u64, dmg_ty: DamageType, dmg_el: DamageElement) -> u64 {
-        <dyn JobEffect>::damage(self, damage, dmg_ty, dmg_el)
+        <dyn JobEffect>::damage(*self, damage, dmg_ty, dmg_el)
     }
     fn crit(&self) -> u64 {
-        <dyn JobEffect>::crit(self)
+        <dyn JobEffect>::crit(*self)
     }
     fn dhit(&self) -> u64 {
-        <dyn JobEffect>::dhit(self)
+        <dyn JobEffect>::dhit(*self)
     }
     fn haste(&self) -> u64 {
-        <dyn JobEffect>::haste(self)
+        <dyn JobEffect>::haste(*self)
+    }
+    fn mp_regen(&self, regen: u64) -> u64 {
+        <dyn JobEffect>::mp_regen(*self, regen)
     }
 }
 
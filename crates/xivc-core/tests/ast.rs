@@ -0,0 +1,88 @@
+#[macro_use]
+mod common;
+
+use common::Harness;
+use xivc_core::{
+    enums::Job,
+    job::{
+        ast::{AstAction::*, AstError, MinorArcana, Star, DIVINING},
+        CastError,
+    },
+    world::EventError,
+};
+
+#[test]
+fn cards() {
+    let mut h = Harness::new(Job::AST);
+
+    assert!(matches!(
+        h.cast(UmbralDraw),
+        Err(EventError::Job(CastError::Ast(AstError::Draw(AstralDraw)))),
+    ));
+    assert!(matches!(
+        h.cast(Play),
+        Err(EventError::Job(CastError::Ast(AstError::Card))),
+    ));
+
+    h.ok(AstralDraw);
+    assert!(state!(h, Ast).umbral_draw);
+    assert_eq!(state!(h, Ast).minor_arcana, Some(MinorArcana::LordOfCrowns));
+    let Some(card) = state!(h, Ast).card else {
+        panic!("no card was drawn");
+    };
+    // with no party member targeted, the card is played on the caster
+    h.ok(Play);
+    h.wait(1000);
+    assert_eq!(state!(h, Ast).card, None);
+    assert!(h.has(card.status()));
+
+    assert!(matches!(
+        h.cast(LadyOfCrowns),
+        Err(EventError::Job(CastError::Ast(AstError::MinorArcana(
+            MinorArcana::LadyOfCrowns
+        )))),
+    ));
+    h.assert_falloff(LordOfCrowns, 100);
+    assert_eq!(state!(h, Ast).minor_arcana, None);
+}
+
+#[test]
+fn earthly_star() {
+    let mut h = Harness::new(Job::AST);
+
+    assert!(matches!(
+        h.cast(StellarDetonation),
+        Err(EventError::Job(CastError::Ast(AstError::EarthlyStar))),
+    ));
+    h.ok(EarthlyStar);
+    assert_eq!(state!(h, Ast).star, Some(Star { id: 0, mature: false }));
+    h.wait(11000);
+    assert_eq!(state!(h, Ast).star, Some(Star { id: 0, mature: true }));
+    h.assert_falloff(StellarDetonation, 100);
+    assert_eq!(state!(h, Ast).star, None);
+
+    // an earthly star that is not detonated explodes on its own
+    h.wait(60000);
+    h.ok(EarthlyStar);
+    h.clear();
+    h.wait(21000);
+    assert_eq!(state!(h, Ast).star, None);
+    assert!(h.damage(h.target) > 0);
+    assert!(h.damage(h.other) > 0);
+}
+
+#[test]
+fn falloff() {
+    let mut h = Harness::new(Job::AST);
+
+    h.assert_falloff(GravityII, 100);
+    assert!(matches!(
+        h.cast(Oracle),
+        Err(EventError::Job(CastError::Ast(AstError::Divining))),
+    ));
+    h.ok(Divination);
+    h.wait(1000);
+    assert!(h.has(DIVINING));
+    h.assert_falloff(Oracle, 100);
+    assert!(!h.has(DIVINING));
+}
@@ -0,0 +1,85 @@
+#[macro_use]
+mod common;
+
+use common::Harness;
+use xivc_core::{
+    enums::Job,
+    job::{
+        blm::{BlmAction::*, BlmError, Enochian, THUNDERHEAD},
+        CastError,
+    },
+    world::EventError,
+};
+
+#[test]
+fn enochian() {
+    let mut h = Harness::new(Job::BLM);
+
+    assert!(matches!(
+        h.cast(FireIV),
+        Err(EventError::Job(CastError::Blm(BlmError::AstralFire))),
+    ));
+    assert!(matches!(
+        h.cast(Transpose),
+        Err(EventError::Job(CastError::Blm(BlmError::Enochian))),
+    ));
+
+    h.ok(BlizzardIII);
+    assert_eq!(state!(h, Blm).enochian, Enochian::UmbralIce(3));
+    h.wait(1000);
+    assert!(h.has(THUNDERHEAD));
+    h.ok(BlizzardIV);
+    assert_eq!(state!(h, Blm).umbral_hearts, 3);
+
+    // swapping from full umbral ice with full umbral hearts enables paradox
+    h.ok(FireIII);
+    assert_eq!(state!(h, Blm).enochian, Enochian::AstralFire(3));
+    assert!(state!(h, Blm).paradox);
+    h.ok(FireIV);
+    assert_eq!(state!(h, Blm).umbral_hearts, 2);
+    assert_eq!(state!(h, Blm).astral_soul, 1);
+
+    h.ok(Transpose);
+    assert_eq!(state!(h, Blm).enochian, Enochian::UmbralIce(1));
+    assert_eq!(state!(h, Blm).astral_soul, 0);
+}
+
+#[test]
+fn polyglot() {
+    let mut h = Harness::new(Job::BLM);
+
+    assert!(matches!(
+        h.cast(Foul),
+        Err(EventError::Job(CastError::Blm(BlmError::Polyglot(1)))),
+    ));
+    h.ok(BlizzardIII);
+    h.ok(Amplifier);
+    assert_eq!(state!(h, Blm).polyglot, 1);
+    h.assert_falloff(Foul, 40);
+    assert_eq!(state!(h, Blm).polyglot, 0);
+}
+
+#[test]
+fn falloff() {
+    let mut h = Harness::new(Job::BLM);
+
+    h.ok(BlizzardIII);
+    h.assert_falloff(HighThunderII, 100);
+    h.assert_falloff(Freeze, 100);
+    h.ok(FireIII);
+    h.assert_falloff(HighFireII, 100);
+
+    h.assert_falloff(Flare, 30);
+    assert_eq!(state!(h, Blm).astral_soul, 3);
+    assert_eq!(h.mp(), 0);
+    assert!(matches!(
+        h.cast(FlareStar),
+        Err(EventError::Job(CastError::Blm(BlmError::AstralSoul(6)))),
+    ));
+    h.ok(Manafont);
+    h.wait(1000);
+    h.ok(Flare);
+    assert_eq!(state!(h, Blm).astral_soul, 6);
+    h.assert_falloff(FlareStar, 35);
+    assert_eq!(state!(h, Blm).astral_soul, 0);
+}
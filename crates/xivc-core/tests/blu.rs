@@ -0,0 +1,112 @@
+#[macro_use]
+mod common;
+
+use common::Harness;
+use xivc_core::{
+    enums::Job,
+    job::{
+        blu::{
+            BluAction::{self, *},
+            BluError, BluState, LOADOUT_SIZE, SURPANAKHAS_FURY, TINGLING,
+        },
+        CastError, State,
+    },
+    world::EventError,
+};
+
+const LOADOUT: [BluAction; 12] = [
+    SonicBoom,
+    Tingle,
+    TripleTrident,
+    MoonFlute,
+    Bristle,
+    Whistle,
+    Nightbloom,
+    Surpanakha,
+    ShockStrike,
+    JKick,
+    PhantomFlurry,
+    MatraMagic,
+];
+
+// a blue mage with every spell in `LOADOUT` loaded
+fn harness() -> Harness {
+    let mut h = Harness::new(Job::BLU);
+    let mut loadout = [None; LOADOUT_SIZE];
+    for (slot, action) in loadout.iter_mut().zip(LOADOUT) {
+        *slot = Some(action);
+    }
+    *h.state_mut() = State::Blu(BluState::new(loadout));
+    h
+}
+
+#[test]
+fn loadout() {
+    let mut h = Harness::new(Job::BLU);
+
+    assert!(matches!(
+        h.cast(SonicBoom),
+        Err(EventError::Job(CastError::Blu(BluError::NotLoaded(SonicBoom)))),
+    ));
+
+    let mut h = harness();
+    assert!(state!(h, Blu).is_loaded(SonicBoom));
+    assert!(matches!(
+        h.cast(SongOfTorment),
+        Err(EventError::Job(CastError::Blu(BluError::NotLoaded(SongOfTorment)))),
+    ));
+    h.ok(SonicBoom);
+}
+
+#[test]
+fn buffs() {
+    let mut h = harness();
+
+    h.ok(SonicBoom);
+    h.wait(1500);
+    let base = h.damage(h.target);
+
+    // boost increases the damage of the next damage spell by 50%
+    h.ok(Bristle);
+    h.clear();
+    h.ok(SonicBoom);
+    h.wait(1500);
+    let boosted = h.damage(h.target);
+    assert!(boosted.abs_diff(base * 3 / 2) <= base / 50 + 1);
+
+    h.assert_falloff(Tingle, 100);
+    assert!(h.has(TINGLING));
+    h.ok(TripleTrident);
+    h.wait(1000);
+    assert!(!h.has(TINGLING));
+}
+
+#[test]
+fn moon_flute() {
+    let mut h = harness();
+
+    h.ok(MoonFlute);
+    h.wait(16000);
+    assert!(matches!(
+        h.cast(SonicBoom),
+        Err(EventError::Job(CastError::Blu(BluError::WaningNocturne))),
+    ));
+    h.wait(15000);
+    h.ok(SonicBoom);
+}
+
+#[test]
+fn falloff() {
+    let mut h = harness();
+
+    h.assert_falloff(Nightbloom, 100);
+    h.assert_falloff(ShockStrike, 100);
+    h.assert_falloff(JKick, 100);
+    h.assert_falloff(PhantomFlurry, 100);
+
+    for stack in 1..=3 {
+        h.assert_falloff(Surpanakha, 100);
+        let fury = h.status(h.player, SURPANAKHAS_FURY);
+        assert_eq!(fury.map(|v| v.stack), Some(stack));
+    }
+}
@@ -0,0 +1,221 @@
+//! Shared setup for the job behavior tests.
+//!
+//! Every test runs a [`Simulation`] over a [`World`] with a single player
+//! and two enemies. The player stands behind the main target, and the
+//! second enemy stands between them, so it is inside the range of every
+//! melee action and every area of effect shape.
+//!
+//! The random number generator always returns the middle of its range,
+//! so no hit is ever a critical or direct hit and the damage variance is always
+//! the same, which makes the damage of two hits directly comparable.
+
+#![allow(dead_code)]
+
+use rand::rngs::mock::StepRng;
+use xivc_core::{
+    enums::{Clan, Job},
+    job::{self, State},
+    math::{PlayerInfo, PlayerStats, WeaponInfo, XivMath},
+    world::{
+        self,
+        reference::{Actor, Player, Position, SampleRng, World},
+        simulation::Simulation,
+        status::{StatusEffect, StatusInstance},
+        ActionEventKind, ActorId, ActorRef, DamageEvent, Event, EventError, Faction, WorldRef,
+    },
+};
+
+// returns the middle of the range for both `u32` and `u64` samples
+const MIDDLE: u64 = 0x8000_0000_8000_0000;
+
+/// A simulation of a single player fighting two enemies.
+pub struct Harness {
+    /// The simulation being run.
+    pub sim: Simulation<SampleRng<StepRng>>,
+    /// The player.
+    pub player: ActorId,
+    /// The main target of the player.
+    pub target: ActorId,
+    /// The enemy standing next to the main target.
+    pub other: ActorId,
+    // every hit dealt since the last time they were cleared
+    hits: Vec<DamageEvent>,
+}
+
+impl Harness {
+    /// Creates a new simulation for a player of a specific job at its maximum level.
+    pub fn new(job: Job) -> Self {
+        let lvl = job.max_level();
+        let math = XivMath::new(
+            PlayerStats::default(lvl),
+            WeaponInfo { wd: 132, auto: 13200, delay: 300 },
+            PlayerInfo { clan: Clan::Wildwood, job, lvl },
+        );
+
+        let mut world = World::new();
+        let target = world.add_actor(Actor::new(Faction::Enemy).hitbox(500));
+        let other = world.add_actor(Actor::new(Faction::Enemy).position(Position::new(0, -300)));
+        let player = world.add_actor(
+            Actor::player(math)
+                .position(Position::new(0, -700))
+                .target(target),
+        );
+
+        Self {
+            sim: Simulation::new(world, SampleRng(StepRng::new(MIDDLE, 0))),
+            player,
+            target,
+            other,
+            hits: Vec::new(),
+        }
+    }
+
+    /// Returns the player data.
+    pub fn data(&self) -> &Player {
+        self.sim
+            .world()
+            .get(self.player)
+            .and_then(|v| v.player.as_ref())
+            .unwrap()
+    }
+
+    /// Returns the job state of the player.
+    pub fn state(&self) -> &State {
+        &self.data().state
+    }
+
+    /// Returns a mutable reference to the job state of the player.
+    pub fn state_mut(&mut self) -> &mut State {
+        &mut self
+            .sim
+            .world_mut()
+            .get_mut(self.player)
+            .and_then(|v| v.player.as_mut())
+            .unwrap()
+            .state
+    }
+
+    /// Returns the instance of a status effect applied by the player on an actor.
+    pub fn status(&self, actor: ActorId, effect: StatusEffect) -> Option<StatusInstance> {
+        self.sim
+            .world()
+            .actor(actor)
+            .and_then(|v| v.get_status(effect, self.player))
+    }
+
+    /// Returns `true` if the player has applied a status effect on themself.
+    pub fn has(&self, effect: StatusEffect) -> bool {
+        self.status(self.player, effect).is_some()
+    }
+
+    /// Returns the MP of the player.
+    pub fn mp(&self) -> u16 {
+        self.data().mp
+    }
+
+    /// Waits until the player can use the action, then casts it.
+    ///
+    /// Returns once the action has been cast, or with the error that stopped it.
+    pub fn cast(&mut self, action: impl Into<job::Action>) -> Result<(), EventError> {
+        let action = action.into();
+        let data = self.data();
+        let wait = if action.gcd() {
+            data.lock.max(data.gcd)
+        } else {
+            data.lock
+        };
+        self.wait(wait);
+
+        let action = world::Action::Job(action);
+        self.sim.cast(self.player, action, 0);
+        loop {
+            let (_, event, result) = self.sim.step().expect("the queue is never empty");
+            if let Event::Damage(event) = event {
+                self.hits.push(event);
+            }
+            match event {
+                Event::Action(event) if event.source == self.player && event.action == action => {
+                    result.map_err(|v| v.error)?;
+                    if event.kind == ActionEventKind::Cast {
+                        return Ok(());
+                    }
+                }
+                _ => (),
+            }
+        }
+    }
+
+    /// Casts an action and panics if it fails.
+    #[track_caller]
+    pub fn ok(&mut self, action: impl Into<job::Action>) {
+        let action = action.into();
+        if let Err(e) = self.cast(action) {
+            panic!("'{}' failed: {}", action.name(), e);
+        }
+    }
+
+    /// Runs the simulation for a certain amount of time.
+    pub fn wait(&mut self, time: u32) {
+        let hits = &mut self.hits;
+        let limit = self.sim.time() + time;
+        self.sim.run(limit, |_, event, _| {
+            if let Event::Damage(event) = event {
+                hits.push(*event);
+            }
+        });
+    }
+
+    /// Returns the total damage dealt to an actor since the hits were last cleared.
+    pub fn damage(&self, actor: ActorId) -> u64 {
+        self.hits
+            .iter()
+            .filter(|v| v.target == actor)
+            .map(|v| v.damage)
+            .sum()
+    }
+
+    /// Forgets every hit dealt so far.
+    pub fn clear(&mut self) {
+        self.hits.clear();
+    }
+
+    /// Casts an action and checks that the second enemy takes
+    /// `falloff` percent of the damage that the main target takes.
+    #[track_caller]
+    pub fn assert_falloff(&mut self, action: impl Into<job::Action>, falloff: u64) {
+        let action = action.into();
+        self.ok(action);
+        // long enough for every damage application delay
+        self.wait(1500);
+        let damage = |actor| {
+            self.hits
+                .iter()
+                .filter(|v| v.target == actor && v.action == world::Action::Job(action))
+                .map(|v| v.damage)
+                .sum::<u64>()
+        };
+        let (target, other) = (damage(self.target), damage(self.other));
+        assert!(target > 0, "'{}' did not damage the target", action.name());
+        // the damage of each hit is rounded separately
+        let expected = target * falloff / 100;
+        assert!(
+            other.abs_diff(expected) <= expected / 50 + 1,
+            "'{}' dealt {} to the second enemy, expected {} ({}% of {})",
+            action.name(),
+            other,
+            expected,
+            falloff,
+            target,
+        );
+    }
+}
+
+/// Extracts the state of a specific job out of a [`State`].
+macro_rules! state {
+    ($h:expr, $var:ident) => {
+        match $h.state() {
+            xivc_core::job::State::$var(v) => v.clone(),
+            _ => unreachable!(),
+        }
+    };
+}
@@ -0,0 +1,91 @@
+#[macro_use]
+mod common;
+
+use common::Harness;
+use xivc_core::{
+    enums::Job,
+    job::{
+        drg::{AoeCombo, DrgAction::*, DrgError, MainCombo, DRACONIAN_FIRE, POWER_SURGE},
+        CastError,
+    },
+    world::EventError,
+};
+
+#[test]
+fn main_combo() {
+    let mut h = Harness::new(Job::DRG);
+
+    assert!(matches!(
+        h.cast(Drakesbane),
+        Err(EventError::Job(CastError::Drg(DrgError::Drakesbane))),
+    ));
+    h.ok(TrueThrust);
+    assert!(state!(h, Drg).combos.main.check(MainCombo::TrueThrust));
+    h.ok(LanceBarrage);
+    assert!(state!(h, Drg).combos.main.check(MainCombo::LanceBarrage));
+    h.ok(HeavensThrust);
+    assert!(state!(h, Drg).combos.main.check(MainCombo::HeavensThrust));
+    h.ok(FangAndClaw);
+    assert!(state!(h, Drg).combos.main.check(MainCombo::Finisher));
+    h.ok(Drakesbane);
+    assert_eq!(state!(h, Drg).combos.main.combo, None);
+    h.wait(1000);
+    assert!(h.has(DRACONIAN_FIRE));
+
+    // Raiden Thrust replaces True Thrust and builds focus
+    h.ok(RaidenThrust);
+    assert!(state!(h, Drg).combos.main.check(MainCombo::TrueThrust));
+    assert_eq!(state!(h, Drg).focus, 1);
+    h.ok(SpiralBlow);
+    assert!(state!(h, Drg).combos.main.check(MainCombo::SpiralBlow));
+    h.wait(1000);
+    assert!(h.has(POWER_SURGE));
+}
+
+#[test]
+fn aoe_combo() {
+    let mut h = Harness::new(Job::DRG);
+
+    h.ok(DoomSpike);
+    assert!(state!(h, Drg).combos.aoe.check(AoeCombo::DoomSpike));
+    h.ok(SonicThrust);
+    assert!(state!(h, Drg).combos.aoe.check(AoeCombo::SonicThrust));
+    h.ok(CoerthanTorment);
+    assert_eq!(state!(h, Drg).combos.aoe.combo, None);
+    h.wait(1000);
+    assert!(h.has(DRACONIAN_FIRE));
+    h.ok(DraconianFury);
+    assert_eq!(state!(h, Drg).focus, 1);
+    assert!(matches!(
+        h.cast(DraconianFury),
+        Err(EventError::Job(CastError::Drg(DrgError::DraconianFire))),
+    ));
+}
+
+#[test]
+fn falloff() {
+    let mut h = Harness::new(Job::DRG);
+
+    h.assert_falloff(DoomSpike, 100);
+    h.assert_falloff(Geirskogul, 50);
+    h.assert_falloff(Nastrond, 50);
+    h.assert_falloff(Stardiver, 50);
+    h.assert_falloff(Starcross, 50);
+    h.assert_falloff(DragonfireDive, 50);
+    h.assert_falloff(RiseOfTheDragon, 50);
+
+    assert!(matches!(
+        h.cast(WyrmwindThrust),
+        Err(EventError::Job(CastError::Drg(DrgError::Focus(2)))),
+    ));
+    for _ in 0..2 {
+        h.ok(TrueThrust);
+        h.ok(LanceBarrage);
+        h.ok(HeavensThrust);
+        h.ok(FangAndClaw);
+        h.ok(Drakesbane);
+        h.ok(RaidenThrust);
+    }
+    h.assert_falloff(WyrmwindThrust, 50);
+    assert_eq!(state!(h, Drg).focus, 0);
+}
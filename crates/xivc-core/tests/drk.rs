@@ -0,0 +1,109 @@
+#[macro_use]
+mod common;
+
+use common::Harness;
+use xivc_core::{
+    enums::Job,
+    job::{
+        drk::{DeliriumCombo, DrkAction::*, DrkError, MainCombo, SCORN},
+        CastError,
+    },
+    world::EventError,
+};
+
+#[test]
+fn main_combo() {
+    let mut h = Harness::new(Job::DRK);
+
+    h.ok(HardSlash);
+    assert!(state!(h, Drk).combos.main.check(MainCombo::HardSlash));
+    h.ok(SyphonStrike);
+    assert!(state!(h, Drk).combos.main.check(MainCombo::SyphonStrike));
+    h.ok(Souleater);
+    assert_eq!(state!(h, Drk).combos.main.combo, None);
+    assert_eq!(state!(h, Drk).blood, 20);
+
+    // Souleater without Syphon Strike does not build gauge
+    h.ok(Souleater);
+    assert_eq!(state!(h, Drk).blood, 20);
+}
+
+#[test]
+fn delirium_combo() {
+    let mut h = Harness::new(Job::DRK);
+
+    assert!(matches!(
+        h.cast(ScarletDelirium),
+        Err(EventError::Job(CastError::Drk(DrkError::Delirium))),
+    ));
+    h.ok(Delirium);
+    assert!(matches!(
+        h.cast(Comeuppance),
+        Err(EventError::Job(CastError::Drk(DrkError::DeliriumCombo(ScarletDelirium)))),
+    ));
+    h.ok(ScarletDelirium);
+    assert!(state!(h, Drk).combos.delirium.check(DeliriumCombo::ScarletDelirium));
+    h.ok(Comeuppance);
+    assert!(state!(h, Drk).combos.delirium.check(DeliriumCombo::Comeuppance));
+    h.ok(Torcleaver);
+    assert_eq!(state!(h, Drk).combos.delirium.combo, None);
+    // every Delirium combo action consumed a stack of Blood Weapon
+    assert_eq!(state!(h, Drk).blood, 30);
+}
+
+#[test]
+fn blood_gauge() {
+    let mut h = Harness::new(Job::DRK);
+
+    assert!(matches!(
+        h.cast(Bloodspiller),
+        Err(EventError::Job(CastError::Drk(DrkError::Blood(50)))),
+    ));
+    assert!(matches!(
+        h.cast(Shadowbringer),
+        Err(EventError::Job(CastError::Drk(DrkError::Darkside))),
+    ));
+
+    for _ in 0..3 {
+        h.ok(HardSlash);
+        h.ok(SyphonStrike);
+        h.ok(Souleater);
+    }
+    assert_eq!(state!(h, Drk).blood, 60);
+    h.ok(Bloodspiller);
+    assert_eq!(state!(h, Drk).blood, 10);
+}
+
+#[test]
+fn falloff() {
+    let mut h = Harness::new(Job::DRK);
+
+    h.assert_falloff(Unleash, 100);
+    h.assert_falloff(AbyssalDrain, 100);
+    h.ok(EdgeOfShadow);
+    assert!(state!(h, Drk).darkside > 0);
+    h.assert_falloff(Shadowbringer, 50);
+    h.ok(LivingShadow);
+    h.wait(1000);
+    assert!(h.has(SCORN));
+    h.assert_falloff(Disesteem, 50);
+}
+
+#[test]
+fn salted_earth() {
+    let mut h = Harness::new(Job::DRK);
+
+    assert!(matches!(
+        h.cast(SaltAndDarkness),
+        Err(EventError::Job(CastError::Drk(DrkError::SaltedEarth))),
+    ));
+    h.ok(SaltedEarth);
+    h.wait(15000);
+    assert!(h.damage(h.target) > 0);
+    assert!(h.damage(h.other) > 0);
+
+    // nothing ticks once the ground effect has expired
+    h.clear();
+    h.wait(6000);
+    assert_eq!(h.damage(h.target), 0);
+}
@@ -0,0 +1,80 @@
+#[macro_use]
+mod common;
+
+use common::Harness;
+use xivc_core::{
+    enums::Job,
+    job::{
+        gnb::{
+            GnashingCombo, GnbAction::*, GnbError, MainCombo, ReignCombo, READY_TO_BLAST,
+            READY_TO_RIP,
+        },
+        CastError,
+    },
+    world::EventError,
+};
+
+#[test]
+fn main_combo() {
+    let mut h = Harness::new(Job::GNB);
+
+    h.ok(KeenEdge);
+    assert!(state!(h, Gnb).combos.main.check(MainCombo::KeenEdge));
+    h.ok(BrutalShell);
+    assert!(state!(h, Gnb).combos.main.check(MainCombo::BrutalShell));
+    h.ok(SolidBarrel);
+    assert_eq!(state!(h, Gnb).combos.main.combo, None);
+    assert_eq!(state!(h, Gnb).cartridge, 1);
+
+    h.ok(BurstStrike);
+    assert_eq!(state!(h, Gnb).cartridge, 0);
+    h.wait(1000);
+    assert!(h.has(READY_TO_BLAST));
+    h.ok(Hypervelocity);
+    h.wait(1000);
+    assert!(!h.has(READY_TO_BLAST));
+}
+
+#[test]
+fn gnashing_combo() {
+    let mut h = Harness::new(Job::GNB);
+
+    assert!(matches!(
+        h.cast(GnashingFang),
+        Err(EventError::Job(CastError::Gnb(GnbError::Cartridge(1)))),
+    ));
+    h.ok(Bloodfest);
+    assert_eq!(state!(h, Gnb).cartridge, 3);
+
+    assert!(matches!(
+        h.cast(WickedTalon),
+        Err(EventError::Job(CastError::Gnb(GnbError::GnashingCombo(SavageClaw)))),
+    ));
+    h.ok(GnashingFang);
+    assert!(state!(h, Gnb).combos.gnashing.check(GnashingCombo::GnashingFang));
+    assert_eq!(state!(h, Gnb).cartridge, 2);
+    h.wait(1000);
+    assert!(h.has(READY_TO_RIP));
+    h.ok(JugularRip);
+    h.ok(SavageClaw);
+    assert!(state!(h, Gnb).combos.gnashing.check(GnashingCombo::SavageClaw));
+    h.ok(WickedTalon);
+    assert_eq!(state!(h, Gnb).combos.gnashing.combo, None);
+}
+
+#[test]
+fn falloff() {
+    let mut h = Harness::new(Job::GNB);
+
+    h.assert_falloff(DemonSlice, 100);
+    h.assert_falloff(DemonSlaughter, 100);
+    assert_eq!(state!(h, Gnb).cartridge, 1);
+    h.assert_falloff(DoubleDown, 85);
+
+    h.ok(Bloodfest);
+    h.assert_falloff(ReignOfBeasts, 40);
+    assert!(state!(h, Gnb).combos.reign.check(ReignCombo::ReignOfBeasts));
+    h.assert_falloff(NobleBlood, 40);
+    h.assert_falloff(LionHeart, 40);
+    assert_eq!(state!(h, Gnb).combos.reign.combo, None);
+}
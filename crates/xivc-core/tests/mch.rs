@@ -0,0 +1,106 @@
+#[macro_use]
+mod common;
+
+use common::Harness;
+use xivc_core::{
+    enums::Job,
+    job::{
+        mch::{MainCombo, MchAction::*, MchError, EXCAVATOR_READY, OVERHEATED},
+        CastError,
+    },
+    world::EventError,
+};
+
+#[test]
+fn main_combo() {
+    let mut h = Harness::new(Job::MCH);
+
+    h.ok(HeatedSplitShot);
+    assert!(state!(h, Mch).combos.main.check(MainCombo::SplitShot));
+    h.ok(HeatedSlugShot);
+    assert!(state!(h, Mch).combos.main.check(MainCombo::SlugShot));
+    h.ok(HeatedCleanShot);
+    assert_eq!(state!(h, Mch).combos.main.combo, None);
+    assert_eq!(state!(h, Mch).heat, 15);
+    assert_eq!(state!(h, Mch).battery, 10);
+
+    // a broken combo gives no heat
+    h.ok(HeatedSlugShot);
+    assert_eq!(state!(h, Mch).heat, 15);
+}
+
+#[test]
+fn hypercharge() {
+    let mut h = Harness::new(Job::MCH);
+
+    assert!(matches!(
+        h.cast(Hypercharge),
+        Err(EventError::Job(CastError::Mch(MchError::Heat(50)))),
+    ));
+    assert!(matches!(
+        h.cast(HeatBlast),
+        Err(EventError::Job(CastError::Mch(MchError::Overheated))),
+    ));
+
+    h.ok(BarrelStabilizer);
+    h.ok(Hypercharge);
+    h.wait(1000);
+    assert!(h.has(OVERHEATED));
+    assert!(matches!(
+        h.cast(Hypercharge),
+        Err(EventError::Job(CastError::Mch(MchError::Overheated))),
+    ));
+    for _ in 0..4 {
+        h.ok(HeatBlast);
+    }
+    h.assert_falloff(AutoCrossbow, 100);
+    h.wait(1000);
+    assert!(!h.has(OVERHEATED));
+    assert_eq!(state!(h, Mch).heat, 0);
+}
+
+#[test]
+fn queen() {
+    let mut h = Harness::new(Job::MCH);
+
+    assert!(matches!(
+        h.cast(AutomatonQueen),
+        Err(EventError::Job(CastError::Mch(MchError::Battery(50)))),
+    ));
+    h.ok(ChainSaw);
+    h.wait(1000);
+    assert!(h.has(EXCAVATOR_READY));
+    h.ok(Excavator);
+    h.ok(AirAnchor);
+    assert_eq!(state!(h, Mch).battery, 60);
+
+    h.ok(AutomatonQueen);
+    assert_eq!(state!(h, Mch).battery, 0);
+    assert!(state!(h, Mch).queen);
+    assert!(matches!(
+        h.cast(AutomatonQueen),
+        Err(EventError::Job(CastError::Mch(MchError::Queen))),
+    ));
+    h.clear();
+    h.wait(20000);
+    assert!(!state!(h, Mch).queen);
+    assert!(h.damage(h.target) > 0);
+    assert_eq!(h.damage(h.other), 0);
+}
+
+#[test]
+fn falloff() {
+    let mut h = Harness::new(Job::MCH);
+
+    h.assert_falloff(Scattergun, 100);
+    h.assert_falloff(ChainSaw, 35);
+    h.assert_falloff(Excavator, 35);
+    assert!(matches!(
+        h.cast(Excavator),
+        Err(EventError::Job(CastError::Mch(MchError::Excavator))),
+    ));
+    h.assert_falloff(DoubleCheck, 50);
+    h.assert_falloff(Checkmate, 50);
+    h.ok(BarrelStabilizer);
+    h.assert_falloff(FullMetalField, 50);
+}
@@ -0,0 +1,107 @@
+#[macro_use]
+mod common;
+
+use common::Harness;
+use xivc_core::{
+    enums::Job,
+    job::{
+        mnk::{MnkAction::*, MnkError, MnkForm, FORMLESS_FIST},
+        CastError,
+    },
+    world::EventError,
+};
+
+#[test]
+fn forms() {
+    let mut h = Harness::new(Job::MNK);
+
+    h.ok(DragonKick);
+    assert!(state!(h, Mnk).form.check(MnkForm::Raptor));
+    assert!(matches!(
+        h.cast(Demolish),
+        Err(EventError::Job(CastError::Mnk(MnkError::Form(MnkForm::Coeurl)))),
+    ));
+    h.ok(TwinSnakes);
+    assert!(state!(h, Mnk).form.check(MnkForm::Coeurl));
+    h.ok(Demolish);
+    assert!(state!(h, Mnk).form.check(MnkForm::OpoOpo));
+    assert_eq!(state!(h, Mnk).coeurl_fury, 2);
+
+    // an opo-opo action in opo-opo form always opens a chakra
+    h.ok(LeapingOpo);
+    assert_eq!(state!(h, Mnk).chakra, 1);
+    assert_eq!(state!(h, Mnk).opo_fury, 0);
+}
+
+#[test]
+fn chakra() {
+    let mut h = Harness::new(Job::MNK);
+
+    assert!(matches!(
+        h.cast(TheForbiddenChakra),
+        Err(EventError::Job(CastError::Mnk(MnkError::Chakra(5)))),
+    ));
+    // out of combat, meditation opens every chakra at once
+    h.ok(ForbiddenMeditation);
+    assert_eq!(state!(h, Mnk).chakra, 5);
+    h.ok(TheForbiddenChakra);
+    assert_eq!(state!(h, Mnk).chakra, 0);
+}
+
+#[test]
+fn masterful_blitz() {
+    let mut h = Harness::new(Job::MNK);
+
+    assert!(matches!(
+        h.cast(ElixirBurst),
+        Err(EventError::Job(CastError::Mnk(MnkError::BeastChakra))),
+    ));
+
+    h.ok(PerfectBalance);
+    for _ in 0..3 {
+        h.ok(LeapingOpo);
+    }
+    assert_eq!(state!(h, Mnk).beast_chakra.count(), 3);
+    assert!(matches!(
+        h.cast(RisingPhoenix),
+        Err(EventError::Job(CastError::Mnk(MnkError::Blitz(ElixirBurst)))),
+    ));
+    h.ok(ElixirBurst);
+    assert!(state!(h, Mnk).lunar_nadi);
+    assert_eq!(state!(h, Mnk).beast_chakra.count(), 0);
+
+    // perfect balance lets any form action be used
+    h.ok(PerfectBalance);
+    h.ok(Demolish);
+    h.ok(TwinSnakes);
+    h.ok(DragonKick);
+    assert_eq!(state!(h, Mnk).blitz(), Some(RisingPhoenix));
+    h.ok(RisingPhoenix);
+    assert!(state!(h, Mnk).solar_nadi);
+
+    h.wait(40000);
+    h.ok(PerfectBalance);
+    for _ in 0..3 {
+        h.ok(LeapingOpo);
+    }
+    assert_eq!(state!(h, Mnk).blitz(), Some(PhantomRush));
+    h.assert_falloff(PhantomRush, 50);
+    assert!(!state!(h, Mnk).lunar_nadi);
+    assert!(!state!(h, Mnk).solar_nadi);
+}
+
+#[test]
+fn falloff() {
+    let mut h = Harness::new(Job::MNK);
+
+    h.assert_falloff(ShadowOfTheDestroyer, 100);
+    h.ok(RiddleOfFire);
+    h.assert_falloff(FiresReply, 50);
+    assert!(h.has(FORMLESS_FIST));
+    h.ok(RiddleOfWind);
+    h.assert_falloff(WindsReply, 50);
+    assert!(matches!(
+        h.cast(WindsReply),
+        Err(EventError::Job(CastError::Mnk(MnkError::WindsRumination))),
+    ));
+}
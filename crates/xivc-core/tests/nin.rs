@@ -0,0 +1,132 @@
+#[macro_use]
+mod common;
+
+use common::Harness;
+use xivc_core::{
+    enums::Job,
+    job::{
+        nin::{MainCombo, NinAction::*, NinError, RAIJU_READY, SHADOW_WALKER, TEN_CHI_JIN},
+        CastError,
+    },
+    world::EventError,
+};
+
+#[test]
+fn main_combo() {
+    let mut h = Harness::new(Job::NIN);
+
+    h.ok(SpinningEdge);
+    assert!(state!(h, Nin).combos.main.check(MainCombo::SpinningEdge));
+    h.ok(GustSlash);
+    assert!(state!(h, Nin).combos.main.check(MainCombo::GustSlash));
+    h.ok(ArmorCrush);
+    assert_eq!(state!(h, Nin).combos.main.combo, None);
+    assert_eq!(state!(h, Nin).ninki, 25);
+    assert_eq!(state!(h, Nin).kazematoi, 2);
+
+    h.ok(SpinningEdge);
+    h.ok(GustSlash);
+    h.ok(AeolianEdge);
+    assert_eq!(state!(h, Nin).ninki, 50);
+    assert_eq!(state!(h, Nin).kazematoi, 1);
+}
+
+#[test]
+fn mudras() {
+    let mut h = Harness::new(Job::NIN);
+
+    assert!(matches!(
+        h.cast(Raiton),
+        Err(EventError::Job(CastError::Nin(NinError::Mudra))),
+    ));
+
+    h.ok(Ten);
+    h.ok(Chi);
+    h.ok(Raiton);
+    h.wait(1000);
+    assert!(h.has(RAIJU_READY));
+    h.ok(FleetingRaiju);
+    assert_eq!(state!(h, Nin).ninki, 5);
+
+    h.ok(Ten);
+    h.ok(Chi);
+    h.ok(Jin);
+    h.ok(Suiton);
+    h.wait(1000);
+    assert!(h.has(SHADOW_WALKER));
+    h.ok(Meisui);
+    assert_eq!(state!(h, Nin).ninki, 55);
+
+    // repeating a mudra fails the sequence
+    h.wait(20000);
+    h.ok(Ten);
+    h.ok(Ten);
+    assert!(state!(h, Nin).mudra.failed);
+    h.clear();
+    h.ok(FumaShuriken);
+    h.wait(1000);
+    assert_eq!(h.damage(h.target), 0);
+}
+
+#[test]
+fn ten_chi_jin() {
+    let mut h = Harness::new(Job::NIN);
+
+    h.ok(TenChiJin);
+    h.ok(Ten);
+    assert!(matches!(
+        h.cast(Ten),
+        Err(EventError::Job(CastError::Nin(NinError::TenChiJin))),
+    ));
+    h.ok(Chi);
+    h.ok(Jin);
+    h.wait(1000);
+    assert!(!h.has(TEN_CHI_JIN));
+    assert_eq!(state!(h, Nin).mudra.count(), 0);
+}
+
+#[test]
+fn falloff() {
+    let mut h = Harness::new(Job::NIN);
+
+    h.ok(Chi);
+    h.ok(Ten);
+    h.assert_falloff(Katon, 100);
+
+    assert!(matches!(
+        h.cast(Bunshin),
+        Err(EventError::Job(CastError::Nin(NinError::Ninki(50)))),
+    ));
+    h.ok(Dokumori);
+    h.ok(SpinningEdge);
+    h.ok(GustSlash);
+    h.ok(Bunshin);
+    assert_eq!(state!(h, Nin).ninki, 0);
+    // only the main target takes more damage from Dokumori
+    h.wait(20000);
+    h.assert_falloff(PhantomKamaitachi, 50);
+
+    h.ok(Ten);
+    h.ok(Chi);
+    h.ok(Jin);
+    h.ok(Suiton);
+    h.ok(Meisui);
+    h.assert_falloff(HellfrogMedium, 100);
+}
+
+#[test]
+fn doton() {
+    let mut h = Harness::new(Job::NIN);
+
+    h.ok(Ten);
+    h.ok(Jin);
+    h.ok(Chi);
+    h.ok(Doton);
+    h.wait(18000);
+    assert!(h.damage(h.target) > 0);
+    assert!(h.damage(h.other) > 0);
+
+    h.clear();
+    h.wait(6000);
+    assert_eq!(h.damage(h.target), 0);
+}
@@ -0,0 +1,132 @@
+#[macro_use]
+mod common;
+
+use common::Harness;
+use xivc_core::{
+    enums::Job,
+    job::{
+        pct::{
+            Canvas, Creature, HammerCombo, PctAction::*, PctError, Portrait, HAMMER_TIME,
+            MONOCHROME_TONES, STARSTRUCK,
+        },
+        CastError,
+    },
+    world::EventError,
+};
+
+#[test]
+fn aetherhues() {
+    let mut h = Harness::new(Job::PCT);
+
+    assert!(matches!(
+        h.cast(AeroInGreen),
+        Err(EventError::Job(CastError::Pct(PctError::Aetherhue(FireInRed)))),
+    ));
+    h.assert_falloff(FireInRed, 30);
+    h.assert_falloff(AeroInGreen, 30);
+    h.assert_falloff(WaterInBlue, 30);
+    assert_eq!(state!(h, Pct).palette, 25);
+    assert_eq!(state!(h, Pct).paint, 1);
+    h.assert_falloff(HolyInWhite, 30);
+    assert!(matches!(
+        h.cast(HolyInWhite),
+        Err(EventError::Job(CastError::Pct(PctError::WhitePaint))),
+    ));
+
+    assert!(matches!(
+        h.cast(SubtractivePalette),
+        Err(EventError::Job(CastError::Pct(PctError::Palette(50)))),
+    ));
+    h.ok(FireInRed);
+    h.ok(AeroInGreen);
+    h.ok(WaterInBlue);
+    h.ok(SubtractivePalette);
+    assert_eq!(state!(h, Pct).palette, 0);
+    assert!(matches!(
+        h.cast(FireInRed),
+        Err(EventError::Job(CastError::Pct(PctError::SubtractivePalette(true)))),
+    ));
+    h.assert_falloff(BlizzardInCyan, 30);
+    h.ok(StoneInYellow);
+    h.ok(ThunderInMagenta);
+    assert_eq!(state!(h, Pct).paint, 2);
+    assert!(h.has(MONOCHROME_TONES));
+    h.assert_falloff(CometInBlack, 30);
+    assert!(!h.has(MONOCHROME_TONES));
+    assert_eq!(state!(h, Pct).paint, 1);
+}
+
+#[test]
+fn creatures() {
+    let mut h = Harness::new(Job::PCT);
+
+    assert!(matches!(
+        h.cast(PomMuse),
+        Err(EventError::Job(CastError::Pct(PctError::Canvas(Canvas::Creature)))),
+    ));
+    assert!(matches!(
+        h.cast(WingMotif),
+        Err(EventError::Job(CastError::Pct(PctError::Creature(Creature::Pom)))),
+    ));
+    h.ok(PomMotif);
+    assert_eq!(state!(h, Pct).creature, Some(Creature::Pom));
+    assert!(matches!(
+        h.cast(WingMotif),
+        Err(EventError::Job(CastError::Pct(PctError::CanvasPainted(Canvas::Creature)))),
+    ));
+    h.assert_falloff(PomMuse, 30);
+    assert_eq!(state!(h, Pct).creature, None);
+
+    h.ok(WingMotif);
+    h.assert_falloff(WingedMuse, 30);
+    assert_eq!(state!(h, Pct).portrait, Some(Portrait::Moogle));
+    h.assert_falloff(MogOfTheAges, 30);
+    assert_eq!(state!(h, Pct).portrait, None);
+}
+
+#[test]
+fn hammer_combo() {
+    let mut h = Harness::new(Job::PCT);
+
+    assert!(matches!(
+        h.cast(StrikingMuse),
+        Err(EventError::Job(CastError::Pct(PctError::Canvas(Canvas::Weapon)))),
+    ));
+    h.ok(HammerMotif);
+    h.ok(StrikingMuse);
+    h.wait(100);
+    assert!(h.has(HAMMER_TIME));
+    assert!(matches!(
+        h.cast(HammerBrush),
+        Err(EventError::Job(CastError::Pct(PctError::Hammer(HammerStamp)))),
+    ));
+    h.assert_falloff(HammerStamp, 30);
+    assert!(state!(h, Pct).combos.hammer.check(HammerCombo::Stamp));
+    h.assert_falloff(HammerBrush, 30);
+    assert!(state!(h, Pct).combos.hammer.check(HammerCombo::Brush));
+    h.assert_falloff(PolishingHammer, 30);
+    assert_eq!(state!(h, Pct).combos.hammer.combo, None);
+    assert!(matches!(
+        h.cast(HammerStamp),
+        Err(EventError::Job(CastError::Pct(PctError::HammerTime))),
+    ));
+}
+
+#[test]
+fn starry_muse() {
+    let mut h = Harness::new(Job::PCT);
+
+    assert!(matches!(
+        h.cast(StarPrism),
+        Err(EventError::Job(CastError::Pct(PctError::Starstruck))),
+    ));
+    h.ok(StarrySkyMotif);
+    h.ok(StarryMuse);
+    h.wait(100);
+    assert!(h.has(STARSTRUCK));
+    // subtractive spectrum makes the next subtractive palette free
+    h.ok(SubtractivePalette);
+    h.assert_falloff(StarPrism, 30);
+    h.assert_falloff(RainbowDrip, 15);
+    assert_eq!(state!(h, Pct).paint, 1);
+}
@@ -0,0 +1,73 @@
+#[macro_use]
+mod common;
+
+use common::Harness;
+use xivc_core::{
+    enums::Job,
+    job::{
+        pld::{MainCombo, PldAction::*, PldError, ATONEMENT_READY, DIVINE_MIGHT, SUPPLICATION_READY},
+        CastError,
+    },
+    world::EventError,
+};
+
+#[test]
+fn main_combo() {
+    let mut h = Harness::new(Job::PLD);
+
+    h.ok(FastBlade);
+    assert!(state!(h, Pld).combos.main.check(MainCombo::FastBlade));
+    h.ok(RiotBlade);
+    assert!(state!(h, Pld).combos.main.check(MainCombo::RiotBlade));
+    h.ok(RoyalAuthority);
+    assert_eq!(state!(h, Pld).combos.main.combo, None);
+    h.wait(1000);
+    assert!(h.has(ATONEMENT_READY));
+    assert!(h.has(DIVINE_MIGHT));
+
+    // the atonement chain passes along its ready status
+    h.ok(Atonement);
+    h.wait(1000);
+    assert!(!h.has(ATONEMENT_READY));
+    assert!(h.has(SUPPLICATION_READY));
+}
+
+#[test]
+fn broken_combo() {
+    let mut h = Harness::new(Job::PLD);
+
+    // Riot Blade without Fast Blade resets the combo
+    h.ok(RiotBlade);
+    assert_eq!(state!(h, Pld).combos.main.combo, None);
+    h.ok(RoyalAuthority);
+    h.wait(1000);
+    assert!(!h.has(ATONEMENT_READY));
+    assert!(!h.has(DIVINE_MIGHT));
+}
+
+#[test]
+fn falloff() {
+    let mut h = Harness::new(Job::PLD);
+
+    h.assert_falloff(TotalEclipse, 100);
+    h.assert_falloff(Expiacion, 40);
+    h.assert_falloff(Imperator, 50);
+    h.assert_falloff(Confiteor, 50);
+    h.assert_falloff(BladeOfFaith, 50);
+}
+
+#[test]
+fn errors() {
+    let mut h = Harness::new(Job::PLD);
+
+    assert!(matches!(
+        h.cast(Atonement),
+        Err(EventError::Job(CastError::Pld(PldError::Atonement))),
+    ));
+    h.ok(Requiescat);
+    assert!(matches!(
+        h.cast(BladeOfTruth),
+        Err(EventError::Job(CastError::Pld(PldError::ConfiteorCombo(BladeOfFaith)))),
+    ));
+    assert!(matches!(h.cast(Requiescat), Err(EventError::Cooldown(_))));
+}
@@ -0,0 +1,104 @@
+#[macro_use]
+mod common;
+
+use common::Harness;
+use xivc_core::{
+    enums::Job,
+    job::{
+        rdm::{
+            FinisherCombo, MeleeCombo, RdmAction::*, RdmError, DUALCAST, MAGICKED_SWORDPLAY,
+            VERFIRE_READY,
+        },
+        CastError,
+    },
+    world::EventError,
+};
+
+#[test]
+fn dualcast() {
+    let mut h = Harness::new(Job::RDM);
+
+    assert!(matches!(
+        h.cast(Verfire),
+        Err(EventError::Job(CastError::Rdm(RdmError::Verfire))),
+    ));
+    h.ok(JoltIII);
+    h.wait(100);
+    assert!(h.has(DUALCAST));
+    assert_eq!(state!(h, Rdm).black, 2);
+    assert_eq!(state!(h, Rdm).white, 2);
+    h.ok(VeraeroIII);
+    h.wait(100);
+    assert!(!h.has(DUALCAST));
+    assert_eq!(state!(h, Rdm).white, 8);
+
+    // acceleration guarantees the proc instead of granting dualcast
+    h.ok(Acceleration);
+    h.ok(VerthunderIII);
+    h.wait(1000);
+    assert!(!h.has(DUALCAST));
+    assert!(h.has(VERFIRE_READY));
+    h.ok(Verfire);
+    assert_eq!(state!(h, Rdm).black, 13);
+    h.assert_falloff(GrandImpact, 40);
+    assert!(matches!(
+        h.cast(GrandImpact),
+        Err(EventError::Job(CastError::Rdm(RdmError::GrandImpact))),
+    ));
+}
+
+#[test]
+fn melee_combo() {
+    let mut h = Harness::new(Job::RDM);
+
+    // without enough mana, the melee combo is not enchanted
+    h.ok(Riposte);
+    assert!(state!(h, Rdm).combos.melee.check(MeleeCombo::Riposte));
+    assert_eq!(state!(h, Rdm).mana_stacks, 0);
+    assert!(matches!(
+        h.cast(Verholy),
+        Err(EventError::Job(CastError::Rdm(RdmError::ManaStacks))),
+    ));
+
+    h.ok(Manafication);
+    h.wait(1000);
+    assert!(h.has(MAGICKED_SWORDPLAY));
+    h.ok(Riposte);
+    h.ok(Zwerchhau);
+    assert!(state!(h, Rdm).combos.melee.check(MeleeCombo::Zwerchhau));
+    h.ok(Redoublement);
+    assert_eq!(state!(h, Rdm).combos.melee.combo, None);
+    assert_eq!(state!(h, Rdm).mana_stacks, 3);
+    h.wait(100);
+    assert!(!h.has(MAGICKED_SWORDPLAY));
+
+    assert!(matches!(
+        h.cast(Scorch),
+        Err(EventError::Job(CastError::Rdm(RdmError::Finisher(Verflare)))),
+    ));
+    h.assert_falloff(Verflare, 40);
+    assert_eq!(state!(h, Rdm).mana_stacks, 0);
+    assert!(state!(h, Rdm).combos.finisher.check(FinisherCombo::Verflare));
+    h.assert_falloff(Scorch, 40);
+    h.assert_falloff(Resolution, 40);
+    assert_eq!(state!(h, Rdm).combos.finisher.combo, None);
+    h.assert_falloff(Prefulgence, 40);
+}
+
+#[test]
+fn falloff() {
+    let mut h = Harness::new(Job::RDM);
+
+    h.assert_falloff(VerthunderII, 100);
+    h.assert_falloff(VeraeroII, 100);
+    h.assert_falloff(Impact, 100);
+    assert_eq!(state!(h, Rdm).black, 10);
+    assert_eq!(state!(h, Rdm).white, 10);
+    h.assert_falloff(ContreSixte, 100);
+    assert!(matches!(
+        h.cast(ViceOfThorns),
+        Err(EventError::Job(CastError::Rdm(RdmError::ViceOfThorns))),
+    ));
+    h.ok(Embolden);
+    h.assert_falloff(ViceOfThorns, 40);
+}
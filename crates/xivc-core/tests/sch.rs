@@ -0,0 +1,74 @@
+#[macro_use]
+mod common;
+
+use common::Harness;
+use xivc_core::{
+    enums::Job,
+    job::{
+        sch::{SchAction::*, SchError, BANEFUL_IMPACTION, FEY_ILLUMINATION, IMPACT_IMMINENT},
+        CastError,
+    },
+    world::EventError,
+};
+
+#[test]
+fn aetherflow() {
+    let mut h = Harness::new(Job::SCH);
+
+    assert!(matches!(
+        h.cast(EnergyDrain),
+        Err(EventError::Job(CastError::Sch(SchError::Aetherflow))),
+    ));
+    h.ok(Aetherflow);
+    assert_eq!(state!(h, Sch).aetherflow, 3);
+    for action in [EnergyDrain, EnergyDrain, Lustrate] {
+        h.ok(action);
+        h.wait(1000);
+    }
+    assert_eq!(state!(h, Sch).aetherflow, 0);
+    assert!(matches!(
+        h.cast(Lustrate),
+        Err(EventError::Job(CastError::Sch(SchError::Aetherflow))),
+    ));
+}
+
+#[test]
+fn fairy() {
+    let mut h = Harness::new(Job::SCH);
+
+    assert!(matches!(
+        h.cast(FeyIllumination),
+        Err(EventError::Job(CastError::Sch(SchError::Fairy))),
+    ));
+    h.ok(SummonEos);
+    assert!(state!(h, Sch).eos);
+    assert!(matches!(
+        h.cast(SummonEos),
+        Err(EventError::Job(CastError::Sch(SchError::FairySummoned))),
+    ));
+    h.ok(FeyIllumination);
+    h.wait(2000);
+    assert!(h.has(FEY_ILLUMINATION));
+}
+
+#[test]
+fn falloff() {
+    let mut h = Harness::new(Job::SCH);
+
+    h.assert_falloff(ArtOfWarII, 100);
+
+    assert!(matches!(
+        h.cast(BanefulImpaction),
+        Err(EventError::Job(CastError::Sch(SchError::ImpactImminent))),
+    ));
+    h.ok(ChainStratagem);
+    h.wait(1000);
+    assert!(h.has(IMPACT_IMMINENT));
+    h.clear();
+    h.ok(BanefulImpaction);
+    h.wait(6000);
+    for actor in [h.target, h.other] {
+        assert!(h.status(actor, BANEFUL_IMPACTION).is_some());
+        assert!(h.damage(actor) > 0);
+    }
+}
@@ -0,0 +1,70 @@
+#[macro_use]
+mod common;
+
+use common::Harness;
+use xivc_core::{
+    enums::Job,
+    job::{
+        sge::{SgeAction::*, SgeError, EUKRASIA, EUKRASIAN_DOSIS_III, EUKRASIAN_DYSKRASIA},
+        CastError,
+    },
+    world::EventError,
+};
+
+#[test]
+fn addersgall() {
+    let mut h = Harness::new(Job::SGE);
+
+    assert!(matches!(
+        h.cast(Druochole),
+        Err(EventError::Job(CastError::Sge(SgeError::Addersgall))),
+    ));
+    h.ok(Rhizomata);
+    assert_eq!(state!(h, Sge).addersgall, 1);
+    h.ok(Druochole);
+    assert_eq!(state!(h, Sge).addersgall, 0);
+
+    // an addersgall is gained every 20 seconds
+    h.wait(20000);
+    assert_eq!(state!(h, Sge).addersgall, 1);
+}
+
+#[test]
+fn eukrasia() {
+    let mut h = Harness::new(Job::SGE);
+
+    assert!(matches!(
+        h.cast(ToxikonII),
+        Err(EventError::Job(CastError::Sge(SgeError::Addersting))),
+    ));
+    h.ok(Eukrasia);
+    h.wait(1000);
+    assert!(h.has(EUKRASIA));
+    h.ok(Diagnosis);
+    h.wait(1000);
+    assert!(!h.has(EUKRASIA));
+    assert_eq!(state!(h, Sge).addersting, 1);
+    h.assert_falloff(ToxikonII, 100);
+    assert_eq!(state!(h, Sge).addersting, 0);
+
+    h.ok(Eukrasia);
+    h.ok(DosisIII);
+    h.wait(1000);
+    assert!(h.status(h.target, EUKRASIAN_DOSIS_III).is_some());
+    assert!(h.status(h.other, EUKRASIAN_DOSIS_III).is_none());
+    h.ok(Eukrasia);
+    h.ok(DyskrasiaII);
+    h.wait(1000);
+    assert!(h.status(h.target, EUKRASIAN_DYSKRASIA).is_some());
+    assert!(h.status(h.other, EUKRASIAN_DYSKRASIA).is_some());
+}
+
+#[test]
+fn falloff() {
+    let mut h = Harness::new(Job::SGE);
+
+    h.assert_falloff(DyskrasiaII, 100);
+    h.assert_falloff(PhlegmaIII, 50);
+    h.assert_falloff(Pneuma, 60);
+    h.assert_falloff(Psyche, 50);
+}
@@ -0,0 +1,116 @@
+#[macro_use]
+mod common;
+
+use common::Harness;
+use xivc_core::{
+    enums::Job,
+    job::{
+        smn::{Demi, Primal, SmnAction::*, SmnError, FURTHER_RUIN, RUBYS_GLIMMER, TITANS_FAVOR},
+        CastError,
+    },
+    world::EventError,
+};
+
+#[test]
+fn demi() {
+    let mut h = Harness::new(Job::SMN);
+
+    assert!(matches!(
+        h.cast(SummonBahamut),
+        Err(EventError::Job(CastError::Smn(SmnError::Demi(Demi::SolarBahamut)))),
+    ));
+    assert!(matches!(
+        h.cast(Enkindle),
+        Err(EventError::Job(CastError::Smn(SmnError::DemiAction))),
+    ));
+    h.ok(SummonSolarBahamut);
+    assert!(matches!(state!(h, Smn).demi, Some((Demi::SolarBahamut, _))));
+    assert!(matches!(
+        h.cast(SummonIfrit),
+        Err(EventError::Job(CastError::Smn(SmnError::DemiActive))),
+    ));
+
+    // the demi-summon attacks alongside every gcd
+    h.clear();
+    h.ok(RuinIII);
+    h.wait(1500);
+    assert!(h.damage(h.target) > 0);
+    assert_eq!(h.damage(h.other), 0);
+
+    h.assert_falloff(Enkindle, 40);
+    h.assert_falloff(AstralFlow, 40);
+    assert!(matches!(
+        h.cast(AstralFlow),
+        Err(EventError::Job(CastError::Smn(SmnError::DemiAction))),
+    ));
+
+    h.wait(15000);
+    assert!(state!(h, Smn).demi.is_none());
+    assert!(matches!(
+        h.cast(SummonSolarBahamut),
+        Err(EventError::Job(CastError::Smn(SmnError::Demi(Demi::Bahamut)))),
+    ));
+}
+
+#[test]
+fn primals() {
+    let mut h = Harness::new(Job::SMN);
+
+    assert!(matches!(
+        h.cast(Gemshine),
+        Err(EventError::Job(CastError::Smn(SmnError::Attunement))),
+    ));
+    h.ok(SummonSolarBahamut);
+    h.wait(15000);
+
+    h.assert_falloff(SummonIfrit, 40);
+    assert!(matches!(state!(h, Smn).attunement, Some((Primal::Ifrit, 2))));
+    assert!(matches!(
+        h.cast(SummonIfrit),
+        Err(EventError::Job(CastError::Smn(SmnError::Primal(Primal::Ifrit)))),
+    ));
+    h.ok(Gemshine);
+    h.ok(Gemshine);
+    assert!(state!(h, Smn).attunement.is_none());
+    assert!(matches!(
+        h.cast(CrimsonStrike),
+        Err(EventError::Job(CastError::Smn(SmnError::CrimsonStrike))),
+    ));
+    h.ok(CrimsonCyclone);
+    h.ok(CrimsonStrike);
+
+    h.ok(SummonTitan);
+    h.assert_falloff(PreciousBrilliance, 100);
+    h.wait(1000);
+    assert!(h.has(TITANS_FAVOR));
+    h.assert_falloff(MountainBuster, 30);
+    for _ in 0..3 {
+        h.ok(Gemshine);
+    }
+    assert!(state!(h, Smn).attunement.is_none());
+}
+
+#[test]
+fn aetherflow() {
+    let mut h = Harness::new(Job::SMN);
+
+    assert!(matches!(
+        h.cast(Painflare),
+        Err(EventError::Job(CastError::Smn(SmnError::Aetherflow))),
+    ));
+    h.assert_falloff(EnergySiphon, 100);
+    assert_eq!(state!(h, Smn).aetherflow, 2);
+    h.assert_falloff(Painflare, 100);
+    h.ok(Necrotize);
+    assert_eq!(state!(h, Smn).aetherflow, 0);
+    h.wait(1000);
+    assert!(h.has(FURTHER_RUIN));
+    h.ok(RuinIV);
+    h.wait(1000);
+    assert!(!h.has(FURTHER_RUIN));
+
+    h.ok(SearingLight);
+    h.wait(1000);
+    assert!(h.has(RUBYS_GLIMMER));
+    h.assert_falloff(SearingFlash, 40);
+}
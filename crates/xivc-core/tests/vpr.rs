@@ -0,0 +1,94 @@
+#[macro_use]
+mod common;
+
+use common::Harness;
+use xivc_core::{
+    enums::Job,
+    job::{
+        vpr::{
+            AoeCombo, MainCombo, VprAction::*, VprError, HONED_REAVERS, HUNTERS_INSTINCT,
+            REAWAKENED,
+        },
+        CastError,
+    },
+    world::EventError,
+};
+
+#[test]
+fn main_combo() {
+    let mut h = Harness::new(Job::VPR);
+
+    assert!(matches!(
+        h.cast(HuntersSting),
+        Err(EventError::Job(CastError::Vpr(VprError::Combo(_)))),
+    ));
+    h.ok(SteelFangs);
+    assert!(state!(h, Vpr).combos.main.check(MainCombo::Fangs));
+    h.ok(HuntersSting);
+    assert!(state!(h, Vpr).combos.main.check(MainCombo::HuntersSting));
+    h.ok(FlankstingStrike);
+    assert_eq!(state!(h, Vpr).combos.main.combo, None);
+    assert_eq!(state!(h, Vpr).offering, 10);
+    assert_eq!(state!(h, Vpr).serpents_tail, Some(DeathRattle));
+    h.wait(1000);
+    assert!(h.has(HONED_REAVERS));
+    assert!(h.has(HUNTERS_INSTINCT));
+
+    h.ok(DeathRattle);
+    assert_eq!(state!(h, Vpr).serpents_tail, None);
+    assert!(matches!(
+        h.cast(DeathRattle),
+        Err(EventError::Job(CastError::Vpr(VprError::Unavailable(DeathRattle)))),
+    ));
+}
+
+#[test]
+fn aoe_combo() {
+    let mut h = Harness::new(Job::VPR);
+
+    h.assert_falloff(SteelMaw, 100);
+    assert!(state!(h, Vpr).combos.aoe.check(AoeCombo::Maw));
+    h.assert_falloff(HuntersBite, 100);
+    h.assert_falloff(JaggedMaw, 100);
+    assert_eq!(state!(h, Vpr).offering, 10);
+    assert_eq!(state!(h, Vpr).serpents_tail, Some(LastLash));
+    h.assert_falloff(LastLash, 100);
+}
+
+#[test]
+fn reawaken() {
+    let mut h = Harness::new(Job::VPR);
+
+    assert!(matches!(
+        h.cast(Reawaken),
+        Err(EventError::Job(CastError::Vpr(VprError::Offering(50)))),
+    ));
+    assert!(matches!(
+        h.cast(UncoiledFury),
+        Err(EventError::Job(CastError::Vpr(VprError::Coil(1)))),
+    ));
+
+    h.ok(SerpentsIre);
+    assert_eq!(state!(h, Vpr).coil, 1);
+    h.assert_falloff(Reawaken, 40);
+    assert_eq!(state!(h, Vpr).anguine, 5);
+    assert!(h.has(REAWAKENED));
+    assert!(matches!(
+        h.cast(SecondGeneration),
+        Err(EventError::Job(CastError::Vpr(VprError::Combo(FirstGeneration)))),
+    ));
+
+    h.assert_falloff(FirstGeneration, 40);
+    h.assert_falloff(FirstLegacy, 40);
+    h.assert_falloff(SecondGeneration, 40);
+    h.ok(ThirdGeneration);
+    h.ok(FourthGeneration);
+    h.assert_falloff(Ouroboros, 40);
+    assert_eq!(state!(h, Vpr).anguine, 0);
+    h.wait(1000);
+    assert!(!h.has(REAWAKENED));
+
+    h.assert_falloff(UncoiledFury, 50);
+    assert_eq!(state!(h, Vpr).coil, 0);
+    h.assert_falloff(UncoiledTwinfang, 50);
+}
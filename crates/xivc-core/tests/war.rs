@@ -0,0 +1,92 @@
+#[macro_use]
+mod common;
+
+use common::Harness;
+use xivc_core::{
+    enums::Job,
+    job::{
+        war::{MainCombo, WarAction::*, WarError, NASCENT_CHAOS, SURGING_TEMPEST, WRATHFUL},
+        CastError,
+    },
+    world::EventError,
+};
+
+#[test]
+fn main_combo() {
+    let mut h = Harness::new(Job::WAR);
+
+    h.ok(HeavySwing);
+    assert!(state!(h, War).combos.main.check(MainCombo::HeavySwing));
+    h.ok(Maim);
+    assert!(state!(h, War).combos.main.check(MainCombo::Maim));
+    assert_eq!(state!(h, War).beast, 10);
+    h.ok(StormsEye);
+    assert_eq!(state!(h, War).beast, 20);
+    h.wait(1000);
+    assert!(h.has(SURGING_TEMPEST));
+
+    h.ok(HeavySwing);
+    h.ok(Maim);
+    h.ok(StormsPath);
+    assert_eq!(state!(h, War).beast, 50);
+    assert_eq!(state!(h, War).combos.main.combo, None);
+
+    // Maim without Heavy Swing does not build gauge
+    h.ok(Maim);
+    assert_eq!(state!(h, War).beast, 50);
+}
+
+#[test]
+fn beast_gauge() {
+    let mut h = Harness::new(Job::WAR);
+
+    assert!(matches!(
+        h.cast(FellCleave),
+        Err(EventError::Job(CastError::War(WarError::Beast(50)))),
+    ));
+    // infuriate can only be used in combat
+    assert!(matches!(h.cast(Infuriate), Err(EventError::InCombat)));
+
+    h.ok(HeavySwing);
+    h.wait(1000);
+    h.ok(Infuriate);
+    assert_eq!(state!(h, War).beast, 50);
+    h.wait(1000);
+    assert!(h.has(NASCENT_CHAOS));
+    h.ok(InnerChaos);
+    assert_eq!(state!(h, War).beast, 0);
+    h.wait(1000);
+    assert!(!h.has(NASCENT_CHAOS));
+}
+
+#[test]
+fn inner_release() {
+    let mut h = Harness::new(Job::WAR);
+
+    h.ok(InnerRelease);
+    // inner release stacks are spent before the gauge
+    for _ in 0..3 {
+        h.ok(FellCleave);
+    }
+    h.wait(1000);
+    assert!(h.has(WRATHFUL));
+    assert!(matches!(
+        h.cast(FellCleave),
+        Err(EventError::Job(CastError::War(WarError::Beast(50)))),
+    ));
+}
+
+#[test]
+fn falloff() {
+    let mut h = Harness::new(Job::WAR);
+
+    h.assert_falloff(Overpower, 100);
+    h.assert_falloff(MythrilTempest, 100);
+    h.ok(InnerRelease);
+    h.assert_falloff(PrimalRend, 30);
+    h.assert_falloff(PrimalRuination, 40);
+    for _ in 0..3 {
+        h.ok(Decimate);
+    }
+    h.assert_falloff(PrimalWrath, 30);
+}
@@ -0,0 +1,62 @@
+#[macro_use]
+mod common;
+
+use common::Harness;
+use xivc_core::{
+    enums::Job,
+    job::{
+        whm::{WhmAction::*, WhmError, DIA, SACRED_SIGHT},
+        CastError,
+    },
+    world::EventError,
+};
+
+#[test]
+fn lilies() {
+    let mut h = Harness::new(Job::WHM);
+
+    assert!(matches!(
+        h.cast(AfflatusSolace),
+        Err(EventError::Job(CastError::Whm(WhmError::Lily))),
+    ));
+    assert!(matches!(
+        h.cast(AfflatusMisery),
+        Err(EventError::Job(CastError::Whm(WhmError::BloodLily))),
+    ));
+
+    // a lily blooms every 20 seconds
+    h.wait(60000);
+    assert_eq!(state!(h, Whm).lily, 3);
+    h.ok(AfflatusSolace);
+    h.ok(AfflatusRapture);
+    h.ok(AfflatusSolace);
+    assert_eq!(state!(h, Whm).lily, 0);
+    assert_eq!(state!(h, Whm).blood_lily, 3);
+    h.assert_falloff(AfflatusMisery, 50);
+    assert_eq!(state!(h, Whm).blood_lily, 0);
+}
+
+#[test]
+fn falloff() {
+    let mut h = Harness::new(Job::WHM);
+
+    assert!(matches!(
+        h.cast(GlareIV),
+        Err(EventError::Job(CastError::Whm(WhmError::SacredSight))),
+    ));
+    h.ok(PresenceOfMind);
+    for _ in 0..3 {
+        h.assert_falloff(GlareIV, 60);
+    }
+    assert!(!h.has(SACRED_SIGHT));
+    h.assert_falloff(HolyIII, 100);
+    h.assert_falloff(Assize, 100);
+
+    // dia only hits the main target
+    h.clear();
+    h.ok(Dia);
+    h.wait(6000);
+    assert!(h.status(h.target, DIA).is_some());
+    assert!(h.damage(h.target) > 0);
+    assert_eq!(h.damage(h.other), 0);
+}